@@ -1,10 +1,19 @@
 import {PublicKey} from "@solana/web3.js";
 import {BN} from "@coral-xyz/anchor";
 
-export const deriveMultisigPDA = (programId: PublicKey): [PublicKey, number] => {
+export type MultisigSeeds = {
+    /** The default pubkey addresses a multisig migrated from the legacy single-multisig layout */
+    createKey: PublicKey, 
+};
+
+export const deriveMultisigPDA = (
+    seeds: MultisigSeeds,
+    programId: PublicKey
+): [PublicKey, number] => {
     return PublicKey.findProgramAddressSync(
         [
             Buffer.from("multisig"),
+            seeds.createKey.equals(PublicKey.default) ? Buffer.alloc(0) : seeds.createKey.toBuffer(),
         ],
         programId,
     )
@@ -29,6 +38,135 @@ export const deriveProposalPDA = (
     )
 };
 
+export type AddressBookSeeds = {
+    multisig: PublicKey, 
+};
+
+export const deriveAddressBookPDA = (
+    seeds: AddressBookSeeds,
+    programId: PublicKey
+): [PublicKey, number] => {
+    return PublicKey.findProgramAddressSync(
+        [
+            Buffer.from("address_book"),
+            seeds.multisig.toBuffer(),
+        ],
+        programId,
+    )
+};
+
+export type TransferPolicySeeds = {
+    multisig: PublicKey, 
+};
+
+export const deriveTransferPolicyPDA = (
+    seeds: TransferPolicySeeds,
+    programId: PublicKey
+): [PublicKey, number] => {
+    return PublicKey.findProgramAddressSync(
+        [
+            Buffer.from("transfer_policy"),
+            seeds.multisig.toBuffer(),
+        ],
+        programId,
+    )
+};
+
+export type RecoveryRequestSeeds = {
+    multisig: PublicKey, 
+    recoveryId: bigint, 
+};
+
+export const deriveRecoveryRequestPDA = (
+    seeds: RecoveryRequestSeeds,
+    programId: PublicKey
+): [PublicKey, number] => {
+    return PublicKey.findProgramAddressSync(
+        [
+            Buffer.from("recovery_request"),
+            seeds.multisig.toBuffer(),
+            Buffer.from(BigUint64Array.from([seeds.recoveryId]).buffer),
+        ],
+        programId,
+    )
+};
+
+export type VaultSeeds = {
+    multisig: PublicKey, 
+    vaultIndex: number, 
+};
+
+export const deriveVaultPDA = (
+    seeds: VaultSeeds,
+    programId: PublicKey
+): [PublicKey, number] => {
+    return PublicKey.findProgramAddressSync(
+        [
+            Buffer.from("vault"),
+            seeds.multisig.toBuffer(),
+            Buffer.from([seeds.vaultIndex]),
+        ],
+        programId,
+    )
+};
+
+export type StakeAccountSeeds = {
+    multisig: PublicKey, 
+    proposalId: bigint, 
+};
+
+export const deriveStakeAccountPDA = (
+    seeds: StakeAccountSeeds,
+    programId: PublicKey
+): [PublicKey, number] => {
+    return PublicKey.findProgramAddressSync(
+        [
+            Buffer.from("stake_account"),
+            seeds.multisig.toBuffer(),
+            Buffer.from(BigUint64Array.from([seeds.proposalId]).buffer),
+        ],
+        programId,
+    )
+};
+
+export type PaymentScheduleSeeds = {
+    multisig: PublicKey, 
+    scheduleId: bigint, 
+};
+
+export const derivePaymentSchedulePDA = (
+    seeds: PaymentScheduleSeeds,
+    programId: PublicKey
+): [PublicKey, number] => {
+    return PublicKey.findProgramAddressSync(
+        [
+            Buffer.from("payment_schedule"),
+            seeds.multisig.toBuffer(),
+            Buffer.from(BigUint64Array.from([seeds.scheduleId]).buffer),
+        ],
+        programId,
+    )
+};
+
+export type VestingStreamSeeds = {
+    multisig: PublicKey, 
+    streamId: bigint, 
+};
+
+export const deriveVestingStreamPDA = (
+    seeds: VestingStreamSeeds,
+    programId: PublicKey
+): [PublicKey, number] => {
+    return PublicKey.findProgramAddressSync(
+        [
+            Buffer.from("vesting_stream"),
+            seeds.multisig.toBuffer(),
+            Buffer.from(BigUint64Array.from([seeds.streamId]).buffer),
+        ],
+        programId,
+    )
+};
+
 export module CslSplTokenPDAs {
     export type AccountSeeds = {
         wallet: PublicKey, 
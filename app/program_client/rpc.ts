@@ -2,6 +2,7 @@ import {
   AnchorProvider,
   BN,
   IdlAccounts,
+  IdlTypes,
   Program,
   web3,
 } from "@coral-xyz/anchor";
@@ -48,8 +49,12 @@ export const initializeClient = (
 
 export type CreateMultisigArgs = {
   feePayer: web3.PublicKey;
+  createKey: web3.PublicKey;
   signers: web3.PublicKey[];
   threshold: number;
+  maxProposalLifetime: bigint;
+  guardian: web3.PublicKey | null;
+  cancelThreshold: number;
 };
 
 /**
@@ -58,27 +63,37 @@ export type CreateMultisigArgs = {
  *
  * Accounts:
  * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
- * 1. `[writable]` multisig: {@link MultisigConfig} The multisig account to initialize
- * 2. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
+ * 1. `[signer]` create_key: {@link PublicKey} One-time key whose address seeds the multisig PDA, so each multisig gets its own address
+ * 2. `[writable]` multisig: {@link MultisigConfig} The multisig account to initialize
+ * 3. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
  *
  * Data:
  * - signers: {@link PublicKey[]} The initial set of authorized signers
  * - threshold: {@link number} The minimum number of approvals needed to execute a transaction
+ * - max_proposal_lifetime: {@link BigInt} Maximum number of seconds a proposal may stay open (0 means unlimited)
+ * - guardian: {@link PublicKey | null} Optional guardian allowed to freeze the multisig in an emergency
+ * - cancel_threshold: {@link number} The number of cancel votes needed to cancel a proposal (0 means the approval threshold)
  */
 export const createMultisigBuilder = (
 	args: CreateMultisigArgs,
 	remainingAccounts: Array<web3.AccountMeta> = [],
 ): MethodsBuilder<MultisigWallet, never> => {
-  const [multisigPubkey] = pda.deriveMultisigPDA(_program.programId);
+  const [multisigPubkey] = pda.deriveMultisigPDA({
+        createKey: args.createKey,
+    }, _program.programId);
 
   return _program
     .methods
     .createMultisig(
       args.signers,
       args.threshold,
+      new BN(args.maxProposalLifetime.toString()),
+      args.guardian,
+      args.cancelThreshold,
     )
     .accountsStrict({
       feePayer: args.feePayer,
+      createKey: args.createKey,
       multisig: multisigPubkey,
       systemProgram: new web3.PublicKey("11111111111111111111111111111111"),
     })
@@ -91,12 +106,16 @@ export const createMultisigBuilder = (
  *
  * Accounts:
  * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
- * 1. `[writable]` multisig: {@link MultisigConfig} The multisig account to initialize
- * 2. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
+ * 1. `[signer]` create_key: {@link PublicKey} One-time key whose address seeds the multisig PDA, so each multisig gets its own address
+ * 2. `[writable]` multisig: {@link MultisigConfig} The multisig account to initialize
+ * 3. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
  *
  * Data:
  * - signers: {@link PublicKey[]} The initial set of authorized signers
  * - threshold: {@link number} The minimum number of approvals needed to execute a transaction
+ * - max_proposal_lifetime: {@link BigInt} Maximum number of seconds a proposal may stay open (0 means unlimited)
+ * - guardian: {@link PublicKey | null} Optional guardian allowed to freeze the multisig in an emergency
+ * - cancel_threshold: {@link number} The number of cancel votes needed to cancel a proposal (0 means the approval threshold)
  */
 export const createMultisig = (
 	args: CreateMultisigArgs,
@@ -110,17 +129,22 @@ export const createMultisig = (
  *
  * Accounts:
  * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
- * 1. `[writable]` multisig: {@link MultisigConfig} The multisig account to initialize
- * 2. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
+ * 1. `[signer]` create_key: {@link PublicKey} One-time key whose address seeds the multisig PDA, so each multisig gets its own address
+ * 2. `[writable]` multisig: {@link MultisigConfig} The multisig account to initialize
+ * 3. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
  *
  * Data:
  * - signers: {@link PublicKey[]} The initial set of authorized signers
  * - threshold: {@link number} The minimum number of approvals needed to execute a transaction
+ * - max_proposal_lifetime: {@link BigInt} Maximum number of seconds a proposal may stay open (0 means unlimited)
+ * - guardian: {@link PublicKey | null} Optional guardian allowed to freeze the multisig in an emergency
+ * - cancel_threshold: {@link number} The number of cancel votes needed to cancel a proposal (0 means the approval threshold)
  */
 export const createMultisigSendAndConfirm = async (
-  args: Omit<CreateMultisigArgs, "feePayer"> & {
+  args: Omit<CreateMultisigArgs, "feePayer" | "createKey"> & {
     signers: {
       feePayer: web3.Signer,
+      createKey: web3.Signer,
     },
   },
   remainingAccounts: Array<web3.AccountMeta> = [],
@@ -131,45 +155,52 @@ export const createMultisigSendAndConfirm = async (
   return createMultisigBuilder({
       ...args,
       feePayer: args.signers.feePayer.publicKey,
+      createKey: args.signers.createKey.publicKey,
     }, remainingAccounts)
     .preInstructions(preInstructions)
-    .signers([args.signers.feePayer])
+    .signers([args.signers.feePayer, args.signers.createKey])
     .rpc();
 }
 
 export type CreateProposalArgs = {
   feePayer: web3.PublicKey;
+  createKey: web3.PublicKey;
   creator: web3.PublicKey;
-  destination: web3.PublicKey;
-  amount: bigint;
-  tokenMint: web3.PublicKey;
+  addressBook: web3.PublicKey | null;
+  terms: IdlTypes<MultisigWallet>["transferTerms"];
+  vaultIndex: number;
   expirationTime: bigint;
+  metadata: IdlTypes<MultisigWallet>["proposalMetadata"];
   proposalSeedProposalId: bigint;
 };
 
 /**
  * ### Returns a {@link MethodsBuilder}
- * Create a new transaction proposal
+ * Create a new transaction proposal (in optimistic mode it becomes executable once the veto window
+ * elapses without enough vetoes)
  *
  * Accounts:
  * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
  * 1. `[writable]` multisig: {@link MultisigConfig} The multisig account
  * 2. `[writable]` proposal: {@link Proposal} The proposal account to initialize
  * 3. `[signer]` creator: {@link PublicKey} The account creating the proposal (must be a signer of the multisig)
- * 4. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
+ * 4. `[optional]` address_book: {@link AddressBook} The multisig's address book, required when the multisig enforces it
+ * 5. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
  *
  * Data:
- * - destination: {@link PublicKey} The destination account for the transfer
- * - amount: {@link BigInt} The amount of tokens to transfer
- * - token_mint: {@link PublicKey} The mint of the token to transfer (can be null for SOL)
- * - expiration_time: {@link BigInt} Optional timestamp when the proposal expires (0 means no expiration)
+ * - terms: {@link TransferTerms} The destination wallet, amount and mint of the transfer
+ * - vault_index: {@link number} The vault the transfer is paid from
+ * - expiration_time: {@link BigInt} Optional timestamp when the proposal expires (0 means no expiration, or the maximum lifetime when the multisig sets one)
+ * - metadata: {@link ProposalMetadata} Optional title, description URI and memo (the memo is attached to the transfer through the SPL Memo program on execution)
  * - proposal_seed_proposal_id: {@link BigInt} Auto-generated, from the input "proposal" for the its seed definition "Proposal", sets the seed named "proposal_id"
  */
 export const createProposalBuilder = (
 	args: CreateProposalArgs,
 	remainingAccounts: Array<web3.AccountMeta> = [],
 ): MethodsBuilder<MultisigWallet, never> => {
-  const [multisigPubkey] = pda.deriveMultisigPDA(_program.programId);
+  const [multisigPubkey] = pda.deriveMultisigPDA({
+        createKey: args.createKey,
+    }, _program.programId);
     const [proposalPubkey] = pda.deriveProposalPDA({
         multisig: args.multisig,
         proposalId: args.proposalSeedProposalId,
@@ -178,10 +209,10 @@ export const createProposalBuilder = (
   return _program
     .methods
     .createProposal(
-      args.destination,
-      new BN(args.amount.toString()),
-      args.tokenMint,
+      args.terms,
+      args.vaultIndex,
       new BN(args.expirationTime.toString()),
+      args.metadata,
       new BN(args.proposalSeedProposalId.toString()),
     )
     .accountsStrict({
@@ -189,6 +220,7 @@ export const createProposalBuilder = (
       multisig: multisigPubkey,
       proposal: proposalPubkey,
       creator: args.creator,
+      addressBook: args.addressBook,
       systemProgram: new web3.PublicKey("11111111111111111111111111111111"),
     })
     .remainingAccounts(remainingAccounts);
@@ -196,20 +228,22 @@ export const createProposalBuilder = (
 
 /**
  * ### Returns a {@link web3.TransactionInstruction}
- * Create a new transaction proposal
+ * Create a new transaction proposal (in optimistic mode it becomes executable once the veto window
+ * elapses without enough vetoes)
  *
  * Accounts:
  * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
  * 1. `[writable]` multisig: {@link MultisigConfig} The multisig account
  * 2. `[writable]` proposal: {@link Proposal} The proposal account to initialize
  * 3. `[signer]` creator: {@link PublicKey} The account creating the proposal (must be a signer of the multisig)
- * 4. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
+ * 4. `[optional]` address_book: {@link AddressBook} The multisig's address book, required when the multisig enforces it
+ * 5. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
  *
  * Data:
- * - destination: {@link PublicKey} The destination account for the transfer
- * - amount: {@link BigInt} The amount of tokens to transfer
- * - token_mint: {@link PublicKey} The mint of the token to transfer (can be null for SOL)
- * - expiration_time: {@link BigInt} Optional timestamp when the proposal expires (0 means no expiration)
+ * - terms: {@link TransferTerms} The destination wallet, amount and mint of the transfer
+ * - vault_index: {@link number} The vault the transfer is paid from
+ * - expiration_time: {@link BigInt} Optional timestamp when the proposal expires (0 means no expiration, or the maximum lifetime when the multisig sets one)
+ * - metadata: {@link ProposalMetadata} Optional title, description URI and memo (the memo is attached to the transfer through the SPL Memo program on execution)
  * - proposal_seed_proposal_id: {@link BigInt} Auto-generated, from the input "proposal" for the its seed definition "Proposal", sets the seed named "proposal_id"
  */
 export const createProposal = (
@@ -220,20 +254,22 @@ export const createProposal = (
 
 /**
  * ### Returns a {@link web3.TransactionSignature}
- * Create a new transaction proposal
+ * Create a new transaction proposal (in optimistic mode it becomes executable once the veto window
+ * elapses without enough vetoes)
  *
  * Accounts:
  * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
  * 1. `[writable]` multisig: {@link MultisigConfig} The multisig account
  * 2. `[writable]` proposal: {@link Proposal} The proposal account to initialize
  * 3. `[signer]` creator: {@link PublicKey} The account creating the proposal (must be a signer of the multisig)
- * 4. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
+ * 4. `[optional]` address_book: {@link AddressBook} The multisig's address book, required when the multisig enforces it
+ * 5. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
  *
  * Data:
- * - destination: {@link PublicKey} The destination account for the transfer
- * - amount: {@link BigInt} The amount of tokens to transfer
- * - token_mint: {@link PublicKey} The mint of the token to transfer (can be null for SOL)
- * - expiration_time: {@link BigInt} Optional timestamp when the proposal expires (0 means no expiration)
+ * - terms: {@link TransferTerms} The destination wallet, amount and mint of the transfer
+ * - vault_index: {@link number} The vault the transfer is paid from
+ * - expiration_time: {@link BigInt} Optional timestamp when the proposal expires (0 means no expiration, or the maximum lifetime when the multisig sets one)
+ * - metadata: {@link ProposalMetadata} Optional title, description URI and memo (the memo is attached to the transfer through the SPL Memo program on execution)
  * - proposal_seed_proposal_id: {@link BigInt} Auto-generated, from the input "proposal" for the its seed definition "Proposal", sets the seed named "proposal_id"
  */
 export const createProposalSendAndConfirm = async (
@@ -260,6 +296,7 @@ export const createProposalSendAndConfirm = async (
 
 export type ApproveProposalArgs = {
   feePayer: web3.PublicKey;
+  createKey: web3.PublicKey;
   signer: web3.PublicKey;
   proposalSeedProposalId: bigint;
 };
@@ -281,7 +318,9 @@ export const approveProposalBuilder = (
 	args: ApproveProposalArgs,
 	remainingAccounts: Array<web3.AccountMeta> = [],
 ): MethodsBuilder<MultisigWallet, never> => {
-  const [multisigPubkey] = pda.deriveMultisigPDA(_program.programId);
+  const [multisigPubkey] = pda.deriveMultisigPDA({
+        createKey: args.createKey,
+    }, _program.programId);
     const [proposalPubkey] = pda.deriveProposalPDA({
         multisig: args.multisig,
         proposalId: args.proposalSeedProposalId,
@@ -355,36 +394,139 @@ export const approveProposalSendAndConfirm = async (
     .rpc();
 }
 
+export type UnapproveProposalArgs = {
+  feePayer: web3.PublicKey;
+  createKey: web3.PublicKey;
+  signer: web3.PublicKey;
+  proposalSeedProposalId: bigint;
+};
+
+/**
+ * ### Returns a {@link MethodsBuilder}
+ * Withdraw an approval from a proposal that has not been executed yet
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[]` multisig: {@link MultisigConfig} The multisig account
+ * 2. `[writable]` proposal: {@link Proposal} The proposal to withdraw the approval from
+ * 3. `[signer]` signer: {@link PublicKey} The signer withdrawing their approval
+ *
+ * Data:
+ * - proposal_seed_proposal_id: {@link BigInt} Auto-generated, from the input "proposal" for the its seed definition "Proposal", sets the seed named "proposal_id"
+ */
+export const unapproveProposalBuilder = (
+	args: UnapproveProposalArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): MethodsBuilder<MultisigWallet, never> => {
+  const [multisigPubkey] = pda.deriveMultisigPDA({
+        createKey: args.createKey,
+    }, _program.programId);
+    const [proposalPubkey] = pda.deriveProposalPDA({
+        multisig: multisigPubkey,
+        proposalId: args.proposalSeedProposalId,
+    }, _program.programId);
+
+  return _program
+    .methods
+    .unapproveProposal(
+      new BN(args.proposalSeedProposalId.toString()),
+    )
+    .accountsStrict({
+      feePayer: args.feePayer,
+      multisig: multisigPubkey,
+      proposal: proposalPubkey,
+      signer: args.signer,
+    })
+    .remainingAccounts(remainingAccounts);
+};
+
+/**
+ * ### Returns a {@link web3.TransactionInstruction}
+ * Withdraw an approval from a proposal that has not been executed yet
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[]` multisig: {@link MultisigConfig} The multisig account
+ * 2. `[writable]` proposal: {@link Proposal} The proposal to withdraw the approval from
+ * 3. `[signer]` signer: {@link PublicKey} The signer withdrawing their approval
+ *
+ * Data:
+ * - proposal_seed_proposal_id: {@link BigInt} Auto-generated, from the input "proposal" for the its seed definition "Proposal", sets the seed named "proposal_id"
+ */
+export const unapproveProposal = (
+	args: UnapproveProposalArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionInstruction> =>
+    unapproveProposalBuilder(args, remainingAccounts).instruction();
+
+/**
+ * ### Returns a {@link web3.TransactionSignature}
+ * Withdraw an approval from a proposal that has not been executed yet
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[]` multisig: {@link MultisigConfig} The multisig account
+ * 2. `[writable]` proposal: {@link Proposal} The proposal to withdraw the approval from
+ * 3. `[signer]` signer: {@link PublicKey} The signer withdrawing their approval
+ *
+ * Data:
+ * - proposal_seed_proposal_id: {@link BigInt} Auto-generated, from the input "proposal" for the its seed definition "Proposal", sets the seed named "proposal_id"
+ */
+export const unapproveProposalSendAndConfirm = async (
+  args: Omit<UnapproveProposalArgs, "feePayer" | "signer"> & {
+    signers: {
+      feePayer: web3.Signer,
+      signer: web3.Signer,
+    },
+  },
+  remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionSignature> => {
+  const preInstructions: Array<web3.TransactionInstruction> = [];
+
+
+  return unapproveProposalBuilder({
+      ...args,
+      feePayer: args.signers.feePayer.publicKey,
+      signer: args.signers.signer.publicKey,
+    }, remainingAccounts)
+    .preInstructions(preInstructions)
+    .signers([args.signers.feePayer, args.signers.signer])
+    .rpc();
+}
+
 export type ExecuteProposalArgs = {
   feePayer: web3.PublicKey;
+  createKey: web3.PublicKey;
+  vaultIndex: number;
   source: web3.PublicKey;
-  destination: web3.PublicKey;
-  authority: web3.PublicKey;
   funding: web3.PublicKey;
   wallet: web3.PublicKey;
   mint: web3.PublicKey;
+  transferPolicy: web3.PublicKey | null;
   proposalSeedProposalId: bigint;
 };
 
 /**
  * ### Returns a {@link MethodsBuilder}
- * Execute a proposal if the threshold is met
+ * Execute a proposal if the threshold is met (optimistic proposals, while the multisig stays in
+ * optimistic mode, once their veto window has elapsed without enough vetoes and with the approvals
+ * of their vault's threshold override, if any)
  *
  * Accounts:
  * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
  * 1. `[]` multisig: {@link MultisigConfig} The multisig account
- * 2. `[writable]` proposal: {@link Proposal} The proposal to execute
- * 3. `[writable]` source: {@link PublicKey} The source account.
- * 4. `[writable]` destination: {@link PublicKey} The destination account.
- * 5. `[signer]` authority: {@link PublicKey} The source account's owner/delegate.
- * 6. `[writable, signer]` funding: {@link PublicKey} Funding account (must be a system account)
- * 7. `[writable]` assoc_token_account: {@link PublicKey} Associated token account address to be created
- * 8. `[]` wallet: {@link PublicKey} Wallet address for the new associated token account
- * 9. `[]` mint: {@link Mint} The token mint for the new associated token account
- * 10. `[]` system_program: {@link PublicKey} System program
- * 11. `[]` token_program: {@link PublicKey} SPL Token program
- * 12. `[]` csl_spl_token_v0_0_0: {@link PublicKey} Auto-generated, CslSplTokenProgram v0.0.0
- * 13. `[]` csl_spl_assoc_token_v0_0_0: {@link PublicKey} Auto-generated, CslSplAssocTokenProgram v0.0.0
+ * 2. `[writable]` proposal: {@link Proposal} The proposal to execute (must belong to the multisig)
+ * 3. `[]` vault: {@link PublicKey} The multisig vault paying the transfer
+ * 4. `[writable]` source: {@link TokenAccount} The vault's associated token account for the proposal's mint
+ * 5. `[writable]` destination: {@link TokenAccount} The destination wallet's associated token account for the proposal's mint, created if missing
+ * 6. `[writable, signer]` funding: {@link PublicKey} Pays the rent of the destination token account when it is created
+ * 7. `[]` wallet: {@link PublicKey} The proposal's destination wallet
+ * 8. `[]` mint: {@link Mint} The mint named in the proposal
+ * 9. `[]` system_program: {@link PublicKey} System program
+ * 10. `[]` token_program: {@link PublicKey} SPL Token program
+ * 11. `[]` associated_token_program: {@link PublicKey} SPL Associated Token Account program
+ * 12. `[]` memo_program: {@link PublicKey} SPL Memo program, used to attach the proposal memo to the transfer
+ * 13. `[writable, optional]` transfer_policy: {@link TransferPolicy} The multisig's transfer policy, required when the multisig has one
  *
  * Data:
  * - proposal_seed_proposal_id: {@link BigInt} Auto-generated, from the input "proposal" for the its seed definition "Proposal", sets the seed named "proposal_id"
@@ -393,12 +535,18 @@ export const executeProposalBuilder = (
 	args: ExecuteProposalArgs,
 	remainingAccounts: Array<web3.AccountMeta> = [],
 ): MethodsBuilder<MultisigWallet, never> => {
-  const [multisigPubkey] = pda.deriveMultisigPDA(_program.programId);
+  const [multisigPubkey] = pda.deriveMultisigPDA({
+        createKey: args.createKey,
+    }, _program.programId);
     const [proposalPubkey] = pda.deriveProposalPDA({
-        multisig: args.multisig,
+        multisig: multisigPubkey,
         proposalId: args.proposalSeedProposalId,
     }, _program.programId);
-    const [assocTokenAccountPubkey] = pda.CslSplTokenPDAs.deriveAccountPDA({
+    const [vaultPubkey] = pda.deriveVaultPDA({
+        multisig: multisigPubkey,
+        vaultIndex: args.vaultIndex,
+    }, _program.programId);
+    const [destinationPubkey] = pda.CslSplTokenPDAs.deriveAccountPDA({
         wallet: args.wallet,
         tokenProgram: new web3.PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"),
         mint: args.mint,
@@ -413,40 +561,42 @@ export const executeProposalBuilder = (
       feePayer: args.feePayer,
       multisig: multisigPubkey,
       proposal: proposalPubkey,
+      vault: vaultPubkey,
       source: args.source,
-      destination: args.destination,
-      authority: args.authority,
+      destination: destinationPubkey,
       funding: args.funding,
-      assocTokenAccount: assocTokenAccountPubkey,
       wallet: args.wallet,
       mint: args.mint,
       systemProgram: new web3.PublicKey("11111111111111111111111111111111"),
       tokenProgram: new web3.PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"),
-      cslSplTokenV000: new web3.PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"),
-      cslSplAssocTokenV000: new web3.PublicKey("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"),
+      associatedTokenProgram: new web3.PublicKey("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"),
+      memoProgram: new web3.PublicKey("MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr"),
+      transferPolicy: args.transferPolicy,
     })
     .remainingAccounts(remainingAccounts);
 };
 
 /**
  * ### Returns a {@link web3.TransactionInstruction}
- * Execute a proposal if the threshold is met
+ * Execute a proposal if the threshold is met (optimistic proposals, while the multisig stays in
+ * optimistic mode, once their veto window has elapsed without enough vetoes and with the approvals
+ * of their vault's threshold override, if any)
  *
  * Accounts:
  * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
  * 1. `[]` multisig: {@link MultisigConfig} The multisig account
- * 2. `[writable]` proposal: {@link Proposal} The proposal to execute
- * 3. `[writable]` source: {@link PublicKey} The source account.
- * 4. `[writable]` destination: {@link PublicKey} The destination account.
- * 5. `[signer]` authority: {@link PublicKey} The source account's owner/delegate.
- * 6. `[writable, signer]` funding: {@link PublicKey} Funding account (must be a system account)
- * 7. `[writable]` assoc_token_account: {@link PublicKey} Associated token account address to be created
- * 8. `[]` wallet: {@link PublicKey} Wallet address for the new associated token account
- * 9. `[]` mint: {@link Mint} The token mint for the new associated token account
- * 10. `[]` system_program: {@link PublicKey} System program
- * 11. `[]` token_program: {@link PublicKey} SPL Token program
- * 12. `[]` csl_spl_token_v0_0_0: {@link PublicKey} Auto-generated, CslSplTokenProgram v0.0.0
- * 13. `[]` csl_spl_assoc_token_v0_0_0: {@link PublicKey} Auto-generated, CslSplAssocTokenProgram v0.0.0
+ * 2. `[writable]` proposal: {@link Proposal} The proposal to execute (must belong to the multisig)
+ * 3. `[]` vault: {@link PublicKey} The multisig vault paying the transfer
+ * 4. `[writable]` source: {@link TokenAccount} The vault's associated token account for the proposal's mint
+ * 5. `[writable]` destination: {@link TokenAccount} The destination wallet's associated token account for the proposal's mint, created if missing
+ * 6. `[writable, signer]` funding: {@link PublicKey} Pays the rent of the destination token account when it is created
+ * 7. `[]` wallet: {@link PublicKey} The proposal's destination wallet
+ * 8. `[]` mint: {@link Mint} The mint named in the proposal
+ * 9. `[]` system_program: {@link PublicKey} System program
+ * 10. `[]` token_program: {@link PublicKey} SPL Token program
+ * 11. `[]` associated_token_program: {@link PublicKey} SPL Associated Token Account program
+ * 12. `[]` memo_program: {@link PublicKey} SPL Memo program, used to attach the proposal memo to the transfer
+ * 13. `[writable, optional]` transfer_policy: {@link TransferPolicy} The multisig's transfer policy, required when the multisig has one
  *
  * Data:
  * - proposal_seed_proposal_id: {@link BigInt} Auto-generated, from the input "proposal" for the its seed definition "Proposal", sets the seed named "proposal_id"
@@ -459,32 +609,33 @@ export const executeProposal = (
 
 /**
  * ### Returns a {@link web3.TransactionSignature}
- * Execute a proposal if the threshold is met
+ * Execute a proposal if the threshold is met (optimistic proposals, while the multisig stays in
+ * optimistic mode, once their veto window has elapsed without enough vetoes and with the approvals
+ * of their vault's threshold override, if any)
  *
  * Accounts:
  * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
  * 1. `[]` multisig: {@link MultisigConfig} The multisig account
- * 2. `[writable]` proposal: {@link Proposal} The proposal to execute
- * 3. `[writable]` source: {@link PublicKey} The source account.
- * 4. `[writable]` destination: {@link PublicKey} The destination account.
- * 5. `[signer]` authority: {@link PublicKey} The source account's owner/delegate.
- * 6. `[writable, signer]` funding: {@link PublicKey} Funding account (must be a system account)
- * 7. `[writable]` assoc_token_account: {@link PublicKey} Associated token account address to be created
- * 8. `[]` wallet: {@link PublicKey} Wallet address for the new associated token account
- * 9. `[]` mint: {@link Mint} The token mint for the new associated token account
- * 10. `[]` system_program: {@link PublicKey} System program
- * 11. `[]` token_program: {@link PublicKey} SPL Token program
- * 12. `[]` csl_spl_token_v0_0_0: {@link PublicKey} Auto-generated, CslSplTokenProgram v0.0.0
- * 13. `[]` csl_spl_assoc_token_v0_0_0: {@link PublicKey} Auto-generated, CslSplAssocTokenProgram v0.0.0
+ * 2. `[writable]` proposal: {@link Proposal} The proposal to execute (must belong to the multisig)
+ * 3. `[]` vault: {@link PublicKey} The multisig vault paying the transfer
+ * 4. `[writable]` source: {@link TokenAccount} The vault's associated token account for the proposal's mint
+ * 5. `[writable]` destination: {@link TokenAccount} The destination wallet's associated token account for the proposal's mint, created if missing
+ * 6. `[writable, signer]` funding: {@link PublicKey} Pays the rent of the destination token account when it is created
+ * 7. `[]` wallet: {@link PublicKey} The proposal's destination wallet
+ * 8. `[]` mint: {@link Mint} The mint named in the proposal
+ * 9. `[]` system_program: {@link PublicKey} System program
+ * 10. `[]` token_program: {@link PublicKey} SPL Token program
+ * 11. `[]` associated_token_program: {@link PublicKey} SPL Associated Token Account program
+ * 12. `[]` memo_program: {@link PublicKey} SPL Memo program, used to attach the proposal memo to the transfer
+ * 13. `[writable, optional]` transfer_policy: {@link TransferPolicy} The multisig's transfer policy, required when the multisig has one
  *
  * Data:
  * - proposal_seed_proposal_id: {@link BigInt} Auto-generated, from the input "proposal" for the its seed definition "Proposal", sets the seed named "proposal_id"
  */
 export const executeProposalSendAndConfirm = async (
-  args: Omit<ExecuteProposalArgs, "feePayer" | "authority" | "funding"> & {
+  args: Omit<ExecuteProposalArgs, "feePayer" | "funding"> & {
     signers: {
       feePayer: web3.Signer,
-      authority: web3.Signer,
       funding: web3.Signer,
     },
   },
@@ -496,29 +647,31 @@ export const executeProposalSendAndConfirm = async (
   return executeProposalBuilder({
       ...args,
       feePayer: args.signers.feePayer.publicKey,
-      authority: args.signers.authority.publicKey,
       funding: args.signers.funding.publicKey,
     }, remainingAccounts)
     .preInstructions(preInstructions)
-    .signers([args.signers.feePayer, args.signers.authority, args.signers.funding])
+    .signers([args.signers.feePayer, args.signers.funding])
     .rpc();
 }
 
 export type CancelProposalArgs = {
   feePayer: web3.PublicKey;
-  creator: web3.PublicKey;
+  createKey: web3.PublicKey;
+  signer: web3.PublicKey;
   proposalSeedProposalId: bigint;
 };
 
 /**
  * ### Returns a {@link MethodsBuilder}
- * Cancel a pending proposal
+ * Cancel a pending proposal: the creator cancels it outright while it has no approvals,
+ * otherwise the signer's cancel vote is recorded and the proposal is cancelled once the
+ * cancel threshold is reached
  *
  * Accounts:
  * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
  * 1. `[]` multisig: {@link MultisigConfig} The multisig account
  * 2. `[writable]` proposal: {@link Proposal} The proposal to cancel
- * 3. `[signer]` creator: {@link PublicKey} The creator of the proposal
+ * 3. `[signer]` signer: {@link PublicKey} The proposal creator or a signer of the multisig voting to cancel
  *
  * Data:
  * - proposal_seed_proposal_id: {@link BigInt} Auto-generated, from the input "proposal" for the its seed definition "Proposal", sets the seed named "proposal_id"
@@ -527,7 +680,9 @@ export const cancelProposalBuilder = (
 	args: CancelProposalArgs,
 	remainingAccounts: Array<web3.AccountMeta> = [],
 ): MethodsBuilder<MultisigWallet, never> => {
-  const [multisigPubkey] = pda.deriveMultisigPDA(_program.programId);
+  const [multisigPubkey] = pda.deriveMultisigPDA({
+        createKey: args.createKey,
+    }, _program.programId);
     const [proposalPubkey] = pda.deriveProposalPDA({
         multisig: args.multisig,
         proposalId: args.proposalSeedProposalId,
@@ -542,20 +697,22 @@ export const cancelProposalBuilder = (
       feePayer: args.feePayer,
       multisig: multisigPubkey,
       proposal: proposalPubkey,
-      creator: args.creator,
+      signer: args.signer,
     })
     .remainingAccounts(remainingAccounts);
 };
 
 /**
  * ### Returns a {@link web3.TransactionInstruction}
- * Cancel a pending proposal
+ * Cancel a pending proposal: the creator cancels it outright while it has no approvals,
+ * otherwise the signer's cancel vote is recorded and the proposal is cancelled once the
+ * cancel threshold is reached
  *
  * Accounts:
  * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
  * 1. `[]` multisig: {@link MultisigConfig} The multisig account
  * 2. `[writable]` proposal: {@link Proposal} The proposal to cancel
- * 3. `[signer]` creator: {@link PublicKey} The creator of the proposal
+ * 3. `[signer]` signer: {@link PublicKey} The proposal creator or a signer of the multisig voting to cancel
  *
  * Data:
  * - proposal_seed_proposal_id: {@link BigInt} Auto-generated, from the input "proposal" for the its seed definition "Proposal", sets the seed named "proposal_id"
@@ -568,22 +725,24 @@ export const cancelProposal = (
 
 /**
  * ### Returns a {@link web3.TransactionSignature}
- * Cancel a pending proposal
+ * Cancel a pending proposal: the creator cancels it outright while it has no approvals,
+ * otherwise the signer's cancel vote is recorded and the proposal is cancelled once the
+ * cancel threshold is reached
  *
  * Accounts:
  * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
  * 1. `[]` multisig: {@link MultisigConfig} The multisig account
  * 2. `[writable]` proposal: {@link Proposal} The proposal to cancel
- * 3. `[signer]` creator: {@link PublicKey} The creator of the proposal
+ * 3. `[signer]` signer: {@link PublicKey} The proposal creator or a signer of the multisig voting to cancel
  *
  * Data:
  * - proposal_seed_proposal_id: {@link BigInt} Auto-generated, from the input "proposal" for the its seed definition "Proposal", sets the seed named "proposal_id"
  */
 export const cancelProposalSendAndConfirm = async (
-  args: Omit<CancelProposalArgs, "feePayer" | "creator"> & {
+  args: Omit<CancelProposalArgs, "feePayer" | "signer"> & {
     signers: {
       feePayer: web3.Signer,
-      creator: web3.Signer,
+      signer: web3.Signer,
     },
   },
   remainingAccounts: Array<web3.AccountMeta> = [],
@@ -594,87 +753,96 @@ export const cancelProposalSendAndConfirm = async (
   return cancelProposalBuilder({
       ...args,
       feePayer: args.signers.feePayer.publicKey,
-      creator: args.signers.creator.publicKey,
+      signer: args.signers.signer.publicKey,
     }, remainingAccounts)
     .preInstructions(preInstructions)
-    .signers([args.signers.feePayer, args.signers.creator])
+    .signers([args.signers.feePayer, args.signers.signer])
     .rpc();
 }
 
-export type UpdateMultisigArgs = {
+export type ExpireProposalArgs = {
   feePayer: web3.PublicKey;
-  newSigners: web3.PublicKey[];
-  newThreshold: number;
-  allCurrentSignersApproved: boolean;
+  rentReceiver: web3.PublicKey;
+  createKey: web3.PublicKey;
+  proposalSeedProposalId: bigint;
 };
 
 /**
  * ### Returns a {@link MethodsBuilder}
- * Update the signers or threshold of the multisig (requires approval from all current signers)
+ * Mark a proposal whose expiration time has passed as expired and close it, returning its rent to
+ * its creator (callable by anyone)
  *
  * Accounts:
  * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
- * 1. `[writable]` multisig: {@link MultisigConfig} The multisig account to update
+ * 1. `[]` multisig: {@link MultisigConfig} The multisig account
+ * 2. `[writable]` proposal: {@link Proposal} The proposal to expire and close
+ * 3. `[writable]` rent_receiver: {@link PublicKey} The proposal's creator, receiving its rent
  *
  * Data:
- * - new_signers: {@link PublicKey[]} The new set of authorized signers
- * - new_threshold: {@link number} The new approval threshold
- * - all_current_signers_approved: {@link boolean} Flag indicating that all current signers have approved this update
+ * - proposal_seed_proposal_id: {@link BigInt} Auto-generated, from the input "proposal" for the its seed definition "Proposal", sets the seed named "proposal_id"
  */
-export const updateMultisigBuilder = (
-	args: UpdateMultisigArgs,
+export const expireProposalBuilder = (
+	args: ExpireProposalArgs,
 	remainingAccounts: Array<web3.AccountMeta> = [],
 ): MethodsBuilder<MultisigWallet, never> => {
-  const [multisigPubkey] = pda.deriveMultisigPDA(_program.programId);
+  const [multisigPubkey] = pda.deriveMultisigPDA({
+        createKey: args.createKey,
+    }, _program.programId);
+    const [proposalPubkey] = pda.deriveProposalPDA({
+        multisig: multisigPubkey,
+        proposalId: args.proposalSeedProposalId,
+    }, _program.programId);
 
   return _program
     .methods
-    .updateMultisig(
-      args.newSigners,
-      args.newThreshold,
-      args.allCurrentSignersApproved,
+    .expireProposal(
+      new BN(args.proposalSeedProposalId.toString()),
     )
     .accountsStrict({
       feePayer: args.feePayer,
       multisig: multisigPubkey,
+      proposal: proposalPubkey,
+      rentReceiver: args.rentReceiver,
     })
     .remainingAccounts(remainingAccounts);
 };
 
 /**
  * ### Returns a {@link web3.TransactionInstruction}
- * Update the signers or threshold of the multisig (requires approval from all current signers)
+ * Mark a proposal whose expiration time has passed as expired and close it, returning its rent to
+ * its creator (callable by anyone)
  *
  * Accounts:
  * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
- * 1. `[writable]` multisig: {@link MultisigConfig} The multisig account to update
+ * 1. `[]` multisig: {@link MultisigConfig} The multisig account
+ * 2. `[writable]` proposal: {@link Proposal} The proposal to expire and close
+ * 3. `[writable]` rent_receiver: {@link PublicKey} The proposal's creator, receiving its rent
  *
  * Data:
- * - new_signers: {@link PublicKey[]} The new set of authorized signers
- * - new_threshold: {@link number} The new approval threshold
- * - all_current_signers_approved: {@link boolean} Flag indicating that all current signers have approved this update
+ * - proposal_seed_proposal_id: {@link BigInt} Auto-generated, from the input "proposal" for the its seed definition "Proposal", sets the seed named "proposal_id"
  */
-export const updateMultisig = (
-	args: UpdateMultisigArgs,
+export const expireProposal = (
+	args: ExpireProposalArgs,
 	remainingAccounts: Array<web3.AccountMeta> = [],
 ): Promise<web3.TransactionInstruction> =>
-    updateMultisigBuilder(args, remainingAccounts).instruction();
+    expireProposalBuilder(args, remainingAccounts).instruction();
 
 /**
  * ### Returns a {@link web3.TransactionSignature}
- * Update the signers or threshold of the multisig (requires approval from all current signers)
+ * Mark a proposal whose expiration time has passed as expired and close it, returning its rent to
+ * its creator (callable by anyone)
  *
  * Accounts:
  * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
- * 1. `[writable]` multisig: {@link MultisigConfig} The multisig account to update
+ * 1. `[]` multisig: {@link MultisigConfig} The multisig account
+ * 2. `[writable]` proposal: {@link Proposal} The proposal to expire and close
+ * 3. `[writable]` rent_receiver: {@link PublicKey} The proposal's creator, receiving its rent
  *
  * Data:
- * - new_signers: {@link PublicKey[]} The new set of authorized signers
- * - new_threshold: {@link number} The new approval threshold
- * - all_current_signers_approved: {@link boolean} Flag indicating that all current signers have approved this update
+ * - proposal_seed_proposal_id: {@link BigInt} Auto-generated, from the input "proposal" for the its seed definition "Proposal", sets the seed named "proposal_id"
  */
-export const updateMultisigSendAndConfirm = async (
-  args: Omit<UpdateMultisigArgs, "feePayer"> & {
+export const expireProposalSendAndConfirm = async (
+  args: Omit<ExpireProposalArgs, "feePayer"> & {
     signers: {
       feePayer: web3.Signer,
     },
@@ -684,7 +852,7 @@ export const updateMultisigSendAndConfirm = async (
   const preInstructions: Array<web3.TransactionInstruction> = [];
 
 
-  return updateMultisigBuilder({
+  return expireProposalBuilder({
       ...args,
       feePayer: args.signers.feePayer.publicKey,
     }, remainingAccounts)
@@ -693,17 +861,3723 @@ export const updateMultisigSendAndConfirm = async (
     .rpc();
 }
 
-// Getters
+export type CreateAddressBookArgs = {
+  feePayer: web3.PublicKey;
+  createKey: web3.PublicKey;
+  creator: web3.PublicKey;
+};
 
-export const getMultisigConfig = (
-    publicKey: web3.PublicKey,
-    commitment?: web3.Commitment
-): Promise<IdlAccounts<MultisigWallet>["multisigConfig"]> => _program.account.multisigConfig.fetch(publicKey, commitment);
+/**
+ * ### Returns a {@link MethodsBuilder}
+ * Create the (empty) address book of approved destinations for the multisig
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[]` multisig: {@link MultisigConfig} The multisig account
+ * 2. `[writable]` address_book: {@link AddressBook} The address book account to initialize
+ * 3. `[signer]` creator: {@link PublicKey} The account creating the address book (must be a signer of the multisig)
+ * 4. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
+ */
+export const createAddressBookBuilder = (
+	args: CreateAddressBookArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): MethodsBuilder<MultisigWallet, never> => {
+  const [multisigPubkey] = pda.deriveMultisigPDA({
+        createKey: args.createKey,
+    }, _program.programId);
+    const [addressBookPubkey] = pda.deriveAddressBookPDA({
+        multisig: multisigPubkey,
+    }, _program.programId);
 
-export const getProposal = (
-    publicKey: web3.PublicKey,
-    commitment?: web3.Commitment
-): Promise<IdlAccounts<MultisigWallet>["proposal"]> => _program.account.proposal.fetch(publicKey, commitment);
+  return _program
+    .methods
+    .createAddressBook(
+    )
+    .accountsStrict({
+      feePayer: args.feePayer,
+      multisig: multisigPubkey,
+      addressBook: addressBookPubkey,
+      creator: args.creator,
+      systemProgram: new web3.PublicKey("11111111111111111111111111111111"),
+    })
+    .remainingAccounts(remainingAccounts);
+};
+
+/**
+ * ### Returns a {@link web3.TransactionInstruction}
+ * Create the (empty) address book of approved destinations for the multisig
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[]` multisig: {@link MultisigConfig} The multisig account
+ * 2. `[writable]` address_book: {@link AddressBook} The address book account to initialize
+ * 3. `[signer]` creator: {@link PublicKey} The account creating the address book (must be a signer of the multisig)
+ * 4. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
+ */
+export const createAddressBook = (
+	args: CreateAddressBookArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionInstruction> =>
+    createAddressBookBuilder(args, remainingAccounts).instruction();
+
+/**
+ * ### Returns a {@link web3.TransactionSignature}
+ * Create the (empty) address book of approved destinations for the multisig
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[]` multisig: {@link MultisigConfig} The multisig account
+ * 2. `[writable]` address_book: {@link AddressBook} The address book account to initialize
+ * 3. `[signer]` creator: {@link PublicKey} The account creating the address book (must be a signer of the multisig)
+ * 4. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
+ */
+export const createAddressBookSendAndConfirm = async (
+  args: Omit<CreateAddressBookArgs, "feePayer" | "creator"> & {
+    signers: {
+      feePayer: web3.Signer,
+      creator: web3.Signer,
+    },
+  },
+  remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionSignature> => {
+  const preInstructions: Array<web3.TransactionInstruction> = [];
+
+
+  return createAddressBookBuilder({
+      ...args,
+      feePayer: args.signers.feePayer.publicKey,
+      creator: args.signers.creator.publicKey,
+    }, remainingAccounts)
+    .preInstructions(preInstructions)
+    .signers([args.signers.feePayer, args.signers.creator])
+    .rpc();
+}
+
+export type CreateAddressBookProposalArgs = {
+  feePayer: web3.PublicKey;
+  createKey: web3.PublicKey;
+  creator: web3.PublicKey;
+  change: IdlTypes<MultisigWallet>["addressBookChange"];
+  expirationTime: bigint;
+  proposalSeedProposalId: bigint;
+};
+
+/**
+ * ### Returns a {@link MethodsBuilder}
+ * Create a proposal that changes the multisig's address book once approved
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable]` multisig: {@link MultisigConfig} The multisig account
+ * 2. `[writable]` proposal: {@link Proposal} The proposal account to initialize
+ * 3. `[signer]` creator: {@link PublicKey} The account creating the proposal (must be a signer of the multisig)
+ * 4. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
+ *
+ * Data:
+ * - change: {@link AddressBookChange} The entry to add or remove, or the new enforcement flag
+ * - expiration_time: {@link BigInt} Optional timestamp when the proposal expires (0 means no expiration, or the maximum lifetime when the multisig sets one)
+ * - proposal_seed_proposal_id: {@link BigInt} Auto-generated, from the input "proposal" for the its seed definition "Proposal", sets the seed named "proposal_id"
+ */
+export const createAddressBookProposalBuilder = (
+	args: CreateAddressBookProposalArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): MethodsBuilder<MultisigWallet, never> => {
+  const [multisigPubkey] = pda.deriveMultisigPDA({
+        createKey: args.createKey,
+    }, _program.programId);
+    const [proposalPubkey] = pda.deriveProposalPDA({
+        multisig: multisigPubkey,
+        proposalId: args.proposalSeedProposalId,
+    }, _program.programId);
+
+  return _program
+    .methods
+    .createAddressBookProposal(
+      args.change,
+      new BN(args.expirationTime.toString()),
+      new BN(args.proposalSeedProposalId.toString()),
+    )
+    .accountsStrict({
+      feePayer: args.feePayer,
+      multisig: multisigPubkey,
+      proposal: proposalPubkey,
+      creator: args.creator,
+      systemProgram: new web3.PublicKey("11111111111111111111111111111111"),
+    })
+    .remainingAccounts(remainingAccounts);
+};
+
+/**
+ * ### Returns a {@link web3.TransactionInstruction}
+ * Create a proposal that changes the multisig's address book once approved
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable]` multisig: {@link MultisigConfig} The multisig account
+ * 2. `[writable]` proposal: {@link Proposal} The proposal account to initialize
+ * 3. `[signer]` creator: {@link PublicKey} The account creating the proposal (must be a signer of the multisig)
+ * 4. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
+ *
+ * Data:
+ * - change: {@link AddressBookChange} The entry to add or remove, or the new enforcement flag
+ * - expiration_time: {@link BigInt} Optional timestamp when the proposal expires (0 means no expiration, or the maximum lifetime when the multisig sets one)
+ * - proposal_seed_proposal_id: {@link BigInt} Auto-generated, from the input "proposal" for the its seed definition "Proposal", sets the seed named "proposal_id"
+ */
+export const createAddressBookProposal = (
+	args: CreateAddressBookProposalArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionInstruction> =>
+    createAddressBookProposalBuilder(args, remainingAccounts).instruction();
+
+/**
+ * ### Returns a {@link web3.TransactionSignature}
+ * Create a proposal that changes the multisig's address book once approved
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable]` multisig: {@link MultisigConfig} The multisig account
+ * 2. `[writable]` proposal: {@link Proposal} The proposal account to initialize
+ * 3. `[signer]` creator: {@link PublicKey} The account creating the proposal (must be a signer of the multisig)
+ * 4. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
+ *
+ * Data:
+ * - change: {@link AddressBookChange} The entry to add or remove, or the new enforcement flag
+ * - expiration_time: {@link BigInt} Optional timestamp when the proposal expires (0 means no expiration, or the maximum lifetime when the multisig sets one)
+ * - proposal_seed_proposal_id: {@link BigInt} Auto-generated, from the input "proposal" for the its seed definition "Proposal", sets the seed named "proposal_id"
+ */
+export const createAddressBookProposalSendAndConfirm = async (
+  args: Omit<CreateAddressBookProposalArgs, "feePayer" | "creator"> & {
+    signers: {
+      feePayer: web3.Signer,
+      creator: web3.Signer,
+    },
+  },
+  remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionSignature> => {
+  const preInstructions: Array<web3.TransactionInstruction> = [];
+
+
+  return createAddressBookProposalBuilder({
+      ...args,
+      feePayer: args.signers.feePayer.publicKey,
+      creator: args.signers.creator.publicKey,
+    }, remainingAccounts)
+    .preInstructions(preInstructions)
+    .signers([args.signers.feePayer, args.signers.creator])
+    .rpc();
+}
+
+export type ExecuteAddressBookProposalArgs = {
+  feePayer: web3.PublicKey;
+  createKey: web3.PublicKey;
+  proposalSeedProposalId: bigint;
+};
+
+/**
+ * ### Returns a {@link MethodsBuilder}
+ * Apply an approved address book proposal
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable]` multisig: {@link MultisigConfig} The multisig account
+ * 2. `[writable]` proposal: {@link Proposal} The address book proposal to execute
+ * 3. `[writable]` address_book: {@link AddressBook} The multisig's address book
+ *
+ * Data:
+ * - proposal_seed_proposal_id: {@link BigInt} Auto-generated, from the input "proposal" for the its seed definition "Proposal", sets the seed named "proposal_id"
+ */
+export const executeAddressBookProposalBuilder = (
+	args: ExecuteAddressBookProposalArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): MethodsBuilder<MultisigWallet, never> => {
+  const [multisigPubkey] = pda.deriveMultisigPDA({
+        createKey: args.createKey,
+    }, _program.programId);
+    const [proposalPubkey] = pda.deriveProposalPDA({
+        multisig: multisigPubkey,
+        proposalId: args.proposalSeedProposalId,
+    }, _program.programId);
+    const [addressBookPubkey] = pda.deriveAddressBookPDA({
+        multisig: multisigPubkey,
+    }, _program.programId);
+
+  return _program
+    .methods
+    .executeAddressBookProposal(
+      new BN(args.proposalSeedProposalId.toString()),
+    )
+    .accountsStrict({
+      feePayer: args.feePayer,
+      multisig: multisigPubkey,
+      proposal: proposalPubkey,
+      addressBook: addressBookPubkey,
+    })
+    .remainingAccounts(remainingAccounts);
+};
+
+/**
+ * ### Returns a {@link web3.TransactionInstruction}
+ * Apply an approved address book proposal
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable]` multisig: {@link MultisigConfig} The multisig account
+ * 2. `[writable]` proposal: {@link Proposal} The address book proposal to execute
+ * 3. `[writable]` address_book: {@link AddressBook} The multisig's address book
+ *
+ * Data:
+ * - proposal_seed_proposal_id: {@link BigInt} Auto-generated, from the input "proposal" for the its seed definition "Proposal", sets the seed named "proposal_id"
+ */
+export const executeAddressBookProposal = (
+	args: ExecuteAddressBookProposalArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionInstruction> =>
+    executeAddressBookProposalBuilder(args, remainingAccounts).instruction();
+
+/**
+ * ### Returns a {@link web3.TransactionSignature}
+ * Apply an approved address book proposal
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable]` multisig: {@link MultisigConfig} The multisig account
+ * 2. `[writable]` proposal: {@link Proposal} The address book proposal to execute
+ * 3. `[writable]` address_book: {@link AddressBook} The multisig's address book
+ *
+ * Data:
+ * - proposal_seed_proposal_id: {@link BigInt} Auto-generated, from the input "proposal" for the its seed definition "Proposal", sets the seed named "proposal_id"
+ */
+export const executeAddressBookProposalSendAndConfirm = async (
+  args: Omit<ExecuteAddressBookProposalArgs, "feePayer"> & {
+    signers: {
+      feePayer: web3.Signer,
+    },
+  },
+  remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionSignature> => {
+  const preInstructions: Array<web3.TransactionInstruction> = [];
+
+
+  return executeAddressBookProposalBuilder({
+      ...args,
+      feePayer: args.signers.feePayer.publicKey,
+    }, remainingAccounts)
+    .preInstructions(preInstructions)
+    .signers([args.signers.feePayer])
+    .rpc();
+}
+
+export type CreateTransferPolicyArgs = {
+  feePayer: web3.PublicKey;
+  createKey: web3.PublicKey;
+  creator: web3.PublicKey;
+};
+
+/**
+ * ### Returns a {@link MethodsBuilder}
+ * Create the (empty) transfer policy of per-mint limits for the multisig
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable]` multisig: {@link MultisigConfig} The multisig account
+ * 2. `[writable]` transfer_policy: {@link TransferPolicy} The transfer policy account to initialize
+ * 3. `[signer]` creator: {@link PublicKey} The account creating the transfer policy (must be a signer of the multisig)
+ * 4. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
+ */
+export const createTransferPolicyBuilder = (
+	args: CreateTransferPolicyArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): MethodsBuilder<MultisigWallet, never> => {
+  const [multisigPubkey] = pda.deriveMultisigPDA({
+        createKey: args.createKey,
+    }, _program.programId);
+    const [transferPolicyPubkey] = pda.deriveTransferPolicyPDA({
+        multisig: multisigPubkey,
+    }, _program.programId);
+
+  return _program
+    .methods
+    .createTransferPolicy(
+    )
+    .accountsStrict({
+      feePayer: args.feePayer,
+      multisig: multisigPubkey,
+      transferPolicy: transferPolicyPubkey,
+      creator: args.creator,
+      systemProgram: new web3.PublicKey("11111111111111111111111111111111"),
+    })
+    .remainingAccounts(remainingAccounts);
+};
+
+/**
+ * ### Returns a {@link web3.TransactionInstruction}
+ * Create the (empty) transfer policy of per-mint limits for the multisig
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable]` multisig: {@link MultisigConfig} The multisig account
+ * 2. `[writable]` transfer_policy: {@link TransferPolicy} The transfer policy account to initialize
+ * 3. `[signer]` creator: {@link PublicKey} The account creating the transfer policy (must be a signer of the multisig)
+ * 4. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
+ */
+export const createTransferPolicy = (
+	args: CreateTransferPolicyArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionInstruction> =>
+    createTransferPolicyBuilder(args, remainingAccounts).instruction();
+
+/**
+ * ### Returns a {@link web3.TransactionSignature}
+ * Create the (empty) transfer policy of per-mint limits for the multisig
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable]` multisig: {@link MultisigConfig} The multisig account
+ * 2. `[writable]` transfer_policy: {@link TransferPolicy} The transfer policy account to initialize
+ * 3. `[signer]` creator: {@link PublicKey} The account creating the transfer policy (must be a signer of the multisig)
+ * 4. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
+ */
+export const createTransferPolicySendAndConfirm = async (
+  args: Omit<CreateTransferPolicyArgs, "feePayer" | "creator"> & {
+    signers: {
+      feePayer: web3.Signer,
+      creator: web3.Signer,
+    },
+  },
+  remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionSignature> => {
+  const preInstructions: Array<web3.TransactionInstruction> = [];
+
+
+  return createTransferPolicyBuilder({
+      ...args,
+      feePayer: args.signers.feePayer.publicKey,
+      creator: args.signers.creator.publicKey,
+    }, remainingAccounts)
+    .preInstructions(preInstructions)
+    .signers([args.signers.feePayer, args.signers.creator])
+    .rpc();
+}
+
+export type CreateTransferPolicyProposalArgs = {
+  feePayer: web3.PublicKey;
+  createKey: web3.PublicKey;
+  creator: web3.PublicKey;
+  change: IdlTypes<MultisigWallet>["transferPolicyChange"];
+  expirationTime: bigint;
+  proposalSeedProposalId: bigint;
+};
+
+/**
+ * ### Returns a {@link MethodsBuilder}
+ * Create a proposal that changes the multisig's transfer policy once approved
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable]` multisig: {@link MultisigConfig} The multisig account
+ * 2. `[writable]` proposal: {@link Proposal} The proposal account to initialize
+ * 3. `[signer]` creator: {@link PublicKey} The account creating the proposal (must be a signer of the multisig)
+ * 4. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
+ *
+ * Data:
+ * - change: {@link TransferPolicyChange} The per-mint limit to set or remove
+ * - expiration_time: {@link BigInt} Optional timestamp when the proposal expires (0 means no expiration, or the maximum lifetime when the multisig sets one)
+ * - proposal_seed_proposal_id: {@link BigInt} Auto-generated, from the input "proposal" for the its seed definition "Proposal", sets the seed named "proposal_id"
+ */
+export const createTransferPolicyProposalBuilder = (
+	args: CreateTransferPolicyProposalArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): MethodsBuilder<MultisigWallet, never> => {
+  const [multisigPubkey] = pda.deriveMultisigPDA({
+        createKey: args.createKey,
+    }, _program.programId);
+    const [proposalPubkey] = pda.deriveProposalPDA({
+        multisig: multisigPubkey,
+        proposalId: args.proposalSeedProposalId,
+    }, _program.programId);
+
+  return _program
+    .methods
+    .createTransferPolicyProposal(
+      args.change,
+      new BN(args.expirationTime.toString()),
+      new BN(args.proposalSeedProposalId.toString()),
+    )
+    .accountsStrict({
+      feePayer: args.feePayer,
+      multisig: multisigPubkey,
+      proposal: proposalPubkey,
+      creator: args.creator,
+      systemProgram: new web3.PublicKey("11111111111111111111111111111111"),
+    })
+    .remainingAccounts(remainingAccounts);
+};
+
+/**
+ * ### Returns a {@link web3.TransactionInstruction}
+ * Create a proposal that changes the multisig's transfer policy once approved
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable]` multisig: {@link MultisigConfig} The multisig account
+ * 2. `[writable]` proposal: {@link Proposal} The proposal account to initialize
+ * 3. `[signer]` creator: {@link PublicKey} The account creating the proposal (must be a signer of the multisig)
+ * 4. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
+ *
+ * Data:
+ * - change: {@link TransferPolicyChange} The per-mint limit to set or remove
+ * - expiration_time: {@link BigInt} Optional timestamp when the proposal expires (0 means no expiration, or the maximum lifetime when the multisig sets one)
+ * - proposal_seed_proposal_id: {@link BigInt} Auto-generated, from the input "proposal" for the its seed definition "Proposal", sets the seed named "proposal_id"
+ */
+export const createTransferPolicyProposal = (
+	args: CreateTransferPolicyProposalArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionInstruction> =>
+    createTransferPolicyProposalBuilder(args, remainingAccounts).instruction();
+
+/**
+ * ### Returns a {@link web3.TransactionSignature}
+ * Create a proposal that changes the multisig's transfer policy once approved
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable]` multisig: {@link MultisigConfig} The multisig account
+ * 2. `[writable]` proposal: {@link Proposal} The proposal account to initialize
+ * 3. `[signer]` creator: {@link PublicKey} The account creating the proposal (must be a signer of the multisig)
+ * 4. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
+ *
+ * Data:
+ * - change: {@link TransferPolicyChange} The per-mint limit to set or remove
+ * - expiration_time: {@link BigInt} Optional timestamp when the proposal expires (0 means no expiration, or the maximum lifetime when the multisig sets one)
+ * - proposal_seed_proposal_id: {@link BigInt} Auto-generated, from the input "proposal" for the its seed definition "Proposal", sets the seed named "proposal_id"
+ */
+export const createTransferPolicyProposalSendAndConfirm = async (
+  args: Omit<CreateTransferPolicyProposalArgs, "feePayer" | "creator"> & {
+    signers: {
+      feePayer: web3.Signer,
+      creator: web3.Signer,
+    },
+  },
+  remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionSignature> => {
+  const preInstructions: Array<web3.TransactionInstruction> = [];
+
+
+  return createTransferPolicyProposalBuilder({
+      ...args,
+      feePayer: args.signers.feePayer.publicKey,
+      creator: args.signers.creator.publicKey,
+    }, remainingAccounts)
+    .preInstructions(preInstructions)
+    .signers([args.signers.feePayer, args.signers.creator])
+    .rpc();
+}
+
+export type ExecuteTransferPolicyProposalArgs = {
+  feePayer: web3.PublicKey;
+  createKey: web3.PublicKey;
+  proposalSeedProposalId: bigint;
+};
+
+/**
+ * ### Returns a {@link MethodsBuilder}
+ * Apply an approved transfer policy proposal
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[]` multisig: {@link MultisigConfig} The multisig account
+ * 2. `[writable]` proposal: {@link Proposal} The transfer policy proposal to execute
+ * 3. `[writable]` transfer_policy: {@link TransferPolicy} The multisig's transfer policy
+ *
+ * Data:
+ * - proposal_seed_proposal_id: {@link BigInt} Auto-generated, from the input "proposal" for the its seed definition "Proposal", sets the seed named "proposal_id"
+ */
+export const executeTransferPolicyProposalBuilder = (
+	args: ExecuteTransferPolicyProposalArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): MethodsBuilder<MultisigWallet, never> => {
+  const [multisigPubkey] = pda.deriveMultisigPDA({
+        createKey: args.createKey,
+    }, _program.programId);
+    const [proposalPubkey] = pda.deriveProposalPDA({
+        multisig: multisigPubkey,
+        proposalId: args.proposalSeedProposalId,
+    }, _program.programId);
+    const [transferPolicyPubkey] = pda.deriveTransferPolicyPDA({
+        multisig: multisigPubkey,
+    }, _program.programId);
+
+  return _program
+    .methods
+    .executeTransferPolicyProposal(
+      new BN(args.proposalSeedProposalId.toString()),
+    )
+    .accountsStrict({
+      feePayer: args.feePayer,
+      multisig: multisigPubkey,
+      proposal: proposalPubkey,
+      transferPolicy: transferPolicyPubkey,
+    })
+    .remainingAccounts(remainingAccounts);
+};
+
+/**
+ * ### Returns a {@link web3.TransactionInstruction}
+ * Apply an approved transfer policy proposal
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[]` multisig: {@link MultisigConfig} The multisig account
+ * 2. `[writable]` proposal: {@link Proposal} The transfer policy proposal to execute
+ * 3. `[writable]` transfer_policy: {@link TransferPolicy} The multisig's transfer policy
+ *
+ * Data:
+ * - proposal_seed_proposal_id: {@link BigInt} Auto-generated, from the input "proposal" for the its seed definition "Proposal", sets the seed named "proposal_id"
+ */
+export const executeTransferPolicyProposal = (
+	args: ExecuteTransferPolicyProposalArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionInstruction> =>
+    executeTransferPolicyProposalBuilder(args, remainingAccounts).instruction();
+
+/**
+ * ### Returns a {@link web3.TransactionSignature}
+ * Apply an approved transfer policy proposal
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[]` multisig: {@link MultisigConfig} The multisig account
+ * 2. `[writable]` proposal: {@link Proposal} The transfer policy proposal to execute
+ * 3. `[writable]` transfer_policy: {@link TransferPolicy} The multisig's transfer policy
+ *
+ * Data:
+ * - proposal_seed_proposal_id: {@link BigInt} Auto-generated, from the input "proposal" for the its seed definition "Proposal", sets the seed named "proposal_id"
+ */
+export const executeTransferPolicyProposalSendAndConfirm = async (
+  args: Omit<ExecuteTransferPolicyProposalArgs, "feePayer"> & {
+    signers: {
+      feePayer: web3.Signer,
+    },
+  },
+  remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionSignature> => {
+  const preInstructions: Array<web3.TransactionInstruction> = [];
+
+
+  return executeTransferPolicyProposalBuilder({
+      ...args,
+      feePayer: args.signers.feePayer.publicKey,
+    }, remainingAccounts)
+    .preInstructions(preInstructions)
+    .signers([args.signers.feePayer])
+    .rpc();
+}
+
+export type FreezeMultisigArgs = {
+  feePayer: web3.PublicKey;
+  createKey: web3.PublicKey;
+  guardian: web3.PublicKey;
+};
+
+/**
+ * ### Returns a {@link MethodsBuilder}
+ * Freeze the multisig, halting proposal creation, approval and execution until all signers unfreeze it
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable]` multisig: {@link MultisigConfig} The multisig account to freeze
+ * 2. `[signer]` guardian: {@link PublicKey} The guardian of the multisig
+ */
+export const freezeMultisigBuilder = (
+	args: FreezeMultisigArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): MethodsBuilder<MultisigWallet, never> => {
+  const [multisigPubkey] = pda.deriveMultisigPDA({
+        createKey: args.createKey,
+    }, _program.programId);
+
+  return _program
+    .methods
+    .freezeMultisig(
+    )
+    .accountsStrict({
+      feePayer: args.feePayer,
+      multisig: multisigPubkey,
+      guardian: args.guardian,
+    })
+    .remainingAccounts(remainingAccounts);
+};
+
+/**
+ * ### Returns a {@link web3.TransactionInstruction}
+ * Freeze the multisig, halting proposal creation, approval and execution until all signers unfreeze it
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable]` multisig: {@link MultisigConfig} The multisig account to freeze
+ * 2. `[signer]` guardian: {@link PublicKey} The guardian of the multisig
+ */
+export const freezeMultisig = (
+	args: FreezeMultisigArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionInstruction> =>
+    freezeMultisigBuilder(args, remainingAccounts).instruction();
+
+/**
+ * ### Returns a {@link web3.TransactionSignature}
+ * Freeze the multisig, halting proposal creation, approval and execution until all signers unfreeze it
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable]` multisig: {@link MultisigConfig} The multisig account to freeze
+ * 2. `[signer]` guardian: {@link PublicKey} The guardian of the multisig
+ */
+export const freezeMultisigSendAndConfirm = async (
+  args: Omit<FreezeMultisigArgs, "feePayer" | "guardian"> & {
+    signers: {
+      feePayer: web3.Signer,
+      guardian: web3.Signer,
+    },
+  },
+  remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionSignature> => {
+  const preInstructions: Array<web3.TransactionInstruction> = [];
+
+
+  return freezeMultisigBuilder({
+      ...args,
+      feePayer: args.signers.feePayer.publicKey,
+      guardian: args.signers.guardian.publicKey,
+    }, remainingAccounts)
+    .preInstructions(preInstructions)
+    .signers([args.signers.feePayer, args.signers.guardian])
+    .rpc();
+}
+
+export type UnfreezeMultisigArgs = {
+  feePayer: web3.PublicKey;
+  createKey: web3.PublicKey;
+  members: web3.PublicKey[];
+};
+
+/**
+ * ### Returns a {@link MethodsBuilder}
+ * Unfreeze the multisig (requires every current signer to sign the transaction)
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable]` multisig: {@link MultisigConfig} The multisig account to unfreeze
+ * 2. `[signer]` remaining_accounts: {@link PublicKey} Every current signer of the multisig (accounts 2 and up)
+ */
+export const unfreezeMultisigBuilder = (
+	args: UnfreezeMultisigArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): MethodsBuilder<MultisigWallet, never> => {
+  const [multisigPubkey] = pda.deriveMultisigPDA({
+        createKey: args.createKey,
+    }, _program.programId);
+  const memberAccounts = args.members.map((pubkey) => ({ pubkey, isSigner: true, isWritable: false }));
+
+  return _program
+    .methods
+    .unfreezeMultisig(
+    )
+    .accountsStrict({
+      feePayer: args.feePayer,
+      multisig: multisigPubkey,
+    })
+    .remainingAccounts([...memberAccounts, ...remainingAccounts]);
+};
+
+/**
+ * ### Returns a {@link web3.TransactionInstruction}
+ * Unfreeze the multisig (requires every current signer to sign the transaction)
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable]` multisig: {@link MultisigConfig} The multisig account to unfreeze
+ * 2. `[signer]` remaining_accounts: {@link PublicKey} Every current signer of the multisig (accounts 2 and up)
+ */
+export const unfreezeMultisig = (
+	args: UnfreezeMultisigArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionInstruction> =>
+    unfreezeMultisigBuilder(args, remainingAccounts).instruction();
+
+/**
+ * ### Returns a {@link web3.TransactionSignature}
+ * Unfreeze the multisig (requires every current signer to sign the transaction)
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable]` multisig: {@link MultisigConfig} The multisig account to unfreeze
+ * 2. `[signer]` remaining_accounts: {@link PublicKey} Every current signer of the multisig (accounts 2 and up)
+ */
+export const unfreezeMultisigSendAndConfirm = async (
+  args: Omit<UnfreezeMultisigArgs, "feePayer" | "members"> & {
+    signers: {
+      feePayer: web3.Signer,
+      members: web3.Signer[],
+    },
+  },
+  remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionSignature> => {
+  const preInstructions: Array<web3.TransactionInstruction> = [];
+
+
+  return unfreezeMultisigBuilder({
+      ...args,
+      feePayer: args.signers.feePayer.publicKey,
+      members: args.signers.members.map((member) => member.publicKey),
+    }, remainingAccounts)
+    .preInstructions(preInstructions)
+    .signers([args.signers.feePayer, ...args.signers.members])
+    .rpc();
+}
+
+export type ConfigureRecoveryArgs = {
+  feePayer: web3.PublicKey;
+  createKey: web3.PublicKey;
+  recoveryKeys: web3.PublicKey[];
+  recoveryThreshold: number;
+  recoveryDelay: bigint;
+  members: web3.PublicKey[];
+};
+
+/**
+ * ### Returns a {@link MethodsBuilder}
+ * Set the recovery keys, recovery threshold and time lock used to replace lost signers (requires every current signer to sign the transaction)
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable]` multisig: {@link MultisigConfig} The multisig account to configure
+ * 2. `[signer]` remaining_accounts: {@link PublicKey} Every current signer of the multisig (accounts 2 and up)
+ *
+ * Data:
+ * - recovery_keys: {@link PublicKey[]} The keys allowed to request and approve signer recovery (empty disables recovery)
+ * - recovery_threshold: {@link number} The number of recovery key approvals needed to start the time lock
+ * - recovery_delay: {@link BigInt} Number of seconds during which current signers can veto an approved recovery (at least `MIN_RECOVERY_DELAY` when recovery is enabled)
+ */
+export const configureRecoveryBuilder = (
+	args: ConfigureRecoveryArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): MethodsBuilder<MultisigWallet, never> => {
+  const [multisigPubkey] = pda.deriveMultisigPDA({
+        createKey: args.createKey,
+    }, _program.programId);
+  const memberAccounts = args.members.map((pubkey) => ({ pubkey, isSigner: true, isWritable: false }));
+
+  return _program
+    .methods
+    .configureRecovery(
+      args.recoveryKeys,
+      args.recoveryThreshold,
+      new BN(args.recoveryDelay.toString()),
+    )
+    .accountsStrict({
+      feePayer: args.feePayer,
+      multisig: multisigPubkey,
+    })
+    .remainingAccounts([...memberAccounts, ...remainingAccounts]);
+};
+
+/**
+ * ### Returns a {@link web3.TransactionInstruction}
+ * Set the recovery keys, recovery threshold and time lock used to replace lost signers (requires every current signer to sign the transaction)
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable]` multisig: {@link MultisigConfig} The multisig account to configure
+ * 2. `[signer]` remaining_accounts: {@link PublicKey} Every current signer of the multisig (accounts 2 and up)
+ *
+ * Data:
+ * - recovery_keys: {@link PublicKey[]} The keys allowed to request and approve signer recovery (empty disables recovery)
+ * - recovery_threshold: {@link number} The number of recovery key approvals needed to start the time lock
+ * - recovery_delay: {@link BigInt} Number of seconds during which current signers can veto an approved recovery (at least `MIN_RECOVERY_DELAY` when recovery is enabled)
+ */
+export const configureRecovery = (
+	args: ConfigureRecoveryArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionInstruction> =>
+    configureRecoveryBuilder(args, remainingAccounts).instruction();
+
+/**
+ * ### Returns a {@link web3.TransactionSignature}
+ * Set the recovery keys, recovery threshold and time lock used to replace lost signers (requires every current signer to sign the transaction)
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable]` multisig: {@link MultisigConfig} The multisig account to configure
+ * 2. `[signer]` remaining_accounts: {@link PublicKey} Every current signer of the multisig (accounts 2 and up)
+ *
+ * Data:
+ * - recovery_keys: {@link PublicKey[]} The keys allowed to request and approve signer recovery (empty disables recovery)
+ * - recovery_threshold: {@link number} The number of recovery key approvals needed to start the time lock
+ * - recovery_delay: {@link BigInt} Number of seconds during which current signers can veto an approved recovery (at least `MIN_RECOVERY_DELAY` when recovery is enabled)
+ */
+export const configureRecoverySendAndConfirm = async (
+  args: Omit<ConfigureRecoveryArgs, "feePayer" | "members"> & {
+    signers: {
+      feePayer: web3.Signer,
+      members: web3.Signer[],
+    },
+  },
+  remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionSignature> => {
+  const preInstructions: Array<web3.TransactionInstruction> = [];
+
+
+  return configureRecoveryBuilder({
+      ...args,
+      feePayer: args.signers.feePayer.publicKey,
+      members: args.signers.members.map((member) => member.publicKey),
+    }, remainingAccounts)
+    .preInstructions(preInstructions)
+    .signers([args.signers.feePayer, ...args.signers.members])
+    .rpc();
+}
+
+export type CreateRecoveryRequestArgs = {
+  feePayer: web3.PublicKey;
+  createKey: web3.PublicKey;
+  recoveryKey: web3.PublicKey;
+  lostSigner: web3.PublicKey;
+  newSigner: web3.PublicKey;
+  recoveryRequestSeedRecoveryId: bigint;
+};
+
+/**
+ * ### Returns a {@link MethodsBuilder}
+ * Request that a lost signer key be replaced, counting as the first recovery key approval
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable]` multisig: {@link MultisigConfig} The multisig account
+ * 2. `[writable]` recovery_request: {@link RecoveryRequest} The recovery request account to initialize
+ * 3. `[signer]` recovery_key: {@link PublicKey} The recovery key creating the request
+ * 4. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
+ *
+ * Data:
+ * - lost_signer: {@link PublicKey} The current signer whose key was lost
+ * - new_signer: {@link PublicKey} The key replacing the lost signer
+ * - recovery_request_seed_recovery_id: {@link BigInt} Auto-generated, from the input "recovery_request" for the its seed definition "RecoveryRequest", sets the seed named "recovery_id"
+ */
+export const createRecoveryRequestBuilder = (
+	args: CreateRecoveryRequestArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): MethodsBuilder<MultisigWallet, never> => {
+  const [multisigPubkey] = pda.deriveMultisigPDA({
+        createKey: args.createKey,
+    }, _program.programId);
+    const [recoveryRequestPubkey] = pda.deriveRecoveryRequestPDA({
+        multisig: multisigPubkey,
+        recoveryId: args.recoveryRequestSeedRecoveryId,
+    }, _program.programId);
+
+  return _program
+    .methods
+    .createRecoveryRequest(
+      args.lostSigner,
+      args.newSigner,
+      new BN(args.recoveryRequestSeedRecoveryId.toString()),
+    )
+    .accountsStrict({
+      feePayer: args.feePayer,
+      multisig: multisigPubkey,
+      recoveryRequest: recoveryRequestPubkey,
+      recoveryKey: args.recoveryKey,
+      systemProgram: new web3.PublicKey("11111111111111111111111111111111"),
+    })
+    .remainingAccounts(remainingAccounts);
+};
+
+/**
+ * ### Returns a {@link web3.TransactionInstruction}
+ * Request that a lost signer key be replaced, counting as the first recovery key approval
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable]` multisig: {@link MultisigConfig} The multisig account
+ * 2. `[writable]` recovery_request: {@link RecoveryRequest} The recovery request account to initialize
+ * 3. `[signer]` recovery_key: {@link PublicKey} The recovery key creating the request
+ * 4. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
+ *
+ * Data:
+ * - lost_signer: {@link PublicKey} The current signer whose key was lost
+ * - new_signer: {@link PublicKey} The key replacing the lost signer
+ * - recovery_request_seed_recovery_id: {@link BigInt} Auto-generated, from the input "recovery_request" for the its seed definition "RecoveryRequest", sets the seed named "recovery_id"
+ */
+export const createRecoveryRequest = (
+	args: CreateRecoveryRequestArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionInstruction> =>
+    createRecoveryRequestBuilder(args, remainingAccounts).instruction();
+
+/**
+ * ### Returns a {@link web3.TransactionSignature}
+ * Request that a lost signer key be replaced, counting as the first recovery key approval
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable]` multisig: {@link MultisigConfig} The multisig account
+ * 2. `[writable]` recovery_request: {@link RecoveryRequest} The recovery request account to initialize
+ * 3. `[signer]` recovery_key: {@link PublicKey} The recovery key creating the request
+ * 4. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
+ *
+ * Data:
+ * - lost_signer: {@link PublicKey} The current signer whose key was lost
+ * - new_signer: {@link PublicKey} The key replacing the lost signer
+ * - recovery_request_seed_recovery_id: {@link BigInt} Auto-generated, from the input "recovery_request" for the its seed definition "RecoveryRequest", sets the seed named "recovery_id"
+ */
+export const createRecoveryRequestSendAndConfirm = async (
+  args: Omit<CreateRecoveryRequestArgs, "feePayer" | "recoveryKey"> & {
+    signers: {
+      feePayer: web3.Signer,
+      recoveryKey: web3.Signer,
+    },
+  },
+  remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionSignature> => {
+  const preInstructions: Array<web3.TransactionInstruction> = [];
+
+
+  return createRecoveryRequestBuilder({
+      ...args,
+      feePayer: args.signers.feePayer.publicKey,
+      recoveryKey: args.signers.recoveryKey.publicKey,
+    }, remainingAccounts)
+    .preInstructions(preInstructions)
+    .signers([args.signers.feePayer, args.signers.recoveryKey])
+    .rpc();
+}
+
+export type ApproveRecoveryRequestArgs = {
+  feePayer: web3.PublicKey;
+  createKey: web3.PublicKey;
+  recoveryKey: web3.PublicKey;
+  recoveryRequestSeedRecoveryId: bigint;
+};
+
+/**
+ * ### Returns a {@link MethodsBuilder}
+ * Approve a pending recovery request as a recovery key
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[]` multisig: {@link MultisigConfig} The multisig account
+ * 2. `[writable]` recovery_request: {@link RecoveryRequest} The recovery request to approve
+ * 3. `[signer]` recovery_key: {@link PublicKey} The recovery key approving the request
+ *
+ * Data:
+ * - recovery_request_seed_recovery_id: {@link BigInt} Auto-generated, from the input "recovery_request" for the its seed definition "RecoveryRequest", sets the seed named "recovery_id"
+ */
+export const approveRecoveryRequestBuilder = (
+	args: ApproveRecoveryRequestArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): MethodsBuilder<MultisigWallet, never> => {
+  const [multisigPubkey] = pda.deriveMultisigPDA({
+        createKey: args.createKey,
+    }, _program.programId);
+    const [recoveryRequestPubkey] = pda.deriveRecoveryRequestPDA({
+        multisig: multisigPubkey,
+        recoveryId: args.recoveryRequestSeedRecoveryId,
+    }, _program.programId);
+
+  return _program
+    .methods
+    .approveRecoveryRequest(
+      new BN(args.recoveryRequestSeedRecoveryId.toString()),
+    )
+    .accountsStrict({
+      feePayer: args.feePayer,
+      multisig: multisigPubkey,
+      recoveryRequest: recoveryRequestPubkey,
+      recoveryKey: args.recoveryKey,
+    })
+    .remainingAccounts(remainingAccounts);
+};
+
+/**
+ * ### Returns a {@link web3.TransactionInstruction}
+ * Approve a pending recovery request as a recovery key
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[]` multisig: {@link MultisigConfig} The multisig account
+ * 2. `[writable]` recovery_request: {@link RecoveryRequest} The recovery request to approve
+ * 3. `[signer]` recovery_key: {@link PublicKey} The recovery key approving the request
+ *
+ * Data:
+ * - recovery_request_seed_recovery_id: {@link BigInt} Auto-generated, from the input "recovery_request" for the its seed definition "RecoveryRequest", sets the seed named "recovery_id"
+ */
+export const approveRecoveryRequest = (
+	args: ApproveRecoveryRequestArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionInstruction> =>
+    approveRecoveryRequestBuilder(args, remainingAccounts).instruction();
+
+/**
+ * ### Returns a {@link web3.TransactionSignature}
+ * Approve a pending recovery request as a recovery key
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[]` multisig: {@link MultisigConfig} The multisig account
+ * 2. `[writable]` recovery_request: {@link RecoveryRequest} The recovery request to approve
+ * 3. `[signer]` recovery_key: {@link PublicKey} The recovery key approving the request
+ *
+ * Data:
+ * - recovery_request_seed_recovery_id: {@link BigInt} Auto-generated, from the input "recovery_request" for the its seed definition "RecoveryRequest", sets the seed named "recovery_id"
+ */
+export const approveRecoveryRequestSendAndConfirm = async (
+  args: Omit<ApproveRecoveryRequestArgs, "feePayer" | "recoveryKey"> & {
+    signers: {
+      feePayer: web3.Signer,
+      recoveryKey: web3.Signer,
+    },
+  },
+  remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionSignature> => {
+  const preInstructions: Array<web3.TransactionInstruction> = [];
+
+
+  return approveRecoveryRequestBuilder({
+      ...args,
+      feePayer: args.signers.feePayer.publicKey,
+      recoveryKey: args.signers.recoveryKey.publicKey,
+    }, remainingAccounts)
+    .preInstructions(preInstructions)
+    .signers([args.signers.feePayer, args.signers.recoveryKey])
+    .rpc();
+}
+
+export type VetoRecoveryRequestArgs = {
+  feePayer: web3.PublicKey;
+  createKey: web3.PublicKey;
+  signer: web3.PublicKey;
+  recoveryRequestSeedRecoveryId: bigint;
+};
+
+/**
+ * ### Returns a {@link MethodsBuilder}
+ * Veto a pending recovery request (any current signer can veto before it is executed)
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[]` multisig: {@link MultisigConfig} The multisig account
+ * 2. `[writable]` recovery_request: {@link RecoveryRequest} The recovery request to veto
+ * 3. `[signer]` signer: {@link PublicKey} The current signer vetoing the request
+ *
+ * Data:
+ * - recovery_request_seed_recovery_id: {@link BigInt} Auto-generated, from the input "recovery_request" for the its seed definition "RecoveryRequest", sets the seed named "recovery_id"
+ */
+export const vetoRecoveryRequestBuilder = (
+	args: VetoRecoveryRequestArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): MethodsBuilder<MultisigWallet, never> => {
+  const [multisigPubkey] = pda.deriveMultisigPDA({
+        createKey: args.createKey,
+    }, _program.programId);
+    const [recoveryRequestPubkey] = pda.deriveRecoveryRequestPDA({
+        multisig: multisigPubkey,
+        recoveryId: args.recoveryRequestSeedRecoveryId,
+    }, _program.programId);
+
+  return _program
+    .methods
+    .vetoRecoveryRequest(
+      new BN(args.recoveryRequestSeedRecoveryId.toString()),
+    )
+    .accountsStrict({
+      feePayer: args.feePayer,
+      multisig: multisigPubkey,
+      recoveryRequest: recoveryRequestPubkey,
+      signer: args.signer,
+    })
+    .remainingAccounts(remainingAccounts);
+};
+
+/**
+ * ### Returns a {@link web3.TransactionInstruction}
+ * Veto a pending recovery request (any current signer can veto before it is executed)
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[]` multisig: {@link MultisigConfig} The multisig account
+ * 2. `[writable]` recovery_request: {@link RecoveryRequest} The recovery request to veto
+ * 3. `[signer]` signer: {@link PublicKey} The current signer vetoing the request
+ *
+ * Data:
+ * - recovery_request_seed_recovery_id: {@link BigInt} Auto-generated, from the input "recovery_request" for the its seed definition "RecoveryRequest", sets the seed named "recovery_id"
+ */
+export const vetoRecoveryRequest = (
+	args: VetoRecoveryRequestArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionInstruction> =>
+    vetoRecoveryRequestBuilder(args, remainingAccounts).instruction();
+
+/**
+ * ### Returns a {@link web3.TransactionSignature}
+ * Veto a pending recovery request (any current signer can veto before it is executed)
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[]` multisig: {@link MultisigConfig} The multisig account
+ * 2. `[writable]` recovery_request: {@link RecoveryRequest} The recovery request to veto
+ * 3. `[signer]` signer: {@link PublicKey} The current signer vetoing the request
+ *
+ * Data:
+ * - recovery_request_seed_recovery_id: {@link BigInt} Auto-generated, from the input "recovery_request" for the its seed definition "RecoveryRequest", sets the seed named "recovery_id"
+ */
+export const vetoRecoveryRequestSendAndConfirm = async (
+  args: Omit<VetoRecoveryRequestArgs, "feePayer" | "signer"> & {
+    signers: {
+      feePayer: web3.Signer,
+      signer: web3.Signer,
+    },
+  },
+  remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionSignature> => {
+  const preInstructions: Array<web3.TransactionInstruction> = [];
+
+
+  return vetoRecoveryRequestBuilder({
+      ...args,
+      feePayer: args.signers.feePayer.publicKey,
+      signer: args.signers.signer.publicKey,
+    }, remainingAccounts)
+    .preInstructions(preInstructions)
+    .signers([args.signers.feePayer, args.signers.signer])
+    .rpc();
+}
+
+export type ExecuteRecoveryRequestArgs = {
+  feePayer: web3.PublicKey;
+  createKey: web3.PublicKey;
+  recoveryRequestSeedRecoveryId: bigint;
+};
+
+/**
+ * ### Returns a {@link MethodsBuilder}
+ * Replace the lost signer once the recovery request is approved and its time lock has elapsed (callable by anyone)
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable]` multisig: {@link MultisigConfig} The multisig account to update
+ * 2. `[writable]` recovery_request: {@link RecoveryRequest} The recovery request to execute
+ *
+ * Data:
+ * - recovery_request_seed_recovery_id: {@link BigInt} Auto-generated, from the input "recovery_request" for the its seed definition "RecoveryRequest", sets the seed named "recovery_id"
+ */
+export const executeRecoveryRequestBuilder = (
+	args: ExecuteRecoveryRequestArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): MethodsBuilder<MultisigWallet, never> => {
+  const [multisigPubkey] = pda.deriveMultisigPDA({
+        createKey: args.createKey,
+    }, _program.programId);
+    const [recoveryRequestPubkey] = pda.deriveRecoveryRequestPDA({
+        multisig: multisigPubkey,
+        recoveryId: args.recoveryRequestSeedRecoveryId,
+    }, _program.programId);
+
+  return _program
+    .methods
+    .executeRecoveryRequest(
+      new BN(args.recoveryRequestSeedRecoveryId.toString()),
+    )
+    .accountsStrict({
+      feePayer: args.feePayer,
+      multisig: multisigPubkey,
+      recoveryRequest: recoveryRequestPubkey,
+    })
+    .remainingAccounts(remainingAccounts);
+};
+
+/**
+ * ### Returns a {@link web3.TransactionInstruction}
+ * Replace the lost signer once the recovery request is approved and its time lock has elapsed (callable by anyone)
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable]` multisig: {@link MultisigConfig} The multisig account to update
+ * 2. `[writable]` recovery_request: {@link RecoveryRequest} The recovery request to execute
+ *
+ * Data:
+ * - recovery_request_seed_recovery_id: {@link BigInt} Auto-generated, from the input "recovery_request" for the its seed definition "RecoveryRequest", sets the seed named "recovery_id"
+ */
+export const executeRecoveryRequest = (
+	args: ExecuteRecoveryRequestArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionInstruction> =>
+    executeRecoveryRequestBuilder(args, remainingAccounts).instruction();
+
+/**
+ * ### Returns a {@link web3.TransactionSignature}
+ * Replace the lost signer once the recovery request is approved and its time lock has elapsed (callable by anyone)
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable]` multisig: {@link MultisigConfig} The multisig account to update
+ * 2. `[writable]` recovery_request: {@link RecoveryRequest} The recovery request to execute
+ *
+ * Data:
+ * - recovery_request_seed_recovery_id: {@link BigInt} Auto-generated, from the input "recovery_request" for the its seed definition "RecoveryRequest", sets the seed named "recovery_id"
+ */
+export const executeRecoveryRequestSendAndConfirm = async (
+  args: Omit<ExecuteRecoveryRequestArgs, "feePayer"> & {
+    signers: {
+      feePayer: web3.Signer,
+    },
+  },
+  remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionSignature> => {
+  const preInstructions: Array<web3.TransactionInstruction> = [];
+
+
+  return executeRecoveryRequestBuilder({
+      ...args,
+      feePayer: args.signers.feePayer.publicKey,
+    }, remainingAccounts)
+    .preInstructions(preInstructions)
+    .signers([args.signers.feePayer])
+    .rpc();
+}
+
+export type CreateProgramUpgradeProposalArgs = {
+  feePayer: web3.PublicKey;
+  createKey: web3.PublicKey;
+  creator: web3.PublicKey;
+  programData: web3.PublicKey;
+  buffer: web3.PublicKey | null;
+  change: IdlTypes<MultisigWallet>["programUpgradeChange"];
+  vaultIndex: number;
+  expirationTime: bigint;
+  proposalSeedProposalId: bigint;
+};
+
+/**
+ * ### Returns a {@link MethodsBuilder}
+ * Create a proposal that upgrades a program, or changes its upgrade authority, through the BPF
+ * upgradeable loader once approved; the program's upgrade authority must be the multisig vault
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable]` multisig: {@link MultisigConfig} The multisig account
+ * 2. `[writable]` proposal: {@link Proposal} The proposal account to initialize
+ * 3. `[signer]` creator: {@link PublicKey} The account creating the proposal (must be a signer of the multisig)
+ * 4. `[]` vault: {@link PublicKey} The multisig vault holding the upgrade authority
+ * 5. `[]` program_data: {@link ProgramData} The program data account of the targeted program
+ * 6. `[optional]` buffer: {@link PublicKey} The buffer holding the new program, required for upgrades
+ * 7. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
+ *
+ * Data:
+ * - change: {@link ProgramUpgradeChange} The upgrade or authority change to perform
+ * - vault_index: {@link number} The vault the proposal acts on
+ * - expiration_time: {@link BigInt} Optional timestamp when the proposal expires (0 means no expiration, or the maximum lifetime when the multisig sets one)
+ * - proposal_seed_proposal_id: {@link BigInt} Auto-generated, from the input "proposal" for the its seed definition "Proposal", sets the seed named "proposal_id"
+ */
+export const createProgramUpgradeProposalBuilder = (
+	args: CreateProgramUpgradeProposalArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): MethodsBuilder<MultisigWallet, never> => {
+  const [multisigPubkey] = pda.deriveMultisigPDA({
+        createKey: args.createKey,
+    }, _program.programId);
+    const [proposalPubkey] = pda.deriveProposalPDA({
+        multisig: multisigPubkey,
+        proposalId: args.proposalSeedProposalId,
+    }, _program.programId);
+    const [vaultPubkey] = pda.deriveVaultPDA({
+        multisig: multisigPubkey,
+        vaultIndex: args.vaultIndex,
+    }, _program.programId);
+
+  return _program
+    .methods
+    .createProgramUpgradeProposal(
+      args.change,
+      args.vaultIndex,
+      new BN(args.expirationTime.toString()),
+      new BN(args.proposalSeedProposalId.toString()),
+    )
+    .accountsStrict({
+      feePayer: args.feePayer,
+      multisig: multisigPubkey,
+      proposal: proposalPubkey,
+      creator: args.creator,
+      vault: vaultPubkey,
+      programData: args.programData,
+      buffer: args.buffer,
+      systemProgram: new web3.PublicKey("11111111111111111111111111111111"),
+    })
+    .remainingAccounts(remainingAccounts);
+};
+
+/**
+ * ### Returns a {@link web3.TransactionInstruction}
+ * Create a proposal that upgrades a program, or changes its upgrade authority, through the BPF
+ * upgradeable loader once approved; the program's upgrade authority must be the multisig vault
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable]` multisig: {@link MultisigConfig} The multisig account
+ * 2. `[writable]` proposal: {@link Proposal} The proposal account to initialize
+ * 3. `[signer]` creator: {@link PublicKey} The account creating the proposal (must be a signer of the multisig)
+ * 4. `[]` vault: {@link PublicKey} The multisig vault holding the upgrade authority
+ * 5. `[]` program_data: {@link ProgramData} The program data account of the targeted program
+ * 6. `[optional]` buffer: {@link PublicKey} The buffer holding the new program, required for upgrades
+ * 7. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
+ *
+ * Data:
+ * - change: {@link ProgramUpgradeChange} The upgrade or authority change to perform
+ * - vault_index: {@link number} The vault the proposal acts on
+ * - expiration_time: {@link BigInt} Optional timestamp when the proposal expires (0 means no expiration, or the maximum lifetime when the multisig sets one)
+ * - proposal_seed_proposal_id: {@link BigInt} Auto-generated, from the input "proposal" for the its seed definition "Proposal", sets the seed named "proposal_id"
+ */
+export const createProgramUpgradeProposal = (
+	args: CreateProgramUpgradeProposalArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionInstruction> =>
+    createProgramUpgradeProposalBuilder(args, remainingAccounts).instruction();
+
+/**
+ * ### Returns a {@link web3.TransactionSignature}
+ * Create a proposal that upgrades a program, or changes its upgrade authority, through the BPF
+ * upgradeable loader once approved; the program's upgrade authority must be the multisig vault
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable]` multisig: {@link MultisigConfig} The multisig account
+ * 2. `[writable]` proposal: {@link Proposal} The proposal account to initialize
+ * 3. `[signer]` creator: {@link PublicKey} The account creating the proposal (must be a signer of the multisig)
+ * 4. `[]` vault: {@link PublicKey} The multisig vault holding the upgrade authority
+ * 5. `[]` program_data: {@link ProgramData} The program data account of the targeted program
+ * 6. `[optional]` buffer: {@link PublicKey} The buffer holding the new program, required for upgrades
+ * 7. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
+ *
+ * Data:
+ * - change: {@link ProgramUpgradeChange} The upgrade or authority change to perform
+ * - vault_index: {@link number} The vault the proposal acts on
+ * - expiration_time: {@link BigInt} Optional timestamp when the proposal expires (0 means no expiration, or the maximum lifetime when the multisig sets one)
+ * - proposal_seed_proposal_id: {@link BigInt} Auto-generated, from the input "proposal" for the its seed definition "Proposal", sets the seed named "proposal_id"
+ */
+export const createProgramUpgradeProposalSendAndConfirm = async (
+  args: Omit<CreateProgramUpgradeProposalArgs, "feePayer" | "creator"> & {
+    signers: {
+      feePayer: web3.Signer,
+      creator: web3.Signer,
+    },
+  },
+  remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionSignature> => {
+  const preInstructions: Array<web3.TransactionInstruction> = [];
+
+
+  return createProgramUpgradeProposalBuilder({
+      ...args,
+      feePayer: args.signers.feePayer.publicKey,
+      creator: args.signers.creator.publicKey,
+    }, remainingAccounts)
+    .preInstructions(preInstructions)
+    .signers([args.signers.feePayer, args.signers.creator])
+    .rpc();
+}
+
+export type ExecuteProgramUpgradeProposalArgs = {
+  feePayer: web3.PublicKey;
+  createKey: web3.PublicKey;
+  program: web3.PublicKey;
+  programData: web3.PublicKey;
+  buffer: web3.PublicKey | null;
+  spill: web3.PublicKey | null;
+  newAuthority: web3.PublicKey | null;
+  proposalSeedProposalId: bigint;
+  vaultIndex: number;
+};
+
+/**
+ * ### Returns a {@link MethodsBuilder}
+ * Apply an approved program upgrade proposal, signing for the upgrade authority with the vault
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[]` multisig: {@link MultisigConfig} The multisig account
+ * 2. `[writable]` proposal: {@link Proposal} The program upgrade proposal to execute
+ * 3. `[]` vault: {@link PublicKey} The multisig vault holding the upgrade authority
+ * 4. `[writable]` program: {@link PublicKey} The program to upgrade
+ * 5. `[writable]` program_data: {@link ProgramData} The program data account of the program
+ * 6. `[writable, optional]` buffer: {@link PublicKey} The buffer holding the new program, required for upgrades
+ * 7. `[writable, optional]` spill: {@link PublicKey} Receives the buffer's lamports, required for upgrades
+ * 8. `[optional]` new_authority: {@link PublicKey} The new upgrade authority, required when one is set
+ * 9. `[]` rent: {@link PublicKey} Rent sysvar
+ * 10. `[]` clock: {@link PublicKey} Clock sysvar
+ * 11. `[]` bpf_loader_upgradeable: {@link PublicKey} BPF upgradeable loader program
+ *
+ * Data:
+ * - proposal_seed_proposal_id: {@link BigInt} Auto-generated, from the input "proposal" for the its seed definition "Proposal", sets the seed named "proposal_id"
+ */
+export const executeProgramUpgradeProposalBuilder = (
+	args: ExecuteProgramUpgradeProposalArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): MethodsBuilder<MultisigWallet, never> => {
+  const [multisigPubkey] = pda.deriveMultisigPDA({
+        createKey: args.createKey,
+    }, _program.programId);
+    const [proposalPubkey] = pda.deriveProposalPDA({
+        multisig: multisigPubkey,
+        proposalId: args.proposalSeedProposalId,
+    }, _program.programId);
+    const [vaultPubkey] = pda.deriveVaultPDA({
+        multisig: multisigPubkey,
+        vaultIndex: args.vaultIndex,
+    }, _program.programId);
+
+  return _program
+    .methods
+    .executeProgramUpgradeProposal(
+      new BN(args.proposalSeedProposalId.toString()),
+    )
+    .accountsStrict({
+      feePayer: args.feePayer,
+      multisig: multisigPubkey,
+      proposal: proposalPubkey,
+      vault: vaultPubkey,
+      program: args.program,
+      programData: args.programData,
+      buffer: args.buffer,
+      spill: args.spill,
+      newAuthority: args.newAuthority,
+      rent: new web3.PublicKey("SysvarRent111111111111111111111111111111111"),
+      clock: new web3.PublicKey("SysvarC1ock11111111111111111111111111111111"),
+      bpfLoaderUpgradeable: new web3.PublicKey("BPFLoaderUpgradeab1e11111111111111111111111"),
+    })
+    .remainingAccounts(remainingAccounts);
+};
+
+/**
+ * ### Returns a {@link web3.TransactionInstruction}
+ * Apply an approved program upgrade proposal, signing for the upgrade authority with the vault
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[]` multisig: {@link MultisigConfig} The multisig account
+ * 2. `[writable]` proposal: {@link Proposal} The program upgrade proposal to execute
+ * 3. `[]` vault: {@link PublicKey} The multisig vault holding the upgrade authority
+ * 4. `[writable]` program: {@link PublicKey} The program to upgrade
+ * 5. `[writable]` program_data: {@link ProgramData} The program data account of the program
+ * 6. `[writable, optional]` buffer: {@link PublicKey} The buffer holding the new program, required for upgrades
+ * 7. `[writable, optional]` spill: {@link PublicKey} Receives the buffer's lamports, required for upgrades
+ * 8. `[optional]` new_authority: {@link PublicKey} The new upgrade authority, required when one is set
+ * 9. `[]` rent: {@link PublicKey} Rent sysvar
+ * 10. `[]` clock: {@link PublicKey} Clock sysvar
+ * 11. `[]` bpf_loader_upgradeable: {@link PublicKey} BPF upgradeable loader program
+ *
+ * Data:
+ * - proposal_seed_proposal_id: {@link BigInt} Auto-generated, from the input "proposal" for the its seed definition "Proposal", sets the seed named "proposal_id"
+ */
+export const executeProgramUpgradeProposal = (
+	args: ExecuteProgramUpgradeProposalArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionInstruction> =>
+    executeProgramUpgradeProposalBuilder(args, remainingAccounts).instruction();
+
+/**
+ * ### Returns a {@link web3.TransactionSignature}
+ * Apply an approved program upgrade proposal, signing for the upgrade authority with the vault
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[]` multisig: {@link MultisigConfig} The multisig account
+ * 2. `[writable]` proposal: {@link Proposal} The program upgrade proposal to execute
+ * 3. `[]` vault: {@link PublicKey} The multisig vault holding the upgrade authority
+ * 4. `[writable]` program: {@link PublicKey} The program to upgrade
+ * 5. `[writable]` program_data: {@link ProgramData} The program data account of the program
+ * 6. `[writable, optional]` buffer: {@link PublicKey} The buffer holding the new program, required for upgrades
+ * 7. `[writable, optional]` spill: {@link PublicKey} Receives the buffer's lamports, required for upgrades
+ * 8. `[optional]` new_authority: {@link PublicKey} The new upgrade authority, required when one is set
+ * 9. `[]` rent: {@link PublicKey} Rent sysvar
+ * 10. `[]` clock: {@link PublicKey} Clock sysvar
+ * 11. `[]` bpf_loader_upgradeable: {@link PublicKey} BPF upgradeable loader program
+ *
+ * Data:
+ * - proposal_seed_proposal_id: {@link BigInt} Auto-generated, from the input "proposal" for the its seed definition "Proposal", sets the seed named "proposal_id"
+ */
+export const executeProgramUpgradeProposalSendAndConfirm = async (
+  args: Omit<ExecuteProgramUpgradeProposalArgs, "feePayer"> & {
+    signers: {
+      feePayer: web3.Signer,
+    },
+  },
+  remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionSignature> => {
+  const preInstructions: Array<web3.TransactionInstruction> = [];
+
+
+  return executeProgramUpgradeProposalBuilder({
+      ...args,
+      feePayer: args.signers.feePayer.publicKey,
+    }, remainingAccounts)
+    .preInstructions(preInstructions)
+    .signers([args.signers.feePayer])
+    .rpc();
+}
+
+export type CreateStakeProposalArgs = {
+  feePayer: web3.PublicKey;
+  createKey: web3.PublicKey;
+  creator: web3.PublicKey;
+  addressBook: web3.PublicKey | null;
+  change: IdlTypes<MultisigWallet>["stakeChange"];
+  vaultIndex: number;
+  expirationTime: bigint;
+  proposalSeedProposalId: bigint;
+};
+
+/**
+ * ### Returns a {@link MethodsBuilder}
+ * Create a proposal that creates, delegates, deactivates or withdraws from a vault stake account
+ * once approved
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable]` multisig: {@link MultisigConfig} The multisig account
+ * 2. `[writable]` proposal: {@link Proposal} The proposal account to initialize
+ * 3. `[signer]` creator: {@link PublicKey} The account creating the proposal (must be a signer of the multisig)
+ * 4. `[optional]` address_book: {@link AddressBook} The multisig's address book, required for withdrawals when the multisig enforces it
+ * 5. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
+ *
+ * Data:
+ * - change: {@link StakeChange} The stake action to perform
+ * - vault_index: {@link number} The vault the proposal acts on
+ * - expiration_time: {@link BigInt} Optional timestamp when the proposal expires (0 means no expiration, or the maximum lifetime when the multisig sets one)
+ * - proposal_seed_proposal_id: {@link BigInt} Auto-generated, from the input "proposal" for the its seed definition "Proposal", sets the seed named "proposal_id"
+ */
+export const createStakeProposalBuilder = (
+	args: CreateStakeProposalArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): MethodsBuilder<MultisigWallet, never> => {
+  const [multisigPubkey] = pda.deriveMultisigPDA({
+        createKey: args.createKey,
+    }, _program.programId);
+    const [proposalPubkey] = pda.deriveProposalPDA({
+        multisig: multisigPubkey,
+        proposalId: args.proposalSeedProposalId,
+    }, _program.programId);
+
+  return _program
+    .methods
+    .createStakeProposal(
+      args.change,
+      args.vaultIndex,
+      new BN(args.expirationTime.toString()),
+      new BN(args.proposalSeedProposalId.toString()),
+    )
+    .accountsStrict({
+      feePayer: args.feePayer,
+      multisig: multisigPubkey,
+      proposal: proposalPubkey,
+      creator: args.creator,
+      addressBook: args.addressBook,
+      systemProgram: new web3.PublicKey("11111111111111111111111111111111"),
+    })
+    .remainingAccounts(remainingAccounts);
+};
+
+/**
+ * ### Returns a {@link web3.TransactionInstruction}
+ * Create a proposal that creates, delegates, deactivates or withdraws from a vault stake account
+ * once approved
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable]` multisig: {@link MultisigConfig} The multisig account
+ * 2. `[writable]` proposal: {@link Proposal} The proposal account to initialize
+ * 3. `[signer]` creator: {@link PublicKey} The account creating the proposal (must be a signer of the multisig)
+ * 4. `[optional]` address_book: {@link AddressBook} The multisig's address book, required for withdrawals when the multisig enforces it
+ * 5. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
+ *
+ * Data:
+ * - change: {@link StakeChange} The stake action to perform
+ * - vault_index: {@link number} The vault the proposal acts on
+ * - expiration_time: {@link BigInt} Optional timestamp when the proposal expires (0 means no expiration, or the maximum lifetime when the multisig sets one)
+ * - proposal_seed_proposal_id: {@link BigInt} Auto-generated, from the input "proposal" for the its seed definition "Proposal", sets the seed named "proposal_id"
+ */
+export const createStakeProposal = (
+	args: CreateStakeProposalArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionInstruction> =>
+    createStakeProposalBuilder(args, remainingAccounts).instruction();
+
+/**
+ * ### Returns a {@link web3.TransactionSignature}
+ * Create a proposal that creates, delegates, deactivates or withdraws from a vault stake account
+ * once approved
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable]` multisig: {@link MultisigConfig} The multisig account
+ * 2. `[writable]` proposal: {@link Proposal} The proposal account to initialize
+ * 3. `[signer]` creator: {@link PublicKey} The account creating the proposal (must be a signer of the multisig)
+ * 4. `[optional]` address_book: {@link AddressBook} The multisig's address book, required for withdrawals when the multisig enforces it
+ * 5. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
+ *
+ * Data:
+ * - change: {@link StakeChange} The stake action to perform
+ * - vault_index: {@link number} The vault the proposal acts on
+ * - expiration_time: {@link BigInt} Optional timestamp when the proposal expires (0 means no expiration, or the maximum lifetime when the multisig sets one)
+ * - proposal_seed_proposal_id: {@link BigInt} Auto-generated, from the input "proposal" for the its seed definition "Proposal", sets the seed named "proposal_id"
+ */
+export const createStakeProposalSendAndConfirm = async (
+  args: Omit<CreateStakeProposalArgs, "feePayer" | "creator"> & {
+    signers: {
+      feePayer: web3.Signer,
+      creator: web3.Signer,
+    },
+  },
+  remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionSignature> => {
+  const preInstructions: Array<web3.TransactionInstruction> = [];
+
+
+  return createStakeProposalBuilder({
+      ...args,
+      feePayer: args.signers.feePayer.publicKey,
+      creator: args.signers.creator.publicKey,
+    }, remainingAccounts)
+    .preInstructions(preInstructions)
+    .signers([args.signers.feePayer, args.signers.creator])
+    .rpc();
+}
+
+export type ExecuteStakeProposalArgs = {
+  feePayer: web3.PublicKey;
+  createKey: web3.PublicKey;
+  stakeAccount: web3.PublicKey;
+  voteAccount: web3.PublicKey | null;
+  destination: web3.PublicKey | null;
+  proposalSeedProposalId: bigint;
+  vaultIndex: number;
+};
+
+/**
+ * ### Returns a {@link MethodsBuilder}
+ * Apply an approved stake proposal through the stake program, signing as staker and withdrawer
+ * with the vault
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[]` multisig: {@link MultisigConfig} The multisig account
+ * 2. `[writable]` proposal: {@link Proposal} The stake proposal to execute
+ * 3. `[writable]` vault: {@link PublicKey} The multisig vault funding and controlling the stake accounts
+ * 4. `[writable]` stake_account: {@link PublicKey} The stake account to create or act on
+ * 5. `[optional]` vote_account: {@link PublicKey} The validator vote account, required for delegations
+ * 6. `[writable, optional]` destination: {@link PublicKey} Receives withdrawn lamports, required for withdrawals
+ * 7. `[]` rent: {@link PublicKey} Rent sysvar
+ * 8. `[]` clock: {@link PublicKey} Clock sysvar
+ * 9. `[]` stake_history: {@link PublicKey} Stake history sysvar
+ * 10. `[]` stake_config: {@link PublicKey} Stake config account
+ * 11. `[]` system_program: {@link PublicKey} System program
+ * 12. `[]` stake_program: {@link PublicKey} Stake program
+ *
+ * Data:
+ * - proposal_seed_proposal_id: {@link BigInt} Auto-generated, from the input "proposal" for the its seed definition "Proposal", sets the seed named "proposal_id"
+ */
+export const executeStakeProposalBuilder = (
+	args: ExecuteStakeProposalArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): MethodsBuilder<MultisigWallet, never> => {
+  const [multisigPubkey] = pda.deriveMultisigPDA({
+        createKey: args.createKey,
+    }, _program.programId);
+    const [proposalPubkey] = pda.deriveProposalPDA({
+        multisig: multisigPubkey,
+        proposalId: args.proposalSeedProposalId,
+    }, _program.programId);
+    const [vaultPubkey] = pda.deriveVaultPDA({
+        multisig: multisigPubkey,
+        vaultIndex: args.vaultIndex,
+    }, _program.programId);
+
+  return _program
+    .methods
+    .executeStakeProposal(
+      new BN(args.proposalSeedProposalId.toString()),
+    )
+    .accountsStrict({
+      feePayer: args.feePayer,
+      multisig: multisigPubkey,
+      proposal: proposalPubkey,
+      vault: vaultPubkey,
+      stakeAccount: args.stakeAccount,
+      voteAccount: args.voteAccount,
+      destination: args.destination,
+      rent: new web3.PublicKey("SysvarRent111111111111111111111111111111111"),
+      clock: new web3.PublicKey("SysvarC1ock11111111111111111111111111111111"),
+      stakeHistory: new web3.PublicKey("SysvarStakeHistory1111111111111111111111111"),
+      stakeConfig: new web3.PublicKey("StakeConfig11111111111111111111111111111111"),
+      systemProgram: new web3.PublicKey("11111111111111111111111111111111"),
+      stakeProgram: new web3.PublicKey("Stake11111111111111111111111111111111111111"),
+    })
+    .remainingAccounts(remainingAccounts);
+};
+
+/**
+ * ### Returns a {@link web3.TransactionInstruction}
+ * Apply an approved stake proposal through the stake program, signing as staker and withdrawer
+ * with the vault
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[]` multisig: {@link MultisigConfig} The multisig account
+ * 2. `[writable]` proposal: {@link Proposal} The stake proposal to execute
+ * 3. `[writable]` vault: {@link PublicKey} The multisig vault funding and controlling the stake accounts
+ * 4. `[writable]` stake_account: {@link PublicKey} The stake account to create or act on
+ * 5. `[optional]` vote_account: {@link PublicKey} The validator vote account, required for delegations
+ * 6. `[writable, optional]` destination: {@link PublicKey} Receives withdrawn lamports, required for withdrawals
+ * 7. `[]` rent: {@link PublicKey} Rent sysvar
+ * 8. `[]` clock: {@link PublicKey} Clock sysvar
+ * 9. `[]` stake_history: {@link PublicKey} Stake history sysvar
+ * 10. `[]` stake_config: {@link PublicKey} Stake config account
+ * 11. `[]` system_program: {@link PublicKey} System program
+ * 12. `[]` stake_program: {@link PublicKey} Stake program
+ *
+ * Data:
+ * - proposal_seed_proposal_id: {@link BigInt} Auto-generated, from the input "proposal" for the its seed definition "Proposal", sets the seed named "proposal_id"
+ */
+export const executeStakeProposal = (
+	args: ExecuteStakeProposalArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionInstruction> =>
+    executeStakeProposalBuilder(args, remainingAccounts).instruction();
+
+/**
+ * ### Returns a {@link web3.TransactionSignature}
+ * Apply an approved stake proposal through the stake program, signing as staker and withdrawer
+ * with the vault
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[]` multisig: {@link MultisigConfig} The multisig account
+ * 2. `[writable]` proposal: {@link Proposal} The stake proposal to execute
+ * 3. `[writable]` vault: {@link PublicKey} The multisig vault funding and controlling the stake accounts
+ * 4. `[writable]` stake_account: {@link PublicKey} The stake account to create or act on
+ * 5. `[optional]` vote_account: {@link PublicKey} The validator vote account, required for delegations
+ * 6. `[writable, optional]` destination: {@link PublicKey} Receives withdrawn lamports, required for withdrawals
+ * 7. `[]` rent: {@link PublicKey} Rent sysvar
+ * 8. `[]` clock: {@link PublicKey} Clock sysvar
+ * 9. `[]` stake_history: {@link PublicKey} Stake history sysvar
+ * 10. `[]` stake_config: {@link PublicKey} Stake config account
+ * 11. `[]` system_program: {@link PublicKey} System program
+ * 12. `[]` stake_program: {@link PublicKey} Stake program
+ *
+ * Data:
+ * - proposal_seed_proposal_id: {@link BigInt} Auto-generated, from the input "proposal" for the its seed definition "Proposal", sets the seed named "proposal_id"
+ */
+export const executeStakeProposalSendAndConfirm = async (
+  args: Omit<ExecuteStakeProposalArgs, "feePayer"> & {
+    signers: {
+      feePayer: web3.Signer,
+    },
+  },
+  remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionSignature> => {
+  const preInstructions: Array<web3.TransactionInstruction> = [];
+
+
+  return executeStakeProposalBuilder({
+      ...args,
+      feePayer: args.signers.feePayer.publicKey,
+    }, remainingAccounts)
+    .preInstructions(preInstructions)
+    .signers([args.signers.feePayer])
+    .rpc();
+}
+
+export type CreatePaymentScheduleProposalArgs = {
+  feePayer: web3.PublicKey;
+  createKey: web3.PublicKey;
+  creator: web3.PublicKey;
+  addressBook: web3.PublicKey | null;
+  terms: IdlTypes<MultisigWallet>["paymentScheduleTerms"];
+  vaultIndex: number;
+  expirationTime: bigint;
+  proposalSeedProposalId: bigint;
+};
+
+/**
+ * ### Returns a {@link MethodsBuilder}
+ * Create a proposal that sets up a recurring payment schedule once approved
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable]` multisig: {@link MultisigConfig} The multisig account
+ * 2. `[writable]` proposal: {@link Proposal} The proposal account to initialize
+ * 3. `[signer]` creator: {@link PublicKey} The account creating the proposal (must be a signer of the multisig)
+ * 4. `[optional]` address_book: {@link AddressBook} The multisig's address book, required when the multisig enforces it
+ * 5. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
+ *
+ * Data:
+ * - terms: {@link PaymentScheduleTerms} The destination, mint, amount, interval and number of payments
+ * - vault_index: {@link number} The vault the proposal acts on
+ * - expiration_time: {@link BigInt} Optional timestamp when the proposal expires (0 means no expiration, or the maximum lifetime when the multisig sets one)
+ * - proposal_seed_proposal_id: {@link BigInt} Auto-generated, from the input "proposal" for the its seed definition "Proposal", sets the seed named "proposal_id"
+ */
+export const createPaymentScheduleProposalBuilder = (
+	args: CreatePaymentScheduleProposalArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): MethodsBuilder<MultisigWallet, never> => {
+  const [multisigPubkey] = pda.deriveMultisigPDA({
+        createKey: args.createKey,
+    }, _program.programId);
+    const [proposalPubkey] = pda.deriveProposalPDA({
+        multisig: multisigPubkey,
+        proposalId: args.proposalSeedProposalId,
+    }, _program.programId);
+
+  return _program
+    .methods
+    .createPaymentScheduleProposal(
+      args.terms,
+      args.vaultIndex,
+      new BN(args.expirationTime.toString()),
+      new BN(args.proposalSeedProposalId.toString()),
+    )
+    .accountsStrict({
+      feePayer: args.feePayer,
+      multisig: multisigPubkey,
+      proposal: proposalPubkey,
+      creator: args.creator,
+      addressBook: args.addressBook,
+      systemProgram: new web3.PublicKey("11111111111111111111111111111111"),
+    })
+    .remainingAccounts(remainingAccounts);
+};
+
+/**
+ * ### Returns a {@link web3.TransactionInstruction}
+ * Create a proposal that sets up a recurring payment schedule once approved
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable]` multisig: {@link MultisigConfig} The multisig account
+ * 2. `[writable]` proposal: {@link Proposal} The proposal account to initialize
+ * 3. `[signer]` creator: {@link PublicKey} The account creating the proposal (must be a signer of the multisig)
+ * 4. `[optional]` address_book: {@link AddressBook} The multisig's address book, required when the multisig enforces it
+ * 5. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
+ *
+ * Data:
+ * - terms: {@link PaymentScheduleTerms} The destination, mint, amount, interval and number of payments
+ * - vault_index: {@link number} The vault the proposal acts on
+ * - expiration_time: {@link BigInt} Optional timestamp when the proposal expires (0 means no expiration, or the maximum lifetime when the multisig sets one)
+ * - proposal_seed_proposal_id: {@link BigInt} Auto-generated, from the input "proposal" for the its seed definition "Proposal", sets the seed named "proposal_id"
+ */
+export const createPaymentScheduleProposal = (
+	args: CreatePaymentScheduleProposalArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionInstruction> =>
+    createPaymentScheduleProposalBuilder(args, remainingAccounts).instruction();
+
+/**
+ * ### Returns a {@link web3.TransactionSignature}
+ * Create a proposal that sets up a recurring payment schedule once approved
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable]` multisig: {@link MultisigConfig} The multisig account
+ * 2. `[writable]` proposal: {@link Proposal} The proposal account to initialize
+ * 3. `[signer]` creator: {@link PublicKey} The account creating the proposal (must be a signer of the multisig)
+ * 4. `[optional]` address_book: {@link AddressBook} The multisig's address book, required when the multisig enforces it
+ * 5. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
+ *
+ * Data:
+ * - terms: {@link PaymentScheduleTerms} The destination, mint, amount, interval and number of payments
+ * - vault_index: {@link number} The vault the proposal acts on
+ * - expiration_time: {@link BigInt} Optional timestamp when the proposal expires (0 means no expiration, or the maximum lifetime when the multisig sets one)
+ * - proposal_seed_proposal_id: {@link BigInt} Auto-generated, from the input "proposal" for the its seed definition "Proposal", sets the seed named "proposal_id"
+ */
+export const createPaymentScheduleProposalSendAndConfirm = async (
+  args: Omit<CreatePaymentScheduleProposalArgs, "feePayer" | "creator"> & {
+    signers: {
+      feePayer: web3.Signer,
+      creator: web3.Signer,
+    },
+  },
+  remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionSignature> => {
+  const preInstructions: Array<web3.TransactionInstruction> = [];
+
+
+  return createPaymentScheduleProposalBuilder({
+      ...args,
+      feePayer: args.signers.feePayer.publicKey,
+      creator: args.signers.creator.publicKey,
+    }, remainingAccounts)
+    .preInstructions(preInstructions)
+    .signers([args.signers.feePayer, args.signers.creator])
+    .rpc();
+}
+
+export type ExecutePaymentScheduleProposalArgs = {
+  feePayer: web3.PublicKey;
+  createKey: web3.PublicKey;
+  proposalSeedProposalId: bigint;
+};
+
+/**
+ * ### Returns a {@link MethodsBuilder}
+ * Create the payment schedule of an approved payment schedule proposal
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[]` multisig: {@link MultisigConfig} The multisig account
+ * 2. `[writable]` proposal: {@link Proposal} The payment schedule proposal to execute
+ * 3. `[writable]` payment_schedule: {@link PaymentSchedule} The payment schedule account to initialize
+ * 4. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
+ *
+ * Data:
+ * - proposal_seed_proposal_id: {@link BigInt} Auto-generated, from the input "proposal" for the its seed definition "Proposal", sets the seed named "proposal_id"
+ */
+export const executePaymentScheduleProposalBuilder = (
+	args: ExecutePaymentScheduleProposalArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): MethodsBuilder<MultisigWallet, never> => {
+  const [multisigPubkey] = pda.deriveMultisigPDA({
+        createKey: args.createKey,
+    }, _program.programId);
+    const [proposalPubkey] = pda.deriveProposalPDA({
+        multisig: multisigPubkey,
+        proposalId: args.proposalSeedProposalId,
+    }, _program.programId);
+    const [paymentSchedulePubkey] = pda.derivePaymentSchedulePDA({
+        multisig: multisigPubkey,
+        scheduleId: args.proposalSeedProposalId,
+    }, _program.programId);
+
+  return _program
+    .methods
+    .executePaymentScheduleProposal(
+      new BN(args.proposalSeedProposalId.toString()),
+    )
+    .accountsStrict({
+      feePayer: args.feePayer,
+      multisig: multisigPubkey,
+      proposal: proposalPubkey,
+      paymentSchedule: paymentSchedulePubkey,
+      systemProgram: new web3.PublicKey("11111111111111111111111111111111"),
+    })
+    .remainingAccounts(remainingAccounts);
+};
+
+/**
+ * ### Returns a {@link web3.TransactionInstruction}
+ * Create the payment schedule of an approved payment schedule proposal
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[]` multisig: {@link MultisigConfig} The multisig account
+ * 2. `[writable]` proposal: {@link Proposal} The payment schedule proposal to execute
+ * 3. `[writable]` payment_schedule: {@link PaymentSchedule} The payment schedule account to initialize
+ * 4. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
+ *
+ * Data:
+ * - proposal_seed_proposal_id: {@link BigInt} Auto-generated, from the input "proposal" for the its seed definition "Proposal", sets the seed named "proposal_id"
+ */
+export const executePaymentScheduleProposal = (
+	args: ExecutePaymentScheduleProposalArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionInstruction> =>
+    executePaymentScheduleProposalBuilder(args, remainingAccounts).instruction();
+
+/**
+ * ### Returns a {@link web3.TransactionSignature}
+ * Create the payment schedule of an approved payment schedule proposal
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[]` multisig: {@link MultisigConfig} The multisig account
+ * 2. `[writable]` proposal: {@link Proposal} The payment schedule proposal to execute
+ * 3. `[writable]` payment_schedule: {@link PaymentSchedule} The payment schedule account to initialize
+ * 4. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
+ *
+ * Data:
+ * - proposal_seed_proposal_id: {@link BigInt} Auto-generated, from the input "proposal" for the its seed definition "Proposal", sets the seed named "proposal_id"
+ */
+export const executePaymentScheduleProposalSendAndConfirm = async (
+  args: Omit<ExecutePaymentScheduleProposalArgs, "feePayer"> & {
+    signers: {
+      feePayer: web3.Signer,
+    },
+  },
+  remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionSignature> => {
+  const preInstructions: Array<web3.TransactionInstruction> = [];
+
+
+  return executePaymentScheduleProposalBuilder({
+      ...args,
+      feePayer: args.signers.feePayer.publicKey,
+    }, remainingAccounts)
+    .preInstructions(preInstructions)
+    .signers([args.signers.feePayer])
+    .rpc();
+}
+
+export type CrankScheduleArgs = {
+  feePayer: web3.PublicKey;
+  createKey: web3.PublicKey;
+  source: web3.PublicKey;
+  destination: web3.PublicKey;
+  mint: web3.PublicKey;
+  transferPolicy: web3.PublicKey | null;
+  paymentScheduleSeedScheduleId: bigint;
+  vaultIndex: number;
+};
+
+/**
+ * ### Returns a {@link MethodsBuilder}
+ * Pay the next due payment of a payment schedule from the vault (callable by anyone)
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[]` multisig: {@link MultisigConfig} The multisig account
+ * 2. `[writable]` payment_schedule: {@link PaymentSchedule} The payment schedule to crank
+ * 3. `[]` vault: {@link PublicKey} The multisig vault paying the schedule
+ * 4. `[writable]` source: {@link Account} The vault's associated token account for the mint
+ * 5. `[writable]` destination: {@link Account} A token account of the schedule's mint owned by the schedule's destination
+ * 6. `[]` mint: {@link Mint} The mint named in the schedule
+ * 7. `[]` token_program: {@link PublicKey} SPL Token program
+ * 8. `[writable, optional]` transfer_policy: {@link TransferPolicy} The multisig's transfer policy, required when it is enabled
+ *
+ * Data:
+ * - payment_schedule_seed_schedule_id: {@link BigInt} Auto-generated, from the input "payment_schedule" for the its seed definition "PaymentSchedule", sets the seed named "schedule_id"
+ */
+export const crankScheduleBuilder = (
+	args: CrankScheduleArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): MethodsBuilder<MultisigWallet, never> => {
+  const [multisigPubkey] = pda.deriveMultisigPDA({
+        createKey: args.createKey,
+    }, _program.programId);
+    const [paymentSchedulePubkey] = pda.derivePaymentSchedulePDA({
+        multisig: multisigPubkey,
+        scheduleId: args.paymentScheduleSeedScheduleId,
+    }, _program.programId);
+    const [vaultPubkey] = pda.deriveVaultPDA({
+        multisig: multisigPubkey,
+        vaultIndex: args.vaultIndex,
+    }, _program.programId);
+
+  return _program
+    .methods
+    .crankSchedule(
+      new BN(args.paymentScheduleSeedScheduleId.toString()),
+    )
+    .accountsStrict({
+      feePayer: args.feePayer,
+      multisig: multisigPubkey,
+      paymentSchedule: paymentSchedulePubkey,
+      vault: vaultPubkey,
+      source: args.source,
+      destination: args.destination,
+      mint: args.mint,
+      tokenProgram: new web3.PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"),
+      transferPolicy: args.transferPolicy,
+    })
+    .remainingAccounts(remainingAccounts);
+};
+
+/**
+ * ### Returns a {@link web3.TransactionInstruction}
+ * Pay the next due payment of a payment schedule from the vault (callable by anyone)
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[]` multisig: {@link MultisigConfig} The multisig account
+ * 2. `[writable]` payment_schedule: {@link PaymentSchedule} The payment schedule to crank
+ * 3. `[]` vault: {@link PublicKey} The multisig vault paying the schedule
+ * 4. `[writable]` source: {@link Account} The vault's associated token account for the mint
+ * 5. `[writable]` destination: {@link Account} A token account of the schedule's mint owned by the schedule's destination
+ * 6. `[]` mint: {@link Mint} The mint named in the schedule
+ * 7. `[]` token_program: {@link PublicKey} SPL Token program
+ * 8. `[writable, optional]` transfer_policy: {@link TransferPolicy} The multisig's transfer policy, required when it is enabled
+ *
+ * Data:
+ * - payment_schedule_seed_schedule_id: {@link BigInt} Auto-generated, from the input "payment_schedule" for the its seed definition "PaymentSchedule", sets the seed named "schedule_id"
+ */
+export const crankSchedule = (
+	args: CrankScheduleArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionInstruction> =>
+    crankScheduleBuilder(args, remainingAccounts).instruction();
+
+/**
+ * ### Returns a {@link web3.TransactionSignature}
+ * Pay the next due payment of a payment schedule from the vault (callable by anyone)
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[]` multisig: {@link MultisigConfig} The multisig account
+ * 2. `[writable]` payment_schedule: {@link PaymentSchedule} The payment schedule to crank
+ * 3. `[]` vault: {@link PublicKey} The multisig vault paying the schedule
+ * 4. `[writable]` source: {@link Account} The vault's associated token account for the mint
+ * 5. `[writable]` destination: {@link Account} A token account of the schedule's mint owned by the schedule's destination
+ * 6. `[]` mint: {@link Mint} The mint named in the schedule
+ * 7. `[]` token_program: {@link PublicKey} SPL Token program
+ * 8. `[writable, optional]` transfer_policy: {@link TransferPolicy} The multisig's transfer policy, required when it is enabled
+ *
+ * Data:
+ * - payment_schedule_seed_schedule_id: {@link BigInt} Auto-generated, from the input "payment_schedule" for the its seed definition "PaymentSchedule", sets the seed named "schedule_id"
+ */
+export const crankScheduleSendAndConfirm = async (
+  args: Omit<CrankScheduleArgs, "feePayer"> & {
+    signers: {
+      feePayer: web3.Signer,
+    },
+  },
+  remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionSignature> => {
+  const preInstructions: Array<web3.TransactionInstruction> = [];
+
+
+  return crankScheduleBuilder({
+      ...args,
+      feePayer: args.signers.feePayer.publicKey,
+    }, remainingAccounts)
+    .preInstructions(preInstructions)
+    .signers([args.signers.feePayer])
+    .rpc();
+}
+
+export type CreateVestingStreamProposalArgs = {
+  feePayer: web3.PublicKey;
+  createKey: web3.PublicKey;
+  creator: web3.PublicKey;
+  addressBook: web3.PublicKey | null;
+  terms: IdlTypes<MultisigWallet>["vestingStreamTerms"];
+  vaultIndex: number;
+  expirationTime: bigint;
+  proposalSeedProposalId: bigint;
+};
+
+/**
+ * ### Returns a {@link MethodsBuilder}
+ * Create a proposal that funds a linear vesting stream from the vault once approved
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable]` multisig: {@link MultisigConfig} The multisig account
+ * 2. `[writable]` proposal: {@link Proposal} The proposal account to initialize
+ * 3. `[signer]` creator: {@link PublicKey} The account creating the proposal (must be a signer of the multisig)
+ * 4. `[optional]` address_book: {@link AddressBook} The multisig's address book, required when the multisig enforces it
+ * 5. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
+ *
+ * Data:
+ * - terms: {@link VestingStreamTerms} The recipient, mint, total amount and start, cliff and end times
+ * - vault_index: {@link number} The vault the proposal acts on
+ * - expiration_time: {@link BigInt} Optional timestamp when the proposal expires (0 means no expiration, or the maximum lifetime when the multisig sets one)
+ * - proposal_seed_proposal_id: {@link BigInt} Auto-generated, from the input "proposal" for the its seed definition "Proposal", sets the seed named "proposal_id"
+ */
+export const createVestingStreamProposalBuilder = (
+	args: CreateVestingStreamProposalArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): MethodsBuilder<MultisigWallet, never> => {
+  const [multisigPubkey] = pda.deriveMultisigPDA({
+        createKey: args.createKey,
+    }, _program.programId);
+    const [proposalPubkey] = pda.deriveProposalPDA({
+        multisig: multisigPubkey,
+        proposalId: args.proposalSeedProposalId,
+    }, _program.programId);
+
+  return _program
+    .methods
+    .createVestingStreamProposal(
+      args.terms,
+      args.vaultIndex,
+      new BN(args.expirationTime.toString()),
+      new BN(args.proposalSeedProposalId.toString()),
+    )
+    .accountsStrict({
+      feePayer: args.feePayer,
+      multisig: multisigPubkey,
+      proposal: proposalPubkey,
+      creator: args.creator,
+      addressBook: args.addressBook,
+      systemProgram: new web3.PublicKey("11111111111111111111111111111111"),
+    })
+    .remainingAccounts(remainingAccounts);
+};
+
+/**
+ * ### Returns a {@link web3.TransactionInstruction}
+ * Create a proposal that funds a linear vesting stream from the vault once approved
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable]` multisig: {@link MultisigConfig} The multisig account
+ * 2. `[writable]` proposal: {@link Proposal} The proposal account to initialize
+ * 3. `[signer]` creator: {@link PublicKey} The account creating the proposal (must be a signer of the multisig)
+ * 4. `[optional]` address_book: {@link AddressBook} The multisig's address book, required when the multisig enforces it
+ * 5. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
+ *
+ * Data:
+ * - terms: {@link VestingStreamTerms} The recipient, mint, total amount and start, cliff and end times
+ * - vault_index: {@link number} The vault the proposal acts on
+ * - expiration_time: {@link BigInt} Optional timestamp when the proposal expires (0 means no expiration, or the maximum lifetime when the multisig sets one)
+ * - proposal_seed_proposal_id: {@link BigInt} Auto-generated, from the input "proposal" for the its seed definition "Proposal", sets the seed named "proposal_id"
+ */
+export const createVestingStreamProposal = (
+	args: CreateVestingStreamProposalArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionInstruction> =>
+    createVestingStreamProposalBuilder(args, remainingAccounts).instruction();
+
+/**
+ * ### Returns a {@link web3.TransactionSignature}
+ * Create a proposal that funds a linear vesting stream from the vault once approved
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable]` multisig: {@link MultisigConfig} The multisig account
+ * 2. `[writable]` proposal: {@link Proposal} The proposal account to initialize
+ * 3. `[signer]` creator: {@link PublicKey} The account creating the proposal (must be a signer of the multisig)
+ * 4. `[optional]` address_book: {@link AddressBook} The multisig's address book, required when the multisig enforces it
+ * 5. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
+ *
+ * Data:
+ * - terms: {@link VestingStreamTerms} The recipient, mint, total amount and start, cliff and end times
+ * - vault_index: {@link number} The vault the proposal acts on
+ * - expiration_time: {@link BigInt} Optional timestamp when the proposal expires (0 means no expiration, or the maximum lifetime when the multisig sets one)
+ * - proposal_seed_proposal_id: {@link BigInt} Auto-generated, from the input "proposal" for the its seed definition "Proposal", sets the seed named "proposal_id"
+ */
+export const createVestingStreamProposalSendAndConfirm = async (
+  args: Omit<CreateVestingStreamProposalArgs, "feePayer" | "creator"> & {
+    signers: {
+      feePayer: web3.Signer,
+      creator: web3.Signer,
+    },
+  },
+  remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionSignature> => {
+  const preInstructions: Array<web3.TransactionInstruction> = [];
+
+
+  return createVestingStreamProposalBuilder({
+      ...args,
+      feePayer: args.signers.feePayer.publicKey,
+      creator: args.signers.creator.publicKey,
+    }, remainingAccounts)
+    .preInstructions(preInstructions)
+    .signers([args.signers.feePayer, args.signers.creator])
+    .rpc();
+}
+
+export type ExecuteVestingStreamProposalArgs = {
+  feePayer: web3.PublicKey;
+  createKey: web3.PublicKey;
+  source: web3.PublicKey;
+  escrow: web3.PublicKey;
+  mint: web3.PublicKey;
+  transferPolicy: web3.PublicKey | null;
+  proposalSeedProposalId: bigint;
+  vaultIndex: number;
+};
+
+/**
+ * ### Returns a {@link MethodsBuilder}
+ * Create the vesting stream of an approved vesting stream proposal, moving its total amount from
+ * the vault into the stream's escrow
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[]` multisig: {@link MultisigConfig} The multisig account
+ * 2. `[writable]` proposal: {@link Proposal} The vesting stream proposal to execute
+ * 3. `[]` vault: {@link PublicKey} The multisig vault funding the stream
+ * 4. `[writable]` vesting_stream: {@link VestingStream} The vesting stream account to initialize
+ * 5. `[writable]` source: {@link Account} The vault's associated token account for the mint
+ * 6. `[writable]` escrow: {@link Account} The stream's associated token account to initialize
+ * 7. `[]` mint: {@link Mint} The mint named in the proposal
+ * 8. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
+ * 9. `[]` token_program: {@link PublicKey} SPL Token program
+ * 10. `[]` associated_token_program: {@link PublicKey} SPL Associated Token program
+ * 11. `[writable, optional]` transfer_policy: {@link TransferPolicy} The multisig's transfer policy, required when it is enabled
+ *
+ * Data:
+ * - proposal_seed_proposal_id: {@link BigInt} Auto-generated, from the input "proposal" for the its seed definition "Proposal", sets the seed named "proposal_id"
+ */
+export const executeVestingStreamProposalBuilder = (
+	args: ExecuteVestingStreamProposalArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): MethodsBuilder<MultisigWallet, never> => {
+  const [multisigPubkey] = pda.deriveMultisigPDA({
+        createKey: args.createKey,
+    }, _program.programId);
+    const [proposalPubkey] = pda.deriveProposalPDA({
+        multisig: multisigPubkey,
+        proposalId: args.proposalSeedProposalId,
+    }, _program.programId);
+    const [vaultPubkey] = pda.deriveVaultPDA({
+        multisig: multisigPubkey,
+        vaultIndex: args.vaultIndex,
+    }, _program.programId);
+    const [vestingStreamPubkey] = pda.deriveVestingStreamPDA({
+        multisig: multisigPubkey,
+        streamId: args.proposalSeedProposalId,
+    }, _program.programId);
+
+  return _program
+    .methods
+    .executeVestingStreamProposal(
+      new BN(args.proposalSeedProposalId.toString()),
+    )
+    .accountsStrict({
+      feePayer: args.feePayer,
+      multisig: multisigPubkey,
+      proposal: proposalPubkey,
+      vault: vaultPubkey,
+      vestingStream: vestingStreamPubkey,
+      source: args.source,
+      escrow: args.escrow,
+      mint: args.mint,
+      systemProgram: new web3.PublicKey("11111111111111111111111111111111"),
+      tokenProgram: new web3.PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"),
+      associatedTokenProgram: new web3.PublicKey("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"),
+      transferPolicy: args.transferPolicy,
+    })
+    .remainingAccounts(remainingAccounts);
+};
+
+/**
+ * ### Returns a {@link web3.TransactionInstruction}
+ * Create the vesting stream of an approved vesting stream proposal, moving its total amount from
+ * the vault into the stream's escrow
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[]` multisig: {@link MultisigConfig} The multisig account
+ * 2. `[writable]` proposal: {@link Proposal} The vesting stream proposal to execute
+ * 3. `[]` vault: {@link PublicKey} The multisig vault funding the stream
+ * 4. `[writable]` vesting_stream: {@link VestingStream} The vesting stream account to initialize
+ * 5. `[writable]` source: {@link Account} The vault's associated token account for the mint
+ * 6. `[writable]` escrow: {@link Account} The stream's associated token account to initialize
+ * 7. `[]` mint: {@link Mint} The mint named in the proposal
+ * 8. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
+ * 9. `[]` token_program: {@link PublicKey} SPL Token program
+ * 10. `[]` associated_token_program: {@link PublicKey} SPL Associated Token program
+ * 11. `[writable, optional]` transfer_policy: {@link TransferPolicy} The multisig's transfer policy, required when it is enabled
+ *
+ * Data:
+ * - proposal_seed_proposal_id: {@link BigInt} Auto-generated, from the input "proposal" for the its seed definition "Proposal", sets the seed named "proposal_id"
+ */
+export const executeVestingStreamProposal = (
+	args: ExecuteVestingStreamProposalArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionInstruction> =>
+    executeVestingStreamProposalBuilder(args, remainingAccounts).instruction();
+
+/**
+ * ### Returns a {@link web3.TransactionSignature}
+ * Create the vesting stream of an approved vesting stream proposal, moving its total amount from
+ * the vault into the stream's escrow
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[]` multisig: {@link MultisigConfig} The multisig account
+ * 2. `[writable]` proposal: {@link Proposal} The vesting stream proposal to execute
+ * 3. `[]` vault: {@link PublicKey} The multisig vault funding the stream
+ * 4. `[writable]` vesting_stream: {@link VestingStream} The vesting stream account to initialize
+ * 5. `[writable]` source: {@link Account} The vault's associated token account for the mint
+ * 6. `[writable]` escrow: {@link Account} The stream's associated token account to initialize
+ * 7. `[]` mint: {@link Mint} The mint named in the proposal
+ * 8. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
+ * 9. `[]` token_program: {@link PublicKey} SPL Token program
+ * 10. `[]` associated_token_program: {@link PublicKey} SPL Associated Token program
+ * 11. `[writable, optional]` transfer_policy: {@link TransferPolicy} The multisig's transfer policy, required when it is enabled
+ *
+ * Data:
+ * - proposal_seed_proposal_id: {@link BigInt} Auto-generated, from the input "proposal" for the its seed definition "Proposal", sets the seed named "proposal_id"
+ */
+export const executeVestingStreamProposalSendAndConfirm = async (
+  args: Omit<ExecuteVestingStreamProposalArgs, "feePayer"> & {
+    signers: {
+      feePayer: web3.Signer,
+    },
+  },
+  remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionSignature> => {
+  const preInstructions: Array<web3.TransactionInstruction> = [];
+
+
+  return executeVestingStreamProposalBuilder({
+      ...args,
+      feePayer: args.signers.feePayer.publicKey,
+    }, remainingAccounts)
+    .preInstructions(preInstructions)
+    .signers([args.signers.feePayer])
+    .rpc();
+}
+
+export type WithdrawVestedArgs = {
+  feePayer: web3.PublicKey;
+  createKey: web3.PublicKey;
+  recipient: web3.PublicKey;
+  escrow: web3.PublicKey;
+  destination: web3.PublicKey;
+  mint: web3.PublicKey;
+  vestingStreamSeedStreamId: bigint;
+};
+
+/**
+ * ### Returns a {@link MethodsBuilder}
+ * Withdraw everything vested so far from a vesting stream to a token account of the recipient
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[]` multisig: {@link MultisigConfig} The multisig account
+ * 2. `[writable]` vesting_stream: {@link VestingStream} The vesting stream to withdraw from
+ * 3. `[signer]` recipient: {@link PublicKey} The recipient of the stream
+ * 4. `[writable]` escrow: {@link Account} The stream's associated token account
+ * 5. `[writable]` destination: {@link Account} A token account owned by the recipient
+ * 6. `[]` mint: {@link Mint} The mint of the stream
+ * 7. `[]` token_program: {@link PublicKey} SPL Token program
+ *
+ * Data:
+ * - vesting_stream_seed_stream_id: {@link BigInt} Auto-generated, from the input "vesting_stream" for the its seed definition "VestingStream", sets the seed named "stream_id"
+ */
+export const withdrawVestedBuilder = (
+	args: WithdrawVestedArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): MethodsBuilder<MultisigWallet, never> => {
+  const [multisigPubkey] = pda.deriveMultisigPDA({
+        createKey: args.createKey,
+    }, _program.programId);
+    const [vestingStreamPubkey] = pda.deriveVestingStreamPDA({
+        multisig: multisigPubkey,
+        streamId: args.vestingStreamSeedStreamId,
+    }, _program.programId);
+
+  return _program
+    .methods
+    .withdrawVested(
+      new BN(args.vestingStreamSeedStreamId.toString()),
+    )
+    .accountsStrict({
+      feePayer: args.feePayer,
+      multisig: multisigPubkey,
+      vestingStream: vestingStreamPubkey,
+      recipient: args.recipient,
+      escrow: args.escrow,
+      destination: args.destination,
+      mint: args.mint,
+      tokenProgram: new web3.PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"),
+    })
+    .remainingAccounts(remainingAccounts);
+};
+
+/**
+ * ### Returns a {@link web3.TransactionInstruction}
+ * Withdraw everything vested so far from a vesting stream to a token account of the recipient
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[]` multisig: {@link MultisigConfig} The multisig account
+ * 2. `[writable]` vesting_stream: {@link VestingStream} The vesting stream to withdraw from
+ * 3. `[signer]` recipient: {@link PublicKey} The recipient of the stream
+ * 4. `[writable]` escrow: {@link Account} The stream's associated token account
+ * 5. `[writable]` destination: {@link Account} A token account owned by the recipient
+ * 6. `[]` mint: {@link Mint} The mint of the stream
+ * 7. `[]` token_program: {@link PublicKey} SPL Token program
+ *
+ * Data:
+ * - vesting_stream_seed_stream_id: {@link BigInt} Auto-generated, from the input "vesting_stream" for the its seed definition "VestingStream", sets the seed named "stream_id"
+ */
+export const withdrawVested = (
+	args: WithdrawVestedArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionInstruction> =>
+    withdrawVestedBuilder(args, remainingAccounts).instruction();
+
+/**
+ * ### Returns a {@link web3.TransactionSignature}
+ * Withdraw everything vested so far from a vesting stream to a token account of the recipient
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[]` multisig: {@link MultisigConfig} The multisig account
+ * 2. `[writable]` vesting_stream: {@link VestingStream} The vesting stream to withdraw from
+ * 3. `[signer]` recipient: {@link PublicKey} The recipient of the stream
+ * 4. `[writable]` escrow: {@link Account} The stream's associated token account
+ * 5. `[writable]` destination: {@link Account} A token account owned by the recipient
+ * 6. `[]` mint: {@link Mint} The mint of the stream
+ * 7. `[]` token_program: {@link PublicKey} SPL Token program
+ *
+ * Data:
+ * - vesting_stream_seed_stream_id: {@link BigInt} Auto-generated, from the input "vesting_stream" for the its seed definition "VestingStream", sets the seed named "stream_id"
+ */
+export const withdrawVestedSendAndConfirm = async (
+  args: Omit<WithdrawVestedArgs, "feePayer" | "recipient"> & {
+    signers: {
+      feePayer: web3.Signer,
+      recipient: web3.Signer,
+    },
+  },
+  remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionSignature> => {
+  const preInstructions: Array<web3.TransactionInstruction> = [];
+
+
+  return withdrawVestedBuilder({
+      ...args,
+      feePayer: args.signers.feePayer.publicKey,
+      recipient: args.signers.recipient.publicKey,
+    }, remainingAccounts)
+    .preInstructions(preInstructions)
+    .signers([args.signers.feePayer, args.signers.recipient])
+    .rpc();
+}
+
+export type CreateCancelStreamProposalArgs = {
+  feePayer: web3.PublicKey;
+  createKey: web3.PublicKey;
+  creator: web3.PublicKey;
+  expirationTime: bigint;
+  proposalSeedProposalId: bigint;
+  vestingStreamSeedStreamId: bigint;
+};
+
+/**
+ * ### Returns a {@link MethodsBuilder}
+ * Create a proposal that cancels a vesting stream once approved
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable]` multisig: {@link MultisigConfig} The multisig account
+ * 2. `[writable]` proposal: {@link Proposal} The proposal account to initialize
+ * 3. `[signer]` creator: {@link PublicKey} The account creating the proposal (must be a signer of the multisig)
+ * 4. `[]` vesting_stream: {@link VestingStream} The vesting stream to cancel
+ * 5. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
+ *
+ * Data:
+ * - expiration_time: {@link BigInt} Optional timestamp when the proposal expires (0 means no expiration, or the maximum lifetime when the multisig sets one)
+ * - proposal_seed_proposal_id: {@link BigInt} Auto-generated, from the input "proposal" for the its seed definition "Proposal", sets the seed named "proposal_id"
+ * - vesting_stream_seed_stream_id: {@link BigInt} Auto-generated, from the input "vesting_stream" for the its seed definition "VestingStream", sets the seed named "stream_id"
+ */
+export const createCancelStreamProposalBuilder = (
+	args: CreateCancelStreamProposalArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): MethodsBuilder<MultisigWallet, never> => {
+  const [multisigPubkey] = pda.deriveMultisigPDA({
+        createKey: args.createKey,
+    }, _program.programId);
+    const [proposalPubkey] = pda.deriveProposalPDA({
+        multisig: multisigPubkey,
+        proposalId: args.proposalSeedProposalId,
+    }, _program.programId);
+    const [vestingStreamPubkey] = pda.deriveVestingStreamPDA({
+        multisig: multisigPubkey,
+        streamId: args.vestingStreamSeedStreamId,
+    }, _program.programId);
+
+  return _program
+    .methods
+    .createCancelStreamProposal(
+      new BN(args.expirationTime.toString()),
+      new BN(args.proposalSeedProposalId.toString()),
+      new BN(args.vestingStreamSeedStreamId.toString()),
+    )
+    .accountsStrict({
+      feePayer: args.feePayer,
+      multisig: multisigPubkey,
+      proposal: proposalPubkey,
+      creator: args.creator,
+      vestingStream: vestingStreamPubkey,
+      systemProgram: new web3.PublicKey("11111111111111111111111111111111"),
+    })
+    .remainingAccounts(remainingAccounts);
+};
+
+/**
+ * ### Returns a {@link web3.TransactionInstruction}
+ * Create a proposal that cancels a vesting stream once approved
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable]` multisig: {@link MultisigConfig} The multisig account
+ * 2. `[writable]` proposal: {@link Proposal} The proposal account to initialize
+ * 3. `[signer]` creator: {@link PublicKey} The account creating the proposal (must be a signer of the multisig)
+ * 4. `[]` vesting_stream: {@link VestingStream} The vesting stream to cancel
+ * 5. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
+ *
+ * Data:
+ * - expiration_time: {@link BigInt} Optional timestamp when the proposal expires (0 means no expiration, or the maximum lifetime when the multisig sets one)
+ * - proposal_seed_proposal_id: {@link BigInt} Auto-generated, from the input "proposal" for the its seed definition "Proposal", sets the seed named "proposal_id"
+ * - vesting_stream_seed_stream_id: {@link BigInt} Auto-generated, from the input "vesting_stream" for the its seed definition "VestingStream", sets the seed named "stream_id"
+ */
+export const createCancelStreamProposal = (
+	args: CreateCancelStreamProposalArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionInstruction> =>
+    createCancelStreamProposalBuilder(args, remainingAccounts).instruction();
+
+/**
+ * ### Returns a {@link web3.TransactionSignature}
+ * Create a proposal that cancels a vesting stream once approved
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable]` multisig: {@link MultisigConfig} The multisig account
+ * 2. `[writable]` proposal: {@link Proposal} The proposal account to initialize
+ * 3. `[signer]` creator: {@link PublicKey} The account creating the proposal (must be a signer of the multisig)
+ * 4. `[]` vesting_stream: {@link VestingStream} The vesting stream to cancel
+ * 5. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
+ *
+ * Data:
+ * - expiration_time: {@link BigInt} Optional timestamp when the proposal expires (0 means no expiration, or the maximum lifetime when the multisig sets one)
+ * - proposal_seed_proposal_id: {@link BigInt} Auto-generated, from the input "proposal" for the its seed definition "Proposal", sets the seed named "proposal_id"
+ * - vesting_stream_seed_stream_id: {@link BigInt} Auto-generated, from the input "vesting_stream" for the its seed definition "VestingStream", sets the seed named "stream_id"
+ */
+export const createCancelStreamProposalSendAndConfirm = async (
+  args: Omit<CreateCancelStreamProposalArgs, "feePayer" | "creator"> & {
+    signers: {
+      feePayer: web3.Signer,
+      creator: web3.Signer,
+    },
+  },
+  remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionSignature> => {
+  const preInstructions: Array<web3.TransactionInstruction> = [];
+
+
+  return createCancelStreamProposalBuilder({
+      ...args,
+      feePayer: args.signers.feePayer.publicKey,
+      creator: args.signers.creator.publicKey,
+    }, remainingAccounts)
+    .preInstructions(preInstructions)
+    .signers([args.signers.feePayer, args.signers.creator])
+    .rpc();
+}
+
+export type CancelStreamArgs = {
+  feePayer: web3.PublicKey;
+  createKey: web3.PublicKey;
+  escrow: web3.PublicKey;
+  vaultTokenAccount: web3.PublicKey;
+  mint: web3.PublicKey;
+  proposalSeedProposalId: bigint;
+  vestingStreamSeedStreamId: bigint;
+  vaultIndex: number;
+};
+
+/**
+ * ### Returns a {@link MethodsBuilder}
+ * Cancel a vesting stream through an approved cancel stream proposal, returning the unvested
+ * amount to the vault; what has already vested stays withdrawable by the recipient
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[]` multisig: {@link MultisigConfig} The multisig account
+ * 2. `[writable]` proposal: {@link Proposal} The cancel stream proposal to execute
+ * 3. `[writable]` vesting_stream: {@link VestingStream} The vesting stream to cancel
+ * 4. `[]` vault: {@link PublicKey} The multisig vault receiving the unvested amount
+ * 5. `[writable]` escrow: {@link Account} The stream's associated token account
+ * 6. `[writable]` vault_token_account: {@link Account} The vault's associated token account for the mint
+ * 7. `[]` mint: {@link Mint} The mint of the stream
+ * 8. `[]` token_program: {@link PublicKey} SPL Token program
+ *
+ * Data:
+ * - proposal_seed_proposal_id: {@link BigInt} Auto-generated, from the input "proposal" for the its seed definition "Proposal", sets the seed named "proposal_id"
+ * - vesting_stream_seed_stream_id: {@link BigInt} Auto-generated, from the input "vesting_stream" for the its seed definition "VestingStream", sets the seed named "stream_id"
+ */
+export const cancelStreamBuilder = (
+	args: CancelStreamArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): MethodsBuilder<MultisigWallet, never> => {
+  const [multisigPubkey] = pda.deriveMultisigPDA({
+        createKey: args.createKey,
+    }, _program.programId);
+    const [proposalPubkey] = pda.deriveProposalPDA({
+        multisig: multisigPubkey,
+        proposalId: args.proposalSeedProposalId,
+    }, _program.programId);
+    const [vestingStreamPubkey] = pda.deriveVestingStreamPDA({
+        multisig: multisigPubkey,
+        streamId: args.vestingStreamSeedStreamId,
+    }, _program.programId);
+    const [vaultPubkey] = pda.deriveVaultPDA({
+        multisig: multisigPubkey,
+        vaultIndex: args.vaultIndex,
+    }, _program.programId);
+
+  return _program
+    .methods
+    .cancelStream(
+      new BN(args.proposalSeedProposalId.toString()),
+      new BN(args.vestingStreamSeedStreamId.toString()),
+    )
+    .accountsStrict({
+      feePayer: args.feePayer,
+      multisig: multisigPubkey,
+      proposal: proposalPubkey,
+      vestingStream: vestingStreamPubkey,
+      vault: vaultPubkey,
+      escrow: args.escrow,
+      vaultTokenAccount: args.vaultTokenAccount,
+      mint: args.mint,
+      tokenProgram: new web3.PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"),
+    })
+    .remainingAccounts(remainingAccounts);
+};
+
+/**
+ * ### Returns a {@link web3.TransactionInstruction}
+ * Cancel a vesting stream through an approved cancel stream proposal, returning the unvested
+ * amount to the vault; what has already vested stays withdrawable by the recipient
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[]` multisig: {@link MultisigConfig} The multisig account
+ * 2. `[writable]` proposal: {@link Proposal} The cancel stream proposal to execute
+ * 3. `[writable]` vesting_stream: {@link VestingStream} The vesting stream to cancel
+ * 4. `[]` vault: {@link PublicKey} The multisig vault receiving the unvested amount
+ * 5. `[writable]` escrow: {@link Account} The stream's associated token account
+ * 6. `[writable]` vault_token_account: {@link Account} The vault's associated token account for the mint
+ * 7. `[]` mint: {@link Mint} The mint of the stream
+ * 8. `[]` token_program: {@link PublicKey} SPL Token program
+ *
+ * Data:
+ * - proposal_seed_proposal_id: {@link BigInt} Auto-generated, from the input "proposal" for the its seed definition "Proposal", sets the seed named "proposal_id"
+ * - vesting_stream_seed_stream_id: {@link BigInt} Auto-generated, from the input "vesting_stream" for the its seed definition "VestingStream", sets the seed named "stream_id"
+ */
+export const cancelStream = (
+	args: CancelStreamArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionInstruction> =>
+    cancelStreamBuilder(args, remainingAccounts).instruction();
+
+/**
+ * ### Returns a {@link web3.TransactionSignature}
+ * Cancel a vesting stream through an approved cancel stream proposal, returning the unvested
+ * amount to the vault; what has already vested stays withdrawable by the recipient
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[]` multisig: {@link MultisigConfig} The multisig account
+ * 2. `[writable]` proposal: {@link Proposal} The cancel stream proposal to execute
+ * 3. `[writable]` vesting_stream: {@link VestingStream} The vesting stream to cancel
+ * 4. `[]` vault: {@link PublicKey} The multisig vault receiving the unvested amount
+ * 5. `[writable]` escrow: {@link Account} The stream's associated token account
+ * 6. `[writable]` vault_token_account: {@link Account} The vault's associated token account for the mint
+ * 7. `[]` mint: {@link Mint} The mint of the stream
+ * 8. `[]` token_program: {@link PublicKey} SPL Token program
+ *
+ * Data:
+ * - proposal_seed_proposal_id: {@link BigInt} Auto-generated, from the input "proposal" for the its seed definition "Proposal", sets the seed named "proposal_id"
+ * - vesting_stream_seed_stream_id: {@link BigInt} Auto-generated, from the input "vesting_stream" for the its seed definition "VestingStream", sets the seed named "stream_id"
+ */
+export const cancelStreamSendAndConfirm = async (
+  args: Omit<CancelStreamArgs, "feePayer"> & {
+    signers: {
+      feePayer: web3.Signer,
+    },
+  },
+  remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionSignature> => {
+  const preInstructions: Array<web3.TransactionInstruction> = [];
+
+
+  return cancelStreamBuilder({
+      ...args,
+      feePayer: args.signers.feePayer.publicKey,
+    }, remainingAccounts)
+    .preInstructions(preInstructions)
+    .signers([args.signers.feePayer])
+    .rpc();
+}
+
+export type SetVaultThresholdArgs = {
+  feePayer: web3.PublicKey;
+  createKey: web3.PublicKey;
+  vaultIndex: number;
+  threshold: number | null;
+  members: web3.PublicKey[];
+};
+
+/**
+ * ### Returns a {@link MethodsBuilder}
+ * Set or remove the approval threshold override of one vault (requires every current signer to sign the transaction)
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable]` multisig: {@link MultisigConfig} The multisig account to configure
+ * 2. `[signer]` remaining_accounts: {@link PublicKey} Every current signer of the multisig (accounts 2 and up)
+ *
+ * Data:
+ * - vault_index: {@link number} The vault whose threshold is overridden
+ * - threshold: {@link number | null} Approvals needed by proposals acting on the vault (None falls back to the multisig threshold)
+ */
+export const setVaultThresholdBuilder = (
+	args: SetVaultThresholdArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): MethodsBuilder<MultisigWallet, never> => {
+  const [multisigPubkey] = pda.deriveMultisigPDA({
+        createKey: args.createKey,
+    }, _program.programId);
+  const memberAccounts = args.members.map((pubkey) => ({ pubkey, isSigner: true, isWritable: false }));
+
+  return _program
+    .methods
+    .setVaultThreshold(
+      args.vaultIndex,
+      args.threshold,
+    )
+    .accountsStrict({
+      feePayer: args.feePayer,
+      multisig: multisigPubkey,
+    })
+    .remainingAccounts([...memberAccounts, ...remainingAccounts]);
+};
+
+/**
+ * ### Returns a {@link web3.TransactionInstruction}
+ * Set or remove the approval threshold override of one vault (requires every current signer to sign the transaction)
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable]` multisig: {@link MultisigConfig} The multisig account to configure
+ * 2. `[signer]` remaining_accounts: {@link PublicKey} Every current signer of the multisig (accounts 2 and up)
+ *
+ * Data:
+ * - vault_index: {@link number} The vault whose threshold is overridden
+ * - threshold: {@link number | null} Approvals needed by proposals acting on the vault (None falls back to the multisig threshold)
+ */
+export const setVaultThreshold = (
+	args: SetVaultThresholdArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionInstruction> =>
+    setVaultThresholdBuilder(args, remainingAccounts).instruction();
+
+/**
+ * ### Returns a {@link web3.TransactionSignature}
+ * Set or remove the approval threshold override of one vault (requires every current signer to sign the transaction)
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable]` multisig: {@link MultisigConfig} The multisig account to configure
+ * 2. `[signer]` remaining_accounts: {@link PublicKey} Every current signer of the multisig (accounts 2 and up)
+ *
+ * Data:
+ * - vault_index: {@link number} The vault whose threshold is overridden
+ * - threshold: {@link number | null} Approvals needed by proposals acting on the vault (None falls back to the multisig threshold)
+ */
+export const setVaultThresholdSendAndConfirm = async (
+  args: Omit<SetVaultThresholdArgs, "feePayer" | "members"> & {
+    signers: {
+      feePayer: web3.Signer,
+      members: web3.Signer[],
+    },
+  },
+  remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionSignature> => {
+  const preInstructions: Array<web3.TransactionInstruction> = [];
+
+
+  return setVaultThresholdBuilder({
+      ...args,
+      feePayer: args.signers.feePayer.publicKey,
+      members: args.signers.members.map((member) => member.publicKey),
+    }, remainingAccounts)
+    .preInstructions(preInstructions)
+    .signers([args.signers.feePayer, ...args.signers.members])
+    .rpc();
+}
+
+export type CreateNestedApprovalProposalArgs = {
+  feePayer: web3.PublicKey;
+  createKey: web3.PublicKey;
+  creator: web3.PublicKey;
+  parentCreateKey: web3.PublicKey;
+  parentProposalId: bigint;
+  vaultIndex: number;
+  expirationTime: bigint;
+  proposalSeedProposalId: bigint;
+};
+
+/**
+ * ### Returns a {@link MethodsBuilder}
+ * Create a proposal that, once approved, approves a proposal of a parent multisig in which one of
+ * this multisig's vaults is a signer
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable]` multisig: {@link MultisigConfig} The multisig account
+ * 2. `[writable]` proposal: {@link Proposal} The proposal account to initialize
+ * 3. `[signer]` creator: {@link PublicKey} The account creating the proposal (must be a signer of the multisig)
+ * 4. `[]` vault: {@link PublicKey} The multisig vault that is a signer of the parent multisig
+ * 5. `[]` parent_multisig: {@link MultisigConfig} The parent multisig whose proposal is approved
+ * 6. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
+ *
+ * Data:
+ * - parent_proposal_id: {@link BigInt} The id of the parent multisig's proposal to approve
+ * - vault_index: {@link number} The vault that approves on the parent multisig
+ * - expiration_time: {@link BigInt} Optional timestamp when the proposal expires (0 means no expiration, or the maximum lifetime when the multisig sets one)
+ * - proposal_seed_proposal_id: {@link BigInt} Auto-generated, from the input "proposal" for the its seed definition "Proposal", sets the seed named "proposal_id"
+ */
+export const createNestedApprovalProposalBuilder = (
+	args: CreateNestedApprovalProposalArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): MethodsBuilder<MultisigWallet, never> => {
+  const [multisigPubkey] = pda.deriveMultisigPDA({
+        createKey: args.createKey,
+    }, _program.programId);
+    const [proposalPubkey] = pda.deriveProposalPDA({
+        multisig: multisigPubkey,
+        proposalId: args.proposalSeedProposalId,
+    }, _program.programId);
+    const [vaultPubkey] = pda.deriveVaultPDA({
+        multisig: multisigPubkey,
+        vaultIndex: args.vaultIndex,
+    }, _program.programId);
+    const [parentMultisigPubkey] = pda.deriveMultisigPDA({
+        createKey: args.parentCreateKey,
+    }, _program.programId);
+
+  return _program
+    .methods
+    .createNestedApprovalProposal(
+      new BN(args.parentProposalId.toString()),
+      args.vaultIndex,
+      new BN(args.expirationTime.toString()),
+      new BN(args.proposalSeedProposalId.toString()),
+    )
+    .accountsStrict({
+      feePayer: args.feePayer,
+      multisig: multisigPubkey,
+      proposal: proposalPubkey,
+      creator: args.creator,
+      vault: vaultPubkey,
+      parentMultisig: parentMultisigPubkey,
+      systemProgram: new web3.PublicKey("11111111111111111111111111111111"),
+    })
+    .remainingAccounts(remainingAccounts);
+};
+
+/**
+ * ### Returns a {@link web3.TransactionInstruction}
+ * Create a proposal that, once approved, approves a proposal of a parent multisig in which one of
+ * this multisig's vaults is a signer
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable]` multisig: {@link MultisigConfig} The multisig account
+ * 2. `[writable]` proposal: {@link Proposal} The proposal account to initialize
+ * 3. `[signer]` creator: {@link PublicKey} The account creating the proposal (must be a signer of the multisig)
+ * 4. `[]` vault: {@link PublicKey} The multisig vault that is a signer of the parent multisig
+ * 5. `[]` parent_multisig: {@link MultisigConfig} The parent multisig whose proposal is approved
+ * 6. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
+ *
+ * Data:
+ * - parent_proposal_id: {@link BigInt} The id of the parent multisig's proposal to approve
+ * - vault_index: {@link number} The vault that approves on the parent multisig
+ * - expiration_time: {@link BigInt} Optional timestamp when the proposal expires (0 means no expiration, or the maximum lifetime when the multisig sets one)
+ * - proposal_seed_proposal_id: {@link BigInt} Auto-generated, from the input "proposal" for the its seed definition "Proposal", sets the seed named "proposal_id"
+ */
+export const createNestedApprovalProposal = (
+	args: CreateNestedApprovalProposalArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionInstruction> =>
+    createNestedApprovalProposalBuilder(args, remainingAccounts).instruction();
+
+/**
+ * ### Returns a {@link web3.TransactionSignature}
+ * Create a proposal that, once approved, approves a proposal of a parent multisig in which one of
+ * this multisig's vaults is a signer
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable]` multisig: {@link MultisigConfig} The multisig account
+ * 2. `[writable]` proposal: {@link Proposal} The proposal account to initialize
+ * 3. `[signer]` creator: {@link PublicKey} The account creating the proposal (must be a signer of the multisig)
+ * 4. `[]` vault: {@link PublicKey} The multisig vault that is a signer of the parent multisig
+ * 5. `[]` parent_multisig: {@link MultisigConfig} The parent multisig whose proposal is approved
+ * 6. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
+ *
+ * Data:
+ * - parent_proposal_id: {@link BigInt} The id of the parent multisig's proposal to approve
+ * - vault_index: {@link number} The vault that approves on the parent multisig
+ * - expiration_time: {@link BigInt} Optional timestamp when the proposal expires (0 means no expiration, or the maximum lifetime when the multisig sets one)
+ * - proposal_seed_proposal_id: {@link BigInt} Auto-generated, from the input "proposal" for the its seed definition "Proposal", sets the seed named "proposal_id"
+ */
+export const createNestedApprovalProposalSendAndConfirm = async (
+  args: Omit<CreateNestedApprovalProposalArgs, "feePayer" | "creator"> & {
+    signers: {
+      feePayer: web3.Signer,
+      creator: web3.Signer,
+    },
+  },
+  remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionSignature> => {
+  const preInstructions: Array<web3.TransactionInstruction> = [];
+
+
+  return createNestedApprovalProposalBuilder({
+      ...args,
+      feePayer: args.signers.feePayer.publicKey,
+      creator: args.signers.creator.publicKey,
+    }, remainingAccounts)
+    .preInstructions(preInstructions)
+    .signers([args.signers.feePayer, args.signers.creator])
+    .rpc();
+}
+
+export type ExecuteNestedApprovalProposalArgs = {
+  feePayer: web3.PublicKey;
+  createKey: web3.PublicKey;
+  parentCreateKey: web3.PublicKey;
+  parentProposalId: bigint;
+  vaultIndex: number;
+  proposalSeedProposalId: bigint;
+};
+
+/**
+ * ### Returns a {@link MethodsBuilder}
+ * Apply an approved nested approval proposal: the vault approves the parent multisig's proposal
+ * by calling `approve_proposal` through CPI
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[]` multisig: {@link MultisigConfig} The multisig account
+ * 2. `[writable]` proposal: {@link Proposal} The nested approval proposal to execute
+ * 3. `[]` vault: {@link PublicKey} The multisig vault that is a signer of the parent multisig
+ * 4. `[]` parent_multisig: {@link MultisigConfig} The parent multisig named in the proposal
+ * 5. `[writable]` parent_proposal: {@link PublicKey} The parent multisig's proposal to approve
+ * 6. `[]` multisig_wallet_program: {@link PublicKey} This program, called back to approve the parent proposal
+ *
+ * Data:
+ * - proposal_seed_proposal_id: {@link BigInt} Auto-generated, from the input "proposal" for the its seed definition "Proposal", sets the seed named "proposal_id"
+ */
+export const executeNestedApprovalProposalBuilder = (
+	args: ExecuteNestedApprovalProposalArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): MethodsBuilder<MultisigWallet, never> => {
+  const [multisigPubkey] = pda.deriveMultisigPDA({
+        createKey: args.createKey,
+    }, _program.programId);
+    const [proposalPubkey] = pda.deriveProposalPDA({
+        multisig: multisigPubkey,
+        proposalId: args.proposalSeedProposalId,
+    }, _program.programId);
+    const [vaultPubkey] = pda.deriveVaultPDA({
+        multisig: multisigPubkey,
+        vaultIndex: args.vaultIndex,
+    }, _program.programId);
+    const [parentMultisigPubkey] = pda.deriveMultisigPDA({
+        createKey: args.parentCreateKey,
+    }, _program.programId);
+    const [parentProposalPubkey] = pda.deriveProposalPDA({
+        multisig: parentMultisigPubkey,
+        proposalId: args.parentProposalId,
+    }, _program.programId);
+
+  return _program
+    .methods
+    .executeNestedApprovalProposal(
+      new BN(args.proposalSeedProposalId.toString()),
+    )
+    .accountsStrict({
+      feePayer: args.feePayer,
+      multisig: multisigPubkey,
+      proposal: proposalPubkey,
+      vault: vaultPubkey,
+      parentMultisig: parentMultisigPubkey,
+      parentProposal: parentProposalPubkey,
+      multisigWalletProgram: _program.programId,
+    })
+    .remainingAccounts(remainingAccounts);
+};
+
+/**
+ * ### Returns a {@link web3.TransactionInstruction}
+ * Apply an approved nested approval proposal: the vault approves the parent multisig's proposal
+ * by calling `approve_proposal` through CPI
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[]` multisig: {@link MultisigConfig} The multisig account
+ * 2. `[writable]` proposal: {@link Proposal} The nested approval proposal to execute
+ * 3. `[]` vault: {@link PublicKey} The multisig vault that is a signer of the parent multisig
+ * 4. `[]` parent_multisig: {@link MultisigConfig} The parent multisig named in the proposal
+ * 5. `[writable]` parent_proposal: {@link PublicKey} The parent multisig's proposal to approve
+ * 6. `[]` multisig_wallet_program: {@link PublicKey} This program, called back to approve the parent proposal
+ *
+ * Data:
+ * - proposal_seed_proposal_id: {@link BigInt} Auto-generated, from the input "proposal" for the its seed definition "Proposal", sets the seed named "proposal_id"
+ */
+export const executeNestedApprovalProposal = (
+	args: ExecuteNestedApprovalProposalArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionInstruction> =>
+    executeNestedApprovalProposalBuilder(args, remainingAccounts).instruction();
+
+/**
+ * ### Returns a {@link web3.TransactionSignature}
+ * Apply an approved nested approval proposal: the vault approves the parent multisig's proposal
+ * by calling `approve_proposal` through CPI
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[]` multisig: {@link MultisigConfig} The multisig account
+ * 2. `[writable]` proposal: {@link Proposal} The nested approval proposal to execute
+ * 3. `[]` vault: {@link PublicKey} The multisig vault that is a signer of the parent multisig
+ * 4. `[]` parent_multisig: {@link MultisigConfig} The parent multisig named in the proposal
+ * 5. `[writable]` parent_proposal: {@link PublicKey} The parent multisig's proposal to approve
+ * 6. `[]` multisig_wallet_program: {@link PublicKey} This program, called back to approve the parent proposal
+ *
+ * Data:
+ * - proposal_seed_proposal_id: {@link BigInt} Auto-generated, from the input "proposal" for the its seed definition "Proposal", sets the seed named "proposal_id"
+ */
+export const executeNestedApprovalProposalSendAndConfirm = async (
+  args: Omit<ExecuteNestedApprovalProposalArgs, "feePayer"> & {
+    signers: {
+      feePayer: web3.Signer,
+    },
+  },
+  remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionSignature> => {
+  const preInstructions: Array<web3.TransactionInstruction> = [];
+
+
+  return executeNestedApprovalProposalBuilder({
+      ...args,
+      feePayer: args.signers.feePayer.publicKey,
+    }, remainingAccounts)
+    .preInstructions(preInstructions)
+    .signers([args.signers.feePayer])
+    .rpc();
+}
+
+export type ConfigureOptimisticArgs = {
+  feePayer: web3.PublicKey;
+  createKey: web3.PublicKey;
+  optimistic: boolean;
+  vetoWindow: bigint;
+  vetoThreshold: number;
+  members: web3.PublicKey[];
+};
+
+/**
+ * ### Returns a {@link MethodsBuilder}
+ * Turn optimistic mode on or off and set its veto window and veto threshold (requires every
+ * current signer to sign the transaction)
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable]` multisig: {@link MultisigConfig} The multisig account to configure
+ * 2. `[signer]` remaining_accounts: {@link PublicKey} Every current signer of the multisig (accounts 2 and up)
+ *
+ * Data:
+ * - optimistic: {@link boolean} Whether new transfer proposals pass unless vetoed instead of needing approvals
+ * - veto_window: {@link BigInt} Number of seconds after creation during which signers can veto an optimistic proposal (at most `MAX_VETO_WINDOW`)
+ * - veto_threshold: {@link number} The number of vetoes that blocks an optimistic proposal
+ */
+export const configureOptimisticBuilder = (
+	args: ConfigureOptimisticArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): MethodsBuilder<MultisigWallet, never> => {
+  const [multisigPubkey] = pda.deriveMultisigPDA({
+        createKey: args.createKey,
+    }, _program.programId);
+  const memberAccounts = args.members.map((pubkey) => ({ pubkey, isSigner: true, isWritable: false }));
+
+  return _program
+    .methods
+    .configureOptimistic(
+      args.optimistic,
+      new BN(args.vetoWindow.toString()),
+      args.vetoThreshold,
+    )
+    .accountsStrict({
+      feePayer: args.feePayer,
+      multisig: multisigPubkey,
+    })
+    .remainingAccounts([...memberAccounts, ...remainingAccounts]);
+};
+
+/**
+ * ### Returns a {@link web3.TransactionInstruction}
+ * Turn optimistic mode on or off and set its veto window and veto threshold (requires every
+ * current signer to sign the transaction)
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable]` multisig: {@link MultisigConfig} The multisig account to configure
+ * 2. `[signer]` remaining_accounts: {@link PublicKey} Every current signer of the multisig (accounts 2 and up)
+ *
+ * Data:
+ * - optimistic: {@link boolean} Whether new transfer proposals pass unless vetoed instead of needing approvals
+ * - veto_window: {@link BigInt} Number of seconds after creation during which signers can veto an optimistic proposal (at most `MAX_VETO_WINDOW`)
+ * - veto_threshold: {@link number} The number of vetoes that blocks an optimistic proposal
+ */
+export const configureOptimistic = (
+	args: ConfigureOptimisticArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionInstruction> =>
+    configureOptimisticBuilder(args, remainingAccounts).instruction();
+
+/**
+ * ### Returns a {@link web3.TransactionSignature}
+ * Turn optimistic mode on or off and set its veto window and veto threshold (requires every
+ * current signer to sign the transaction)
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable]` multisig: {@link MultisigConfig} The multisig account to configure
+ * 2. `[signer]` remaining_accounts: {@link PublicKey} Every current signer of the multisig (accounts 2 and up)
+ *
+ * Data:
+ * - optimistic: {@link boolean} Whether new transfer proposals pass unless vetoed instead of needing approvals
+ * - veto_window: {@link BigInt} Number of seconds after creation during which signers can veto an optimistic proposal (at most `MAX_VETO_WINDOW`)
+ * - veto_threshold: {@link number} The number of vetoes that blocks an optimistic proposal
+ */
+export const configureOptimisticSendAndConfirm = async (
+  args: Omit<ConfigureOptimisticArgs, "feePayer" | "members"> & {
+    signers: {
+      feePayer: web3.Signer,
+      members: web3.Signer[],
+    },
+  },
+  remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionSignature> => {
+  const preInstructions: Array<web3.TransactionInstruction> = [];
+
+
+  return configureOptimisticBuilder({
+      ...args,
+      feePayer: args.signers.feePayer.publicKey,
+      members: args.signers.members.map((member) => member.publicKey),
+    }, remainingAccounts)
+    .preInstructions(preInstructions)
+    .signers([args.signers.feePayer, ...args.signers.members])
+    .rpc();
+}
+
+export type VetoProposalArgs = {
+  feePayer: web3.PublicKey;
+  createKey: web3.PublicKey;
+  signer: web3.PublicKey;
+  proposalSeedProposalId: bigint;
+};
+
+/**
+ * ### Returns a {@link MethodsBuilder}
+ * Veto an optimistic proposal during its veto window; once the veto threshold is reached the
+ * proposal can no longer be executed
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[]` multisig: {@link MultisigConfig} The multisig account
+ * 2. `[writable]` proposal: {@link Proposal} The optimistic proposal to veto
+ * 3. `[signer]` signer: {@link PublicKey} The signer vetoing the proposal (must be a signer of the multisig)
+ *
+ * Data:
+ * - proposal_seed_proposal_id: {@link BigInt} Auto-generated, from the input "proposal" for the its seed definition "Proposal", sets the seed named "proposal_id"
+ */
+export const vetoProposalBuilder = (
+	args: VetoProposalArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): MethodsBuilder<MultisigWallet, never> => {
+  const [multisigPubkey] = pda.deriveMultisigPDA({
+        createKey: args.createKey,
+    }, _program.programId);
+    const [proposalPubkey] = pda.deriveProposalPDA({
+        multisig: args.multisig,
+        proposalId: args.proposalSeedProposalId,
+    }, _program.programId);
+
+  return _program
+    .methods
+    .vetoProposal(
+      new BN(args.proposalSeedProposalId.toString()),
+    )
+    .accountsStrict({
+      feePayer: args.feePayer,
+      multisig: multisigPubkey,
+      proposal: proposalPubkey,
+      signer: args.signer,
+    })
+    .remainingAccounts(remainingAccounts);
+};
+
+/**
+ * ### Returns a {@link web3.TransactionInstruction}
+ * Veto an optimistic proposal during its veto window; once the veto threshold is reached the
+ * proposal can no longer be executed
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[]` multisig: {@link MultisigConfig} The multisig account
+ * 2. `[writable]` proposal: {@link Proposal} The optimistic proposal to veto
+ * 3. `[signer]` signer: {@link PublicKey} The signer vetoing the proposal (must be a signer of the multisig)
+ *
+ * Data:
+ * - proposal_seed_proposal_id: {@link BigInt} Auto-generated, from the input "proposal" for the its seed definition "Proposal", sets the seed named "proposal_id"
+ */
+export const vetoProposal = (
+	args: VetoProposalArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionInstruction> =>
+    vetoProposalBuilder(args, remainingAccounts).instruction();
+
+/**
+ * ### Returns a {@link web3.TransactionSignature}
+ * Veto an optimistic proposal during its veto window; once the veto threshold is reached the
+ * proposal can no longer be executed
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[]` multisig: {@link MultisigConfig} The multisig account
+ * 2. `[writable]` proposal: {@link Proposal} The optimistic proposal to veto
+ * 3. `[signer]` signer: {@link PublicKey} The signer vetoing the proposal (must be a signer of the multisig)
+ *
+ * Data:
+ * - proposal_seed_proposal_id: {@link BigInt} Auto-generated, from the input "proposal" for the its seed definition "Proposal", sets the seed named "proposal_id"
+ */
+export const vetoProposalSendAndConfirm = async (
+  args: Omit<VetoProposalArgs, "feePayer" | "signer"> & {
+    signers: {
+      feePayer: web3.Signer,
+      signer: web3.Signer,
+    },
+  },
+  remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionSignature> => {
+  const preInstructions: Array<web3.TransactionInstruction> = [];
+
+
+  return vetoProposalBuilder({
+      ...args,
+      feePayer: args.signers.feePayer.publicKey,
+      signer: args.signers.signer.publicKey,
+    }, remainingAccounts)
+    .preInstructions(preInstructions)
+    .signers([args.signers.feePayer, args.signers.signer])
+    .rpc();
+}
+
+export type MigrateMultisigArgs = {
+  feePayer: web3.PublicKey;
+};
+
+/**
+ * ### Returns a {@link MethodsBuilder}
+ * Rewrite a multisig created before accounts were versioned into the current layout, growing the
+ * account as needed (callable by anyone; the fee payer covers the extra rent)
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable]` multisig: {@link PublicKey} The legacy multisig account to migrate
+ * 2. `[]` system_program: {@link PublicKey} System program, to top up the account's rent
+ */
+export const migrateMultisigBuilder = (
+	args: MigrateMultisigArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): MethodsBuilder<MultisigWallet, never> => {
+  const [multisigPubkey] = pda.deriveMultisigPDA({
+        createKey: web3.PublicKey.default,
+    }, _program.programId);
+
+  return _program
+    .methods
+    .migrateMultisig(
+    )
+    .accountsStrict({
+      feePayer: args.feePayer,
+      multisig: multisigPubkey,
+      systemProgram: new web3.PublicKey("11111111111111111111111111111111"),
+    })
+    .remainingAccounts(remainingAccounts);
+};
+
+/**
+ * ### Returns a {@link web3.TransactionInstruction}
+ * Rewrite a multisig created before accounts were versioned into the current layout, growing the
+ * account as needed (callable by anyone; the fee payer covers the extra rent)
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable]` multisig: {@link PublicKey} The legacy multisig account to migrate
+ * 2. `[]` system_program: {@link PublicKey} System program, to top up the account's rent
+ */
+export const migrateMultisig = (
+	args: MigrateMultisigArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionInstruction> =>
+    migrateMultisigBuilder(args, remainingAccounts).instruction();
+
+/**
+ * ### Returns a {@link web3.TransactionSignature}
+ * Rewrite a multisig created before accounts were versioned into the current layout, growing the
+ * account as needed (callable by anyone; the fee payer covers the extra rent)
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable]` multisig: {@link PublicKey} The legacy multisig account to migrate
+ * 2. `[]` system_program: {@link PublicKey} System program, to top up the account's rent
+ */
+export const migrateMultisigSendAndConfirm = async (
+  args: Omit<MigrateMultisigArgs, "feePayer"> & {
+    signers: {
+      feePayer: web3.Signer,
+    },
+  },
+  remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionSignature> => {
+  const preInstructions: Array<web3.TransactionInstruction> = [];
+
+
+  return migrateMultisigBuilder({
+      ...args,
+      feePayer: args.signers.feePayer.publicKey,
+    }, remainingAccounts)
+    .preInstructions(preInstructions)
+    .signers([args.signers.feePayer])
+    .rpc();
+}
+
+export type MigrateProposalArgs = {
+  feePayer: web3.PublicKey;
+  proposalSeedProposalId: bigint;
+};
+
+/**
+ * ### Returns a {@link MethodsBuilder}
+ * Rewrite a proposal created before accounts were versioned into the current layout, growing the
+ * account as needed (callable by anyone once the multisig has been migrated; the fee payer covers
+ * the extra rent)
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[]` multisig: {@link MultisigConfig} The (already migrated) multisig account
+ * 2. `[writable]` proposal: {@link PublicKey} The legacy proposal account to migrate
+ * 3. `[]` system_program: {@link PublicKey} System program, to top up the account's rent
+ *
+ * Data:
+ * - proposal_seed_proposal_id: {@link BigInt} Auto-generated, from the input "proposal" for the its seed definition "Proposal", sets the seed named "proposal_id"
+ */
+export const migrateProposalBuilder = (
+	args: MigrateProposalArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): MethodsBuilder<MultisigWallet, never> => {
+  const [multisigPubkey] = pda.deriveMultisigPDA({
+        createKey: web3.PublicKey.default,
+    }, _program.programId);
+    const [proposalPubkey] = pda.deriveProposalPDA({
+        multisig: multisigPubkey,
+        proposalId: args.proposalSeedProposalId,
+    }, _program.programId);
+
+  return _program
+    .methods
+    .migrateProposal(
+      new BN(args.proposalSeedProposalId.toString()),
+    )
+    .accountsStrict({
+      feePayer: args.feePayer,
+      multisig: multisigPubkey,
+      proposal: proposalPubkey,
+      systemProgram: new web3.PublicKey("11111111111111111111111111111111"),
+    })
+    .remainingAccounts(remainingAccounts);
+};
+
+/**
+ * ### Returns a {@link web3.TransactionInstruction}
+ * Rewrite a proposal created before accounts were versioned into the current layout, growing the
+ * account as needed (callable by anyone once the multisig has been migrated; the fee payer covers
+ * the extra rent)
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[]` multisig: {@link MultisigConfig} The (already migrated) multisig account
+ * 2. `[writable]` proposal: {@link PublicKey} The legacy proposal account to migrate
+ * 3. `[]` system_program: {@link PublicKey} System program, to top up the account's rent
+ *
+ * Data:
+ * - proposal_seed_proposal_id: {@link BigInt} Auto-generated, from the input "proposal" for the its seed definition "Proposal", sets the seed named "proposal_id"
+ */
+export const migrateProposal = (
+	args: MigrateProposalArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionInstruction> =>
+    migrateProposalBuilder(args, remainingAccounts).instruction();
+
+/**
+ * ### Returns a {@link web3.TransactionSignature}
+ * Rewrite a proposal created before accounts were versioned into the current layout, growing the
+ * account as needed (callable by anyone once the multisig has been migrated; the fee payer covers
+ * the extra rent)
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[]` multisig: {@link MultisigConfig} The (already migrated) multisig account
+ * 2. `[writable]` proposal: {@link PublicKey} The legacy proposal account to migrate
+ * 3. `[]` system_program: {@link PublicKey} System program, to top up the account's rent
+ *
+ * Data:
+ * - proposal_seed_proposal_id: {@link BigInt} Auto-generated, from the input "proposal" for the its seed definition "Proposal", sets the seed named "proposal_id"
+ */
+export const migrateProposalSendAndConfirm = async (
+  args: Omit<MigrateProposalArgs, "feePayer"> & {
+    signers: {
+      feePayer: web3.Signer,
+    },
+  },
+  remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionSignature> => {
+  const preInstructions: Array<web3.TransactionInstruction> = [];
+
+
+  return migrateProposalBuilder({
+      ...args,
+      feePayer: args.signers.feePayer.publicKey,
+    }, remainingAccounts)
+    .preInstructions(preInstructions)
+    .signers([args.signers.feePayer])
+    .rpc();
+}
+
+export type UpdateMultisigArgs = {
+  feePayer: web3.PublicKey;
+  createKey: web3.PublicKey;
+  newSigners: web3.PublicKey[];
+  newThreshold: number;
+  allCurrentSignersApproved: boolean;
+};
+
+/**
+ * ### Returns a {@link MethodsBuilder}
+ * Update the signers or threshold of the multisig (requires approval from all current signers)
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable]` multisig: {@link MultisigConfig} The multisig account to update
+ *
+ * Data:
+ * - new_signers: {@link PublicKey[]} The new set of authorized signers
+ * - new_threshold: {@link number} The new approval threshold
+ * - all_current_signers_approved: {@link boolean} Flag indicating that all current signers have approved this update
+ */
+export const updateMultisigBuilder = (
+	args: UpdateMultisigArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): MethodsBuilder<MultisigWallet, never> => {
+  const [multisigPubkey] = pda.deriveMultisigPDA({
+        createKey: args.createKey,
+    }, _program.programId);
+
+  return _program
+    .methods
+    .updateMultisig(
+      args.newSigners,
+      args.newThreshold,
+      args.allCurrentSignersApproved,
+    )
+    .accountsStrict({
+      feePayer: args.feePayer,
+      multisig: multisigPubkey,
+    })
+    .remainingAccounts(remainingAccounts);
+};
+
+/**
+ * ### Returns a {@link web3.TransactionInstruction}
+ * Update the signers or threshold of the multisig (requires approval from all current signers)
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable]` multisig: {@link MultisigConfig} The multisig account to update
+ *
+ * Data:
+ * - new_signers: {@link PublicKey[]} The new set of authorized signers
+ * - new_threshold: {@link number} The new approval threshold
+ * - all_current_signers_approved: {@link boolean} Flag indicating that all current signers have approved this update
+ */
+export const updateMultisig = (
+	args: UpdateMultisigArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionInstruction> =>
+    updateMultisigBuilder(args, remainingAccounts).instruction();
+
+/**
+ * ### Returns a {@link web3.TransactionSignature}
+ * Update the signers or threshold of the multisig (requires approval from all current signers)
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
+ * 1. `[writable]` multisig: {@link MultisigConfig} The multisig account to update
+ *
+ * Data:
+ * - new_signers: {@link PublicKey[]} The new set of authorized signers
+ * - new_threshold: {@link number} The new approval threshold
+ * - all_current_signers_approved: {@link boolean} Flag indicating that all current signers have approved this update
+ */
+export const updateMultisigSendAndConfirm = async (
+  args: Omit<UpdateMultisigArgs, "feePayer"> & {
+    signers: {
+      feePayer: web3.Signer,
+    },
+  },
+  remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionSignature> => {
+  const preInstructions: Array<web3.TransactionInstruction> = [];
+
+
+  return updateMultisigBuilder({
+      ...args,
+      feePayer: args.signers.feePayer.publicKey,
+    }, remainingAccounts)
+    .preInstructions(preInstructions)
+    .signers([args.signers.feePayer])
+    .rpc();
+}
+
+// Getters
+
+export const getMultisigConfig = (
+    publicKey: web3.PublicKey,
+    commitment?: web3.Commitment
+): Promise<IdlAccounts<MultisigWallet>["multisigConfig"]> => _program.account.multisigConfig.fetch(publicKey, commitment);
+
+export const getProposal = (
+    publicKey: web3.PublicKey,
+    commitment?: web3.Commitment
+): Promise<IdlAccounts<MultisigWallet>["proposal"]> => _program.account.proposal.fetch(publicKey, commitment);
+
+export const getAddressBook = (
+    publicKey: web3.PublicKey,
+    commitment?: web3.Commitment
+): Promise<IdlAccounts<MultisigWallet>["addressBook"]> => _program.account.addressBook.fetch(publicKey, commitment);
+
+export const getTransferPolicy = (
+    publicKey: web3.PublicKey,
+    commitment?: web3.Commitment
+): Promise<IdlAccounts<MultisigWallet>["transferPolicy"]> => _program.account.transferPolicy.fetch(publicKey, commitment);
+
+export const getRecoveryRequest = (
+    publicKey: web3.PublicKey,
+    commitment?: web3.Commitment
+): Promise<IdlAccounts<MultisigWallet>["recoveryRequest"]> => _program.account.recoveryRequest.fetch(publicKey, commitment);
+
+export const getPaymentSchedule = (
+    publicKey: web3.PublicKey,
+    commitment?: web3.Commitment
+): Promise<IdlAccounts<MultisigWallet>["paymentSchedule"]> => _program.account.paymentSchedule.fetch(publicKey, commitment);
+
+export const getVestingStream = (
+    publicKey: web3.PublicKey,
+    commitment?: web3.Commitment
+): Promise<IdlAccounts<MultisigWallet>["vestingStream"]> => _program.account.vestingStream.fetch(publicKey, commitment);
 export module CslSplTokenGetters {
     export const getMint = (
         publicKey: web3.PublicKey,
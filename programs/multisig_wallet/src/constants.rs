@@ -2,3 +2,48 @@ use anchor_lang::prelude::*;
 
 #[constant]
 pub const SEED: &str = "anchor";
+
+#[constant]
+pub const MAX_SIGNERS: usize = 10;
+
+#[constant]
+pub const MAX_TITLE_LEN: usize = 64;
+
+#[constant]
+pub const MAX_DESCRIPTION_URI_LEN: usize = 200;
+
+#[constant]
+pub const MAX_MEMO_LEN: usize = 128;
+
+#[constant]
+pub const MAX_ADDRESS_BOOK_ENTRIES: usize = 32;
+
+#[constant]
+pub const MAX_LABEL_LEN: usize = 32;
+
+#[constant]
+pub const MAX_TRANSFER_LIMITS: usize = 16;
+
+#[constant]
+pub const TRANSFER_VOLUME_WINDOW: i64 = 24 * 60 * 60;
+
+/// Number of buckets the volume window is tracked in
+#[constant]
+pub const TRANSFER_VOLUME_BUCKETS: usize = 24;
+
+#[constant]
+pub const TRANSFER_VOLUME_BUCKET: i64 = TRANSFER_VOLUME_WINDOW / TRANSFER_VOLUME_BUCKETS as i64;
+
+#[constant]
+pub const MAX_RECOVERY_KEYS: usize = 5;
+
+/// Shortest time lock, in seconds, signers get to veto an approved recovery
+#[constant]
+pub const MIN_RECOVERY_DELAY: i64 = 24 * 60 * 60;
+
+#[constant]
+pub const MAX_VAULT_THRESHOLDS: usize = 8;
+
+/// Longest veto window, in seconds, optimistic mode can be configured with
+#[constant]
+pub const MAX_VETO_WINDOW: i64 = 30 * 24 * 60 * 60;
@@ -25,4 +25,114 @@ pub enum MultisigWalletError {
 	InvalidThreshold,
 	#[msg("Maximum number of signers exceeded")]
 	MaxSignersExceeded,
+	#[msg("The proposal title exceeds the maximum length")]
+	TitleTooLong,
+	#[msg("The proposal description URI exceeds the maximum length")]
+	DescriptionUriTooLong,
+	#[msg("The proposal memo exceeds the maximum length")]
+	MemoTooLong,
+	#[msg("Expiration time must be in the future and within the maximum proposal lifetime")]
+	InvalidExpirationTime,
+	#[msg("Maximum proposal lifetime must not be negative")]
+	InvalidMaxProposalLifetime,
+	#[msg("The proposal has not expired yet")]
+	ProposalNotExpired,
+	#[msg("The signer has already approved this proposal")]
+	AlreadyApproved,
+	#[msg("The destination is not in the multisig's address book")]
+	DestinationNotAllowed,
+	#[msg("The address book is full")]
+	AddressBookFull,
+	#[msg("The address is already in the address book")]
+	AddressBookEntryExists,
+	#[msg("The address is not in the address book")]
+	AddressBookEntryNotFound,
+	#[msg("The address book label exceeds the maximum length")]
+	LabelTooLong,
+	#[msg("This instruction cannot execute this kind of proposal")]
+	InvalidProposalKind,
+	#[msg("The transfer amount exceeds the per-transfer limit for this mint")]
+	TransferLimitExceeded,
+	#[msg("The transfer would exceed the volume limit over the rolling 24h window for this mint")]
+	TransferVolumeExceeded,
+	#[msg("The transfer policy already holds the maximum number of mint limits")]
+	TransferPolicyFull,
+	#[msg("The transfer policy has no limit for this mint")]
+	TransferLimitNotFound,
+	#[msg("The multisig has a transfer policy that must be passed to this instruction")]
+	TransferPolicyRequired,
+	#[msg("The multisig is frozen")]
+	MultisigFrozen,
+	#[msg("The multisig is not frozen")]
+	MultisigNotFrozen,
+	#[msg("The signer is not the guardian of this multisig")]
+	NotGuardian,
+	#[msg("The signer is not a recovery key of this multisig")]
+	NotARecoveryKey,
+	#[msg("Recovery threshold must be between 1 and the number of recovery keys, with a delay of at least MIN_RECOVERY_DELAY")]
+	InvalidRecoveryConfig,
+	#[msg("The lost signer must be a current signer and the replacement must not be")]
+	InvalidRecoveryTarget,
+	#[msg("The recovery key has already approved this recovery request")]
+	RecoveryAlreadyApproved,
+	#[msg("The recovery request has not reached the recovery threshold")]
+	RecoveryThresholdNotMet,
+	#[msg("The recovery time lock has not elapsed yet")]
+	RecoveryTimelockActive,
+	#[msg("The recovery request has been vetoed")]
+	RecoveryVetoed,
+	#[msg("The recovery request has already been executed")]
+	RecoveryAlreadyExecuted,
+	#[msg("The signer has not approved this proposal")]
+	NotApproved,
+	#[msg("The signer has already voted to cancel this proposal")]
+	AlreadyVotedToCancel,
+	#[msg("The program data account does not belong to the program or is not controlled by the vault")]
+	InvalidProgramData,
+	#[msg("The buffer account is missing or not controlled by the vault")]
+	InvalidBuffer,
+	#[msg("The buffer contents do not match the proposed buffer hash")]
+	BufferHashMismatch,
+	#[msg("An account does not match the one named in the proposal")]
+	AccountMismatch,
+	#[msg("A payment schedule needs a positive amount, interval and number of payments")]
+	InvalidPaymentSchedule,
+	#[msg("The next scheduled payment is not due yet")]
+	PaymentNotDue,
+	#[msg("Every payment of the schedule has already been made")]
+	PaymentScheduleCompleted,
+	#[msg("A vesting stream needs a positive amount and start <= cliff <= end with start < end")]
+	InvalidVestingSchedule,
+	#[msg("The vesting stream has already been cancelled")]
+	StreamCancelled,
+	#[msg("Only the recipient of the vesting stream can withdraw from it")]
+	NotStreamRecipient,
+	#[msg("Nothing has vested since the last withdrawal")]
+	NothingToWithdraw,
+	#[msg("The maximum number of vault threshold overrides has been reached")]
+	VaultThresholdsFull,
+	#[msg("Optimistic mode needs a veto window of at most 30 days and a veto threshold between 1 and the number of signers")]
+	InvalidOptimisticConfig,
+	#[msg("The proposal's veto window has not elapsed yet")]
+	VetoWindowOpen,
+	#[msg("The proposal is not optimistic or its veto window has closed")]
+	VetoWindowClosed,
+	#[msg("The signer has already vetoed this proposal")]
+	AlreadyVetoed,
+	#[msg("The proposal was vetoed")]
+	ProposalVetoed,
+	#[msg("The account is not a legacy account of the expected type")]
+	InvalidLegacyAccount,
+	#[msg("The account already uses the current layout")]
+	AccountAlreadyMigrated,
+	#[msg("A multisig needs at least one signer")]
+	NoSigners,
+	#[msg("The signer list contains the same signer more than once")]
+	DuplicateSigner,
+	#[msg("The proposal id must be the multisig's next proposal id")]
+	InvalidProposalId,
+	#[msg("The multisig has run out of proposal or recovery request ids")]
+	IdOverflow,
+	#[msg("The recovery request id must be the multisig's next recovery request id")]
+	InvalidRecoveryId,
 }
@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct ProposalUnapproved {
+	pub multisig: Pubkey,
+	pub proposal: Pubkey,
+	pub signer: Pubkey,
+	pub approvals: u8,
+	pub threshold_met: bool,
+}
+
+#[event]
+pub struct ProposalCancelVoted {
+	pub multisig: Pubkey,
+	pub proposal: Pubkey,
+	pub signer: Pubkey,
+	pub cancel_votes: u8,
+	pub cancelled: bool,
+}
+
+#[event]
+pub struct ProposalVetoCast {
+	pub multisig: Pubkey,
+	pub proposal: Pubkey,
+	pub signer: Pubkey,
+	pub vetoes: u8,
+	pub vetoed: bool,
+}
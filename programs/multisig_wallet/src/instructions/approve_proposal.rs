@@ -1,4 +1,5 @@
 use crate::*;
+use crate::error::MultisigWalletError;
 use anchor_lang::prelude::*;
 use std::str::FromStr;
 
@@ -23,8 +24,9 @@ use anchor_spl::{
 		#[account(
 			seeds = [
 				b"multisig",
+				multisig.create_key_seed(),
 			],
-			bump,
+			bump = multisig.nonce,
 		)]
 		pub multisig: Account<'info, MultisigConfig>,
 
@@ -35,9 +37,10 @@ use anchor_spl::{
 				multisig.key().as_ref(),
 				proposal_seed_proposal_id.to_le_bytes().as_ref(),
 			],
-			bump,
+			bump = proposal.load()?.nonce,
+			has_one = multisig @ MultisigWalletError::AccountMismatch,
 		)]
-		pub proposal: Account<'info, Proposal>,
+		pub proposal: AccountLoader<'info, Proposal>,
 
 		pub signer: Signer<'info>,
 	}
@@ -48,14 +51,22 @@ use anchor_spl::{
 /// 0. `[writable, signer]` fee_payer: [AccountInfo] 
 /// 1. `[]` multisig: [MultisigConfig] The multisig account
 /// 2. `[writable]` proposal: [Proposal] The proposal to approve
-/// 3. `[signer]` signer: [AccountInfo] The signer approving the proposal (must be a signer of the multisig)
+/// 3. `[signer]` signer: [AccountInfo] The signer approving the proposal (must be a signer of the multisig; may be another multisig's vault signing through CPI)
 ///
 /// Data:
 /// - proposal_seed_proposal_id: [u64] Auto-generated, from the input "proposal" for the its seed definition "Proposal", sets the seed named "proposal_id"
 pub fn handler(
 	ctx: Context<ApproveProposal>,
 ) -> Result<()> {
-    // Implement your business logic here...
-	
+	let multisig = &ctx.accounts.multisig;
+	let proposal = &mut ctx.accounts.proposal.load_mut()?;
+	let signer = ctx.accounts.signer.key();
+
+	multisig.require_not_frozen()?;
+	let signer_index = multisig.signer_index(&signer).ok_or(MultisigWalletError::NotASigner)?;
+	proposal.require_active(Clock::get()?.unix_timestamp)?;
+
+	proposal.approve(signer_index)?;
+
 	Ok(())
 }
@@ -0,0 +1,64 @@
+use crate::*;
+use crate::error::MultisigWalletError;
+use anchor_lang::prelude::*;
+
+
+
+
+	#[derive(Accounts)]
+	#[instruction(
+		recovery_request_seed_recovery_id: u64,
+	)]
+	pub struct ApproveRecoveryRequest<'info> {
+		#[account(
+			mut,
+		)]
+		pub fee_payer: Signer<'info>,
+
+		#[account(
+			seeds = [
+				b"multisig",
+				multisig.create_key_seed(),
+			],
+			bump = multisig.nonce,
+		)]
+		pub multisig: Account<'info, MultisigConfig>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"recovery_request",
+				multisig.key().as_ref(),
+				recovery_request_seed_recovery_id.to_le_bytes().as_ref(),
+			],
+			bump = recovery_request.nonce,
+		)]
+		pub recovery_request: Account<'info, RecoveryRequest>,
+
+		pub recovery_key: Signer<'info>,
+	}
+
+/// Approve a pending recovery request as a recovery key
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[]` multisig: [MultisigConfig] The multisig account
+/// 2. `[writable]` recovery_request: [RecoveryRequest] The recovery request to approve
+/// 3. `[signer]` recovery_key: [AccountInfo] The recovery key approving the request
+///
+/// Data:
+/// - recovery_request_seed_recovery_id: [u64] Auto-generated, from the input "recovery_request" for the its seed definition "RecoveryRequest", sets the seed named "recovery_id"
+pub fn handler(
+	ctx: Context<ApproveRecoveryRequest>,
+) -> Result<()> {
+	let multisig = &ctx.accounts.multisig;
+	let recovery_request = &mut ctx.accounts.recovery_request;
+	let recovery_key = ctx.accounts.recovery_key.key();
+
+	require!(multisig.recovery_keys.contains(&recovery_key), MultisigWalletError::NotARecoveryKey);
+	recovery_request.require_pending()?;
+
+	recovery_request.approve(recovery_key, multisig.recovery_threshold, Clock::get()?.unix_timestamp)?;
+
+	Ok(())
+}
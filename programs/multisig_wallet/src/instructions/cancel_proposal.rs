@@ -1,4 +1,5 @@
 use crate::*;
+use crate::error::MultisigWalletError;
 use anchor_lang::prelude::*;
 use std::str::FromStr;
 
@@ -23,8 +24,9 @@ use anchor_spl::{
 		#[account(
 			seeds = [
 				b"multisig",
+				multisig.create_key_seed(),
 			],
-			bump,
+			bump = multisig.nonce,
 		)]
 		pub multisig: Account<'info, MultisigConfig>,
 
@@ -35,27 +37,56 @@ use anchor_spl::{
 				multisig.key().as_ref(),
 				proposal_seed_proposal_id.to_le_bytes().as_ref(),
 			],
-			bump,
+			bump = proposal.load()?.nonce,
+			has_one = multisig @ MultisigWalletError::AccountMismatch,
 		)]
-		pub proposal: Account<'info, Proposal>,
+		pub proposal: AccountLoader<'info, Proposal>,
 
-		pub creator: Signer<'info>,
+		pub signer: Signer<'info>,
 	}
 
-/// Cancel a pending proposal
+/// Cancel a pending proposal: the creator cancels it outright while it has no approvals,
+/// otherwise the signer's cancel vote is recorded and the proposal is cancelled once the
+/// cancel threshold is reached
 ///
 /// Accounts:
 /// 0. `[writable, signer]` fee_payer: [AccountInfo] 
 /// 1. `[]` multisig: [MultisigConfig] The multisig account
 /// 2. `[writable]` proposal: [Proposal] The proposal to cancel
-/// 3. `[signer]` creator: [AccountInfo] The creator of the proposal
+/// 3. `[signer]` signer: [AccountInfo] The proposal creator or a signer of the multisig voting to cancel
 ///
 /// Data:
 /// - proposal_seed_proposal_id: [u64] Auto-generated, from the input "proposal" for the its seed definition "Proposal", sets the seed named "proposal_id"
 pub fn handler(
 	ctx: Context<CancelProposal>,
 ) -> Result<()> {
-    // Implement your business logic here...
-	
+	let multisig = &ctx.accounts.multisig;
+	let proposal = &mut ctx.accounts.proposal.load_mut()?;
+	let signer = ctx.accounts.signer.key();
+
+	proposal.require_active(Clock::get()?.unix_timestamp)?;
+
+	if signer == proposal.creator && proposal.approval_count() == 0 {
+		proposal.mark_cancelled();
+
+		return Ok(());
+	}
+
+	let signer_index = multisig.signer_index(&signer).ok_or(MultisigWalletError::NotASigner)?;
+
+	proposal.vote_to_cancel(signer_index)?;
+
+	if proposal.cancel_vote_count() >= multisig.effective_cancel_threshold() {
+		proposal.mark_cancelled();
+	}
+
+	emit!(ProposalCancelVoted {
+		multisig: multisig.key(),
+		proposal: ctx.accounts.proposal.key(),
+		signer,
+		cancel_votes: proposal.cancel_vote_count(),
+		cancelled: proposal.cancelled(),
+	});
+
 	Ok(())
 }
@@ -0,0 +1,153 @@
+use crate::*;
+use crate::error::MultisigWalletError;
+use anchor_lang::prelude::*;
+
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+
+
+
+	#[derive(Accounts)]
+	#[instruction(
+		proposal_seed_proposal_id: u64,
+		vesting_stream_seed_stream_id: u64,
+	)]
+	pub struct CancelStream<'info> {
+		#[account(
+			mut,
+		)]
+		pub fee_payer: Signer<'info>,
+
+		#[account(
+			seeds = [
+				b"multisig",
+				multisig.create_key_seed(),
+			],
+			bump = multisig.nonce,
+		)]
+		pub multisig: Account<'info, MultisigConfig>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"proposal",
+				multisig.key().as_ref(),
+				proposal_seed_proposal_id.to_le_bytes().as_ref(),
+			],
+			bump = proposal.load()?.nonce,
+			has_one = multisig @ MultisigWalletError::AccountMismatch,
+		)]
+		pub proposal: AccountLoader<'info, Proposal>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"vesting_stream",
+				multisig.key().as_ref(),
+				vesting_stream_seed_stream_id.to_le_bytes().as_ref(),
+			],
+			bump = vesting_stream.nonce,
+		)]
+		pub vesting_stream: Account<'info, VestingStream>,
+
+		#[account(
+			seeds = [
+				b"vault",
+				multisig.key().as_ref(),
+				&[vesting_stream.vault_index],
+			],
+			bump = vesting_stream.vault_nonce,
+		)]
+		pub vault: SystemAccount<'info>,
+
+		#[account(
+			mut,
+			associated_token::mint = mint,
+			associated_token::authority = vesting_stream,
+			associated_token::token_program = token_program,
+		)]
+		pub escrow: Account<'info, TokenAccount>,
+
+		#[account(
+			mut,
+			associated_token::mint = mint,
+			associated_token::authority = vault,
+			associated_token::token_program = token_program,
+		)]
+		pub vault_token_account: Account<'info, TokenAccount>,
+
+		#[account(
+			address = vesting_stream.mint,
+		)]
+		pub mint: Account<'info, Mint>,
+
+		pub token_program: Program<'info, Token>,
+	}
+
+/// Cancel a vesting stream through an approved cancel stream proposal, returning the unvested
+/// amount to the vault; what has already vested stays withdrawable by the recipient
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[]` multisig: [MultisigConfig] The multisig account
+/// 2. `[writable]` proposal: [Proposal] The cancel stream proposal to execute
+/// 3. `[writable]` vesting_stream: [VestingStream] The vesting stream to cancel
+/// 4. `[]` vault: [AccountInfo] The multisig vault receiving the unvested amount
+/// 5. `[writable]` escrow: [TokenAccount] The stream's associated token account
+/// 6. `[writable]` vault_token_account: [TokenAccount] The vault's associated token account for the mint
+/// 7. `[]` mint: [Mint] The mint of the stream
+/// 8. `[]` token_program: [AccountInfo] SPL Token program
+///
+/// Data:
+/// - proposal_seed_proposal_id: [u64] Auto-generated, from the input "proposal" for the its seed definition "Proposal", sets the seed named "proposal_id"
+/// - vesting_stream_seed_stream_id: [u64] Auto-generated, from the input "vesting_stream" for the its seed definition "VestingStream", sets the seed named "stream_id"
+pub fn handler(
+	ctx: Context<CancelStream>,
+	vesting_stream_seed_stream_id: u64,
+) -> Result<()> {
+	let multisig = &ctx.accounts.multisig;
+	let proposal = &mut ctx.accounts.proposal.load_mut()?;
+	let vesting_stream = &mut ctx.accounts.vesting_stream;
+	let now = Clock::get()?.unix_timestamp;
+
+	let kind = proposal.kind()?;
+	let threshold = multisig.proposal_threshold(&kind, proposal.vault_index);
+
+	let ProposalKind::CancelStream { stream_id } = kind else {
+		return err!(MultisigWalletError::InvalidProposalKind);
+	};
+
+	multisig.require_not_frozen()?;
+	proposal.require_executable(threshold, now)?;
+	require!(vesting_stream_seed_stream_id == stream_id, MultisigWalletError::AccountMismatch);
+
+	let unvested_amount = vesting_stream.cancel(now)?;
+
+	if unvested_amount > 0 {
+		let multisig_key = multisig.key();
+		let stream_id = stream_id.to_le_bytes();
+		let stream_seeds: &[&[u8]] = &[
+			b"vesting_stream",
+			multisig_key.as_ref(),
+			stream_id.as_ref(),
+			&[vesting_stream.nonce],
+		];
+
+		anchor_spl::token::transfer(
+			CpiContext::new_with_signer(
+				ctx.accounts.token_program.to_account_info(),
+				anchor_spl::token::Transfer {
+					from: ctx.accounts.escrow.to_account_info(),
+					to: ctx.accounts.vault_token_account.to_account_info(),
+					authority: vesting_stream.to_account_info(),
+				},
+				&[stream_seeds],
+			),
+			unvested_amount,
+		)?;
+	}
+
+	proposal.mark_executed();
+
+	Ok(())
+}
@@ -0,0 +1,67 @@
+use crate::*;
+use crate::error::MultisigWalletError;
+use anchor_lang::prelude::*;
+
+
+
+
+	#[derive(Accounts)]
+	#[instruction(
+		optimistic: bool,
+		veto_window: i64,
+		veto_threshold: u8,
+	)]
+	pub struct ConfigureOptimistic<'info> {
+		#[account(
+			mut,
+		)]
+		pub fee_payer: Signer<'info>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"multisig",
+				multisig.create_key_seed(),
+			],
+			bump = multisig.nonce,
+		)]
+		pub multisig: Account<'info, MultisigConfig>,
+	}
+
+/// Turn optimistic mode on or off and set its veto window and veto threshold (requires every
+/// current signer to sign the transaction)
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable]` multisig: [MultisigConfig] The multisig account to configure
+/// 2. `[signer]` remaining_accounts: [AccountInfo] Every current signer of the multisig (accounts 2 and up)
+///
+/// Data:
+/// - optimistic: [bool] Whether new transfer proposals pass unless vetoed instead of needing approvals
+/// - veto_window: [i64] Number of seconds after creation during which signers can veto an optimistic proposal (at most `MAX_VETO_WINDOW`)
+/// - veto_threshold: [u8] The number of vetoes that blocks an optimistic proposal
+pub fn handler<'info>(
+	ctx: Context<'_, '_, '_, 'info, ConfigureOptimistic<'info>>,
+	optimistic: bool,
+	veto_window: i64,
+	veto_threshold: u8,
+) -> Result<()> {
+	let multisig = &mut ctx.accounts.multisig;
+
+	multisig.require_all_signers(ctx.remaining_accounts)?;
+	multisig.require_not_frozen()?;
+
+	if optimistic {
+		require!(veto_window > 0 && veto_window <= MAX_VETO_WINDOW, MultisigWalletError::InvalidOptimisticConfig);
+		require!(
+			veto_threshold > 0 && veto_threshold as usize <= multisig.signers.len(),
+			MultisigWalletError::InvalidOptimisticConfig,
+		);
+	}
+
+	multisig.optimistic = optimistic;
+	multisig.veto_window = veto_window;
+	multisig.veto_threshold = veto_threshold;
+
+	Ok(())
+}
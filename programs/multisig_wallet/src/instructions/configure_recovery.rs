@@ -0,0 +1,68 @@
+use crate::*;
+use crate::error::MultisigWalletError;
+use anchor_lang::prelude::*;
+
+
+
+
+	#[derive(Accounts)]
+	#[instruction(
+		recovery_keys: Vec<Pubkey>,
+		recovery_threshold: u8,
+		recovery_delay: i64,
+	)]
+	pub struct ConfigureRecovery<'info> {
+		#[account(
+			mut,
+		)]
+		pub fee_payer: Signer<'info>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"multisig",
+				multisig.create_key_seed(),
+			],
+			bump = multisig.nonce,
+		)]
+		pub multisig: Account<'info, MultisigConfig>,
+	}
+
+/// Set the recovery keys, recovery threshold and time lock used to replace lost signers (requires every current signer to sign the transaction)
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable]` multisig: [MultisigConfig] The multisig account to configure
+/// 2. `[signer]` remaining_accounts: [AccountInfo] Every current signer of the multisig (accounts 2 and up)
+///
+/// Data:
+/// - recovery_keys: [Vec<Pubkey>] The keys allowed to request and approve signer recovery (empty disables recovery)
+/// - recovery_threshold: [u8] The number of recovery key approvals needed to start the time lock
+/// - recovery_delay: [i64] Number of seconds during which current signers can veto an approved recovery (at least `MIN_RECOVERY_DELAY` when recovery is enabled)
+pub fn handler<'info>(
+	ctx: Context<'_, '_, '_, 'info, ConfigureRecovery<'info>>,
+	recovery_keys: Vec<Pubkey>,
+	recovery_threshold: u8,
+	recovery_delay: i64,
+) -> Result<()> {
+	let multisig = &mut ctx.accounts.multisig;
+
+	multisig.require_all_signers(ctx.remaining_accounts)?;
+
+	require!(recovery_keys.len() <= MAX_RECOVERY_KEYS, MultisigWalletError::InvalidRecoveryConfig);
+	require!(
+		(recovery_keys.is_empty() && recovery_threshold == 0 && recovery_delay == 0)
+			|| (
+				recovery_threshold > 0
+					&& recovery_threshold as usize <= recovery_keys.len()
+					&& recovery_delay >= MIN_RECOVERY_DELAY
+			),
+		MultisigWalletError::InvalidRecoveryConfig,
+	);
+
+	multisig.recovery_keys = recovery_keys;
+	multisig.recovery_threshold = recovery_threshold;
+	multisig.recovery_delay = recovery_delay;
+
+	Ok(())
+}
@@ -0,0 +1,136 @@
+use crate::*;
+use crate::error::MultisigWalletError;
+use anchor_lang::prelude::*;
+
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+
+
+
+	#[derive(Accounts)]
+	#[instruction(
+		payment_schedule_seed_schedule_id: u64,
+	)]
+	pub struct CrankSchedule<'info> {
+		#[account(
+			mut,
+		)]
+		pub fee_payer: Signer<'info>,
+
+		#[account(
+			seeds = [
+				b"multisig",
+				multisig.create_key_seed(),
+			],
+			bump = multisig.nonce,
+		)]
+		pub multisig: Account<'info, MultisigConfig>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"payment_schedule",
+				multisig.key().as_ref(),
+				payment_schedule_seed_schedule_id.to_le_bytes().as_ref(),
+			],
+			bump = payment_schedule.nonce,
+		)]
+		pub payment_schedule: Account<'info, PaymentSchedule>,
+
+		#[account(
+			seeds = [
+				b"vault",
+				multisig.key().as_ref(),
+				&[payment_schedule.vault_index],
+			],
+			bump = payment_schedule.vault_nonce,
+		)]
+		pub vault: SystemAccount<'info>,
+
+		#[account(
+			mut,
+			associated_token::mint = mint,
+			associated_token::authority = vault,
+			associated_token::token_program = token_program,
+		)]
+		pub source: Account<'info, TokenAccount>,
+
+		#[account(
+			mut,
+			token::mint = mint,
+			token::authority = payment_schedule.destination,
+			token::token_program = token_program,
+		)]
+		pub destination: Account<'info, TokenAccount>,
+
+		#[account(
+			address = payment_schedule.mint,
+		)]
+		pub mint: Account<'info, Mint>,
+
+		pub token_program: Program<'info, Token>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"transfer_policy",
+				multisig.key().as_ref(),
+			],
+			bump = transfer_policy.nonce,
+		)]
+		pub transfer_policy: Option<Account<'info, TransferPolicy>>,
+	}
+
+/// Pay the next due payment of a payment schedule from the vault (callable by anyone)
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[]` multisig: [MultisigConfig] The multisig account
+/// 2. `[writable]` payment_schedule: [PaymentSchedule] The payment schedule to crank
+/// 3. `[]` vault: [AccountInfo] The multisig vault paying the schedule
+/// 4. `[writable]` source: [TokenAccount] The vault's associated token account for the mint
+/// 5. `[writable]` destination: [TokenAccount] A token account of the schedule's mint owned by the schedule's destination
+/// 6. `[]` mint: [Mint] The mint named in the schedule
+/// 7. `[]` token_program: [AccountInfo] SPL Token program
+/// 8. `[writable, optional]` transfer_policy: [TransferPolicy] The multisig's transfer policy, required when it is enabled
+///
+/// Data:
+/// - payment_schedule_seed_schedule_id: [u64] Auto-generated, from the input "payment_schedule" for the its seed definition "PaymentSchedule", sets the seed named "schedule_id"
+pub fn handler(
+	ctx: Context<CrankSchedule>,
+) -> Result<()> {
+	let multisig = &ctx.accounts.multisig;
+	let payment_schedule = &mut ctx.accounts.payment_schedule;
+	let now = Clock::get()?.unix_timestamp;
+
+	multisig.require_not_frozen()?;
+	payment_schedule.require_due(now)?;
+
+	if multisig.transfer_policy_enabled {
+		let transfer_policy = ctx.accounts.transfer_policy.as_mut()
+			.ok_or(MultisigWalletError::TransferPolicyRequired)?;
+
+		transfer_policy.record_transfer(&payment_schedule.mint, payment_schedule.amount, now)?;
+	}
+
+	let multisig_key = multisig.key();
+	let vault_index = [payment_schedule.vault_index];
+	let vault_seeds: &[&[u8]] = &[b"vault", multisig_key.as_ref(), &vault_index, &[payment_schedule.vault_nonce]];
+
+	anchor_spl::token::transfer(
+		CpiContext::new_with_signer(
+			ctx.accounts.token_program.to_account_info(),
+			anchor_spl::token::Transfer {
+				from: ctx.accounts.source.to_account_info(),
+				to: ctx.accounts.destination.to_account_info(),
+				authority: ctx.accounts.vault.to_account_info(),
+			},
+			&[vault_seeds],
+		),
+		payment_schedule.amount,
+	)?;
+
+	payment_schedule.record_payment();
+
+	Ok(())
+}
@@ -0,0 +1,62 @@
+use crate::*;
+use crate::error::MultisigWalletError;
+use anchor_lang::prelude::*;
+
+
+
+
+	#[derive(Accounts)]
+	pub struct CreateAddressBook<'info> {
+		#[account(
+			mut,
+		)]
+		pub fee_payer: Signer<'info>,
+
+		#[account(
+			seeds = [
+				b"multisig",
+				multisig.create_key_seed(),
+			],
+			bump = multisig.nonce,
+		)]
+		pub multisig: Account<'info, MultisigConfig>,
+
+		#[account(
+			init,
+			space=2221,
+			payer=fee_payer,
+			seeds = [
+				b"address_book",
+				multisig.key().as_ref(),
+			],
+			bump,
+		)]
+		pub address_book: Account<'info, AddressBook>,
+
+		pub creator: Signer<'info>,
+
+		pub system_program: Program<'info, System>,
+	}
+
+/// Create the (empty) address book of approved destinations for the multisig
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[]` multisig: [MultisigConfig] The multisig account
+/// 2. `[writable]` address_book: [AddressBook] The address book account to initialize
+/// 3. `[signer]` creator: [AccountInfo] The account creating the address book (must be a signer of the multisig)
+/// 4. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+pub fn handler(
+	ctx: Context<CreateAddressBook>,
+) -> Result<()> {
+	let multisig = &ctx.accounts.multisig;
+
+	require!(multisig.signers.contains(&ctx.accounts.creator.key()), MultisigWalletError::NotASigner);
+
+	let address_book = &mut ctx.accounts.address_book;
+	address_book.multisig = multisig.key();
+	address_book.entries = Vec::new();
+	address_book.nonce = ctx.bumps.address_book;
+
+	Ok(())
+}
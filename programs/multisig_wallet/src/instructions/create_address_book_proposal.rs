@@ -0,0 +1,92 @@
+use crate::*;
+use crate::error::MultisigWalletError;
+use anchor_lang::prelude::*;
+
+
+
+
+	#[derive(Accounts)]
+	#[instruction(
+		change: AddressBookChange,
+		expiration_time: i64,
+		proposal_seed_proposal_id: u64,
+	)]
+	pub struct CreateAddressBookProposal<'info> {
+		#[account(
+			mut,
+		)]
+		pub fee_payer: Signer<'info>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"multisig",
+				multisig.create_key_seed(),
+			],
+			bump = multisig.nonce,
+		)]
+		pub multisig: Account<'info, MultisigConfig>,
+
+		#[account(
+			init,
+			space=Proposal::SPACE,
+			payer=fee_payer,
+			seeds = [
+				b"proposal",
+				multisig.key().as_ref(),
+				proposal_seed_proposal_id.to_le_bytes().as_ref(),
+			],
+			bump,
+		)]
+		pub proposal: AccountLoader<'info, Proposal>,
+
+		pub creator: Signer<'info>,
+
+		pub system_program: Program<'info, System>,
+	}
+
+/// Create a proposal that changes the multisig's address book once approved
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable]` multisig: [MultisigConfig] The multisig account
+/// 2. `[writable]` proposal: [Proposal] The proposal account to initialize
+/// 3. `[signer]` creator: [AccountInfo] The account creating the proposal (must be a signer of the multisig)
+/// 4. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data:
+/// - change: [AddressBookChange] The entry to add or remove, or the new enforcement flag
+/// - expiration_time: [i64] Optional timestamp when the proposal expires (0 means no expiration, or the maximum lifetime when the multisig sets one)
+/// - proposal_seed_proposal_id: [u64] Auto-generated, from the input "proposal" for the its seed definition "Proposal", sets the seed named "proposal_id"
+pub fn handler(
+	ctx: Context<CreateAddressBookProposal>,
+	change: AddressBookChange,
+	expiration_time: i64,
+	proposal_seed_proposal_id: u64,
+) -> Result<()> {
+	let multisig = &mut ctx.accounts.multisig;
+	let creator = ctx.accounts.creator.key();
+
+	multisig.require_not_frozen()?;
+	require!(multisig.signers.contains(&creator), MultisigWalletError::NotASigner);
+	require!(proposal_seed_proposal_id == multisig.proposal_count, MultisigWalletError::InvalidProposalId);
+
+	if let AddressBookChange::AddEntry { label, .. } = &change {
+		require!(label.len() <= MAX_LABEL_LEN, MultisigWalletError::LabelTooLong);
+	}
+
+	let expiration_time = multisig.resolve_expiration_time(expiration_time, Clock::get()?.unix_timestamp)?;
+
+	let proposal = &mut ctx.accounts.proposal.load_init()?;
+	proposal.version = Proposal::CURRENT_VERSION;
+	proposal.nonce = ctx.bumps.proposal;
+	proposal.id = multisig.proposal_count;
+	proposal.creator = creator;
+	proposal.expiration_time = expiration_time;
+	proposal.multisig = multisig.key();
+	proposal.set_kind(&ProposalKind::AddressBook(change))?;
+
+	multisig.proposal_count = multisig.next_proposal_id()?;
+
+	Ok(())
+}
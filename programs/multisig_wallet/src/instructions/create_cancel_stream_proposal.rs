@@ -0,0 +1,102 @@
+use crate::*;
+use crate::error::MultisigWalletError;
+use anchor_lang::prelude::*;
+
+
+
+
+	#[derive(Accounts)]
+	#[instruction(
+		expiration_time: i64,
+		proposal_seed_proposal_id: u64,
+		vesting_stream_seed_stream_id: u64,
+	)]
+	pub struct CreateCancelStreamProposal<'info> {
+		#[account(
+			mut,
+		)]
+		pub fee_payer: Signer<'info>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"multisig",
+				multisig.create_key_seed(),
+			],
+			bump = multisig.nonce,
+		)]
+		pub multisig: Account<'info, MultisigConfig>,
+
+		#[account(
+			init,
+			space=Proposal::SPACE,
+			payer=fee_payer,
+			seeds = [
+				b"proposal",
+				multisig.key().as_ref(),
+				proposal_seed_proposal_id.to_le_bytes().as_ref(),
+			],
+			bump,
+		)]
+		pub proposal: AccountLoader<'info, Proposal>,
+
+		pub creator: Signer<'info>,
+
+		#[account(
+			seeds = [
+				b"vesting_stream",
+				multisig.key().as_ref(),
+				vesting_stream_seed_stream_id.to_le_bytes().as_ref(),
+			],
+			bump = vesting_stream.nonce,
+		)]
+		pub vesting_stream: Account<'info, VestingStream>,
+
+		pub system_program: Program<'info, System>,
+	}
+
+/// Create a proposal that cancels a vesting stream once approved
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable]` multisig: [MultisigConfig] The multisig account
+/// 2. `[writable]` proposal: [Proposal] The proposal account to initialize
+/// 3. `[signer]` creator: [AccountInfo] The account creating the proposal (must be a signer of the multisig)
+/// 4. `[]` vesting_stream: [VestingStream] The vesting stream to cancel
+/// 5. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data:
+/// - expiration_time: [i64] Optional timestamp when the proposal expires (0 means no expiration, or the maximum lifetime when the multisig sets one)
+/// - proposal_seed_proposal_id: [u64] Auto-generated, from the input "proposal" for the its seed definition "Proposal", sets the seed named "proposal_id"
+/// - vesting_stream_seed_stream_id: [u64] Auto-generated, from the input "vesting_stream" for the its seed definition "VestingStream", sets the seed named "stream_id"
+pub fn handler(
+	ctx: Context<CreateCancelStreamProposal>,
+	expiration_time: i64,
+	proposal_seed_proposal_id: u64,
+) -> Result<()> {
+	let multisig = &mut ctx.accounts.multisig;
+	let creator = ctx.accounts.creator.key();
+	let vesting_stream = &ctx.accounts.vesting_stream;
+
+	multisig.require_not_frozen()?;
+	require!(multisig.signers.contains(&creator), MultisigWalletError::NotASigner);
+	require!(proposal_seed_proposal_id == multisig.proposal_count, MultisigWalletError::InvalidProposalId);
+	require!(!vesting_stream.cancelled, MultisigWalletError::StreamCancelled);
+
+	let expiration_time = multisig.resolve_expiration_time(expiration_time, Clock::get()?.unix_timestamp)?;
+
+	let proposal = &mut ctx.accounts.proposal.load_init()?;
+	proposal.version = Proposal::CURRENT_VERSION;
+	proposal.nonce = ctx.bumps.proposal;
+	proposal.id = multisig.proposal_count;
+	proposal.creator = creator;
+	proposal.vault_index = vesting_stream.vault_index;
+	proposal.vault_nonce = vesting_stream.vault_nonce;
+	proposal.expiration_time = expiration_time;
+	proposal.multisig = multisig.key();
+	proposal.set_kind(&ProposalKind::CancelStream { stream_id: vesting_stream.id })?;
+
+	multisig.proposal_count = multisig.next_proposal_id()?;
+
+	Ok(())
+}
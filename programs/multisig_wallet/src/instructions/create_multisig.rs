@@ -1,4 +1,5 @@
 use crate::*;
+use crate::error::MultisigWalletError;
 use anchor_lang::prelude::*;
 use std::str::FromStr;
 
@@ -14,6 +15,9 @@ use anchor_spl::{
 	#[instruction(
 		signers: Vec<Pubkey>,
 		threshold: u8,
+		max_proposal_lifetime: i64,
+		guardian: Option<Pubkey>,
+		cancel_threshold: u8,
 	)]
 	pub struct CreateMultisig<'info> {
 		#[account(
@@ -21,12 +25,15 @@ use anchor_spl::{
 		)]
 		pub fee_payer: Signer<'info>,
 
+		pub create_key: Signer<'info>,
+
 		#[account(
 			init,
-			space=342,
+			space=MultisigConfig::SPACE,
 			payer=fee_payer,
 			seeds = [
 				b"multisig",
+				create_key.key().as_ref(),
 			],
 			bump,
 		)]
@@ -39,18 +46,43 @@ use anchor_spl::{
 ///
 /// Accounts:
 /// 0. `[writable, signer]` fee_payer: [AccountInfo] 
-/// 1. `[writable]` multisig: [MultisigConfig] The multisig account to initialize
-/// 2. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+/// 1. `[signer]` create_key: [AccountInfo] One-time key whose address seeds the multisig PDA, so each multisig gets its own address
+/// 2. `[writable]` multisig: [MultisigConfig] The multisig account to initialize
+/// 3. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
 ///
 /// Data:
-/// - signers: [Vec<Pubkey>] The initial set of authorized signers
+/// - signers: [Vec<Pubkey>] The initial set of authorized signers (1 to MAX_SIGNERS distinct keys)
 /// - threshold: [u8] The minimum number of approvals needed to execute a transaction
+/// - max_proposal_lifetime: [i64] Maximum number of seconds a proposal may stay open (0 means unlimited)
+/// - guardian: [Option<Pubkey>] Optional guardian allowed to freeze the multisig in an emergency
+/// - cancel_threshold: [u8] The number of cancel votes needed to cancel a proposal (0 means the approval threshold)
 pub fn handler(
 	ctx: Context<CreateMultisig>,
 	signers: Vec<Pubkey>,
 	threshold: u8,
+	max_proposal_lifetime: i64,
+	guardian: Option<Pubkey>,
+	cancel_threshold: u8,
 ) -> Result<()> {
-    // Implement your business logic here...
-	
+	require!(!signers.is_empty(), MultisigWalletError::NoSigners);
+	require!(signers.len() <= MAX_SIGNERS, MultisigWalletError::MaxSignersExceeded);
+	require!(
+		signers.iter().enumerate().all(|(index, signer)| !signers[..index].contains(signer)),
+		MultisigWalletError::DuplicateSigner,
+	);
+	require!(threshold > 0 && threshold as usize <= signers.len(), MultisigWalletError::InvalidThreshold);
+	require!(max_proposal_lifetime >= 0, MultisigWalletError::InvalidMaxProposalLifetime);
+	require!(cancel_threshold as usize <= signers.len(), MultisigWalletError::InvalidThreshold);
+
+	let multisig = &mut ctx.accounts.multisig;
+	multisig.version = MultisigConfig::CURRENT_VERSION;
+	multisig.signers = signers;
+	multisig.threshold = threshold;
+	multisig.nonce = ctx.bumps.multisig;
+	multisig.create_key = ctx.accounts.create_key.key();
+	multisig.max_proposal_lifetime = max_proposal_lifetime;
+	multisig.guardian = guardian;
+	multisig.cancel_threshold = cancel_threshold;
+
 	Ok(())
 }
@@ -0,0 +1,113 @@
+use crate::*;
+use crate::error::MultisigWalletError;
+use anchor_lang::prelude::*;
+
+
+
+
+	#[derive(Accounts)]
+	#[instruction(
+		parent_proposal_id: u64,
+		vault_index: u8,
+		expiration_time: i64,
+		proposal_seed_proposal_id: u64,
+	)]
+	pub struct CreateNestedApprovalProposal<'info> {
+		#[account(
+			mut,
+		)]
+		pub fee_payer: Signer<'info>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"multisig",
+				multisig.create_key_seed(),
+			],
+			bump = multisig.nonce,
+		)]
+		pub multisig: Account<'info, MultisigConfig>,
+
+		#[account(
+			init,
+			space=Proposal::SPACE,
+			payer=fee_payer,
+			seeds = [
+				b"proposal",
+				multisig.key().as_ref(),
+				proposal_seed_proposal_id.to_le_bytes().as_ref(),
+			],
+			bump,
+		)]
+		pub proposal: AccountLoader<'info, Proposal>,
+
+		pub creator: Signer<'info>,
+
+		#[account(
+			seeds = [
+				b"vault",
+				multisig.key().as_ref(),
+				&[vault_index],
+			],
+			bump,
+		)]
+		pub vault: SystemAccount<'info>,
+
+		pub parent_multisig: Account<'info, MultisigConfig>,
+
+		pub system_program: Program<'info, System>,
+	}
+
+/// Create a proposal that, once approved, approves a proposal of a parent multisig in which one of
+/// this multisig's vaults is a signer
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable]` multisig: [MultisigConfig] The multisig account
+/// 2. `[writable]` proposal: [Proposal] The proposal account to initialize
+/// 3. `[signer]` creator: [AccountInfo] The account creating the proposal (must be a signer of the multisig)
+/// 4. `[]` vault: [AccountInfo] The multisig vault that is a signer of the parent multisig
+/// 5. `[]` parent_multisig: [MultisigConfig] The parent multisig whose proposal is approved
+/// 6. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data:
+/// - parent_proposal_id: [u64] The id of the parent multisig's proposal to approve
+/// - vault_index: [u8] The vault that approves on the parent multisig
+/// - expiration_time: [i64] Optional timestamp when the proposal expires (0 means no expiration, or the maximum lifetime when the multisig sets one)
+/// - proposal_seed_proposal_id: [u64] Auto-generated, from the input "proposal" for the its seed definition "Proposal", sets the seed named "proposal_id"
+pub fn handler(
+	ctx: Context<CreateNestedApprovalProposal>,
+	parent_proposal_id: u64,
+	vault_index: u8,
+	expiration_time: i64,
+	proposal_seed_proposal_id: u64,
+) -> Result<()> {
+	let multisig = &mut ctx.accounts.multisig;
+	let creator = ctx.accounts.creator.key();
+	let parent_multisig = &ctx.accounts.parent_multisig;
+
+	multisig.require_not_frozen()?;
+	require!(multisig.signers.contains(&creator), MultisigWalletError::NotASigner);
+	require!(proposal_seed_proposal_id == multisig.proposal_count, MultisigWalletError::InvalidProposalId);
+	require!(parent_multisig.signers.contains(&ctx.accounts.vault.key()), MultisigWalletError::NotASigner);
+
+	let expiration_time = multisig.resolve_expiration_time(expiration_time, Clock::get()?.unix_timestamp)?;
+
+	let proposal = &mut ctx.accounts.proposal.load_init()?;
+	proposal.version = Proposal::CURRENT_VERSION;
+	proposal.nonce = ctx.bumps.proposal;
+	proposal.id = multisig.proposal_count;
+	proposal.creator = creator;
+	proposal.vault_index = vault_index;
+	proposal.vault_nonce = ctx.bumps.vault;
+	proposal.expiration_time = expiration_time;
+	proposal.multisig = multisig.key();
+	proposal.set_kind(&ProposalKind::NestedApproval {
+		parent_multisig: parent_multisig.key(),
+		parent_proposal_id,
+	})?;
+
+	multisig.proposal_count = multisig.next_proposal_id()?;
+
+	Ok(())
+}
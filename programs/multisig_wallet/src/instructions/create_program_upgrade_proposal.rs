@@ -0,0 +1,116 @@
+use crate::*;
+use crate::error::MultisigWalletError;
+use anchor_lang::prelude::*;
+
+
+
+
+	#[derive(Accounts)]
+	#[instruction(
+		change: ProgramUpgradeChange,
+		vault_index: u8,
+		expiration_time: i64,
+		proposal_seed_proposal_id: u64,
+	)]
+	pub struct CreateProgramUpgradeProposal<'info> {
+		#[account(
+			mut,
+		)]
+		pub fee_payer: Signer<'info>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"multisig",
+				multisig.create_key_seed(),
+			],
+			bump = multisig.nonce,
+		)]
+		pub multisig: Account<'info, MultisigConfig>,
+
+		#[account(
+			init,
+			space=Proposal::SPACE,
+			payer=fee_payer,
+			seeds = [
+				b"proposal",
+				multisig.key().as_ref(),
+				proposal_seed_proposal_id.to_le_bytes().as_ref(),
+			],
+			bump,
+		)]
+		pub proposal: AccountLoader<'info, Proposal>,
+
+		pub creator: Signer<'info>,
+
+		#[account(
+			seeds = [
+				b"vault",
+				multisig.key().as_ref(),
+				&[vault_index],
+			],
+			bump,
+		)]
+		pub vault: SystemAccount<'info>,
+
+		pub program_data: Account<'info, ProgramData>,
+
+		/// CHECK: validated against the proposed change in the handler
+		pub buffer: Option<UncheckedAccount<'info>>,
+
+		pub system_program: Program<'info, System>,
+	}
+
+/// Create a proposal that upgrades a program, or changes its upgrade authority, through the BPF
+/// upgradeable loader once approved; the program's upgrade authority must be the multisig vault
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable]` multisig: [MultisigConfig] The multisig account
+/// 2. `[writable]` proposal: [Proposal] The proposal account to initialize
+/// 3. `[signer]` creator: [AccountInfo] The account creating the proposal (must be a signer of the multisig)
+/// 4. `[]` vault: [AccountInfo] The multisig vault holding the upgrade authority
+/// 5. `[]` program_data: [ProgramData] The program data account of the targeted program
+/// 6. `[optional]` buffer: [AccountInfo] The buffer holding the new program, required for upgrades
+/// 7. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data:
+/// - change: [ProgramUpgradeChange] The upgrade or authority change to perform
+/// - vault_index: [u8] The vault the proposal acts on
+/// - expiration_time: [i64] Optional timestamp when the proposal expires (0 means no expiration, or the maximum lifetime when the multisig sets one)
+/// - proposal_seed_proposal_id: [u64] Auto-generated, from the input "proposal" for the its seed definition "Proposal", sets the seed named "proposal_id"
+pub fn handler(
+	ctx: Context<CreateProgramUpgradeProposal>,
+	change: ProgramUpgradeChange,
+	vault_index: u8,
+	expiration_time: i64,
+	proposal_seed_proposal_id: u64,
+) -> Result<()> {
+	let multisig = &mut ctx.accounts.multisig;
+	let creator = ctx.accounts.creator.key();
+	let vault = ctx.accounts.vault.key();
+
+	multisig.require_not_frozen()?;
+	require!(multisig.signers.contains(&creator), MultisigWalletError::NotASigner);
+	require!(proposal_seed_proposal_id == multisig.proposal_count, MultisigWalletError::InvalidProposalId);
+
+	let expiration_time = multisig.resolve_expiration_time(expiration_time, Clock::get()?.unix_timestamp)?;
+
+	change.validate_program_data(&ctx.accounts.program_data, &vault)?;
+	change.validate_buffer(ctx.accounts.buffer.as_ref().map(|buffer| buffer.as_ref()), &vault)?;
+
+	let proposal = &mut ctx.accounts.proposal.load_init()?;
+	proposal.version = Proposal::CURRENT_VERSION;
+	proposal.nonce = ctx.bumps.proposal;
+	proposal.id = multisig.proposal_count;
+	proposal.creator = creator;
+	proposal.vault_index = vault_index;
+	proposal.vault_nonce = ctx.bumps.vault;
+	proposal.expiration_time = expiration_time;
+	proposal.multisig = multisig.key();
+	proposal.set_kind(&ProposalKind::ProgramUpgrade(change))?;
+
+	multisig.proposal_count = multisig.next_proposal_id()?;
+
+	Ok(())
+}
@@ -1,4 +1,5 @@
 use crate::*;
+use crate::error::MultisigWalletError;
 use anchor_lang::prelude::*;
 use std::str::FromStr;
 
@@ -12,10 +13,10 @@ use anchor_spl::{
 
 	#[derive(Accounts)]
 	#[instruction(
-		destination: Pubkey,
-		amount: u64,
-		token_mint: Pubkey,
+		terms: TransferTerms,
+		vault_index: u8,
 		expiration_time: i64,
+		metadata: ProposalMetadata,
 		proposal_seed_proposal_id: u64,
 	)]
 	pub struct CreateProposal<'info> {
@@ -28,14 +29,15 @@ use anchor_spl::{
 			mut,
 			seeds = [
 				b"multisig",
+				multisig.create_key_seed(),
 			],
-			bump,
+			bump = multisig.nonce,
 		)]
 		pub multisig: Account<'info, MultisigConfig>,
 
 		#[account(
 			init,
-			space=487,
+			space=Proposal::SPACE,
 			payer=fee_payer,
 			seeds = [
 				b"proposal",
@@ -44,36 +46,83 @@ use anchor_spl::{
 			],
 			bump,
 		)]
-		pub proposal: Account<'info, Proposal>,
+		pub proposal: AccountLoader<'info, Proposal>,
 
 		pub creator: Signer<'info>,
 
+		#[account(
+			seeds = [
+				b"address_book",
+				multisig.key().as_ref(),
+			],
+			bump = address_book.nonce,
+		)]
+		pub address_book: Option<Account<'info, AddressBook>>,
+
 		pub system_program: Program<'info, System>,
 	}
 
-/// Create a new transaction proposal
+/// Create a new transaction proposal (in optimistic mode it becomes executable once the veto window
+/// elapses without enough vetoes)
 ///
 /// Accounts:
 /// 0. `[writable, signer]` fee_payer: [AccountInfo] 
 /// 1. `[writable]` multisig: [MultisigConfig] The multisig account
 /// 2. `[writable]` proposal: [Proposal] The proposal account to initialize
 /// 3. `[signer]` creator: [AccountInfo] The account creating the proposal (must be a signer of the multisig)
-/// 4. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+/// 4. `[optional]` address_book: [AddressBook] The multisig's address book, required when the multisig enforces it
+/// 5. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
 ///
 /// Data:
-/// - destination: [Pubkey] The destination account for the transfer
-/// - amount: [u64] The amount of tokens to transfer
-/// - token_mint: [Pubkey] The mint of the token to transfer (can be null for SOL)
-/// - expiration_time: [i64] Optional timestamp when the proposal expires (0 means no expiration)
+/// - terms: [TransferTerms] The destination wallet, amount and mint of the transfer
+/// - vault_index: [u8] The vault the transfer is paid from
+/// - expiration_time: [i64] Optional timestamp when the proposal expires (0 means no expiration, or the maximum lifetime when the multisig sets one)
+/// - metadata: [ProposalMetadata] Optional title, description URI and memo (the memo is attached to the transfer through the SPL Memo program on execution)
 /// - proposal_seed_proposal_id: [u64] Auto-generated, from the input "proposal" for the its seed definition "Proposal", sets the seed named "proposal_id"
 pub fn handler(
 	ctx: Context<CreateProposal>,
-	destination: Pubkey,
-	amount: u64,
-	token_mint: Pubkey,
+	terms: TransferTerms,
+	vault_index: u8,
 	expiration_time: i64,
+	metadata: ProposalMetadata,
+	proposal_seed_proposal_id: u64,
 ) -> Result<()> {
-    // Implement your business logic here...
-	
+	let multisig = &mut ctx.accounts.multisig;
+	let creator = ctx.accounts.creator.key();
+
+	multisig.require_not_frozen()?;
+	require!(multisig.signers.contains(&creator), MultisigWalletError::NotASigner);
+	require!(proposal_seed_proposal_id == multisig.proposal_count, MultisigWalletError::InvalidProposalId);
+
+	let now = Clock::get()?.unix_timestamp;
+	let expiration_time = multisig.resolve_expiration_time(expiration_time, now)?;
+
+	if multisig.address_book_enforced {
+		let address_book = ctx.accounts.address_book.as_ref()
+			.ok_or(MultisigWalletError::DestinationNotAllowed)?;
+
+		require!(address_book.contains(&terms.destination), MultisigWalletError::DestinationNotAllowed);
+	}
+
+	metadata.validate()?;
+
+	let proposal = &mut ctx.accounts.proposal.load_init()?;
+	proposal.version = Proposal::CURRENT_VERSION;
+	proposal.nonce = ctx.bumps.proposal;
+	proposal.id = multisig.proposal_count;
+	proposal.creator = creator;
+	proposal.destination = terms.destination;
+	proposal.amount = terms.amount;
+	proposal.token_mint = terms.token_mint;
+	proposal.vault_index = vault_index;
+	proposal.vault_nonce = MultisigConfig::vault_nonce(&multisig.key(), vault_index);
+	proposal.expiration_time = expiration_time;
+	proposal.multisig = multisig.key();
+	proposal.set_metadata(metadata);
+	proposal.set_kind(&ProposalKind::Transfer)?;
+	proposal.veto_deadline = multisig.veto_deadline(now)?;
+
+	multisig.proposal_count = multisig.next_proposal_id()?;
+
 	Ok(())
 }
@@ -0,0 +1,88 @@
+use crate::*;
+use crate::error::MultisigWalletError;
+use anchor_lang::prelude::*;
+
+
+
+
+	#[derive(Accounts)]
+	#[instruction(
+		lost_signer: Pubkey,
+		new_signer: Pubkey,
+		recovery_request_seed_recovery_id: u64,
+	)]
+	pub struct CreateRecoveryRequest<'info> {
+		#[account(
+			mut,
+		)]
+		pub fee_payer: Signer<'info>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"multisig",
+				multisig.create_key_seed(),
+			],
+			bump = multisig.nonce,
+		)]
+		pub multisig: Account<'info, MultisigConfig>,
+
+		#[account(
+			init,
+			space=287,
+			payer=fee_payer,
+			seeds = [
+				b"recovery_request",
+				multisig.key().as_ref(),
+				recovery_request_seed_recovery_id.to_le_bytes().as_ref(),
+			],
+			bump,
+		)]
+		pub recovery_request: Account<'info, RecoveryRequest>,
+
+		pub recovery_key: Signer<'info>,
+
+		pub system_program: Program<'info, System>,
+	}
+
+/// Request that a lost signer key be replaced, counting as the first recovery key approval
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable]` multisig: [MultisigConfig] The multisig account
+/// 2. `[writable]` recovery_request: [RecoveryRequest] The recovery request account to initialize
+/// 3. `[signer]` recovery_key: [AccountInfo] The recovery key creating the request
+/// 4. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data:
+/// - lost_signer: [Pubkey] The current signer whose key was lost
+/// - new_signer: [Pubkey] The key replacing the lost signer
+/// - recovery_request_seed_recovery_id: [u64] Auto-generated, from the input "recovery_request" for the its seed definition "RecoveryRequest", sets the seed named "recovery_id"
+pub fn handler(
+	ctx: Context<CreateRecoveryRequest>,
+	lost_signer: Pubkey,
+	new_signer: Pubkey,
+	recovery_request_seed_recovery_id: u64,
+) -> Result<()> {
+	let multisig = &mut ctx.accounts.multisig;
+	let recovery_key = ctx.accounts.recovery_key.key();
+
+	require!(recovery_request_seed_recovery_id == multisig.recovery_count, MultisigWalletError::InvalidRecoveryId);
+	require!(multisig.recovery_keys.contains(&recovery_key), MultisigWalletError::NotARecoveryKey);
+	require!(
+		multisig.signers.contains(&lost_signer) && !multisig.signers.contains(&new_signer),
+		MultisigWalletError::InvalidRecoveryTarget,
+	);
+
+	let recovery_request = &mut ctx.accounts.recovery_request;
+	recovery_request.id = multisig.recovery_count;
+	recovery_request.multisig = multisig.key();
+	recovery_request.lost_signer = lost_signer;
+	recovery_request.new_signer = new_signer;
+	recovery_request.nonce = ctx.bumps.recovery_request;
+	recovery_request.approve(recovery_key, multisig.recovery_threshold, Clock::get()?.unix_timestamp)?;
+
+	multisig.recovery_count = multisig.next_recovery_id()?;
+
+	Ok(())
+}
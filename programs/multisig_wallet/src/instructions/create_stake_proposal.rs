@@ -0,0 +1,111 @@
+use crate::*;
+use crate::error::MultisigWalletError;
+use anchor_lang::prelude::*;
+
+
+
+
+	#[derive(Accounts)]
+	#[instruction(
+		change: StakeChange,
+		vault_index: u8,
+		expiration_time: i64,
+		proposal_seed_proposal_id: u64,
+	)]
+	pub struct CreateStakeProposal<'info> {
+		#[account(
+			mut,
+		)]
+		pub fee_payer: Signer<'info>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"multisig",
+				multisig.create_key_seed(),
+			],
+			bump = multisig.nonce,
+		)]
+		pub multisig: Account<'info, MultisigConfig>,
+
+		#[account(
+			init,
+			space=Proposal::SPACE,
+			payer=fee_payer,
+			seeds = [
+				b"proposal",
+				multisig.key().as_ref(),
+				proposal_seed_proposal_id.to_le_bytes().as_ref(),
+			],
+			bump,
+		)]
+		pub proposal: AccountLoader<'info, Proposal>,
+
+		pub creator: Signer<'info>,
+
+		#[account(
+			seeds = [
+				b"address_book",
+				multisig.key().as_ref(),
+			],
+			bump = address_book.nonce,
+		)]
+		pub address_book: Option<Account<'info, AddressBook>>,
+
+		pub system_program: Program<'info, System>,
+	}
+
+/// Create a proposal that creates, delegates, deactivates or withdraws from a vault stake account
+/// once approved
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable]` multisig: [MultisigConfig] The multisig account
+/// 2. `[writable]` proposal: [Proposal] The proposal account to initialize
+/// 3. `[signer]` creator: [AccountInfo] The account creating the proposal (must be a signer of the multisig)
+/// 4. `[optional]` address_book: [AddressBook] The multisig's address book, required for withdrawals when the multisig enforces it
+/// 5. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data:
+/// - change: [StakeChange] The stake action to perform
+/// - vault_index: [u8] The vault the proposal acts on
+/// - expiration_time: [i64] Optional timestamp when the proposal expires (0 means no expiration, or the maximum lifetime when the multisig sets one)
+/// - proposal_seed_proposal_id: [u64] Auto-generated, from the input "proposal" for the its seed definition "Proposal", sets the seed named "proposal_id"
+pub fn handler(
+	ctx: Context<CreateStakeProposal>,
+	change: StakeChange,
+	vault_index: u8,
+	expiration_time: i64,
+	proposal_seed_proposal_id: u64,
+) -> Result<()> {
+	let multisig = &mut ctx.accounts.multisig;
+	let creator = ctx.accounts.creator.key();
+
+	multisig.require_not_frozen()?;
+	require!(multisig.signers.contains(&creator), MultisigWalletError::NotASigner);
+	require!(proposal_seed_proposal_id == multisig.proposal_count, MultisigWalletError::InvalidProposalId);
+
+	let expiration_time = multisig.resolve_expiration_time(expiration_time, Clock::get()?.unix_timestamp)?;
+
+	if let (StakeChange::Withdraw { destination, .. }, true) = (&change, multisig.address_book_enforced) {
+		let address_book = ctx.accounts.address_book.as_ref()
+			.ok_or(MultisigWalletError::DestinationNotAllowed)?;
+
+		require!(address_book.contains(destination), MultisigWalletError::DestinationNotAllowed);
+	}
+
+	let proposal = &mut ctx.accounts.proposal.load_init()?;
+	proposal.version = Proposal::CURRENT_VERSION;
+	proposal.nonce = ctx.bumps.proposal;
+	proposal.id = multisig.proposal_count;
+	proposal.creator = creator;
+	proposal.vault_index = vault_index;
+	proposal.vault_nonce = MultisigConfig::vault_nonce(&multisig.key(), vault_index);
+	proposal.expiration_time = expiration_time;
+	proposal.multisig = multisig.key();
+	proposal.set_kind(&ProposalKind::Stake(change))?;
+
+	multisig.proposal_count = multisig.next_proposal_id()?;
+
+	Ok(())
+}
@@ -0,0 +1,65 @@
+use crate::*;
+use crate::error::MultisigWalletError;
+use anchor_lang::prelude::*;
+
+
+
+
+	#[derive(Accounts)]
+	pub struct CreateTransferPolicy<'info> {
+		#[account(
+			mut,
+		)]
+		pub fee_payer: Signer<'info>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"multisig",
+				multisig.create_key_seed(),
+			],
+			bump = multisig.nonce,
+		)]
+		pub multisig: Account<'info, MultisigConfig>,
+
+		#[account(
+			init,
+			space=4141,
+			payer=fee_payer,
+			seeds = [
+				b"transfer_policy",
+				multisig.key().as_ref(),
+			],
+			bump,
+		)]
+		pub transfer_policy: Account<'info, TransferPolicy>,
+
+		pub creator: Signer<'info>,
+
+		pub system_program: Program<'info, System>,
+	}
+
+/// Create the (empty) transfer policy of per-mint limits for the multisig
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable]` multisig: [MultisigConfig] The multisig account
+/// 2. `[writable]` transfer_policy: [TransferPolicy] The transfer policy account to initialize
+/// 3. `[signer]` creator: [AccountInfo] The account creating the transfer policy (must be a signer of the multisig)
+/// 4. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+pub fn handler(
+	ctx: Context<CreateTransferPolicy>,
+) -> Result<()> {
+	let multisig = &mut ctx.accounts.multisig;
+
+	require!(multisig.signers.contains(&ctx.accounts.creator.key()), MultisigWalletError::NotASigner);
+
+	let transfer_policy = &mut ctx.accounts.transfer_policy;
+	transfer_policy.multisig = multisig.key();
+	transfer_policy.limits = Vec::new();
+	transfer_policy.nonce = ctx.bumps.transfer_policy;
+
+	multisig.transfer_policy_enabled = true;
+
+	Ok(())
+}
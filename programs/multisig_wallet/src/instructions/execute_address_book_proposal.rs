@@ -0,0 +1,87 @@
+use crate::*;
+use crate::error::MultisigWalletError;
+use anchor_lang::prelude::*;
+
+
+
+
+	#[derive(Accounts)]
+	#[instruction(
+		proposal_seed_proposal_id: u64,
+	)]
+	pub struct ExecuteAddressBookProposal<'info> {
+		#[account(
+			mut,
+		)]
+		pub fee_payer: Signer<'info>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"multisig",
+				multisig.create_key_seed(),
+			],
+			bump = multisig.nonce,
+		)]
+		pub multisig: Account<'info, MultisigConfig>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"proposal",
+				multisig.key().as_ref(),
+				proposal_seed_proposal_id.to_le_bytes().as_ref(),
+			],
+			bump = proposal.load()?.nonce,
+			has_one = multisig @ MultisigWalletError::AccountMismatch,
+		)]
+		pub proposal: AccountLoader<'info, Proposal>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"address_book",
+				multisig.key().as_ref(),
+			],
+			bump = address_book.nonce,
+		)]
+		pub address_book: Account<'info, AddressBook>,
+	}
+
+/// Apply an approved address book proposal
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable]` multisig: [MultisigConfig] The multisig account
+/// 2. `[writable]` proposal: [Proposal] The address book proposal to execute
+/// 3. `[writable]` address_book: [AddressBook] The multisig's address book
+///
+/// Data:
+/// - proposal_seed_proposal_id: [u64] Auto-generated, from the input "proposal" for the its seed definition "Proposal", sets the seed named "proposal_id"
+pub fn handler(
+	ctx: Context<ExecuteAddressBookProposal>,
+) -> Result<()> {
+	let multisig = &mut ctx.accounts.multisig;
+	let proposal = &mut ctx.accounts.proposal.load_mut()?;
+	let address_book = &mut ctx.accounts.address_book;
+
+	let kind = proposal.kind()?;
+	let threshold = multisig.proposal_threshold(&kind, proposal.vault_index);
+
+	let ProposalKind::AddressBook(change) = kind else {
+		return err!(MultisigWalletError::InvalidProposalKind);
+	};
+
+	multisig.require_not_frozen()?;
+	proposal.require_executable(threshold, Clock::get()?.unix_timestamp)?;
+
+	match change {
+		AddressBookChange::AddEntry { address, label } => address_book.add_entry(address, label)?,
+		AddressBookChange::RemoveEntry { address } => address_book.remove_entry(&address)?,
+		AddressBookChange::SetEnforced { enforced } => multisig.address_book_enforced = enforced,
+	}
+
+	proposal.mark_executed();
+
+	Ok(())
+}
@@ -0,0 +1,129 @@
+use crate::*;
+use crate::error::MultisigWalletError;
+use crate::program::MultisigWallet;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::program::invoke_signed;
+use anchor_lang::InstructionData;
+
+
+
+
+	#[derive(Accounts)]
+	#[instruction(
+		proposal_seed_proposal_id: u64,
+	)]
+	pub struct ExecuteNestedApprovalProposal<'info> {
+		#[account(
+			mut,
+		)]
+		pub fee_payer: Signer<'info>,
+
+		#[account(
+			seeds = [
+				b"multisig",
+				multisig.create_key_seed(),
+			],
+			bump = multisig.nonce,
+		)]
+		pub multisig: Account<'info, MultisigConfig>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"proposal",
+				multisig.key().as_ref(),
+				proposal_seed_proposal_id.to_le_bytes().as_ref(),
+			],
+			bump = proposal.load()?.nonce,
+			has_one = multisig @ MultisigWalletError::AccountMismatch,
+		)]
+		pub proposal: AccountLoader<'info, Proposal>,
+
+		#[account(
+			seeds = [
+				b"vault",
+				multisig.key().as_ref(),
+				&[proposal.load()?.vault_index],
+			],
+			bump = proposal.load()?.vault_nonce,
+		)]
+		pub vault: SystemAccount<'info>,
+
+		pub parent_multisig: Account<'info, MultisigConfig>,
+
+		/// CHECK: checked by `approve_proposal` against the parent multisig and the proposal id
+		#[account(
+			mut,
+		)]
+		pub parent_proposal: UncheckedAccount<'info>,
+
+		pub multisig_wallet_program: Program<'info, MultisigWallet>,
+	}
+
+/// Apply an approved nested approval proposal: the vault approves the parent multisig's proposal
+/// by calling `approve_proposal` through CPI
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[]` multisig: [MultisigConfig] The multisig account
+/// 2. `[writable]` proposal: [Proposal] The nested approval proposal to execute
+/// 3. `[]` vault: [AccountInfo] The multisig vault that is a signer of the parent multisig
+/// 4. `[]` parent_multisig: [MultisigConfig] The parent multisig named in the proposal
+/// 5. `[writable]` parent_proposal: [AccountInfo] The parent multisig's proposal to approve
+/// 6. `[]` multisig_wallet_program: [AccountInfo] This program, called back to approve the parent proposal
+///
+/// Data:
+/// - proposal_seed_proposal_id: [u64] Auto-generated, from the input "proposal" for the its seed definition "Proposal", sets the seed named "proposal_id"
+pub fn handler(
+	ctx: Context<ExecuteNestedApprovalProposal>,
+) -> Result<()> {
+	let multisig = &ctx.accounts.multisig;
+	let proposal = &mut ctx.accounts.proposal.load_mut()?;
+	let vault = &ctx.accounts.vault;
+
+	let kind = proposal.kind()?;
+	let threshold = multisig.proposal_threshold(&kind, proposal.vault_index);
+
+	let ProposalKind::NestedApproval { parent_multisig, parent_proposal_id } = kind else {
+		return err!(MultisigWalletError::InvalidProposalKind);
+	};
+
+	multisig.require_not_frozen()?;
+	proposal.require_executable(threshold, Clock::get()?.unix_timestamp)?;
+
+	require_keys_eq!(ctx.accounts.parent_multisig.key(), parent_multisig, MultisigWalletError::AccountMismatch);
+
+	let approve_proposal = Instruction {
+		program_id: crate::ID,
+		accounts: crate::accounts::ApproveProposal {
+			fee_payer: ctx.accounts.fee_payer.key(),
+			multisig: parent_multisig,
+			proposal: ctx.accounts.parent_proposal.key(),
+			signer: vault.key(),
+		}.to_account_metas(None),
+		data: crate::instruction::ApproveProposal {
+			_proposal_seed_proposal_id: parent_proposal_id,
+		}.data(),
+	};
+
+	let multisig_key = multisig.key();
+	let vault_index = [proposal.vault_index];
+	let vault_seeds: &[&[u8]] = &[b"vault", multisig_key.as_ref(), &vault_index, &[proposal.vault_nonce]];
+
+	invoke_signed(
+		&approve_proposal,
+		&[
+			ctx.accounts.fee_payer.to_account_info(),
+			ctx.accounts.parent_multisig.to_account_info(),
+			ctx.accounts.parent_proposal.to_account_info(),
+			vault.to_account_info(),
+			ctx.accounts.multisig_wallet_program.to_account_info(),
+		],
+		&[vault_seeds],
+	)?;
+
+	proposal.mark_executed();
+
+	Ok(())
+}
@@ -0,0 +1,101 @@
+use crate::*;
+use crate::error::MultisigWalletError;
+use anchor_lang::prelude::*;
+
+
+
+
+	#[derive(Accounts)]
+	#[instruction(
+		proposal_seed_proposal_id: u64,
+	)]
+	pub struct ExecutePaymentScheduleProposal<'info> {
+		#[account(
+			mut,
+		)]
+		pub fee_payer: Signer<'info>,
+
+		#[account(
+			seeds = [
+				b"multisig",
+				multisig.create_key_seed(),
+			],
+			bump = multisig.nonce,
+		)]
+		pub multisig: Account<'info, MultisigConfig>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"proposal",
+				multisig.key().as_ref(),
+				proposal_seed_proposal_id.to_le_bytes().as_ref(),
+			],
+			bump = proposal.load()?.nonce,
+			has_one = multisig @ MultisigWalletError::AccountMismatch,
+		)]
+		pub proposal: AccountLoader<'info, Proposal>,
+
+		#[account(
+			init,
+			space=147,
+			payer=fee_payer,
+			seeds = [
+				b"payment_schedule",
+				multisig.key().as_ref(),
+				proposal_seed_proposal_id.to_le_bytes().as_ref(),
+			],
+			bump,
+		)]
+		pub payment_schedule: Account<'info, PaymentSchedule>,
+
+		pub system_program: Program<'info, System>,
+	}
+
+/// Create the payment schedule of an approved payment schedule proposal
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[]` multisig: [MultisigConfig] The multisig account
+/// 2. `[writable]` proposal: [Proposal] The payment schedule proposal to execute
+/// 3. `[writable]` payment_schedule: [PaymentSchedule] The payment schedule account to initialize
+/// 4. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data:
+/// - proposal_seed_proposal_id: [u64] Auto-generated, from the input "proposal" for the its seed definition "Proposal", sets the seed named "proposal_id"
+pub fn handler(
+	ctx: Context<ExecutePaymentScheduleProposal>,
+	proposal_seed_proposal_id: u64,
+) -> Result<()> {
+	let multisig = &ctx.accounts.multisig;
+	let proposal = &mut ctx.accounts.proposal.load_mut()?;
+	let now = Clock::get()?.unix_timestamp;
+
+	let kind = proposal.kind()?;
+	let threshold = multisig.proposal_threshold(&kind, proposal.vault_index);
+
+	let ProposalKind::PaymentSchedule(terms) = kind else {
+		return err!(MultisigWalletError::InvalidProposalKind);
+	};
+
+	multisig.require_not_frozen()?;
+	proposal.require_executable(threshold, now)?;
+
+	let payment_schedule = &mut ctx.accounts.payment_schedule;
+	payment_schedule.id = proposal_seed_proposal_id;
+	payment_schedule.multisig = multisig.key();
+	payment_schedule.destination = terms.destination;
+	payment_schedule.mint = terms.mint;
+	payment_schedule.amount = terms.amount;
+	payment_schedule.interval = terms.interval;
+	payment_schedule.payment_count = terms.payment_count;
+	payment_schedule.payments_made = 0;
+	payment_schedule.next_payment_time = terms.start_time.max(now);
+	payment_schedule.vault_index = proposal.vault_index;
+	payment_schedule.vault_nonce = proposal.vault_nonce;
+	payment_schedule.nonce = ctx.bumps.payment_schedule;
+
+	proposal.mark_executed();
+
+	Ok(())
+}
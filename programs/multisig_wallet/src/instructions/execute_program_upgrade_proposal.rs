@@ -0,0 +1,186 @@
+use crate::*;
+use crate::error::MultisigWalletError;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::invoke_signed;
+
+#[allow(deprecated)]
+use anchor_lang::solana_program::bpf_loader_upgradeable;
+
+
+
+
+	#[derive(Accounts)]
+	#[instruction(
+		proposal_seed_proposal_id: u64,
+	)]
+	pub struct ExecuteProgramUpgradeProposal<'info> {
+		#[account(
+			mut,
+		)]
+		pub fee_payer: Signer<'info>,
+
+		#[account(
+			seeds = [
+				b"multisig",
+				multisig.create_key_seed(),
+			],
+			bump = multisig.nonce,
+		)]
+		pub multisig: Account<'info, MultisigConfig>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"proposal",
+				multisig.key().as_ref(),
+				proposal_seed_proposal_id.to_le_bytes().as_ref(),
+			],
+			bump = proposal.load()?.nonce,
+			has_one = multisig @ MultisigWalletError::AccountMismatch,
+		)]
+		pub proposal: AccountLoader<'info, Proposal>,
+
+		#[account(
+			seeds = [
+				b"vault",
+				multisig.key().as_ref(),
+				&[proposal.load()?.vault_index],
+			],
+			bump = proposal.load()?.vault_nonce,
+		)]
+		pub vault: SystemAccount<'info>,
+
+		/// CHECK: matched against the proposal in the handler
+		#[account(
+			mut,
+		)]
+		pub program: UncheckedAccount<'info>,
+
+		#[account(
+			mut,
+		)]
+		pub program_data: Account<'info, ProgramData>,
+
+		/// CHECK: matched against the proposal in the handler
+		#[account(
+			mut,
+		)]
+		pub buffer: Option<UncheckedAccount<'info>>,
+
+		/// CHECK: matched against the proposal in the handler
+		#[account(
+			mut,
+		)]
+		pub spill: Option<UncheckedAccount<'info>>,
+
+		/// CHECK: matched against the proposal in the handler
+		pub new_authority: Option<UncheckedAccount<'info>>,
+
+		pub rent: Sysvar<'info, Rent>,
+
+		pub clock: Sysvar<'info, Clock>,
+
+		/// CHECK: address is checked
+		#[account(
+			address = bpf_loader_upgradeable::ID,
+		)]
+		pub bpf_loader_upgradeable: UncheckedAccount<'info>,
+	}
+
+/// Apply an approved program upgrade proposal, signing for the upgrade authority with the vault
+///
+/// The buffer is not hashed again here: its authority is the vault, so its contents cannot change
+/// after the hash was checked at proposal creation.
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[]` multisig: [MultisigConfig] The multisig account
+/// 2. `[writable]` proposal: [Proposal] The program upgrade proposal to execute
+/// 3. `[]` vault: [AccountInfo] The multisig vault holding the upgrade authority
+/// 4. `[writable]` program: [AccountInfo] The program to upgrade
+/// 5. `[writable]` program_data: [ProgramData] The program data account of the program
+/// 6. `[writable, optional]` buffer: [AccountInfo] The buffer holding the new program, required for upgrades
+/// 7. `[writable, optional]` spill: [AccountInfo] Receives the buffer's lamports, required for upgrades
+/// 8. `[optional]` new_authority: [AccountInfo] The new upgrade authority, required when one is set
+/// 9. `[]` rent: [AccountInfo] Rent sysvar
+/// 10. `[]` clock: [AccountInfo] Clock sysvar
+/// 11. `[]` bpf_loader_upgradeable: [AccountInfo] BPF upgradeable loader program
+///
+/// Data:
+/// - proposal_seed_proposal_id: [u64] Auto-generated, from the input "proposal" for the its seed definition "Proposal", sets the seed named "proposal_id"
+#[allow(deprecated)]
+pub fn handler(
+	ctx: Context<ExecuteProgramUpgradeProposal>,
+) -> Result<()> {
+	let multisig = &ctx.accounts.multisig;
+	let proposal = &mut ctx.accounts.proposal.load_mut()?;
+	let vault = &ctx.accounts.vault;
+
+	let kind = proposal.kind()?;
+	let threshold = multisig.proposal_threshold(&kind, proposal.vault_index);
+
+	let ProposalKind::ProgramUpgrade(change) = kind else {
+		return err!(MultisigWalletError::InvalidProposalKind);
+	};
+
+	multisig.require_not_frozen()?;
+	proposal.require_executable(threshold, Clock::get()?.unix_timestamp)?;
+
+	require_keys_eq!(ctx.accounts.program.key(), change.program(), MultisigWalletError::AccountMismatch);
+	change.validate_program_data(&ctx.accounts.program_data, &vault.key())?;
+
+	let multisig_key = multisig.key();
+	let vault_index = [proposal.vault_index];
+	let vault_seeds: &[&[u8]] = &[b"vault", multisig_key.as_ref(), &vault_index, &[proposal.vault_nonce]];
+
+	match change {
+		ProgramUpgradeChange::Upgrade { program, buffer, spill, .. } => {
+			let buffer_account = ctx.accounts.buffer.as_ref().ok_or(MultisigWalletError::InvalidBuffer)?;
+			let spill_account = ctx.accounts.spill.as_ref().ok_or(MultisigWalletError::AccountMismatch)?;
+
+			require_keys_eq!(buffer_account.key(), buffer, MultisigWalletError::InvalidBuffer);
+			require_keys_eq!(spill_account.key(), spill, MultisigWalletError::AccountMismatch);
+
+			invoke_signed(
+				&bpf_loader_upgradeable::upgrade(&program, &buffer, &vault.key(), &spill),
+				&[
+					ctx.accounts.program_data.to_account_info(),
+					ctx.accounts.program.to_account_info(),
+					buffer_account.to_account_info(),
+					spill_account.to_account_info(),
+					ctx.accounts.rent.to_account_info(),
+					ctx.accounts.clock.to_account_info(),
+					vault.to_account_info(),
+					ctx.accounts.bpf_loader_upgradeable.to_account_info(),
+				],
+				&[vault_seeds],
+			)?;
+		}
+		ProgramUpgradeChange::SetAuthority { program, new_authority } => {
+			let mut account_infos = vec![
+				ctx.accounts.program_data.to_account_info(),
+				vault.to_account_info(),
+			];
+
+			if let Some(new_authority) = new_authority {
+				let new_authority_account = ctx.accounts.new_authority.as_ref()
+					.ok_or(MultisigWalletError::AccountMismatch)?;
+
+				require_keys_eq!(new_authority_account.key(), new_authority, MultisigWalletError::AccountMismatch);
+				account_infos.push(new_authority_account.to_account_info());
+			}
+
+			account_infos.push(ctx.accounts.bpf_loader_upgradeable.to_account_info());
+
+			invoke_signed(
+				&bpf_loader_upgradeable::set_upgrade_authority(&program, &vault.key(), new_authority.as_ref()),
+				&account_infos,
+				&[vault_seeds],
+			)?;
+		}
+	}
+
+	proposal.mark_executed();
+
+	Ok(())
+}
@@ -1,9 +1,10 @@
 use crate::*;
+use crate::error::MultisigWalletError;
 use anchor_lang::prelude::*;
-use std::str::FromStr;
 
 use anchor_spl::{
     associated_token::AssociatedToken,
+    memo::Memo,
     token::{Mint, Token, TokenAccount},
 };
 
@@ -23,8 +24,9 @@ use anchor_spl::{
 		#[account(
 			seeds = [
 				b"multisig",
+				multisig.create_key_seed(),
 			],
-			bump,
+			bump = multisig.nonce,
 		)]
 		pub multisig: Account<'info, MultisigConfig>,
 
@@ -35,101 +37,163 @@ use anchor_spl::{
 				multisig.key().as_ref(),
 				proposal_seed_proposal_id.to_le_bytes().as_ref(),
 			],
-			bump,
+			bump = proposal.load()?.nonce,
+			has_one = multisig @ MultisigWalletError::AccountMismatch,
 		)]
-		pub proposal: Account<'info, Proposal>,
+		pub proposal: AccountLoader<'info, Proposal>,
 
 		#[account(
-			mut,
+			seeds = [
+				b"vault",
+				multisig.key().as_ref(),
+				&[proposal.load()?.vault_index],
+			],
+			bump = proposal.load()?.vault_nonce,
 		)]
-		/// CHECK: implement manual checks if needed
-		pub source: UncheckedAccount<'info>,
+		pub vault: SystemAccount<'info>,
 
 		#[account(
 			mut,
+			associated_token::mint = mint,
+			associated_token::authority = vault,
+			associated_token::token_program = token_program,
 		)]
-		/// CHECK: implement manual checks if needed
-		pub destination: UncheckedAccount<'info>,
+		pub source: Account<'info, TokenAccount>,
 
 		#[account(
-			owner=Pubkey::from_str("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA").unwrap(),
+			init_if_needed,
+			payer = funding,
+			associated_token::mint = mint,
+			associated_token::authority = wallet,
+			associated_token::token_program = token_program,
 		)]
-		pub authority: Signer<'info>,
+		pub destination: Account<'info, TokenAccount>,
 
 		#[account(
 			mut,
-			owner=Pubkey::from_str("11111111111111111111111111111111").unwrap(),
 		)]
 		pub funding: Signer<'info>,
 
 		#[account(
-			init,
-			payer = funding,
-			associated_token::mint = mint,
-			associated_token::authority = wallet,
-			associated_token::token_program = token_program,
+			address = proposal.load()?.destination @ MultisigWalletError::AccountMismatch,
 		)]
-		pub assoc_token_account: Account<'info, TokenAccount>,
-
-		/// CHECK: implement manual checks if needed
+		/// CHECK: the proposal's destination wallet, checked by address
 		pub wallet: UncheckedAccount<'info>,
 
+		#[account(
+			address = proposal.load()?.token_mint @ MultisigWalletError::AccountMismatch,
+		)]
 		pub mint: Account<'info, Mint>,
 
 		pub system_program: Program<'info, System>,
 
 		pub token_program: Program<'info, Token>,
 
-		pub csl_spl_token_v0_0_0: Program<'info, Token>,
+		pub associated_token_program: Program<'info, AssociatedToken>,
 
-		pub csl_spl_assoc_token_v0_0_0: Program<'info, AssociatedToken>,
+		pub memo_program: Program<'info, Memo>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"transfer_policy",
+				multisig.key().as_ref(),
+			],
+			bump = transfer_policy.nonce,
+		)]
+		pub transfer_policy: Option<Account<'info, TransferPolicy>>,
 	}
 
 	impl<'info> ExecuteProposal<'info> {
-		pub fn cpi_csl_spl_token_transfer(&self, amount: u64) -> Result<()> {
+		pub fn cpi_spl_token_transfer(&self, amount: u64, vault_seeds: &[&[u8]]) -> Result<()> {
 			anchor_spl::token::transfer(
-				CpiContext::new(self.csl_spl_token_v0_0_0.to_account_info(), 
+				CpiContext::new_with_signer(self.token_program.to_account_info(), 
 					anchor_spl::token::Transfer {
 						from: self.source.to_account_info(),
 						to: self.destination.to_account_info(),
-						authority: self.authority.to_account_info()
-					}
+						authority: self.vault.to_account_info()
+					},
+					&[vault_seeds],
 				),
 				amount, 
 			)
 		}
+
+		pub fn cpi_spl_memo_build_memo(&self, memo: &str) -> Result<()> {
+			anchor_spl::memo::build_memo(
+				CpiContext::new(self.memo_program.to_account_info(), 
+					anchor_spl::memo::BuildMemo {}
+				),
+				memo.as_bytes(), 
+			)
+		}
 	}
 
 
-/// Execute a proposal if the threshold is met
+/// Execute a proposal if the threshold is met (optimistic proposals, while the multisig stays in
+/// optimistic mode, once their veto window has elapsed without enough vetoes and with the approvals
+/// of their vault's threshold override, if any)
 ///
 /// Accounts:
 /// 0. `[writable, signer]` fee_payer: [AccountInfo] 
 /// 1. `[]` multisig: [MultisigConfig] The multisig account
-/// 2. `[writable]` proposal: [Proposal] The proposal to execute
-/// 3. `[writable]` source: [AccountInfo] The source account.
-/// 4. `[writable]` destination: [AccountInfo] The destination account.
-/// 5. `[signer]` authority: [AccountInfo] The source account's owner/delegate.
-/// 6. `[writable, signer]` funding: [AccountInfo] Funding account (must be a system account)
-/// 7. `[writable]` assoc_token_account: [AccountInfo] Associated token account address to be created
-/// 8. `[]` wallet: [AccountInfo] Wallet address for the new associated token account
-/// 9. `[]` mint: [Mint] The token mint for the new associated token account
-/// 10. `[]` system_program: [AccountInfo] System program
-/// 11. `[]` token_program: [AccountInfo] SPL Token program
-/// 12. `[]` csl_spl_token_v0_0_0: [AccountInfo] Auto-generated, CslSplTokenProgram v0.0.0
-/// 13. `[]` csl_spl_assoc_token_v0_0_0: [AccountInfo] Auto-generated, CslSplAssocTokenProgram v0.0.0
+/// 2. `[writable]` proposal: [Proposal] The proposal to execute (must belong to the multisig)
+/// 3. `[]` vault: [AccountInfo] The multisig vault paying the transfer
+/// 4. `[writable]` source: [TokenAccount] The vault's associated token account for the proposal's mint
+/// 5. `[writable]` destination: [TokenAccount] The destination wallet's associated token account for the proposal's mint, created if missing
+/// 6. `[writable, signer]` funding: [AccountInfo] Pays the rent of the destination token account when it is created
+/// 7. `[]` wallet: [AccountInfo] The proposal's destination wallet
+/// 8. `[]` mint: [Mint] The mint named in the proposal
+/// 9. `[]` system_program: [AccountInfo] System program
+/// 10. `[]` token_program: [AccountInfo] SPL Token program
+/// 11. `[]` associated_token_program: [AccountInfo] SPL Associated Token Account program
+/// 12. `[]` memo_program: [AccountInfo] SPL Memo program, used to attach the proposal memo to the transfer
+/// 13. `[writable, optional]` transfer_policy: [TransferPolicy] The multisig's transfer policy, required when the multisig has one
 ///
 /// Data:
 /// - proposal_seed_proposal_id: [u64] Auto-generated, from the input "proposal" for the its seed definition "Proposal", sets the seed named "proposal_id"
 pub fn handler(
 	ctx: Context<ExecuteProposal>,
 ) -> Result<()> {
-    // Implement your business logic here...
-	
+	let multisig = &ctx.accounts.multisig;
+	let proposal = &mut ctx.accounts.proposal.load_mut()?;
+	let now = Clock::get()?.unix_timestamp;
+
+	let kind = proposal.kind()?;
+	require!(kind == ProposalKind::Transfer, MultisigWalletError::InvalidProposalKind);
+	multisig.require_not_frozen()?;
+	if proposal.is_optimistic() && multisig.optimistic {
+		proposal.require_unvetoed(multisig.veto_threshold, now)?;
+		require!(
+			proposal.approval_count() >= multisig.optimistic_threshold(proposal.vault_index),
+			MultisigWalletError::ThresholdNotMet,
+		);
+	} else {
+		proposal.require_executable(multisig.proposal_threshold(&kind, proposal.vault_index), now)?;
+	}
+
+	if multisig.transfer_policy_enabled {
+		let transfer_policy = ctx.accounts.transfer_policy.as_mut()
+			.ok_or(MultisigWalletError::TransferPolicyRequired)?;
+
+		transfer_policy.record_transfer(&proposal.token_mint, proposal.amount, now)?;
+	}
+
+	let multisig_key = multisig.key();
+	let vault_index = [proposal.vault_index];
+	let vault_seeds: &[&[u8]] = &[b"vault", multisig_key.as_ref(), &vault_index, &[proposal.vault_nonce]];
+
 	// Cpi calls wrappers
-	ctx.accounts.cpi_csl_spl_token_transfer(
-		Default::default(),
+	ctx.accounts.cpi_spl_token_transfer(
+		proposal.amount,
+		vault_seeds,
 	)?;
 
+	if let Some(memo) = proposal.memo() {
+		ctx.accounts.cpi_spl_memo_build_memo(memo)?;
+	}
+
+	proposal.mark_executed();
+
 	Ok(())
 }
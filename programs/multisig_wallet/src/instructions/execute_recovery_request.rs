@@ -0,0 +1,75 @@
+use crate::*;
+use crate::error::MultisigWalletError;
+use anchor_lang::prelude::*;
+
+
+
+
+	#[derive(Accounts)]
+	#[instruction(
+		recovery_request_seed_recovery_id: u64,
+	)]
+	pub struct ExecuteRecoveryRequest<'info> {
+		#[account(
+			mut,
+		)]
+		pub fee_payer: Signer<'info>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"multisig",
+				multisig.create_key_seed(),
+			],
+			bump = multisig.nonce,
+		)]
+		pub multisig: Account<'info, MultisigConfig>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"recovery_request",
+				multisig.key().as_ref(),
+				recovery_request_seed_recovery_id.to_le_bytes().as_ref(),
+			],
+			bump = recovery_request.nonce,
+		)]
+		pub recovery_request: Account<'info, RecoveryRequest>,
+	}
+
+/// Replace the lost signer once the recovery request is approved and its time lock has elapsed (callable by anyone)
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable]` multisig: [MultisigConfig] The multisig account to update
+/// 2. `[writable]` recovery_request: [RecoveryRequest] The recovery request to execute
+///
+/// Data:
+/// - recovery_request_seed_recovery_id: [u64] Auto-generated, from the input "recovery_request" for the its seed definition "RecoveryRequest", sets the seed named "recovery_id"
+pub fn handler(
+	ctx: Context<ExecuteRecoveryRequest>,
+) -> Result<()> {
+	let multisig = &mut ctx.accounts.multisig;
+	let recovery_request = &mut ctx.accounts.recovery_request;
+	let now = Clock::get()?.unix_timestamp;
+
+	recovery_request.require_pending()?;
+	require!(recovery_request.approved_at != 0, MultisigWalletError::RecoveryThresholdNotMet);
+	require!(
+		now >= recovery_request.approved_at.saturating_add(multisig.recovery_delay),
+		MultisigWalletError::RecoveryTimelockActive,
+	);
+	require!(
+		!multisig.signers.contains(&recovery_request.new_signer),
+		MultisigWalletError::InvalidRecoveryTarget,
+	);
+
+	let index = multisig.signers.iter()
+		.position(|signer| *signer == recovery_request.lost_signer)
+		.ok_or(MultisigWalletError::InvalidRecoveryTarget)?;
+
+	multisig.signers[index] = recovery_request.new_signer;
+	recovery_request.executed = true;
+
+	Ok(())
+}
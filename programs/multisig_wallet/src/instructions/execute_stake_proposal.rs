@@ -0,0 +1,245 @@
+use crate::*;
+use crate::error::MultisigWalletError;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::invoke_signed;
+use anchor_lang::system_program;
+
+#[allow(deprecated)]
+use anchor_lang::solana_program::{
+	stake::{self, instruction as stake_instruction, state::{Authorized, Lockup, StakeStateV2}},
+	sysvar::stake_history,
+};
+
+use anchor_spl::stake::{DeactivateStake, Stake, Withdraw};
+
+
+
+
+	#[derive(Accounts)]
+	#[instruction(
+		proposal_seed_proposal_id: u64,
+	)]
+	pub struct ExecuteStakeProposal<'info> {
+		#[account(
+			mut,
+		)]
+		pub fee_payer: Signer<'info>,
+
+		#[account(
+			seeds = [
+				b"multisig",
+				multisig.create_key_seed(),
+			],
+			bump = multisig.nonce,
+		)]
+		pub multisig: Account<'info, MultisigConfig>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"proposal",
+				multisig.key().as_ref(),
+				proposal_seed_proposal_id.to_le_bytes().as_ref(),
+			],
+			bump = proposal.load()?.nonce,
+			has_one = multisig @ MultisigWalletError::AccountMismatch,
+		)]
+		pub proposal: AccountLoader<'info, Proposal>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"vault",
+				multisig.key().as_ref(),
+				&[proposal.load()?.vault_index],
+			],
+			bump = proposal.load()?.vault_nonce,
+		)]
+		pub vault: SystemAccount<'info>,
+
+		/// CHECK: matched against the proposal in the handler
+		#[account(
+			mut,
+		)]
+		pub stake_account: UncheckedAccount<'info>,
+
+		/// CHECK: matched against the proposal in the handler
+		pub vote_account: Option<UncheckedAccount<'info>>,
+
+		/// CHECK: matched against the proposal in the handler
+		#[account(
+			mut,
+		)]
+		pub destination: Option<UncheckedAccount<'info>>,
+
+		pub rent: Sysvar<'info, Rent>,
+
+		pub clock: Sysvar<'info, Clock>,
+
+		/// CHECK: address is checked
+		#[account(
+			address = stake_history::ID,
+		)]
+		pub stake_history: UncheckedAccount<'info>,
+
+		/// CHECK: address is checked
+		#[account(
+			address = stake::config::ID,
+		)]
+		pub stake_config: UncheckedAccount<'info>,
+
+		pub system_program: Program<'info, System>,
+
+		pub stake_program: Program<'info, Stake>,
+	}
+
+/// Apply an approved stake proposal through the stake program, signing as staker and withdrawer
+/// with the vault
+///
+/// New stake accounts live at the PDA `["stake_account", multisig, proposal id]`.
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[]` multisig: [MultisigConfig] The multisig account
+/// 2. `[writable]` proposal: [Proposal] The stake proposal to execute
+/// 3. `[writable]` vault: [AccountInfo] The multisig vault funding and controlling the stake accounts
+/// 4. `[writable]` stake_account: [AccountInfo] The stake account to create or act on
+/// 5. `[optional]` vote_account: [AccountInfo] The validator vote account, required for delegations
+/// 6. `[writable, optional]` destination: [AccountInfo] Receives withdrawn lamports, required for withdrawals
+/// 7. `[]` rent: [AccountInfo] Rent sysvar
+/// 8. `[]` clock: [AccountInfo] Clock sysvar
+/// 9. `[]` stake_history: [AccountInfo] Stake history sysvar
+/// 10. `[]` stake_config: [AccountInfo] Stake config account
+/// 11. `[]` system_program: [AccountInfo] System program
+/// 12. `[]` stake_program: [AccountInfo] Stake program
+///
+/// Data:
+/// - proposal_seed_proposal_id: [u64] Auto-generated, from the input "proposal" for the its seed definition "Proposal", sets the seed named "proposal_id"
+#[allow(deprecated)]
+pub fn handler(
+	ctx: Context<ExecuteStakeProposal>,
+	proposal_seed_proposal_id: u64,
+) -> Result<()> {
+	let multisig = &ctx.accounts.multisig;
+	let proposal = &mut ctx.accounts.proposal.load_mut()?;
+	let vault = &ctx.accounts.vault;
+	let stake_account = &ctx.accounts.stake_account;
+
+	let kind = proposal.kind()?;
+	let threshold = multisig.proposal_threshold(&kind, proposal.vault_index);
+
+	let ProposalKind::Stake(change) = kind else {
+		return err!(MultisigWalletError::InvalidProposalKind);
+	};
+
+	multisig.require_not_frozen()?;
+	proposal.require_executable(threshold, Clock::get()?.unix_timestamp)?;
+
+	let multisig_key = multisig.key();
+	let vault_index = [proposal.vault_index];
+	let vault_seeds: &[&[u8]] = &[b"vault", multisig_key.as_ref(), &vault_index, &[proposal.vault_nonce]];
+
+	match change {
+		StakeChange::Create { lamports } => {
+			let proposal_id = proposal_seed_proposal_id.to_le_bytes();
+			let (stake_account_address, stake_account_bump) = Pubkey::find_program_address(
+				&[b"stake_account", multisig_key.as_ref(), proposal_id.as_ref()],
+				ctx.program_id,
+			);
+			require_keys_eq!(stake_account.key(), stake_account_address, MultisigWalletError::AccountMismatch);
+
+			let stake_account_seeds: &[&[u8]] = &[
+				b"stake_account",
+				multisig_key.as_ref(),
+				proposal_id.as_ref(),
+				&[stake_account_bump],
+			];
+
+			system_program::create_account(
+				CpiContext::new_with_signer(
+					ctx.accounts.system_program.to_account_info(),
+					system_program::CreateAccount {
+						from: vault.to_account_info(),
+						to: stake_account.to_account_info(),
+					},
+					&[vault_seeds, stake_account_seeds],
+				),
+				lamports,
+				StakeStateV2::size_of() as u64,
+				&stake::program::ID,
+			)?;
+
+			invoke_signed(
+				&stake_instruction::initialize(
+					&stake_account.key(),
+					&Authorized { staker: vault.key(), withdrawer: vault.key() },
+					&Lockup::default(),
+				),
+				&[stake_account.to_account_info(), ctx.accounts.rent.to_account_info()],
+				&[],
+			)?;
+		}
+		StakeChange::Delegate { stake_account: stake_account_address, vote_account } => {
+			require_keys_eq!(stake_account.key(), stake_account_address, MultisigWalletError::AccountMismatch);
+
+			let vote_account_info = ctx.accounts.vote_account.as_ref()
+				.ok_or(MultisigWalletError::AccountMismatch)?;
+			require_keys_eq!(vote_account_info.key(), vote_account, MultisigWalletError::AccountMismatch);
+
+			invoke_signed(
+				&stake_instruction::delegate_stake(&stake_account.key(), &vault.key(), &vote_account),
+				&[
+					stake_account.to_account_info(),
+					vote_account_info.to_account_info(),
+					ctx.accounts.clock.to_account_info(),
+					ctx.accounts.stake_history.to_account_info(),
+					ctx.accounts.stake_config.to_account_info(),
+					vault.to_account_info(),
+				],
+				&[vault_seeds],
+			)?;
+		}
+		StakeChange::Deactivate { stake_account: stake_account_address } => {
+			require_keys_eq!(stake_account.key(), stake_account_address, MultisigWalletError::AccountMismatch);
+
+			anchor_spl::stake::deactivate_stake(
+				CpiContext::new_with_signer(
+					ctx.accounts.stake_program.to_account_info(),
+					DeactivateStake {
+						stake: stake_account.to_account_info(),
+						staker: vault.to_account_info(),
+						clock: ctx.accounts.clock.to_account_info(),
+					},
+					&[vault_seeds],
+				),
+			)?;
+		}
+		StakeChange::Withdraw { stake_account: stake_account_address, destination, lamports } => {
+			require_keys_eq!(stake_account.key(), stake_account_address, MultisigWalletError::AccountMismatch);
+
+			let destination_info = ctx.accounts.destination.as_ref()
+				.ok_or(MultisigWalletError::AccountMismatch)?;
+			require_keys_eq!(destination_info.key(), destination, MultisigWalletError::AccountMismatch);
+
+			anchor_spl::stake::withdraw(
+				CpiContext::new_with_signer(
+					ctx.accounts.stake_program.to_account_info(),
+					Withdraw {
+						stake: stake_account.to_account_info(),
+						withdrawer: vault.to_account_info(),
+						to: destination_info.to_account_info(),
+						clock: ctx.accounts.clock.to_account_info(),
+						stake_history: ctx.accounts.stake_history.to_account_info(),
+					},
+					&[vault_seeds],
+				),
+				lamports,
+				None,
+			)?;
+		}
+	}
+
+	proposal.mark_executed();
+
+	Ok(())
+}
@@ -0,0 +1,87 @@
+use crate::*;
+use crate::error::MultisigWalletError;
+use anchor_lang::prelude::*;
+
+
+
+
+	#[derive(Accounts)]
+	#[instruction(
+		proposal_seed_proposal_id: u64,
+	)]
+	pub struct ExecuteTransferPolicyProposal<'info> {
+		#[account(
+			mut,
+		)]
+		pub fee_payer: Signer<'info>,
+
+		#[account(
+			seeds = [
+				b"multisig",
+				multisig.create_key_seed(),
+			],
+			bump = multisig.nonce,
+		)]
+		pub multisig: Account<'info, MultisigConfig>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"proposal",
+				multisig.key().as_ref(),
+				proposal_seed_proposal_id.to_le_bytes().as_ref(),
+			],
+			bump = proposal.load()?.nonce,
+			has_one = multisig @ MultisigWalletError::AccountMismatch,
+		)]
+		pub proposal: AccountLoader<'info, Proposal>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"transfer_policy",
+				multisig.key().as_ref(),
+			],
+			bump = transfer_policy.nonce,
+		)]
+		pub transfer_policy: Account<'info, TransferPolicy>,
+	}
+
+/// Apply an approved transfer policy proposal
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[]` multisig: [MultisigConfig] The multisig account
+/// 2. `[writable]` proposal: [Proposal] The transfer policy proposal to execute
+/// 3. `[writable]` transfer_policy: [TransferPolicy] The multisig's transfer policy
+///
+/// Data:
+/// - proposal_seed_proposal_id: [u64] Auto-generated, from the input "proposal" for the its seed definition "Proposal", sets the seed named "proposal_id"
+pub fn handler(
+	ctx: Context<ExecuteTransferPolicyProposal>,
+) -> Result<()> {
+	let multisig = &ctx.accounts.multisig;
+	let proposal = &mut ctx.accounts.proposal.load_mut()?;
+	let transfer_policy = &mut ctx.accounts.transfer_policy;
+
+	let kind = proposal.kind()?;
+	let threshold = multisig.proposal_threshold(&kind, proposal.vault_index);
+
+	let ProposalKind::TransferPolicy(change) = kind else {
+		return err!(MultisigWalletError::InvalidProposalKind);
+	};
+
+	multisig.require_not_frozen()?;
+	proposal.require_executable(threshold, Clock::get()?.unix_timestamp)?;
+
+	match change {
+		TransferPolicyChange::SetLimit { mint, max_transfer_amount, max_window_volume } => {
+			transfer_policy.set_limit(mint, max_transfer_amount, max_window_volume)?
+		}
+		TransferPolicyChange::RemoveLimit { mint } => transfer_policy.remove_limit(&mint)?,
+	}
+
+	proposal.mark_executed();
+
+	Ok(())
+}
@@ -0,0 +1,183 @@
+use crate::*;
+use crate::error::MultisigWalletError;
+use anchor_lang::prelude::*;
+
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{Mint, Token, TokenAccount},
+};
+
+
+
+
+	#[derive(Accounts)]
+	#[instruction(
+		proposal_seed_proposal_id: u64,
+	)]
+	pub struct ExecuteVestingStreamProposal<'info> {
+		#[account(
+			mut,
+		)]
+		pub fee_payer: Signer<'info>,
+
+		#[account(
+			seeds = [
+				b"multisig",
+				multisig.create_key_seed(),
+			],
+			bump = multisig.nonce,
+		)]
+		pub multisig: Account<'info, MultisigConfig>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"proposal",
+				multisig.key().as_ref(),
+				proposal_seed_proposal_id.to_le_bytes().as_ref(),
+			],
+			bump = proposal.load()?.nonce,
+			has_one = multisig @ MultisigWalletError::AccountMismatch,
+		)]
+		pub proposal: AccountLoader<'info, Proposal>,
+
+		#[account(
+			seeds = [
+				b"vault",
+				multisig.key().as_ref(),
+				&[proposal.load()?.vault_index],
+			],
+			bump = proposal.load()?.vault_nonce,
+		)]
+		pub vault: SystemAccount<'info>,
+
+		#[account(
+			init,
+			space=156,
+			payer=fee_payer,
+			seeds = [
+				b"vesting_stream",
+				multisig.key().as_ref(),
+				proposal_seed_proposal_id.to_le_bytes().as_ref(),
+			],
+			bump,
+		)]
+		pub vesting_stream: Account<'info, VestingStream>,
+
+		#[account(
+			mut,
+			associated_token::mint = mint,
+			associated_token::authority = vault,
+			associated_token::token_program = token_program,
+		)]
+		pub source: Account<'info, TokenAccount>,
+
+		#[account(
+			init,
+			payer = fee_payer,
+			associated_token::mint = mint,
+			associated_token::authority = vesting_stream,
+			associated_token::token_program = token_program,
+		)]
+		pub escrow: Account<'info, TokenAccount>,
+
+		pub mint: Account<'info, Mint>,
+
+		pub system_program: Program<'info, System>,
+
+		pub token_program: Program<'info, Token>,
+
+		pub associated_token_program: Program<'info, AssociatedToken>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"transfer_policy",
+				multisig.key().as_ref(),
+			],
+			bump = transfer_policy.nonce,
+		)]
+		pub transfer_policy: Option<Account<'info, TransferPolicy>>,
+	}
+
+/// Create the vesting stream of an approved vesting stream proposal, moving its total amount from
+/// the vault into the stream's escrow
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[]` multisig: [MultisigConfig] The multisig account
+/// 2. `[writable]` proposal: [Proposal] The vesting stream proposal to execute
+/// 3. `[]` vault: [AccountInfo] The multisig vault funding the stream
+/// 4. `[writable]` vesting_stream: [VestingStream] The vesting stream account to initialize
+/// 5. `[writable]` source: [TokenAccount] The vault's associated token account for the mint
+/// 6. `[writable]` escrow: [TokenAccount] The stream's associated token account to initialize
+/// 7. `[]` mint: [Mint] The mint named in the proposal
+/// 8. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+/// 9. `[]` token_program: [AccountInfo] SPL Token program
+/// 10. `[]` associated_token_program: [AccountInfo] SPL Associated Token program
+/// 11. `[writable, optional]` transfer_policy: [TransferPolicy] The multisig's transfer policy, required when it is enabled
+///
+/// Data:
+/// - proposal_seed_proposal_id: [u64] Auto-generated, from the input "proposal" for the its seed definition "Proposal", sets the seed named "proposal_id"
+pub fn handler(
+	ctx: Context<ExecuteVestingStreamProposal>,
+	proposal_seed_proposal_id: u64,
+) -> Result<()> {
+	let multisig = &ctx.accounts.multisig;
+	let proposal = &mut ctx.accounts.proposal.load_mut()?;
+	let now = Clock::get()?.unix_timestamp;
+
+	let kind = proposal.kind()?;
+	let threshold = multisig.proposal_threshold(&kind, proposal.vault_index);
+
+	let ProposalKind::VestingStream(terms) = kind else {
+		return err!(MultisigWalletError::InvalidProposalKind);
+	};
+
+	multisig.require_not_frozen()?;
+	proposal.require_executable(threshold, now)?;
+	require_keys_eq!(ctx.accounts.mint.key(), terms.mint, MultisigWalletError::AccountMismatch);
+
+	if multisig.transfer_policy_enabled {
+		let transfer_policy = ctx.accounts.transfer_policy.as_mut()
+			.ok_or(MultisigWalletError::TransferPolicyRequired)?;
+
+		transfer_policy.record_transfer(&terms.mint, terms.total_amount, now)?;
+	}
+
+	let multisig_key = multisig.key();
+	let vault_index = [proposal.vault_index];
+	let vault_seeds: &[&[u8]] = &[b"vault", multisig_key.as_ref(), &vault_index, &[proposal.vault_nonce]];
+
+	anchor_spl::token::transfer(
+		CpiContext::new_with_signer(
+			ctx.accounts.token_program.to_account_info(),
+			anchor_spl::token::Transfer {
+				from: ctx.accounts.source.to_account_info(),
+				to: ctx.accounts.escrow.to_account_info(),
+				authority: ctx.accounts.vault.to_account_info(),
+			},
+			&[vault_seeds],
+		),
+		terms.total_amount,
+	)?;
+
+	let vesting_stream = &mut ctx.accounts.vesting_stream;
+	vesting_stream.id = proposal_seed_proposal_id;
+	vesting_stream.multisig = multisig_key;
+	vesting_stream.recipient = terms.recipient;
+	vesting_stream.mint = terms.mint;
+	vesting_stream.total_amount = terms.total_amount;
+	vesting_stream.withdrawn_amount = 0;
+	vesting_stream.start_time = terms.start_time;
+	vesting_stream.cliff_time = terms.cliff_time;
+	vesting_stream.end_time = terms.end_time;
+	vesting_stream.cancelled = false;
+	vesting_stream.vault_index = proposal.vault_index;
+	vesting_stream.vault_nonce = proposal.vault_nonce;
+	vesting_stream.nonce = ctx.bumps.vesting_stream;
+
+	proposal.mark_executed();
+
+	Ok(())
+}
@@ -0,0 +1,72 @@
+use crate::*;
+use crate::error::MultisigWalletError;
+use anchor_lang::prelude::*;
+
+
+
+
+	#[derive(Accounts)]
+	#[instruction(
+		proposal_seed_proposal_id: u64,
+	)]
+	pub struct ExpireProposal<'info> {
+		#[account(
+			mut,
+		)]
+		pub fee_payer: Signer<'info>,
+
+		#[account(
+			seeds = [
+				b"multisig",
+				multisig.create_key_seed(),
+			],
+			bump = multisig.nonce,
+		)]
+		pub multisig: Account<'info, MultisigConfig>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"proposal",
+				multisig.key().as_ref(),
+				proposal_seed_proposal_id.to_le_bytes().as_ref(),
+			],
+			bump = proposal.load()?.nonce,
+			has_one = multisig @ MultisigWalletError::AccountMismatch,
+			close = rent_receiver,
+		)]
+		pub proposal: AccountLoader<'info, Proposal>,
+
+		/// CHECK: only receives the proposal's rent, checked against its creator
+		#[account(
+			mut,
+			address = proposal.load()?.creator @ MultisigWalletError::AccountMismatch,
+		)]
+		pub rent_receiver: UncheckedAccount<'info>,
+	}
+
+/// Mark a proposal whose expiration time has passed as expired and close it, returning its rent to
+/// its creator (callable by anyone)
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[]` multisig: [MultisigConfig] The multisig account
+/// 2. `[writable]` proposal: [Proposal] The proposal to expire and close
+/// 3. `[writable]` rent_receiver: [AccountInfo] The proposal's creator, receiving its rent
+///
+/// Data:
+/// - proposal_seed_proposal_id: [u64] Auto-generated, from the input "proposal" for the its seed definition "Proposal", sets the seed named "proposal_id"
+pub fn handler(
+	ctx: Context<ExpireProposal>,
+) -> Result<()> {
+	let proposal = &mut ctx.accounts.proposal.load_mut()?;
+
+	require!(!proposal.executed(), MultisigWalletError::ProposalAlreadyExecuted);
+	require!(!proposal.cancelled(), MultisigWalletError::ProposalCancelled);
+	require!(!proposal.expired(), MultisigWalletError::ProposalExpired);
+	require!(proposal.is_expired(Clock::get()?.unix_timestamp), MultisigWalletError::ProposalNotExpired);
+
+	proposal.mark_expired();
+
+	Ok(())
+}
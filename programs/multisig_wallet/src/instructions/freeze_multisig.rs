@@ -0,0 +1,45 @@
+use crate::*;
+use crate::error::MultisigWalletError;
+use anchor_lang::prelude::*;
+
+
+
+
+	#[derive(Accounts)]
+	pub struct FreezeMultisig<'info> {
+		#[account(
+			mut,
+		)]
+		pub fee_payer: Signer<'info>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"multisig",
+				multisig.create_key_seed(),
+			],
+			bump = multisig.nonce,
+		)]
+		pub multisig: Account<'info, MultisigConfig>,
+
+		pub guardian: Signer<'info>,
+	}
+
+/// Freeze the multisig, halting proposal creation, approval and execution until all signers unfreeze it
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable]` multisig: [MultisigConfig] The multisig account to freeze
+/// 2. `[signer]` guardian: [AccountInfo] The guardian of the multisig
+pub fn handler(
+	ctx: Context<FreezeMultisig>,
+) -> Result<()> {
+	let multisig = &mut ctx.accounts.multisig;
+
+	require!(multisig.guardian == Some(ctx.accounts.guardian.key()), MultisigWalletError::NotGuardian);
+	multisig.require_not_frozen()?;
+
+	multisig.frozen = true;
+
+	Ok(())
+}
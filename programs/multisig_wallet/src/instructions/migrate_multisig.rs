@@ -0,0 +1,51 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+
+
+
+	#[derive(Accounts)]
+	pub struct MigrateMultisig<'info> {
+		#[account(
+			mut,
+		)]
+		pub fee_payer: Signer<'info>,
+
+		/// CHECK: read as a legacy multisig in the handler
+		#[account(
+			mut,
+			owner = crate::ID,
+			seeds = [
+				b"multisig",
+			],
+			bump,
+		)]
+		pub multisig: UncheckedAccount<'info>,
+
+		pub system_program: Program<'info, System>,
+	}
+
+/// Rewrite a multisig created before accounts were versioned into the current layout, growing the
+/// account as needed (callable by anyone; the fee payer covers the extra rent)
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable]` multisig: [AccountInfo] The legacy multisig account to migrate
+/// 2. `[]` system_program: [AccountInfo] System program, to top up the account's rent
+pub fn handler(
+	ctx: Context<MigrateMultisig>,
+) -> Result<()> {
+	let multisig = ctx.accounts.multisig.to_account_info();
+
+	let legacy: LegacyMultisigConfig = read_legacy_account::<MultisigConfig, _>(&multisig, MultisigConfig::SPACE)?;
+	let mut migrated = Vec::with_capacity(MultisigConfig::SPACE);
+	legacy.migrate(ctx.bumps.multisig).try_serialize(&mut migrated)?;
+
+	write_migrated_account(
+		&multisig,
+		&ctx.accounts.fee_payer.to_account_info(),
+		&ctx.accounts.system_program.to_account_info(),
+		MultisigConfig::SPACE,
+		&migrated,
+	)
+}
@@ -0,0 +1,73 @@
+use crate::*;
+use crate::error::MultisigWalletError;
+use anchor_lang::prelude::*;
+
+
+
+
+	#[derive(Accounts)]
+	#[instruction(
+		proposal_seed_proposal_id: u64,
+	)]
+	pub struct MigrateProposal<'info> {
+		#[account(
+			mut,
+		)]
+		pub fee_payer: Signer<'info>,
+
+		#[account(
+			seeds = [
+				b"multisig",
+				multisig.create_key_seed(),
+			],
+			bump = multisig.nonce,
+		)]
+		pub multisig: Account<'info, MultisigConfig>,
+
+		/// CHECK: read as a legacy proposal in the handler
+		#[account(
+			mut,
+			owner = crate::ID,
+			seeds = [
+				b"proposal",
+				multisig.key().as_ref(),
+				proposal_seed_proposal_id.to_le_bytes().as_ref(),
+			],
+			bump,
+		)]
+		pub proposal: UncheckedAccount<'info>,
+
+		pub system_program: Program<'info, System>,
+	}
+
+/// Rewrite a proposal created before accounts were versioned into the current layout, growing the
+/// account as needed (callable by anyone once the multisig has been migrated; the fee payer covers
+/// the extra rent)
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[]` multisig: [MultisigConfig] The (already migrated) multisig account
+/// 2. `[writable]` proposal: [AccountInfo] The legacy proposal account to migrate
+/// 3. `[]` system_program: [AccountInfo] System program, to top up the account's rent
+///
+/// Data:
+/// - proposal_seed_proposal_id: [u64] Auto-generated, from the input "proposal" for the its seed definition "Proposal", sets the seed named "proposal_id"
+pub fn handler(
+	ctx: Context<MigrateProposal>,
+) -> Result<()> {
+	let proposal = ctx.accounts.proposal.to_account_info();
+	let multisig = &ctx.accounts.multisig;
+
+	let legacy: LegacyProposal = read_legacy_account::<Proposal, _>(&proposal, Proposal::SPACE)?;
+	require_keys_eq!(legacy.multisig, multisig.key(), MultisigWalletError::InvalidLegacyAccount);
+
+	let migrated = legacy.migrate(ctx.bumps.proposal, MultisigConfig::vault_nonce(&multisig.key(), 0), &multisig.signers);
+
+	write_migrated_account(
+		&proposal,
+		&ctx.accounts.fee_payer.to_account_info(),
+		&ctx.accounts.system_program.to_account_info(),
+		Proposal::SPACE,
+		&migrated.to_account_data(),
+	)
+}
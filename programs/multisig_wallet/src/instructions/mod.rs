@@ -2,13 +2,81 @@
 pub mod create_multisig;
 pub mod create_proposal;
 pub mod approve_proposal;
+pub mod unapprove_proposal;
 pub mod execute_proposal;
 pub mod cancel_proposal;
 pub mod update_multisig;
+pub mod expire_proposal;
+pub mod create_address_book;
+pub mod create_address_book_proposal;
+pub mod execute_address_book_proposal;
+pub mod create_transfer_policy;
+pub mod create_transfer_policy_proposal;
+pub mod execute_transfer_policy_proposal;
+pub mod freeze_multisig;
+pub mod unfreeze_multisig;
+pub mod configure_recovery;
+pub mod create_recovery_request;
+pub mod approve_recovery_request;
+pub mod veto_recovery_request;
+pub mod execute_recovery_request;
+pub mod create_program_upgrade_proposal;
+pub mod execute_program_upgrade_proposal;
+pub mod create_stake_proposal;
+pub mod execute_stake_proposal;
+pub mod create_payment_schedule_proposal;
+pub mod execute_payment_schedule_proposal;
+pub mod crank_schedule;
+pub mod create_vesting_stream_proposal;
+pub mod execute_vesting_stream_proposal;
+pub mod withdraw_vested;
+pub mod create_cancel_stream_proposal;
+pub mod cancel_stream;
+pub mod set_vault_threshold;
+pub mod create_nested_approval_proposal;
+pub mod execute_nested_approval_proposal;
+pub mod configure_optimistic;
+pub mod veto_proposal;
+pub mod migrate_multisig;
+pub mod migrate_proposal;
 
 pub use create_multisig::*;
 pub use create_proposal::*;
 pub use approve_proposal::*;
+pub use unapprove_proposal::*;
 pub use execute_proposal::*;
 pub use cancel_proposal::*;
 pub use update_multisig::*;
+pub use expire_proposal::*;
+pub use create_address_book::*;
+pub use create_address_book_proposal::*;
+pub use execute_address_book_proposal::*;
+pub use create_transfer_policy::*;
+pub use create_transfer_policy_proposal::*;
+pub use execute_transfer_policy_proposal::*;
+pub use freeze_multisig::*;
+pub use unfreeze_multisig::*;
+pub use configure_recovery::*;
+pub use create_recovery_request::*;
+pub use approve_recovery_request::*;
+pub use veto_recovery_request::*;
+pub use execute_recovery_request::*;
+pub use create_program_upgrade_proposal::*;
+pub use execute_program_upgrade_proposal::*;
+pub use create_stake_proposal::*;
+pub use execute_stake_proposal::*;
+pub use create_payment_schedule_proposal::*;
+pub use execute_payment_schedule_proposal::*;
+pub use crank_schedule::*;
+pub use create_vesting_stream_proposal::*;
+pub use execute_vesting_stream_proposal::*;
+pub use withdraw_vested::*;
+pub use create_cancel_stream_proposal::*;
+pub use cancel_stream::*;
+pub use set_vault_threshold::*;
+pub use create_nested_approval_proposal::*;
+pub use execute_nested_approval_proposal::*;
+pub use configure_optimistic::*;
+pub use veto_proposal::*;
+pub use migrate_multisig::*;
+pub use migrate_proposal::*;
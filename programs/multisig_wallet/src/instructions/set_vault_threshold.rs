@@ -0,0 +1,51 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+
+
+
+	#[derive(Accounts)]
+	#[instruction(
+		vault_index: u8,
+		threshold: Option<u8>,
+	)]
+	pub struct SetVaultThreshold<'info> {
+		#[account(
+			mut,
+		)]
+		pub fee_payer: Signer<'info>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"multisig",
+				multisig.create_key_seed(),
+			],
+			bump = multisig.nonce,
+		)]
+		pub multisig: Account<'info, MultisigConfig>,
+	}
+
+/// Set or remove the approval threshold override of one vault (requires every current signer to sign the transaction)
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable]` multisig: [MultisigConfig] The multisig account to configure
+/// 2. `[signer]` remaining_accounts: [AccountInfo] Every current signer of the multisig (accounts 2 and up)
+///
+/// Data:
+/// - vault_index: [u8] The vault whose threshold is overridden
+/// - threshold: [Option<u8>] Approvals needed by proposals acting on the vault (None falls back to the multisig threshold)
+pub fn handler<'info>(
+	ctx: Context<'_, '_, '_, 'info, SetVaultThreshold<'info>>,
+	vault_index: u8,
+	threshold: Option<u8>,
+) -> Result<()> {
+	let multisig = &mut ctx.accounts.multisig;
+
+	multisig.require_all_signers(ctx.remaining_accounts)?;
+	multisig.require_not_frozen()?;
+	multisig.set_vault_threshold(vault_index, threshold)?;
+
+	Ok(())
+}
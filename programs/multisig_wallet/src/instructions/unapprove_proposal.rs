@@ -0,0 +1,74 @@
+use crate::*;
+use crate::error::MultisigWalletError;
+use anchor_lang::prelude::*;
+
+
+
+
+	#[derive(Accounts)]
+	#[instruction(
+		proposal_seed_proposal_id: u64,
+	)]
+	pub struct UnapproveProposal<'info> {
+		#[account(
+			mut,
+		)]
+		pub fee_payer: Signer<'info>,
+
+		#[account(
+			seeds = [
+				b"multisig",
+				multisig.create_key_seed(),
+			],
+			bump = multisig.nonce,
+		)]
+		pub multisig: Account<'info, MultisigConfig>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"proposal",
+				multisig.key().as_ref(),
+				proposal_seed_proposal_id.to_le_bytes().as_ref(),
+			],
+			bump = proposal.load()?.nonce,
+			has_one = multisig @ MultisigWalletError::AccountMismatch,
+		)]
+		pub proposal: AccountLoader<'info, Proposal>,
+
+		pub signer: Signer<'info>,
+	}
+
+/// Withdraw an approval from a proposal that has not been executed yet
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[]` multisig: [MultisigConfig] The multisig account
+/// 2. `[writable]` proposal: [Proposal] The proposal to withdraw the approval from
+/// 3. `[signer]` signer: [AccountInfo] The signer withdrawing their approval
+///
+/// Data:
+/// - proposal_seed_proposal_id: [u64] Auto-generated, from the input "proposal" for the its seed definition "Proposal", sets the seed named "proposal_id"
+pub fn handler(
+	ctx: Context<UnapproveProposal>,
+) -> Result<()> {
+	let multisig = &ctx.accounts.multisig;
+	let proposal = &mut ctx.accounts.proposal.load_mut()?;
+	let signer = ctx.accounts.signer.key();
+
+	proposal.require_active(Clock::get()?.unix_timestamp)?;
+
+	let signer_index = multisig.signer_index(&signer).ok_or(MultisigWalletError::NotApproved)?;
+
+	proposal.unapprove(signer_index)?;
+
+	emit!(ProposalUnapproved {
+		multisig: multisig.key(),
+		proposal: ctx.accounts.proposal.key(),
+		signer,
+		approvals: proposal.approval_count(),
+		threshold_met: proposal.approval_count() >= multisig.proposal_threshold(&proposal.kind()?, proposal.vault_index),
+	});
+
+	Ok(())
+}
@@ -0,0 +1,44 @@
+use crate::*;
+use crate::error::MultisigWalletError;
+use anchor_lang::prelude::*;
+
+
+
+
+	#[derive(Accounts)]
+	pub struct UnfreezeMultisig<'info> {
+		#[account(
+			mut,
+		)]
+		pub fee_payer: Signer<'info>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"multisig",
+				multisig.create_key_seed(),
+			],
+			bump = multisig.nonce,
+		)]
+		pub multisig: Account<'info, MultisigConfig>,
+	}
+
+/// Unfreeze the multisig (requires every current signer to sign the transaction)
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable]` multisig: [MultisigConfig] The multisig account to unfreeze
+/// 2. `[signer]` remaining_accounts: [AccountInfo] Every current signer of the multisig (accounts 2 and up)
+pub fn handler<'info>(
+	ctx: Context<'_, '_, '_, 'info, UnfreezeMultisig<'info>>,
+) -> Result<()> {
+	let multisig = &mut ctx.accounts.multisig;
+
+	require!(multisig.frozen, MultisigWalletError::MultisigNotFrozen);
+
+	multisig.require_all_signers(ctx.remaining_accounts)?;
+
+	multisig.frozen = false;
+
+	Ok(())
+}
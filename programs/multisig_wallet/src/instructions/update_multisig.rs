@@ -26,8 +26,9 @@ use anchor_spl::{
 			mut,
 			seeds = [
 				b"multisig",
+				multisig.create_key_seed(),
 			],
-			bump,
+			bump = multisig.nonce,
 		)]
 		pub multisig: Account<'info, MultisigConfig>,
 	}
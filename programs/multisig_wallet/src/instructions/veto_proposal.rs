@@ -0,0 +1,76 @@
+use crate::*;
+use crate::error::MultisigWalletError;
+use anchor_lang::prelude::*;
+
+
+
+
+	#[derive(Accounts)]
+	#[instruction(
+		proposal_seed_proposal_id: u64,
+	)]
+	pub struct VetoProposal<'info> {
+		#[account(
+			mut,
+		)]
+		pub fee_payer: Signer<'info>,
+
+		#[account(
+			seeds = [
+				b"multisig",
+				multisig.create_key_seed(),
+			],
+			bump = multisig.nonce,
+		)]
+		pub multisig: Account<'info, MultisigConfig>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"proposal",
+				multisig.key().as_ref(),
+				proposal_seed_proposal_id.to_le_bytes().as_ref(),
+			],
+			bump = proposal.load()?.nonce,
+			has_one = multisig @ MultisigWalletError::AccountMismatch,
+		)]
+		pub proposal: AccountLoader<'info, Proposal>,
+
+		pub signer: Signer<'info>,
+	}
+
+/// Veto an optimistic proposal during its veto window; once the veto threshold is reached the
+/// proposal can no longer be executed
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[]` multisig: [MultisigConfig] The multisig account
+/// 2. `[writable]` proposal: [Proposal] The optimistic proposal to veto
+/// 3. `[signer]` signer: [AccountInfo] The signer vetoing the proposal (must be a signer of the multisig)
+///
+/// Data:
+/// - proposal_seed_proposal_id: [u64] Auto-generated, from the input "proposal" for the its seed definition "Proposal", sets the seed named "proposal_id"
+pub fn handler(
+	ctx: Context<VetoProposal>,
+) -> Result<()> {
+	let multisig = &ctx.accounts.multisig;
+	let proposal = &mut ctx.accounts.proposal.load_mut()?;
+	let signer = ctx.accounts.signer.key();
+	let now = Clock::get()?.unix_timestamp;
+
+	let signer_index = multisig.signer_index(&signer).ok_or(MultisigWalletError::NotASigner)?;
+	proposal.require_active(now)?;
+	require!(proposal.is_optimistic() && now <= proposal.veto_deadline, MultisigWalletError::VetoWindowClosed);
+
+	proposal.veto(signer_index)?;
+
+	emit!(ProposalVetoCast {
+		multisig: multisig.key(),
+		proposal: ctx.accounts.proposal.key(),
+		signer,
+		vetoes: proposal.veto_count(),
+		vetoed: proposal.veto_count() >= multisig.veto_threshold,
+	});
+
+	Ok(())
+}
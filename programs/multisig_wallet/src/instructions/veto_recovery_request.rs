@@ -0,0 +1,63 @@
+use crate::*;
+use crate::error::MultisigWalletError;
+use anchor_lang::prelude::*;
+
+
+
+
+	#[derive(Accounts)]
+	#[instruction(
+		recovery_request_seed_recovery_id: u64,
+	)]
+	pub struct VetoRecoveryRequest<'info> {
+		#[account(
+			mut,
+		)]
+		pub fee_payer: Signer<'info>,
+
+		#[account(
+			seeds = [
+				b"multisig",
+				multisig.create_key_seed(),
+			],
+			bump = multisig.nonce,
+		)]
+		pub multisig: Account<'info, MultisigConfig>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"recovery_request",
+				multisig.key().as_ref(),
+				recovery_request_seed_recovery_id.to_le_bytes().as_ref(),
+			],
+			bump = recovery_request.nonce,
+		)]
+		pub recovery_request: Account<'info, RecoveryRequest>,
+
+		pub signer: Signer<'info>,
+	}
+
+/// Veto a pending recovery request (any current signer can veto before it is executed)
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[]` multisig: [MultisigConfig] The multisig account
+/// 2. `[writable]` recovery_request: [RecoveryRequest] The recovery request to veto
+/// 3. `[signer]` signer: [AccountInfo] The current signer vetoing the request
+///
+/// Data:
+/// - recovery_request_seed_recovery_id: [u64] Auto-generated, from the input "recovery_request" for the its seed definition "RecoveryRequest", sets the seed named "recovery_id"
+pub fn handler(
+	ctx: Context<VetoRecoveryRequest>,
+) -> Result<()> {
+	let multisig = &ctx.accounts.multisig;
+	let recovery_request = &mut ctx.accounts.recovery_request;
+
+	require!(multisig.signers.contains(&ctx.accounts.signer.key()), MultisigWalletError::NotASigner);
+	recovery_request.require_pending()?;
+
+	recovery_request.vetoed = true;
+
+	Ok(())
+}
@@ -0,0 +1,119 @@
+use crate::*;
+use crate::error::MultisigWalletError;
+use anchor_lang::prelude::*;
+
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+
+
+
+	#[derive(Accounts)]
+	#[instruction(
+		vesting_stream_seed_stream_id: u64,
+	)]
+	pub struct WithdrawVested<'info> {
+		#[account(
+			mut,
+		)]
+		pub fee_payer: Signer<'info>,
+
+		#[account(
+			seeds = [
+				b"multisig",
+				multisig.create_key_seed(),
+			],
+			bump = multisig.nonce,
+		)]
+		pub multisig: Account<'info, MultisigConfig>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"vesting_stream",
+				multisig.key().as_ref(),
+				vesting_stream_seed_stream_id.to_le_bytes().as_ref(),
+			],
+			bump = vesting_stream.nonce,
+		)]
+		pub vesting_stream: Account<'info, VestingStream>,
+
+		pub recipient: Signer<'info>,
+
+		#[account(
+			mut,
+			associated_token::mint = mint,
+			associated_token::authority = vesting_stream,
+			associated_token::token_program = token_program,
+		)]
+		pub escrow: Account<'info, TokenAccount>,
+
+		#[account(
+			mut,
+			token::mint = mint,
+			token::authority = recipient,
+		)]
+		pub destination: Account<'info, TokenAccount>,
+
+		#[account(
+			address = vesting_stream.mint,
+		)]
+		pub mint: Account<'info, Mint>,
+
+		pub token_program: Program<'info, Token>,
+	}
+
+/// Withdraw everything vested so far from a vesting stream to a token account of the recipient
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[]` multisig: [MultisigConfig] The multisig account
+/// 2. `[writable]` vesting_stream: [VestingStream] The vesting stream to withdraw from
+/// 3. `[signer]` recipient: [AccountInfo] The recipient of the stream
+/// 4. `[writable]` escrow: [TokenAccount] The stream's associated token account
+/// 5. `[writable]` destination: [TokenAccount] A token account owned by the recipient
+/// 6. `[]` mint: [Mint] The mint of the stream
+/// 7. `[]` token_program: [AccountInfo] SPL Token program
+///
+/// Data:
+/// - vesting_stream_seed_stream_id: [u64] Auto-generated, from the input "vesting_stream" for the its seed definition "VestingStream", sets the seed named "stream_id"
+pub fn handler(
+	ctx: Context<WithdrawVested>,
+	vesting_stream_seed_stream_id: u64,
+) -> Result<()> {
+	let vesting_stream = &ctx.accounts.vesting_stream;
+
+	require_keys_eq!(
+		ctx.accounts.recipient.key(),
+		vesting_stream.recipient,
+		MultisigWalletError::NotStreamRecipient,
+	);
+
+	let amount = vesting_stream.withdrawable_amount(Clock::get()?.unix_timestamp);
+	require!(amount > 0, MultisigWalletError::NothingToWithdraw);
+
+	let multisig_key = ctx.accounts.multisig.key();
+	let stream_id = vesting_stream_seed_stream_id.to_le_bytes();
+	let stream_seeds: &[&[u8]] = &[
+		b"vesting_stream",
+		multisig_key.as_ref(),
+		stream_id.as_ref(),
+		&[vesting_stream.nonce],
+	];
+
+	anchor_spl::token::transfer(
+		CpiContext::new_with_signer(
+			ctx.accounts.token_program.to_account_info(),
+			anchor_spl::token::Transfer {
+				from: ctx.accounts.escrow.to_account_info(),
+				to: ctx.accounts.destination.to_account_info(),
+				authority: ctx.accounts.vesting_stream.to_account_info(),
+			},
+			&[stream_seeds],
+		),
+		amount,
+	)?;
+
+	ctx.accounts.vesting_stream.withdrawn_amount += amount;
+
+	Ok(())
+}
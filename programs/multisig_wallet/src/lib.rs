@@ -1,6 +1,7 @@
 
 pub mod constants;
 pub mod error;
+pub mod events;
 pub mod instructions;
 pub mod state;
 
@@ -8,6 +9,7 @@ use anchor_lang::prelude::*;
 use std::str::FromStr;
 
 pub use constants::*;
+pub use events::*;
 pub use instructions::*;
 pub use state::*;
 
@@ -21,33 +23,39 @@ pub mod multisig_wallet {
 ///
 /// Accounts:
 /// 0. `[writable, signer]` fee_payer: [AccountInfo] 
-/// 1. `[writable]` multisig: [MultisigConfig] The multisig account to initialize
-/// 2. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+/// 1. `[signer]` create_key: [AccountInfo] One-time key whose address seeds the multisig PDA, so each multisig gets its own address
+/// 2. `[writable]` multisig: [MultisigConfig] The multisig account to initialize
+/// 3. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
 ///
 /// Data:
 /// - signers: [Vec<Pubkey>] The initial set of authorized signers
 /// - threshold: [u8] The minimum number of approvals needed to execute a transaction
-	pub fn create_multisig(ctx: Context<CreateMultisig>, signers: Vec<Pubkey>, threshold: u8) -> Result<()> {
-		create_multisig::handler(ctx, signers, threshold)
+/// - max_proposal_lifetime: [i64] Maximum number of seconds a proposal may stay open (0 means unlimited)
+/// - guardian: [Option<Pubkey>] Optional guardian allowed to freeze the multisig in an emergency
+/// - cancel_threshold: [u8] The number of cancel votes needed to cancel a proposal (0 means the approval threshold)
+	pub fn create_multisig(ctx: Context<CreateMultisig>, signers: Vec<Pubkey>, threshold: u8, max_proposal_lifetime: i64, guardian: Option<Pubkey>, cancel_threshold: u8) -> Result<()> {
+		create_multisig::handler(ctx, signers, threshold, max_proposal_lifetime, guardian, cancel_threshold)
 	}
 
-/// Create a new transaction proposal
+/// Create a new transaction proposal (in optimistic mode it becomes executable once the veto window
+/// elapses without enough vetoes)
 ///
 /// Accounts:
 /// 0. `[writable, signer]` fee_payer: [AccountInfo] 
 /// 1. `[writable]` multisig: [MultisigConfig] The multisig account
 /// 2. `[writable]` proposal: [Proposal] The proposal account to initialize
 /// 3. `[signer]` creator: [AccountInfo] The account creating the proposal (must be a signer of the multisig)
-/// 4. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+/// 4. `[optional]` address_book: [AddressBook] The multisig's address book, required when the multisig enforces it
+/// 5. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
 ///
 /// Data:
-/// - destination: [Pubkey] The destination account for the transfer
-/// - amount: [u64] The amount of tokens to transfer
-/// - token_mint: [Pubkey] The mint of the token to transfer (can be null for SOL)
-/// - expiration_time: [i64] Optional timestamp when the proposal expires (0 means no expiration)
+/// - terms: [TransferTerms] The destination wallet, amount and mint of the transfer
+/// - vault_index: [u8] The vault the transfer is paid from
+/// - expiration_time: [i64] Optional timestamp when the proposal expires (0 means no expiration, or the maximum lifetime when the multisig sets one)
+/// - metadata: [ProposalMetadata] Optional title, description URI and memo (the memo is attached to the transfer through the SPL Memo program on execution)
 /// - proposal_seed_proposal_id: [u64] Auto-generated, from the input "proposal" for the its seed definition "Proposal", sets the seed named "proposal_id"
-	pub fn create_proposal(ctx: Context<CreateProposal>, destination: Pubkey, amount: u64, token_mint: Pubkey, expiration_time: i64, _proposal_seed_proposal_id: u64) -> Result<()> {
-		create_proposal::handler(ctx, destination, amount, token_mint, expiration_time)
+	pub fn create_proposal(ctx: Context<CreateProposal>, terms: TransferTerms, vault_index: u8, expiration_time: i64, metadata: ProposalMetadata, proposal_seed_proposal_id: u64) -> Result<()> {
+		create_proposal::handler(ctx, terms, vault_index, expiration_time, metadata, proposal_seed_proposal_id)
 	}
 
 /// Approve a pending proposal
@@ -56,7 +64,7 @@ pub mod multisig_wallet {
 /// 0. `[writable, signer]` fee_payer: [AccountInfo] 
 /// 1. `[]` multisig: [MultisigConfig] The multisig account
 /// 2. `[writable]` proposal: [Proposal] The proposal to approve
-/// 3. `[signer]` signer: [AccountInfo] The signer approving the proposal (must be a signer of the multisig)
+/// 3. `[signer]` signer: [AccountInfo] The signer approving the proposal (must be a signer of the multisig; may be another multisig's vault signing through CPI)
 ///
 /// Data:
 /// - proposal_seed_proposal_id: [u64] Auto-generated, from the input "proposal" for the its seed definition "Proposal", sets the seed named "proposal_id"
@@ -64,23 +72,39 @@ pub mod multisig_wallet {
 		approve_proposal::handler(ctx, )
 	}
 
-/// Execute a proposal if the threshold is met
+/// Withdraw an approval from a proposal that has not been executed yet
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[]` multisig: [MultisigConfig] The multisig account
+/// 2. `[writable]` proposal: [Proposal] The proposal to withdraw the approval from
+/// 3. `[signer]` signer: [AccountInfo] The signer withdrawing their approval
+///
+/// Data:
+/// - proposal_seed_proposal_id: [u64] Auto-generated, from the input "proposal" for the its seed definition "Proposal", sets the seed named "proposal_id"
+	pub fn unapprove_proposal(ctx: Context<UnapproveProposal>, _proposal_seed_proposal_id: u64) -> Result<()> {
+		unapprove_proposal::handler(ctx, )
+	}
+
+/// Execute a proposal if the threshold is met (optimistic proposals, while the multisig stays in
+/// optimistic mode, once their veto window has elapsed without enough vetoes and with the approvals
+/// of their vault's threshold override, if any)
 ///
 /// Accounts:
 /// 0. `[writable, signer]` fee_payer: [AccountInfo] 
 /// 1. `[]` multisig: [MultisigConfig] The multisig account
-/// 2. `[writable]` proposal: [Proposal] The proposal to execute
-/// 3. `[writable]` source: [AccountInfo] The source account.
-/// 4. `[writable]` destination: [AccountInfo] The destination account.
-/// 5. `[signer]` authority: [AccountInfo] The source account's owner/delegate.
-/// 6. `[writable, signer]` funding: [AccountInfo] Funding account (must be a system account)
-/// 7. `[writable]` assoc_token_account: [AccountInfo] Associated token account address to be created
-/// 8. `[]` wallet: [AccountInfo] Wallet address for the new associated token account
-/// 9. `[]` mint: [Mint] The token mint for the new associated token account
-/// 10. `[]` system_program: [AccountInfo] System program
-/// 11. `[]` token_program: [AccountInfo] SPL Token program
-/// 12. `[]` csl_spl_token_v0_0_0: [AccountInfo] Auto-generated, CslSplTokenProgram v0.0.0
-/// 13. `[]` csl_spl_assoc_token_v0_0_0: [AccountInfo] Auto-generated, CslSplAssocTokenProgram v0.0.0
+/// 2. `[writable]` proposal: [Proposal] The proposal to execute (must belong to the multisig)
+/// 3. `[]` vault: [AccountInfo] The multisig vault paying the transfer
+/// 4. `[writable]` source: [TokenAccount] The vault's associated token account for the proposal's mint
+/// 5. `[writable]` destination: [TokenAccount] The destination wallet's associated token account for the proposal's mint, created if missing
+/// 6. `[writable, signer]` funding: [AccountInfo] Pays the rent of the destination token account when it is created
+/// 7. `[]` wallet: [AccountInfo] The proposal's destination wallet
+/// 8. `[]` mint: [Mint] The mint named in the proposal
+/// 9. `[]` system_program: [AccountInfo] System program
+/// 10. `[]` token_program: [AccountInfo] SPL Token program
+/// 11. `[]` associated_token_program: [AccountInfo] SPL Associated Token Account program
+/// 12. `[]` memo_program: [AccountInfo] SPL Memo program, used to attach the proposal memo to the transfer
+/// 13. `[writable, optional]` transfer_policy: [TransferPolicy] The multisig's transfer policy, required when the multisig has one
 ///
 /// Data:
 /// - proposal_seed_proposal_id: [u64] Auto-generated, from the input "proposal" for the its seed definition "Proposal", sets the seed named "proposal_id"
@@ -88,13 +112,15 @@ pub mod multisig_wallet {
 		execute_proposal::handler(ctx, )
 	}
 
-/// Cancel a pending proposal
+/// Cancel a pending proposal: the creator cancels it outright while it has no approvals,
+/// otherwise the signer's cancel vote is recorded and the proposal is cancelled once the
+/// cancel threshold is reached
 ///
 /// Accounts:
 /// 0. `[writable, signer]` fee_payer: [AccountInfo] 
 /// 1. `[]` multisig: [MultisigConfig] The multisig account
 /// 2. `[writable]` proposal: [Proposal] The proposal to cancel
-/// 3. `[signer]` creator: [AccountInfo] The creator of the proposal
+/// 3. `[signer]` signer: [AccountInfo] The proposal creator or a signer of the multisig voting to cancel
 ///
 /// Data:
 /// - proposal_seed_proposal_id: [u64] Auto-generated, from the input "proposal" for the its seed definition "Proposal", sets the seed named "proposal_id"
@@ -102,6 +128,556 @@ pub mod multisig_wallet {
 		cancel_proposal::handler(ctx, )
 	}
 
+/// Mark a proposal whose expiration time has passed as expired and close it, returning its rent to
+/// its creator (callable by anyone)
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[]` multisig: [MultisigConfig] The multisig account
+/// 2. `[writable]` proposal: [Proposal] The proposal to expire and close
+/// 3. `[writable]` rent_receiver: [AccountInfo] The proposal's creator, receiving its rent
+///
+/// Data:
+/// - proposal_seed_proposal_id: [u64] Auto-generated, from the input "proposal" for the its seed definition "Proposal", sets the seed named "proposal_id"
+	pub fn expire_proposal(ctx: Context<ExpireProposal>, _proposal_seed_proposal_id: u64) -> Result<()> {
+		expire_proposal::handler(ctx, )
+	}
+
+/// Create the (empty) address book of approved destinations for the multisig
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[]` multisig: [MultisigConfig] The multisig account
+/// 2. `[writable]` address_book: [AddressBook] The address book account to initialize
+/// 3. `[signer]` creator: [AccountInfo] The account creating the address book (must be a signer of the multisig)
+/// 4. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+	pub fn create_address_book(ctx: Context<CreateAddressBook>) -> Result<()> {
+		create_address_book::handler(ctx, )
+	}
+
+/// Create a proposal that changes the multisig's address book once approved
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable]` multisig: [MultisigConfig] The multisig account
+/// 2. `[writable]` proposal: [Proposal] The proposal account to initialize
+/// 3. `[signer]` creator: [AccountInfo] The account creating the proposal (must be a signer of the multisig)
+/// 4. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data:
+/// - change: [AddressBookChange] The entry to add or remove, or the new enforcement flag
+/// - expiration_time: [i64] Optional timestamp when the proposal expires (0 means no expiration, or the maximum lifetime when the multisig sets one)
+/// - proposal_seed_proposal_id: [u64] Auto-generated, from the input "proposal" for the its seed definition "Proposal", sets the seed named "proposal_id"
+	pub fn create_address_book_proposal(ctx: Context<CreateAddressBookProposal>, change: AddressBookChange, expiration_time: i64, proposal_seed_proposal_id: u64) -> Result<()> {
+		create_address_book_proposal::handler(ctx, change, expiration_time, proposal_seed_proposal_id)
+	}
+
+/// Apply an approved address book proposal
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable]` multisig: [MultisigConfig] The multisig account
+/// 2. `[writable]` proposal: [Proposal] The address book proposal to execute
+/// 3. `[writable]` address_book: [AddressBook] The multisig's address book
+///
+/// Data:
+/// - proposal_seed_proposal_id: [u64] Auto-generated, from the input "proposal" for the its seed definition "Proposal", sets the seed named "proposal_id"
+	pub fn execute_address_book_proposal(ctx: Context<ExecuteAddressBookProposal>, _proposal_seed_proposal_id: u64) -> Result<()> {
+		execute_address_book_proposal::handler(ctx, )
+	}
+
+/// Create the (empty) transfer policy of per-mint limits for the multisig
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable]` multisig: [MultisigConfig] The multisig account
+/// 2. `[writable]` transfer_policy: [TransferPolicy] The transfer policy account to initialize
+/// 3. `[signer]` creator: [AccountInfo] The account creating the transfer policy (must be a signer of the multisig)
+/// 4. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+	pub fn create_transfer_policy(ctx: Context<CreateTransferPolicy>) -> Result<()> {
+		create_transfer_policy::handler(ctx, )
+	}
+
+/// Create a proposal that changes the multisig's transfer policy once approved
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable]` multisig: [MultisigConfig] The multisig account
+/// 2. `[writable]` proposal: [Proposal] The proposal account to initialize
+/// 3. `[signer]` creator: [AccountInfo] The account creating the proposal (must be a signer of the multisig)
+/// 4. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data:
+/// - change: [TransferPolicyChange] The per-mint limit to set or remove
+/// - expiration_time: [i64] Optional timestamp when the proposal expires (0 means no expiration, or the maximum lifetime when the multisig sets one)
+/// - proposal_seed_proposal_id: [u64] Auto-generated, from the input "proposal" for the its seed definition "Proposal", sets the seed named "proposal_id"
+	pub fn create_transfer_policy_proposal(ctx: Context<CreateTransferPolicyProposal>, change: TransferPolicyChange, expiration_time: i64, proposal_seed_proposal_id: u64) -> Result<()> {
+		create_transfer_policy_proposal::handler(ctx, change, expiration_time, proposal_seed_proposal_id)
+	}
+
+/// Apply an approved transfer policy proposal
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[]` multisig: [MultisigConfig] The multisig account
+/// 2. `[writable]` proposal: [Proposal] The transfer policy proposal to execute
+/// 3. `[writable]` transfer_policy: [TransferPolicy] The multisig's transfer policy
+///
+/// Data:
+/// - proposal_seed_proposal_id: [u64] Auto-generated, from the input "proposal" for the its seed definition "Proposal", sets the seed named "proposal_id"
+	pub fn execute_transfer_policy_proposal(ctx: Context<ExecuteTransferPolicyProposal>, _proposal_seed_proposal_id: u64) -> Result<()> {
+		execute_transfer_policy_proposal::handler(ctx, )
+	}
+
+/// Freeze the multisig, halting proposal creation, approval and execution until all signers unfreeze it
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable]` multisig: [MultisigConfig] The multisig account to freeze
+/// 2. `[signer]` guardian: [AccountInfo] The guardian of the multisig
+	pub fn freeze_multisig(ctx: Context<FreezeMultisig>) -> Result<()> {
+		freeze_multisig::handler(ctx, )
+	}
+
+/// Unfreeze the multisig (requires every current signer to sign the transaction)
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable]` multisig: [MultisigConfig] The multisig account to unfreeze
+/// 2. `[signer]` remaining_accounts: [AccountInfo] Every current signer of the multisig (accounts 2 and up)
+	pub fn unfreeze_multisig<'info>(ctx: Context<'_, '_, '_, 'info, UnfreezeMultisig<'info>>) -> Result<()> {
+		unfreeze_multisig::handler(ctx, )
+	}
+
+/// Set the recovery keys, recovery threshold and time lock used to replace lost signers (requires every current signer to sign the transaction)
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable]` multisig: [MultisigConfig] The multisig account to configure
+/// 2. `[signer]` remaining_accounts: [AccountInfo] Every current signer of the multisig (accounts 2 and up)
+///
+/// Data:
+/// - recovery_keys: [Vec<Pubkey>] The keys allowed to request and approve signer recovery (empty disables recovery)
+/// - recovery_threshold: [u8] The number of recovery key approvals needed to start the time lock
+/// - recovery_delay: [i64] Number of seconds during which current signers can veto an approved recovery (at least `MIN_RECOVERY_DELAY` when recovery is enabled)
+	pub fn configure_recovery<'info>(ctx: Context<'_, '_, '_, 'info, ConfigureRecovery<'info>>, recovery_keys: Vec<Pubkey>, recovery_threshold: u8, recovery_delay: i64) -> Result<()> {
+		configure_recovery::handler(ctx, recovery_keys, recovery_threshold, recovery_delay)
+	}
+
+/// Request that a lost signer key be replaced, counting as the first recovery key approval
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable]` multisig: [MultisigConfig] The multisig account
+/// 2. `[writable]` recovery_request: [RecoveryRequest] The recovery request account to initialize
+/// 3. `[signer]` recovery_key: [AccountInfo] The recovery key creating the request
+/// 4. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data:
+/// - lost_signer: [Pubkey] The current signer whose key was lost
+/// - new_signer: [Pubkey] The key replacing the lost signer
+/// - recovery_request_seed_recovery_id: [u64] Auto-generated, from the input "recovery_request" for the its seed definition "RecoveryRequest", sets the seed named "recovery_id"
+	pub fn create_recovery_request(ctx: Context<CreateRecoveryRequest>, lost_signer: Pubkey, new_signer: Pubkey, recovery_request_seed_recovery_id: u64) -> Result<()> {
+		create_recovery_request::handler(ctx, lost_signer, new_signer, recovery_request_seed_recovery_id)
+	}
+
+/// Approve a pending recovery request as a recovery key
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[]` multisig: [MultisigConfig] The multisig account
+/// 2. `[writable]` recovery_request: [RecoveryRequest] The recovery request to approve
+/// 3. `[signer]` recovery_key: [AccountInfo] The recovery key approving the request
+///
+/// Data:
+/// - recovery_request_seed_recovery_id: [u64] Auto-generated, from the input "recovery_request" for the its seed definition "RecoveryRequest", sets the seed named "recovery_id"
+	pub fn approve_recovery_request(ctx: Context<ApproveRecoveryRequest>, _recovery_request_seed_recovery_id: u64) -> Result<()> {
+		approve_recovery_request::handler(ctx, )
+	}
+
+/// Veto a pending recovery request (any current signer can veto before it is executed)
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[]` multisig: [MultisigConfig] The multisig account
+/// 2. `[writable]` recovery_request: [RecoveryRequest] The recovery request to veto
+/// 3. `[signer]` signer: [AccountInfo] The current signer vetoing the request
+///
+/// Data:
+/// - recovery_request_seed_recovery_id: [u64] Auto-generated, from the input "recovery_request" for the its seed definition "RecoveryRequest", sets the seed named "recovery_id"
+	pub fn veto_recovery_request(ctx: Context<VetoRecoveryRequest>, _recovery_request_seed_recovery_id: u64) -> Result<()> {
+		veto_recovery_request::handler(ctx, )
+	}
+
+/// Replace the lost signer once the recovery request is approved and its time lock has elapsed (callable by anyone)
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable]` multisig: [MultisigConfig] The multisig account to update
+/// 2. `[writable]` recovery_request: [RecoveryRequest] The recovery request to execute
+///
+/// Data:
+/// - recovery_request_seed_recovery_id: [u64] Auto-generated, from the input "recovery_request" for the its seed definition "RecoveryRequest", sets the seed named "recovery_id"
+	pub fn execute_recovery_request(ctx: Context<ExecuteRecoveryRequest>, _recovery_request_seed_recovery_id: u64) -> Result<()> {
+		execute_recovery_request::handler(ctx, )
+	}
+
+/// Create a proposal that upgrades a program, or changes its upgrade authority, through the BPF
+/// upgradeable loader once approved; the program's upgrade authority must be the multisig vault
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable]` multisig: [MultisigConfig] The multisig account
+/// 2. `[writable]` proposal: [Proposal] The proposal account to initialize
+/// 3. `[signer]` creator: [AccountInfo] The account creating the proposal (must be a signer of the multisig)
+/// 4. `[]` vault: [AccountInfo] The multisig vault holding the upgrade authority
+/// 5. `[]` program_data: [ProgramData] The program data account of the targeted program
+/// 6. `[optional]` buffer: [AccountInfo] The buffer holding the new program, required for upgrades
+/// 7. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data:
+/// - change: [ProgramUpgradeChange] The upgrade or authority change to perform
+/// - vault_index: [u8] The vault the proposal acts on
+/// - expiration_time: [i64] Optional timestamp when the proposal expires (0 means no expiration, or the maximum lifetime when the multisig sets one)
+/// - proposal_seed_proposal_id: [u64] Auto-generated, from the input "proposal" for the its seed definition "Proposal", sets the seed named "proposal_id"
+	pub fn create_program_upgrade_proposal(ctx: Context<CreateProgramUpgradeProposal>, change: ProgramUpgradeChange, vault_index: u8, expiration_time: i64, proposal_seed_proposal_id: u64) -> Result<()> {
+		create_program_upgrade_proposal::handler(ctx, change, vault_index, expiration_time, proposal_seed_proposal_id)
+	}
+
+/// Apply an approved program upgrade proposal, signing for the upgrade authority with the vault
+///
+/// The buffer is not hashed again here: its authority is the vault, so its contents cannot change
+/// after the hash was checked at proposal creation.
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[]` multisig: [MultisigConfig] The multisig account
+/// 2. `[writable]` proposal: [Proposal] The program upgrade proposal to execute
+/// 3. `[]` vault: [AccountInfo] The multisig vault holding the upgrade authority
+/// 4. `[writable]` program: [AccountInfo] The program to upgrade
+/// 5. `[writable]` program_data: [ProgramData] The program data account of the program
+/// 6. `[writable, optional]` buffer: [AccountInfo] The buffer holding the new program, required for upgrades
+/// 7. `[writable, optional]` spill: [AccountInfo] Receives the buffer's lamports, required for upgrades
+/// 8. `[optional]` new_authority: [AccountInfo] The new upgrade authority, required when one is set
+/// 9. `[]` rent: [AccountInfo] Rent sysvar
+/// 10. `[]` clock: [AccountInfo] Clock sysvar
+/// 11. `[]` bpf_loader_upgradeable: [AccountInfo] BPF upgradeable loader program
+///
+/// Data:
+/// - proposal_seed_proposal_id: [u64] Auto-generated, from the input "proposal" for the its seed definition "Proposal", sets the seed named "proposal_id"
+	pub fn execute_program_upgrade_proposal(ctx: Context<ExecuteProgramUpgradeProposal>, _proposal_seed_proposal_id: u64) -> Result<()> {
+		execute_program_upgrade_proposal::handler(ctx, )
+	}
+
+/// Create a proposal that creates, delegates, deactivates or withdraws from a vault stake account
+/// once approved
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable]` multisig: [MultisigConfig] The multisig account
+/// 2. `[writable]` proposal: [Proposal] The proposal account to initialize
+/// 3. `[signer]` creator: [AccountInfo] The account creating the proposal (must be a signer of the multisig)
+/// 4. `[optional]` address_book: [AddressBook] The multisig's address book, required for withdrawals when the multisig enforces it
+/// 5. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data:
+/// - change: [StakeChange] The stake action to perform
+/// - vault_index: [u8] The vault the proposal acts on
+/// - expiration_time: [i64] Optional timestamp when the proposal expires (0 means no expiration, or the maximum lifetime when the multisig sets one)
+/// - proposal_seed_proposal_id: [u64] Auto-generated, from the input "proposal" for the its seed definition "Proposal", sets the seed named "proposal_id"
+	pub fn create_stake_proposal(ctx: Context<CreateStakeProposal>, change: StakeChange, vault_index: u8, expiration_time: i64, proposal_seed_proposal_id: u64) -> Result<()> {
+		create_stake_proposal::handler(ctx, change, vault_index, expiration_time, proposal_seed_proposal_id)
+	}
+
+/// Apply an approved stake proposal through the stake program, signing as staker and withdrawer
+/// with the vault
+///
+/// New stake accounts live at the PDA `["stake_account", multisig, proposal id]`.
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[]` multisig: [MultisigConfig] The multisig account
+/// 2. `[writable]` proposal: [Proposal] The stake proposal to execute
+/// 3. `[writable]` vault: [AccountInfo] The multisig vault funding and controlling the stake accounts
+/// 4. `[writable]` stake_account: [AccountInfo] The stake account to create or act on
+/// 5. `[optional]` vote_account: [AccountInfo] The validator vote account, required for delegations
+/// 6. `[writable, optional]` destination: [AccountInfo] Receives withdrawn lamports, required for withdrawals
+/// 7. `[]` rent: [AccountInfo] Rent sysvar
+/// 8. `[]` clock: [AccountInfo] Clock sysvar
+/// 9. `[]` stake_history: [AccountInfo] Stake history sysvar
+/// 10. `[]` stake_config: [AccountInfo] Stake config account
+/// 11. `[]` system_program: [AccountInfo] System program
+/// 12. `[]` stake_program: [AccountInfo] Stake program
+///
+/// Data:
+/// - proposal_seed_proposal_id: [u64] Auto-generated, from the input "proposal" for the its seed definition "Proposal", sets the seed named "proposal_id"
+	pub fn execute_stake_proposal(ctx: Context<ExecuteStakeProposal>, proposal_seed_proposal_id: u64) -> Result<()> {
+		execute_stake_proposal::handler(ctx, proposal_seed_proposal_id)
+	}
+
+/// Create a proposal that sets up a recurring payment schedule once approved
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable]` multisig: [MultisigConfig] The multisig account
+/// 2. `[writable]` proposal: [Proposal] The proposal account to initialize
+/// 3. `[signer]` creator: [AccountInfo] The account creating the proposal (must be a signer of the multisig)
+/// 4. `[optional]` address_book: [AddressBook] The multisig's address book, required when the multisig enforces it
+/// 5. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data:
+/// - terms: [PaymentScheduleTerms] The destination, mint, amount, interval and number of payments
+/// - vault_index: [u8] The vault the proposal acts on
+/// - expiration_time: [i64] Optional timestamp when the proposal expires (0 means no expiration, or the maximum lifetime when the multisig sets one)
+/// - proposal_seed_proposal_id: [u64] Auto-generated, from the input "proposal" for the its seed definition "Proposal", sets the seed named "proposal_id"
+	pub fn create_payment_schedule_proposal(ctx: Context<CreatePaymentScheduleProposal>, terms: PaymentScheduleTerms, vault_index: u8, expiration_time: i64, proposal_seed_proposal_id: u64) -> Result<()> {
+		create_payment_schedule_proposal::handler(ctx, terms, vault_index, expiration_time, proposal_seed_proposal_id)
+	}
+
+/// Create the payment schedule of an approved payment schedule proposal
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[]` multisig: [MultisigConfig] The multisig account
+/// 2. `[writable]` proposal: [Proposal] The payment schedule proposal to execute
+/// 3. `[writable]` payment_schedule: [PaymentSchedule] The payment schedule account to initialize
+/// 4. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data:
+/// - proposal_seed_proposal_id: [u64] Auto-generated, from the input "proposal" for the its seed definition "Proposal", sets the seed named "proposal_id"
+	pub fn execute_payment_schedule_proposal(ctx: Context<ExecutePaymentScheduleProposal>, proposal_seed_proposal_id: u64) -> Result<()> {
+		execute_payment_schedule_proposal::handler(ctx, proposal_seed_proposal_id)
+	}
+
+/// Pay the next due payment of a payment schedule from the vault (callable by anyone)
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[]` multisig: [MultisigConfig] The multisig account
+/// 2. `[writable]` payment_schedule: [PaymentSchedule] The payment schedule to crank
+/// 3. `[]` vault: [AccountInfo] The multisig vault paying the schedule
+/// 4. `[writable]` source: [TokenAccount] The vault's associated token account for the mint
+/// 5. `[writable]` destination: [TokenAccount] A token account of the schedule's mint owned by the schedule's destination
+/// 6. `[]` mint: [Mint] The mint named in the schedule
+/// 7. `[]` token_program: [AccountInfo] SPL Token program
+/// 8. `[writable, optional]` transfer_policy: [TransferPolicy] The multisig's transfer policy, required when it is enabled
+///
+/// Data:
+/// - payment_schedule_seed_schedule_id: [u64] Auto-generated, from the input "payment_schedule" for the its seed definition "PaymentSchedule", sets the seed named "schedule_id"
+	pub fn crank_schedule(ctx: Context<CrankSchedule>, _payment_schedule_seed_schedule_id: u64) -> Result<()> {
+		crank_schedule::handler(ctx, )
+	}
+
+/// Create a proposal that funds a linear vesting stream from the vault once approved
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable]` multisig: [MultisigConfig] The multisig account
+/// 2. `[writable]` proposal: [Proposal] The proposal account to initialize
+/// 3. `[signer]` creator: [AccountInfo] The account creating the proposal (must be a signer of the multisig)
+/// 4. `[optional]` address_book: [AddressBook] The multisig's address book, required when the multisig enforces it
+/// 5. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data:
+/// - terms: [VestingStreamTerms] The recipient, mint, total amount and start, cliff and end times
+/// - vault_index: [u8] The vault the proposal acts on
+/// - expiration_time: [i64] Optional timestamp when the proposal expires (0 means no expiration, or the maximum lifetime when the multisig sets one)
+/// - proposal_seed_proposal_id: [u64] Auto-generated, from the input "proposal" for the its seed definition "Proposal", sets the seed named "proposal_id"
+	pub fn create_vesting_stream_proposal(ctx: Context<CreateVestingStreamProposal>, terms: VestingStreamTerms, vault_index: u8, expiration_time: i64, proposal_seed_proposal_id: u64) -> Result<()> {
+		create_vesting_stream_proposal::handler(ctx, terms, vault_index, expiration_time, proposal_seed_proposal_id)
+	}
+
+/// Create the vesting stream of an approved vesting stream proposal, moving its total amount from
+/// the vault into the stream's escrow
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[]` multisig: [MultisigConfig] The multisig account
+/// 2. `[writable]` proposal: [Proposal] The vesting stream proposal to execute
+/// 3. `[]` vault: [AccountInfo] The multisig vault funding the stream
+/// 4. `[writable]` vesting_stream: [VestingStream] The vesting stream account to initialize
+/// 5. `[writable]` source: [TokenAccount] The vault's associated token account for the mint
+/// 6. `[writable]` escrow: [TokenAccount] The stream's associated token account to initialize
+/// 7. `[]` mint: [Mint] The mint named in the proposal
+/// 8. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+/// 9. `[]` token_program: [AccountInfo] SPL Token program
+/// 10. `[]` associated_token_program: [AccountInfo] SPL Associated Token program
+/// 11. `[writable, optional]` transfer_policy: [TransferPolicy] The multisig's transfer policy, required when it is enabled
+///
+/// Data:
+/// - proposal_seed_proposal_id: [u64] Auto-generated, from the input "proposal" for the its seed definition "Proposal", sets the seed named "proposal_id"
+	pub fn execute_vesting_stream_proposal(ctx: Context<ExecuteVestingStreamProposal>, proposal_seed_proposal_id: u64) -> Result<()> {
+		execute_vesting_stream_proposal::handler(ctx, proposal_seed_proposal_id)
+	}
+
+/// Withdraw everything vested so far from a vesting stream to a token account of the recipient
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[]` multisig: [MultisigConfig] The multisig account
+/// 2. `[writable]` vesting_stream: [VestingStream] The vesting stream to withdraw from
+/// 3. `[signer]` recipient: [AccountInfo] The recipient of the stream
+/// 4. `[writable]` escrow: [TokenAccount] The stream's associated token account
+/// 5. `[writable]` destination: [TokenAccount] A token account owned by the recipient
+/// 6. `[]` mint: [Mint] The mint of the stream
+/// 7. `[]` token_program: [AccountInfo] SPL Token program
+///
+/// Data:
+/// - vesting_stream_seed_stream_id: [u64] Auto-generated, from the input "vesting_stream" for the its seed definition "VestingStream", sets the seed named "stream_id"
+	pub fn withdraw_vested(ctx: Context<WithdrawVested>, vesting_stream_seed_stream_id: u64) -> Result<()> {
+		withdraw_vested::handler(ctx, vesting_stream_seed_stream_id)
+	}
+
+/// Create a proposal that cancels a vesting stream once approved
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable]` multisig: [MultisigConfig] The multisig account
+/// 2. `[writable]` proposal: [Proposal] The proposal account to initialize
+/// 3. `[signer]` creator: [AccountInfo] The account creating the proposal (must be a signer of the multisig)
+/// 4. `[]` vesting_stream: [VestingStream] The vesting stream to cancel
+/// 5. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data:
+/// - expiration_time: [i64] Optional timestamp when the proposal expires (0 means no expiration, or the maximum lifetime when the multisig sets one)
+/// - proposal_seed_proposal_id: [u64] Auto-generated, from the input "proposal" for the its seed definition "Proposal", sets the seed named "proposal_id"
+/// - vesting_stream_seed_stream_id: [u64] Auto-generated, from the input "vesting_stream" for the its seed definition "VestingStream", sets the seed named "stream_id"
+	pub fn create_cancel_stream_proposal(ctx: Context<CreateCancelStreamProposal>, expiration_time: i64, proposal_seed_proposal_id: u64, _vesting_stream_seed_stream_id: u64) -> Result<()> {
+		create_cancel_stream_proposal::handler(ctx, expiration_time, proposal_seed_proposal_id)
+	}
+
+/// Cancel a vesting stream through an approved cancel stream proposal, returning the unvested
+/// amount to the vault; what has already vested stays withdrawable by the recipient
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[]` multisig: [MultisigConfig] The multisig account
+/// 2. `[writable]` proposal: [Proposal] The cancel stream proposal to execute
+/// 3. `[writable]` vesting_stream: [VestingStream] The vesting stream to cancel
+/// 4. `[]` vault: [AccountInfo] The multisig vault receiving the unvested amount
+/// 5. `[writable]` escrow: [TokenAccount] The stream's associated token account
+/// 6. `[writable]` vault_token_account: [TokenAccount] The vault's associated token account for the mint
+/// 7. `[]` mint: [Mint] The mint of the stream
+/// 8. `[]` token_program: [AccountInfo] SPL Token program
+///
+/// Data:
+/// - proposal_seed_proposal_id: [u64] Auto-generated, from the input "proposal" for the its seed definition "Proposal", sets the seed named "proposal_id"
+/// - vesting_stream_seed_stream_id: [u64] Auto-generated, from the input "vesting_stream" for the its seed definition "VestingStream", sets the seed named "stream_id"
+	pub fn cancel_stream(ctx: Context<CancelStream>, _proposal_seed_proposal_id: u64, vesting_stream_seed_stream_id: u64) -> Result<()> {
+		cancel_stream::handler(ctx, vesting_stream_seed_stream_id)
+	}
+
+/// Set or remove the approval threshold override of one vault (requires every current signer to sign the transaction)
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable]` multisig: [MultisigConfig] The multisig account to configure
+/// 2. `[signer]` remaining_accounts: [AccountInfo] Every current signer of the multisig (accounts 2 and up)
+///
+/// Data:
+/// - vault_index: [u8] The vault whose threshold is overridden
+/// - threshold: [Option<u8>] Approvals needed by proposals acting on the vault (None falls back to the multisig threshold)
+	pub fn set_vault_threshold<'info>(ctx: Context<'_, '_, '_, 'info, SetVaultThreshold<'info>>, vault_index: u8, threshold: Option<u8>) -> Result<()> {
+		set_vault_threshold::handler(ctx, vault_index, threshold)
+	}
+
+/// Create a proposal that, once approved, approves a proposal of a parent multisig in which one of
+/// this multisig's vaults is a signer
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable]` multisig: [MultisigConfig] The multisig account
+/// 2. `[writable]` proposal: [Proposal] The proposal account to initialize
+/// 3. `[signer]` creator: [AccountInfo] The account creating the proposal (must be a signer of the multisig)
+/// 4. `[]` vault: [AccountInfo] The multisig vault that is a signer of the parent multisig
+/// 5. `[]` parent_multisig: [MultisigConfig] The parent multisig whose proposal is approved
+/// 6. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data:
+/// - parent_proposal_id: [u64] The id of the parent multisig's proposal to approve
+/// - vault_index: [u8] The vault that approves on the parent multisig
+/// - expiration_time: [i64] Optional timestamp when the proposal expires (0 means no expiration, or the maximum lifetime when the multisig sets one)
+/// - proposal_seed_proposal_id: [u64] Auto-generated, from the input "proposal" for the its seed definition "Proposal", sets the seed named "proposal_id"
+	pub fn create_nested_approval_proposal(ctx: Context<CreateNestedApprovalProposal>, parent_proposal_id: u64, vault_index: u8, expiration_time: i64, proposal_seed_proposal_id: u64) -> Result<()> {
+		create_nested_approval_proposal::handler(ctx, parent_proposal_id, vault_index, expiration_time, proposal_seed_proposal_id)
+	}
+
+/// Apply an approved nested approval proposal: the vault approves the parent multisig's proposal
+/// by calling `approve_proposal` through CPI
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[]` multisig: [MultisigConfig] The multisig account
+/// 2. `[writable]` proposal: [Proposal] The nested approval proposal to execute
+/// 3. `[]` vault: [AccountInfo] The multisig vault that is a signer of the parent multisig
+/// 4. `[]` parent_multisig: [MultisigConfig] The parent multisig named in the proposal
+/// 5. `[writable]` parent_proposal: [AccountInfo] The parent multisig's proposal to approve
+/// 6. `[]` multisig_wallet_program: [AccountInfo] This program, called back to approve the parent proposal
+///
+/// Data:
+/// - proposal_seed_proposal_id: [u64] Auto-generated, from the input "proposal" for the its seed definition "Proposal", sets the seed named "proposal_id"
+	pub fn execute_nested_approval_proposal(ctx: Context<ExecuteNestedApprovalProposal>, _proposal_seed_proposal_id: u64) -> Result<()> {
+		execute_nested_approval_proposal::handler(ctx, )
+	}
+
+/// Turn optimistic mode on or off and set its veto window and veto threshold (requires every
+/// current signer to sign the transaction)
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable]` multisig: [MultisigConfig] The multisig account to configure
+/// 2. `[signer]` remaining_accounts: [AccountInfo] Every current signer of the multisig (accounts 2 and up)
+///
+/// Data:
+/// - optimistic: [bool] Whether new transfer proposals pass unless vetoed instead of needing approvals
+/// - veto_window: [i64] Number of seconds after creation during which signers can veto an optimistic proposal (at most `MAX_VETO_WINDOW`)
+/// - veto_threshold: [u8] The number of vetoes that blocks an optimistic proposal
+	pub fn configure_optimistic<'info>(ctx: Context<'_, '_, '_, 'info, ConfigureOptimistic<'info>>, optimistic: bool, veto_window: i64, veto_threshold: u8) -> Result<()> {
+		configure_optimistic::handler(ctx, optimistic, veto_window, veto_threshold)
+	}
+
+/// Veto an optimistic proposal during its veto window; once the veto threshold is reached the
+/// proposal can no longer be executed
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[]` multisig: [MultisigConfig] The multisig account
+/// 2. `[writable]` proposal: [Proposal] The optimistic proposal to veto
+/// 3. `[signer]` signer: [AccountInfo] The signer vetoing the proposal (must be a signer of the multisig)
+///
+/// Data:
+/// - proposal_seed_proposal_id: [u64] Auto-generated, from the input "proposal" for the its seed definition "Proposal", sets the seed named "proposal_id"
+	pub fn veto_proposal(ctx: Context<VetoProposal>, _proposal_seed_proposal_id: u64) -> Result<()> {
+		veto_proposal::handler(ctx, )
+	}
+
+/// Rewrite a multisig created before accounts were versioned into the current layout, growing the
+/// account as needed (callable by anyone; the fee payer covers the extra rent)
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[writable]` multisig: [AccountInfo] The legacy multisig account to migrate
+/// 2. `[]` system_program: [AccountInfo] System program, to top up the account's rent
+	pub fn migrate_multisig(ctx: Context<MigrateMultisig>) -> Result<()> {
+		migrate_multisig::handler(ctx, )
+	}
+
+/// Rewrite a proposal created before accounts were versioned into the current layout, growing the
+/// account as needed (callable by anyone once the multisig has been migrated; the fee payer covers
+/// the extra rent)
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] 
+/// 1. `[]` multisig: [MultisigConfig] The (already migrated) multisig account
+/// 2. `[writable]` proposal: [AccountInfo] The legacy proposal account to migrate
+/// 3. `[]` system_program: [AccountInfo] System program, to top up the account's rent
+///
+/// Data:
+/// - proposal_seed_proposal_id: [u64] Auto-generated, from the input "proposal" for the its seed definition "Proposal", sets the seed named "proposal_id"
+	pub fn migrate_proposal(ctx: Context<MigrateProposal>, _proposal_seed_proposal_id: u64) -> Result<()> {
+		migrate_proposal::handler(ctx, )
+	}
+
 /// Update the signers or threshold of the multisig (requires approval from all current signers)
 ///
 /// Accounts:
@@ -0,0 +1,51 @@
+
+use anchor_lang::prelude::*;
+
+use crate::error::MultisigWalletError;
+use crate::MAX_ADDRESS_BOOK_ENTRIES;
+
+#[account]
+pub struct AddressBook {
+	pub multisig: Pubkey,
+	pub entries: Vec<AddressBookEntry>,
+	pub nonce: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub struct AddressBookEntry {
+	pub address: Pubkey,
+	pub label: String,
+}
+
+/// A change to the address book, applied once the proposal carrying it reaches the threshold
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub enum AddressBookChange {
+	AddEntry { address: Pubkey, label: String },
+	RemoveEntry { address: Pubkey },
+	SetEnforced { enforced: bool },
+}
+
+impl AddressBook {
+	pub fn contains(&self, address: &Pubkey) -> bool {
+		self.entries.iter().any(|entry| entry.address == *address)
+	}
+
+	pub fn add_entry(&mut self, address: Pubkey, label: String) -> Result<()> {
+		require!(!self.contains(&address), MultisigWalletError::AddressBookEntryExists);
+		require!(self.entries.len() < MAX_ADDRESS_BOOK_ENTRIES, MultisigWalletError::AddressBookFull);
+
+		self.entries.push(AddressBookEntry { address, label });
+
+		Ok(())
+	}
+
+	pub fn remove_entry(&mut self, address: &Pubkey) -> Result<()> {
+		let index = self.entries.iter()
+			.position(|entry| entry.address == *address)
+			.ok_or(MultisigWalletError::AddressBookEntryNotFound)?;
+
+		self.entries.remove(index);
+
+		Ok(())
+	}
+}
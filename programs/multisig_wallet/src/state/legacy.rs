@@ -0,0 +1,120 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_lang::Discriminator;
+
+use crate::error::MultisigWalletError;
+use crate::{MultisigConfig, Proposal};
+
+/// Reads a legacy-layout account of type `T` (same discriminator, shorter data than `space`),
+/// failing if the account is not one or has already been migrated
+pub fn read_legacy_account<T: Discriminator, L: AnchorDeserialize>(account: &AccountInfo, space: usize) -> Result<L> {
+	let data = account.try_borrow_data()?;
+
+	require!(data.starts_with(T::DISCRIMINATOR), MultisigWalletError::InvalidLegacyAccount);
+	require!(data.len() < space, MultisigWalletError::AccountAlreadyMigrated);
+
+	L::deserialize(&mut &data[T::DISCRIMINATOR.len()..])
+		.map_err(|_| error!(MultisigWalletError::InvalidLegacyAccount))
+}
+
+/// Grows `account` to `space`, topping up its rent from `payer`, and writes `migrated` (the
+/// discriminator followed by the account data) over it
+pub fn write_migrated_account<'info>(
+	account: &AccountInfo<'info>,
+	payer: &AccountInfo<'info>,
+	system_program: &AccountInfo<'info>,
+	space: usize,
+	migrated: &[u8],
+) -> Result<()> {
+	let rent = Rent::get()?.minimum_balance(space).saturating_sub(account.lamports());
+
+	if rent > 0 {
+		system_program::transfer(
+			CpiContext::new(system_program.clone(), system_program::Transfer {
+				from: payer.clone(),
+				to: account.clone(),
+			}),
+			rent,
+		)?;
+	}
+
+	account.resize(space)?;
+	account.try_borrow_mut_data()?[..migrated.len()].copy_from_slice(migrated);
+
+	Ok(())
+}
+
+/// `MultisigConfig` as laid out before accounts were versioned (one multisig per program, seeded by
+/// `b"multisig"` alone)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub struct LegacyMultisigConfig {
+	pub signers: Vec<Pubkey>,
+	pub threshold: u8,
+	pub proposal_count: u64,
+	pub nonce: u8,
+}
+
+impl LegacyMultisigConfig {
+	pub const SPACE: usize = 342;
+
+	/// The current layout, with every setting added since left at its default (a default create key
+	/// keeps the legacy PDA address valid) and `bump` recorded as the nonce, which legacy accounts
+	/// never set
+	pub fn migrate(self, bump: u8) -> MultisigConfig {
+		MultisigConfig {
+			version: MultisigConfig::CURRENT_VERSION,
+			signers: self.signers,
+			threshold: self.threshold,
+			proposal_count: self.proposal_count,
+			nonce: bump,
+			..Default::default()
+		}
+	}
+}
+
+/// `Proposal` as laid out before accounts were versioned; every legacy proposal is a transfer
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub struct LegacyProposal {
+	pub id: u64,
+	pub creator: Pubkey,
+	pub destination: Pubkey,
+	pub amount: u64,
+	pub token_mint: Pubkey,
+	pub approvals: Vec<Pubkey>,
+	pub executed: bool,
+	pub cancelled: bool,
+	pub expiration_time: i64,
+	pub multisig: Pubkey,
+	pub nonce: u8,
+}
+
+impl LegacyProposal {
+	pub const SPACE: usize = 487;
+
+	/// The current layout: a transfer from the first vault with no metadata, cancel votes or vetoes,
+	/// with `bump` and `vault_bump` recorded as the nonces and the approvals of keys still among
+	/// `signers` turned into their bits
+	pub fn migrate(self, bump: u8, vault_bump: u8, signers: &[Pubkey]) -> Proposal {
+		let approvals = signers.iter()
+			.enumerate()
+			.filter(|(_, signer)| self.approvals.contains(signer))
+			.fold(0u16, |approvals, (index, _)| approvals | 1 << index);
+
+		Proposal {
+			version: Proposal::CURRENT_VERSION,
+			id: self.id,
+			creator: self.creator,
+			destination: self.destination,
+			amount: self.amount,
+			token_mint: self.token_mint,
+			approvals,
+			executed: self.executed as u8,
+			cancelled: self.cancelled as u8,
+			expiration_time: self.expiration_time,
+			multisig: self.multisig,
+			nonce: bump,
+			vault_nonce: vault_bump,
+			..Default::default()
+		}
+	}
+}
@@ -1,8 +1,24 @@
 
 use anchor_lang::prelude::*;
 
+pub mod address_book;
+pub mod legacy;
 pub mod multisig_config;
+pub mod payment_schedule;
+pub mod program_upgrade;
 pub mod proposal;
+pub mod recovery_request;
+pub mod stake;
+pub mod transfer_policy;
+pub mod vesting_stream;
 
+pub use address_book::*;
+pub use legacy::*;
 pub use multisig_config::*;
+pub use payment_schedule::*;
+pub use program_upgrade::*;
 pub use proposal::*;
+pub use recovery_request::*;
+pub use stake::*;
+pub use transfer_policy::*;
+pub use vesting_stream::*;
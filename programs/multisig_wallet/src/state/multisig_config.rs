@@ -1,10 +1,181 @@
 
 use anchor_lang::prelude::*;
 
+use crate::error::MultisigWalletError;
+use crate::{ProposalKind, MAX_VAULT_THRESHOLDS};
+
 #[account]
+#[derive(Default)]
 pub struct MultisigConfig {
+	pub version: u8,
 	pub signers: Vec<Pubkey>,
 	pub threshold: u8,
 	pub proposal_count: u64,
 	pub nonce: u8,
+	pub max_proposal_lifetime: i64,
+	pub address_book_enforced: bool,
+	pub transfer_policy_enabled: bool,
+	pub guardian: Option<Pubkey>,
+	pub frozen: bool,
+	pub recovery_keys: Vec<Pubkey>,
+	pub recovery_threshold: u8,
+	pub recovery_delay: i64,
+	pub recovery_count: u64,
+	pub cancel_threshold: u8,
+	pub vault_thresholds: Vec<VaultThreshold>,
+	pub create_key: Pubkey,
+	pub optimistic: bool,
+	pub veto_window: i64,
+	pub veto_threshold: u8,
+}
+
+/// Threshold override for proposals acting on one of the multisig's vaults
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub struct VaultThreshold {
+	pub vault_index: u8,
+	pub threshold: u8,
+}
+
+impl MultisigConfig {
+	pub const CURRENT_VERSION: u8 = 1;
+	pub const SPACE: usize = 631;
+
+	/// The seed after `b"multisig"` in the multisig PDA: the create key, or nothing for multisigs
+	/// migrated from the legacy single-multisig layout (whose create key is the default pubkey)
+	pub fn create_key_seed(&self) -> &[u8] {
+		match self.create_key == Pubkey::default() {
+			true => &[],
+			false => self.create_key.as_ref(),
+		}
+	}
+
+	/// Canonical bump of vault `vault_index` of `multisig`, looked up once when a proposal acting on
+	/// the vault is created and stored on it so that execution signs for the vault without a search
+	pub fn vault_nonce(multisig: &Pubkey, vault_index: u8) -> u8 {
+		Pubkey::find_program_address(&[b"vault", multisig.as_ref(), &[vault_index]], &crate::ID).1
+	}
+
+	pub fn require_not_frozen(&self) -> Result<()> {
+		require!(!self.frozen, MultisigWalletError::MultisigFrozen);
+
+		Ok(())
+	}
+
+	/// Fails unless every current signer is among the signing `accounts`
+	pub fn require_all_signers(&self, accounts: &[AccountInfo]) -> Result<()> {
+		let all_signed = self.signers.iter().all(|signer| {
+			accounts.iter().any(|account| account.is_signer && account.key == signer)
+		});
+		require!(all_signed, MultisigWalletError::NotAllSignersApproved);
+
+		Ok(())
+	}
+
+	/// Index of `key` among the signers, which proposals use as its bit in their approval, cancel vote
+	/// and veto bitmaps (recovery replaces a signer in place, so indexes stay stable)
+	pub fn signer_index(&self, key: &Pubkey) -> Option<usize> {
+		self.signers.iter().position(|signer| signer == key)
+	}
+
+	/// Approvals needed by proposals acting on vault `vault_index` (the override, if any, else the threshold)
+	pub fn vault_threshold(&self, vault_index: u8) -> u8 {
+		self.vault_thresholds.iter()
+			.find(|vault_threshold| vault_threshold.vault_index == vault_index)
+			.map_or(self.threshold, |vault_threshold| vault_threshold.threshold)
+	}
+
+	/// Approvals needed to execute a proposal of `kind` acting on vault `vault_index`
+	pub fn proposal_threshold(&self, kind: &ProposalKind, vault_index: u8) -> u8 {
+		if kind.uses_vault() {
+			self.vault_threshold(vault_index)
+		} else {
+			self.threshold
+		}
+	}
+
+	/// Sets (or with `None` removes) the threshold override of vault `vault_index`
+	pub fn set_vault_threshold(&mut self, vault_index: u8, threshold: Option<u8>) -> Result<()> {
+		let index = self.vault_thresholds.iter()
+			.position(|vault_threshold| vault_threshold.vault_index == vault_index);
+
+		match (index, threshold) {
+			(Some(index), None) => {
+				self.vault_thresholds.remove(index);
+			}
+			(None, None) => {}
+			(index, Some(threshold)) => {
+				require!(
+					threshold > 0 && threshold as usize <= self.signers.len(),
+					MultisigWalletError::InvalidThreshold,
+				);
+
+				match index {
+					Some(index) => self.vault_thresholds[index].threshold = threshold,
+					None => {
+						require!(
+							self.vault_thresholds.len() < MAX_VAULT_THRESHOLDS,
+							MultisigWalletError::VaultThresholdsFull,
+						);
+						self.vault_thresholds.push(VaultThreshold { vault_index, threshold });
+					}
+				}
+			}
+		}
+
+		Ok(())
+	}
+
+	/// Proposal count once the proposal at the current count is created
+	pub fn next_proposal_id(&self) -> Result<u64> {
+		Ok(self.proposal_count.checked_add(1).ok_or(MultisigWalletError::IdOverflow)?)
+	}
+
+	/// Recovery request count once the request at the current count is created
+	pub fn next_recovery_id(&self) -> Result<u64> {
+		Ok(self.recovery_count.checked_add(1).ok_or(MultisigWalletError::IdOverflow)?)
+	}
+
+	/// Number of cancel votes needed to cancel a proposal (0 falls back to the approval threshold)
+	pub fn effective_cancel_threshold(&self) -> u8 {
+		match self.cancel_threshold {
+			0 => self.threshold,
+			cancel_threshold => cancel_threshold,
+		}
+	}
+
+	/// Approvals an optimistic proposal acting on vault `vault_index` needs besides surviving its
+	/// veto window: the vault's threshold override if it has one, none otherwise
+	pub fn optimistic_threshold(&self, vault_index: u8) -> u8 {
+		self.vault_thresholds.iter()
+			.find(|vault_threshold| vault_threshold.vault_index == vault_index)
+			.map_or(0, |vault_threshold| vault_threshold.threshold)
+	}
+
+	/// Deadline for vetoing a transfer proposal created at `now` (0 when optimistic mode is off)
+	pub fn veto_deadline(&self, now: i64) -> Result<i64> {
+		match self.optimistic {
+			true => Ok(now.checked_add(self.veto_window).ok_or(MultisigWalletError::InvalidOptimisticConfig)?),
+			false => Ok(0),
+		}
+	}
+
+	/// Validates a requested proposal expiration time against `now` and the maximum proposal
+	/// lifetime, returning the expiration time to store (0 defaults to the maximum lifetime)
+	pub fn resolve_expiration_time(&self, expiration_time: i64, now: i64) -> Result<i64> {
+		match (expiration_time, self.max_proposal_lifetime) {
+			(0, 0) => Ok(0),
+			(0, max_proposal_lifetime) => Ok(
+				now.checked_add(max_proposal_lifetime).ok_or(MultisigWalletError::InvalidMaxProposalLifetime)?
+			),
+			(expiration_time, max_proposal_lifetime) => {
+				require!(expiration_time > now, MultisigWalletError::InvalidExpirationTime);
+				require!(
+					max_proposal_lifetime == 0 || expiration_time - now <= max_proposal_lifetime,
+					MultisigWalletError::InvalidExpirationTime,
+				);
+
+				Ok(expiration_time)
+			}
+		}
+	}
 }
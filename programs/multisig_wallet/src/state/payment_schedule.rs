@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+
+use crate::error::MultisigWalletError;
+
+#[account]
+pub struct PaymentSchedule {
+	pub id: u64,
+	pub multisig: Pubkey,
+	pub destination: Pubkey,
+	pub mint: Pubkey,
+	pub amount: u64,
+	pub interval: i64,
+	pub payment_count: u32,
+	pub payments_made: u32,
+	pub next_payment_time: i64,
+	pub vault_index: u8,
+	pub vault_nonce: u8,
+	pub nonce: u8,
+}
+
+/// The terms of a payment schedule, carried by the proposal that approves it
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub struct PaymentScheduleTerms {
+	/// Wallet receiving every payment, through any token account of `mint` it owns
+	pub destination: Pubkey,
+	pub mint: Pubkey,
+	pub amount: u64,
+	/// Seconds between two payments
+	pub interval: i64,
+	pub payment_count: u32,
+	/// Due time of the first payment (0 means as soon as the schedule is created)
+	pub start_time: i64,
+}
+
+impl PaymentScheduleTerms {
+	pub fn validate(&self) -> Result<()> {
+		require!(
+			self.amount > 0 && self.interval > 0 && self.payment_count > 0 && self.start_time >= 0,
+			MultisigWalletError::InvalidPaymentSchedule,
+		);
+
+		Ok(())
+	}
+}
+
+impl PaymentSchedule {
+	/// Fails unless a payment is left and due at `now`
+	pub fn require_due(&self, now: i64) -> Result<()> {
+		require!(self.payments_made < self.payment_count, MultisigWalletError::PaymentScheduleCompleted);
+		require!(now >= self.next_payment_time, MultisigWalletError::PaymentNotDue);
+
+		Ok(())
+	}
+
+	/// Records the payment that was due, moving the due time one interval forward
+	pub fn record_payment(&mut self) {
+		self.payments_made += 1;
+		self.next_payment_time = self.next_payment_time.saturating_add(self.interval);
+	}
+}
@@ -0,0 +1,68 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hash;
+
+use crate::error::MultisigWalletError;
+
+#[allow(deprecated)]
+use anchor_lang::solana_program::bpf_loader_upgradeable::{self, UpgradeableLoaderState};
+
+/// A BPF upgradeable loader action on a program whose upgrade authority is the multisig vault
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub enum ProgramUpgradeChange {
+	/// Deploy `buffer` to `program`, refunding the buffer's lamports to `spill`; `buffer_hash` is
+	/// the SHA-256 of the buffer's program bytes, checked when the proposal is created
+	Upgrade { program: Pubkey, buffer: Pubkey, spill: Pubkey, buffer_hash: [u8; 32] },
+	/// Hand the upgrade authority of `program` to `new_authority` (`None` makes it immutable)
+	SetAuthority { program: Pubkey, new_authority: Option<Pubkey> },
+}
+
+impl ProgramUpgradeChange {
+	pub fn program(&self) -> Pubkey {
+		match self {
+			ProgramUpgradeChange::Upgrade { program, .. } => *program,
+			ProgramUpgradeChange::SetAuthority { program, .. } => *program,
+		}
+	}
+
+	/// Fails unless `program_data` is the program data account of the targeted program and its
+	/// upgrade authority is `vault`
+	pub fn validate_program_data(&self, program_data: &Account<ProgramData>, vault: &Pubkey) -> Result<()> {
+		#[allow(deprecated)]
+		let program_data_address = bpf_loader_upgradeable::get_program_data_address(&self.program());
+
+		require_keys_eq!(program_data.key(), program_data_address, MultisigWalletError::InvalidProgramData);
+		require!(
+			program_data.upgrade_authority_address == Some(*vault),
+			MultisigWalletError::InvalidProgramData,
+		);
+
+		Ok(())
+	}
+
+	/// Fails unless `buffer` is the proposed buffer, its authority is `vault` and its program
+	/// bytes hash to the proposed buffer hash
+	pub fn validate_buffer(&self, buffer: Option<&AccountInfo>, vault: &Pubkey) -> Result<()> {
+		let ProgramUpgradeChange::Upgrade { buffer: buffer_address, buffer_hash, .. } = self else {
+			return Ok(());
+		};
+		let buffer = buffer.ok_or(MultisigWalletError::InvalidBuffer)?;
+
+		require_keys_eq!(buffer.key(), *buffer_address, MultisigWalletError::InvalidBuffer);
+		#[allow(deprecated)]
+		let owner = bpf_loader_upgradeable::ID;
+		require_keys_eq!(*buffer.owner, owner, MultisigWalletError::InvalidBuffer);
+
+		let data = buffer.try_borrow_data()?;
+		let state = UpgradeableLoaderState::try_deserialize_unchecked(&mut &data[..])?;
+		require!(
+			state == UpgradeableLoaderState::Buffer { authority_address: Some(*vault) },
+			MultisigWalletError::InvalidBuffer,
+		);
+		require!(
+			hash(&data[UpgradeableLoaderState::size_of_buffer_metadata()..]).to_bytes() == *buffer_hash,
+			MultisigWalletError::BufferHashMismatch,
+		);
+
+		Ok(())
+	}
+}
@@ -1,17 +1,279 @@
-
 use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+use bytemuck::Zeroable;
+
+use crate::error::MultisigWalletError;
+use crate::{
+	AddressBookChange, PaymentScheduleTerms, ProgramUpgradeChange, StakeChange, TransferPolicyChange,
+	VestingStreamTerms, MAX_DESCRIPTION_URI_LEN, MAX_MEMO_LEN, MAX_SIGNERS, MAX_TITLE_LEN,
+};
 
-#[account]
+/// A proposal, laid out zero-copy so instructions touch its fields in place instead of decoding it.
+/// Approvals, cancel votes and vetoes are bitmaps over the indexes of `MultisigConfig.signers`;
+/// flags are 0/1 bytes, the kind is Borsh-encoded into a fixed buffer and the optional texts are
+/// fixed buffers with a length (0 meaning none). `nonce` and `vault_nonce` are the canonical bumps
+/// of the proposal and of the vault it acts on, recorded at creation
+#[account(zero_copy)]
 pub struct Proposal {
+	pub version: u8,
+	pub nonce: u8,
+	pub executed: u8,
+	pub cancelled: u8,
+	pub expired: u8,
+	pub vault_index: u8,
+	pub vault_nonce: u8,
+	pub title_len: u8,
+	pub memo_len: u8,
+	pub reserved: [u8; 7],
 	pub id: u64,
+	pub amount: u64,
+	pub expiration_time: i64,
+	pub veto_deadline: i64,
 	pub creator: Pubkey,
 	pub destination: Pubkey,
-	pub amount: u64,
 	pub token_mint: Pubkey,
-	pub approvals: Vec<Pubkey>,
-	pub executed: bool,
-	pub cancelled: bool,
-	pub expiration_time: i64,
 	pub multisig: Pubkey,
-	pub nonce: u8,
+	pub approvals: u16,
+	pub cancel_votes: u16,
+	pub vetoes: u16,
+	pub description_uri_len: u16,
+	pub kind: [u8; 256],
+	pub title: [u8; MAX_TITLE_LEN],
+	pub description_uri: [u8; MAX_DESCRIPTION_URI_LEN],
+	pub memo: [u8; MAX_MEMO_LEN],
+}
+
+const _: () = assert!(MAX_SIGNERS <= u16::BITS as usize);
+
+impl Default for Proposal {
+	fn default() -> Self {
+		Proposal::zeroed()
+	}
+}
+
+/// The transfer a `Transfer` proposal makes from its vault once executed
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub struct TransferTerms {
+	/// Wallet whose token account receives the transfer
+	pub destination: Pubkey,
+	pub amount: u64,
+	pub token_mint: Pubkey,
+}
+
+/// Optional texts describing a proposal to its reviewers
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug, Default)]
+pub struct ProposalMetadata {
+	/// Short title shown to reviewers
+	pub title: Option<String>,
+	/// URI pointing to the full description of the proposal
+	pub description_uri: Option<String>,
+	/// Memo attached to the transfer through the SPL Memo program on execution
+	pub memo: Option<String>,
+}
+
+impl ProposalMetadata {
+	pub fn validate(&self) -> Result<()> {
+		if let Some(title) = &self.title {
+			require!(title.len() <= MAX_TITLE_LEN, MultisigWalletError::TitleTooLong);
+		}
+
+		if let Some(description_uri) = &self.description_uri {
+			require!(description_uri.len() <= MAX_DESCRIPTION_URI_LEN, MultisigWalletError::DescriptionUriTooLong);
+		}
+
+		if let Some(memo) = &self.memo {
+			require!(memo.len() <= MAX_MEMO_LEN, MultisigWalletError::MemoTooLong);
+		}
+
+		Ok(())
+	}
+}
+
+/// What a proposal does once executed; only `Transfer` proposals go through `execute_proposal`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug, Default)]
+pub enum ProposalKind {
+	#[default]
+	Transfer,
+	AddressBook(AddressBookChange),
+	TransferPolicy(TransferPolicyChange),
+	ProgramUpgrade(ProgramUpgradeChange),
+	Stake(StakeChange),
+	PaymentSchedule(PaymentScheduleTerms),
+	VestingStream(VestingStreamTerms),
+	CancelStream { stream_id: u64 },
+	NestedApproval { parent_multisig: Pubkey, parent_proposal_id: u64 },
+}
+
+impl ProposalKind {
+	/// Whether the proposal moves funds or authority held by a vault, making it subject to the
+	/// vault's threshold override
+	pub fn uses_vault(&self) -> bool {
+		!matches!(self, ProposalKind::AddressBook(_) | ProposalKind::TransferPolicy(_))
+	}
+}
+
+/// Sets bit `index` of `bitmap`, failing with `error` if it is already set
+fn set_bit(bitmap: &mut u16, index: usize, error: MultisigWalletError) -> Result<()> {
+	let bit = 1u16 << index;
+	if *bitmap & bit != 0 {
+		return Err(error.into());
+	}
+	*bitmap |= bit;
+
+	Ok(())
+}
+
+/// Copies `text` into `buffer`, returning its length (0 for `None`)
+fn write_text(buffer: &mut [u8], text: Option<String>) -> usize {
+	let text = text.unwrap_or_default();
+	buffer[..text.len()].copy_from_slice(text.as_bytes());
+
+	text.len()
+}
+
+/// The first `len` bytes of `buffer` as text, or `None` when empty
+fn read_text(buffer: &[u8], len: usize) -> Option<&str> {
+	match len {
+		0 => None,
+		len => std::str::from_utf8(&buffer[..len]).ok(),
+	}
+}
+
+impl Proposal {
+	pub const CURRENT_VERSION: u8 = 1;
+	pub const SPACE: usize = 8 + std::mem::size_of::<Proposal>();
+
+	/// The discriminator followed by the proposal's bytes, as stored in its account
+	pub fn to_account_data(&self) -> Vec<u8> {
+		[Proposal::DISCRIMINATOR, bytemuck::bytes_of(self)].concat()
+	}
+
+	pub fn kind(&self) -> Result<ProposalKind> {
+		ProposalKind::deserialize(&mut &self.kind[..]).map_err(Into::into)
+	}
+
+	pub fn set_kind(&mut self, kind: &ProposalKind) -> Result<()> {
+		kind.serialize(&mut &mut self.kind[..]).map_err(Into::into)
+	}
+
+	pub fn title(&self) -> Option<&str> {
+		read_text(&self.title, self.title_len as usize)
+	}
+
+	pub fn description_uri(&self) -> Option<&str> {
+		read_text(&self.description_uri, self.description_uri_len as usize)
+	}
+
+	pub fn memo(&self) -> Option<&str> {
+		read_text(&self.memo, self.memo_len as usize)
+	}
+
+	/// Stores the proposal's title, description URI and memo, already validated against their
+	/// maximum lengths
+	pub fn set_metadata(&mut self, metadata: ProposalMetadata) {
+		self.title_len = write_text(&mut self.title, metadata.title) as u8;
+		self.description_uri_len = write_text(&mut self.description_uri, metadata.description_uri) as u16;
+		self.memo_len = write_text(&mut self.memo, metadata.memo) as u8;
+	}
+
+	pub fn executed(&self) -> bool {
+		self.executed != 0
+	}
+
+	pub fn cancelled(&self) -> bool {
+		self.cancelled != 0
+	}
+
+	/// Whether the proposal was explicitly marked expired through `expire_proposal`
+	pub fn expired(&self) -> bool {
+		self.expired != 0
+	}
+
+	pub fn mark_executed(&mut self) {
+		self.executed = 1;
+	}
+
+	pub fn mark_cancelled(&mut self) {
+		self.cancelled = 1;
+	}
+
+	pub fn mark_expired(&mut self) {
+		self.expired = 1;
+	}
+
+	/// Records the approval of the signer at `signer_index`
+	pub fn approve(&mut self, signer_index: usize) -> Result<()> {
+		set_bit(&mut self.approvals, signer_index, MultisigWalletError::AlreadyApproved)
+	}
+
+	pub fn has_approved(&self, signer_index: usize) -> bool {
+		self.approvals & (1 << signer_index) != 0
+	}
+
+	/// Withdraws the approval of the signer at `signer_index`
+	pub fn unapprove(&mut self, signer_index: usize) -> Result<()> {
+		require!(self.has_approved(signer_index), MultisigWalletError::NotApproved);
+		self.approvals &= !(1 << signer_index);
+
+		Ok(())
+	}
+
+	pub fn approval_count(&self) -> u8 {
+		self.approvals.count_ones() as u8
+	}
+
+	/// Records the cancel vote of the signer at `signer_index`
+	pub fn vote_to_cancel(&mut self, signer_index: usize) -> Result<()> {
+		set_bit(&mut self.cancel_votes, signer_index, MultisigWalletError::AlreadyVotedToCancel)
+	}
+
+	pub fn cancel_vote_count(&self) -> u8 {
+		self.cancel_votes.count_ones() as u8
+	}
+
+	/// Records the veto of the signer at `signer_index`
+	pub fn veto(&mut self, signer_index: usize) -> Result<()> {
+		set_bit(&mut self.vetoes, signer_index, MultisigWalletError::AlreadyVetoed)
+	}
+
+	pub fn veto_count(&self) -> u8 {
+		self.vetoes.count_ones() as u8
+	}
+
+	/// Whether the expiration time has passed at `now` (a zero expiration time never expires)
+	pub fn is_expired(&self, now: i64) -> bool {
+		self.expiration_time != 0 && now > self.expiration_time
+	}
+
+	/// Fails unless the proposal is still open for approvals and execution at `now`
+	pub fn require_active(&self, now: i64) -> Result<()> {
+		require!(!self.executed(), MultisigWalletError::ProposalAlreadyExecuted);
+		require!(!self.cancelled(), MultisigWalletError::ProposalCancelled);
+		require!(!self.expired() && !self.is_expired(now), MultisigWalletError::ProposalExpired);
+
+		Ok(())
+	}
+
+	/// Fails unless the proposal is active and has collected `threshold` approvals at `now`
+	pub fn require_executable(&self, threshold: u8, now: i64) -> Result<()> {
+		self.require_active(now)?;
+		require!(self.approval_count() >= threshold, MultisigWalletError::InsufficientApprovals);
+
+		Ok(())
+	}
+
+	/// Whether the proposal was created in optimistic mode and passes unless vetoed
+	pub fn is_optimistic(&self) -> bool {
+		self.veto_deadline != 0
+	}
+
+	/// Fails unless the optimistic proposal is active, its veto window has elapsed at `now` and it
+	/// collected fewer than `veto_threshold` vetoes
+	pub fn require_unvetoed(&self, veto_threshold: u8, now: i64) -> Result<()> {
+		self.require_active(now)?;
+		require!(self.veto_count() < veto_threshold, MultisigWalletError::ProposalVetoed);
+		require!(now > self.veto_deadline, MultisigWalletError::VetoWindowOpen);
+
+		Ok(())
+	}
 }
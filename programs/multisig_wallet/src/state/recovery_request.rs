@@ -0,0 +1,40 @@
+
+use anchor_lang::prelude::*;
+
+use crate::error::MultisigWalletError;
+
+#[account]
+pub struct RecoveryRequest {
+	pub id: u64,
+	pub multisig: Pubkey,
+	pub lost_signer: Pubkey,
+	pub new_signer: Pubkey,
+	pub approvals: Vec<Pubkey>,
+	pub approved_at: i64,
+	pub vetoed: bool,
+	pub executed: bool,
+	pub nonce: u8,
+}
+
+impl RecoveryRequest {
+	/// Fails once the request has been vetoed or executed
+	pub fn require_pending(&self) -> Result<()> {
+		require!(!self.vetoed, MultisigWalletError::RecoveryVetoed);
+		require!(!self.executed, MultisigWalletError::RecoveryAlreadyExecuted);
+
+		Ok(())
+	}
+
+	/// Records an approval from `recovery_key`, starting the time lock once `recovery_threshold` is reached
+	pub fn approve(&mut self, recovery_key: Pubkey, recovery_threshold: u8, now: i64) -> Result<()> {
+		require!(!self.approvals.contains(&recovery_key), MultisigWalletError::RecoveryAlreadyApproved);
+
+		self.approvals.push(recovery_key);
+
+		if self.approved_at == 0 && self.approvals.len() >= recovery_threshold as usize {
+			self.approved_at = now;
+		}
+
+		Ok(())
+	}
+}
@@ -0,0 +1,12 @@
+use anchor_lang::prelude::*;
+
+/// A stake program action taken by the multisig vault, which is the staker and withdrawer of
+/// every stake account it creates
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub enum StakeChange {
+	/// Fund a new stake account (the proposal's stake account PDA) with `lamports` from the vault
+	Create { lamports: u64 },
+	Delegate { stake_account: Pubkey, vote_account: Pubkey },
+	Deactivate { stake_account: Pubkey },
+	Withdraw { stake_account: Pubkey, destination: Pubkey, lamports: u64 },
+}
@@ -0,0 +1,107 @@
+
+use anchor_lang::prelude::*;
+
+use crate::error::MultisigWalletError;
+use crate::{MAX_TRANSFER_LIMITS, TRANSFER_VOLUME_BUCKET, TRANSFER_VOLUME_BUCKETS};
+
+#[account]
+pub struct TransferPolicy {
+	pub multisig: Pubkey,
+	pub limits: Vec<MintTransferLimit>,
+	pub nonce: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub struct MintTransferLimit {
+	pub mint: Pubkey,
+	pub max_transfer_amount: u64,
+	pub max_window_volume: u64,
+	/// Index (timestamp / `TRANSFER_VOLUME_BUCKET`) of the latest bucket transfers were recorded in
+	pub latest_bucket: i64,
+	/// Volume sent per bucket, in a ring indexed by bucket index modulo its length. It holds the
+	/// latest bucket and the `TRANSFER_VOLUME_BUCKETS` before it, so it spans the whole volume window
+	pub bucket_volumes: [u64; TRANSFER_VOLUME_BUCKETS + 1],
+}
+
+/// A change to the transfer policy, applied once the proposal carrying it reaches the threshold
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub enum TransferPolicyChange {
+	SetLimit { mint: Pubkey, max_transfer_amount: u64, max_window_volume: u64 },
+	RemoveLimit { mint: Pubkey },
+}
+
+impl TransferPolicy {
+	pub fn set_limit(&mut self, mint: Pubkey, max_transfer_amount: u64, max_window_volume: u64) -> Result<()> {
+		if let Some(limit) = self.limits.iter_mut().find(|limit| limit.mint == mint) {
+			limit.max_transfer_amount = max_transfer_amount;
+			limit.max_window_volume = max_window_volume;
+
+			return Ok(());
+		}
+
+		require!(self.limits.len() < MAX_TRANSFER_LIMITS, MultisigWalletError::TransferPolicyFull);
+
+		self.limits.push(MintTransferLimit {
+			mint,
+			max_transfer_amount,
+			max_window_volume,
+			latest_bucket: 0,
+			bucket_volumes: [0; TRANSFER_VOLUME_BUCKETS + 1],
+		});
+
+		Ok(())
+	}
+
+	pub fn remove_limit(&mut self, mint: &Pubkey) -> Result<()> {
+		let index = self.limits.iter()
+			.position(|limit| limit.mint == *mint)
+			.ok_or(MultisigWalletError::TransferLimitNotFound)?;
+
+		self.limits.remove(index);
+
+		Ok(())
+	}
+
+	/// Checks a transfer of `amount` of `mint` against its limits and records it in the bucket of
+	/// `now`. The volume limit applies to the buckets the ring holds, so the volume sent within any
+	/// `TRANSFER_VOLUME_WINDOW` seconds never exceeds it (a rolling window, at the cost of a transfer
+	/// counting for up to one bucket longer than the window)
+	pub fn record_transfer(&mut self, mint: &Pubkey, amount: u64, now: i64) -> Result<()> {
+		let Some(limit) = self.limits.iter_mut().find(|limit| limit.mint == *mint) else {
+			return Ok(());
+		};
+
+		require!(amount <= limit.max_transfer_amount, MultisigWalletError::TransferLimitExceeded);
+
+		limit.roll_to(now.div_euclid(TRANSFER_VOLUME_BUCKET));
+
+		let window_volume = limit.bucket_volumes.iter()
+			.try_fold(amount, |volume, bucket_volume| volume.checked_add(*bucket_volume))
+			.ok_or(MultisigWalletError::TransferVolumeExceeded)?;
+		require!(window_volume <= limit.max_window_volume, MultisigWalletError::TransferVolumeExceeded);
+
+		let slot = limit.slot(limit.latest_bucket);
+		limit.bucket_volumes[slot] += amount;
+
+		Ok(())
+	}
+}
+
+impl MintTransferLimit {
+	fn slot(&self, bucket: i64) -> usize {
+		bucket.rem_euclid(self.bucket_volumes.len() as i64) as usize
+	}
+
+	/// Makes `bucket` the latest bucket, clearing the buckets that fell out of the window. A bucket
+	/// before the latest one (a clock going back) records into the latest bucket
+	fn roll_to(&mut self, bucket: i64) {
+		let stale_buckets = bucket.saturating_sub(self.latest_bucket).clamp(0, self.bucket_volumes.len() as i64);
+
+		for offset in 1..=stale_buckets {
+			let slot = self.slot(self.latest_bucket + offset);
+			self.bucket_volumes[slot] = 0;
+		}
+
+		self.latest_bucket = self.latest_bucket.max(bucket);
+	}
+}
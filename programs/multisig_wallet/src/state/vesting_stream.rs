@@ -0,0 +1,83 @@
+use anchor_lang::prelude::*;
+
+use crate::error::MultisigWalletError;
+
+#[account]
+pub struct VestingStream {
+	pub id: u64,
+	pub multisig: Pubkey,
+	pub recipient: Pubkey,
+	pub mint: Pubkey,
+	pub total_amount: u64,
+	pub withdrawn_amount: u64,
+	pub start_time: i64,
+	pub cliff_time: i64,
+	pub end_time: i64,
+	pub cancelled: bool,
+	pub vault_index: u8,
+	pub vault_nonce: u8,
+	pub nonce: u8,
+}
+
+/// The terms of a vesting stream, carried by the proposal that funds it
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub struct VestingStreamTerms {
+	pub recipient: Pubkey,
+	pub mint: Pubkey,
+	pub total_amount: u64,
+	pub start_time: i64,
+	/// Nothing can be withdrawn before the cliff, after which the linearly vested amount unlocks
+	pub cliff_time: i64,
+	pub end_time: i64,
+}
+
+impl VestingStreamTerms {
+	pub fn validate(&self) -> Result<()> {
+		require!(
+			self.total_amount > 0
+				&& self.start_time < self.end_time
+				&& self.start_time <= self.cliff_time
+				&& self.cliff_time <= self.end_time,
+			MultisigWalletError::InvalidVestingSchedule,
+		);
+
+		Ok(())
+	}
+}
+
+impl VestingStream {
+	/// Amount vested at `now`, linear between the start and end times once the cliff has passed
+	pub fn vested_amount(&self, now: i64) -> u64 {
+		if now < self.cliff_time {
+			return 0;
+		}
+
+		if now >= self.end_time {
+			return self.total_amount;
+		}
+
+		let elapsed = (now - self.start_time) as u128;
+		let duration = (self.end_time - self.start_time) as u128;
+
+		(self.total_amount as u128 * elapsed / duration) as u64
+	}
+
+	/// Amount the recipient can withdraw at `now`
+	pub fn withdrawable_amount(&self, now: i64) -> u64 {
+		self.vested_amount(now).saturating_sub(self.withdrawn_amount)
+	}
+
+	/// Stops vesting at `now`, returning the unvested amount to give back to the vault
+	pub fn cancel(&mut self, now: i64) -> Result<u64> {
+		require!(!self.cancelled, MultisigWalletError::StreamCancelled);
+
+		let vested_amount = self.vested_amount(now);
+		let unvested_amount = self.total_amount - vested_amount;
+
+		self.total_amount = vested_amount;
+		self.end_time = self.end_time.min(now);
+		self.cancelled = true;
+
+		Ok(unvested_amount)
+	}
+}
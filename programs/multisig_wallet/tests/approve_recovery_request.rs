@@ -0,0 +1,78 @@
+pub mod common;
+
+use {
+    common::{
+		error_assertions::assert_multisig_wallet_error,
+		multisig_fixture::{MultisigFixture, MultisigScenario},
+	},
+    solana_program_test::tokio,
+    solana_sdk::{pubkey::Pubkey, signer::Signer},
+};
+use multisig_wallet::{error::MultisigWalletError, RecoveryRequest, MIN_RECOVERY_DELAY};
+
+/// A 2-of-2 multisig recoverable by 2 of its 3 recovery keys, with a request from `recovery_0` to
+/// replace `signer_1`
+async fn requested() -> (MultisigScenario, u64) {
+	let mut scenario = MultisigFixture::new()
+		.signers(2)
+		.start()
+		.await;
+	scenario.configure_recovery(
+		&["signer_0", "signer_1"],
+		&["recovery_0", "recovery_1", "recovery_2"],
+		2,
+		MIN_RECOVERY_DELAY,
+	).await.unwrap();
+
+	let lost_signer = scenario.member("signer_1").pubkey();
+	let recovery_id = scenario.request_recovery("recovery_0", lost_signer, Pubkey::new_unique()).await.unwrap();
+
+	(scenario, recovery_id)
+}
+
+
+#[tokio::test]
+async fn approve_recovery_request_ix_success() {
+	let (mut scenario, recovery_id) = requested().await;
+
+	// INSTRUCTIONS
+	let result = scenario.approve_recovery("recovery_2", recovery_id).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+
+	let recovery_request: RecoveryRequest = scenario.account_state(scenario.recovery_request(recovery_id)).await;
+	assert_eq!(recovery_request.approvals, vec![
+		scenario.member("recovery_0").pubkey(),
+		scenario.member("recovery_2").pubkey(),
+	]);
+	assert_eq!(recovery_request.approved_at, scenario.now().await);
+}
+
+#[tokio::test]
+async fn approve_recovery_request_twice() {
+	let (mut scenario, recovery_id) = requested().await;
+
+	let result = scenario.approve_recovery("recovery_0", recovery_id).await;
+
+	assert_multisig_wallet_error(result, MultisigWalletError::RecoveryAlreadyApproved);
+}
+
+#[tokio::test]
+async fn approve_recovery_request_as_signer() {
+	let (mut scenario, recovery_id) = requested().await;
+
+	let result = scenario.approve_recovery("signer_0", recovery_id).await;
+
+	assert_multisig_wallet_error(result, MultisigWalletError::NotARecoveryKey);
+}
+
+#[tokio::test]
+async fn approve_vetoed_recovery_request() {
+	let (mut scenario, recovery_id) = requested().await;
+	scenario.veto_recovery("signer_0", recovery_id).await.unwrap();
+
+	let result = scenario.approve_recovery("recovery_1", recovery_id).await;
+
+	assert_multisig_wallet_error(result, MultisigWalletError::RecoveryVetoed);
+}
@@ -1,105 +1,99 @@
 pub mod common;
 
-use std::str::FromStr;
 use {
     common::{
-		get_program_test,
-		multisig_wallet_ix_interface,
-		csl_spl_token_ix_interface,
-		csl_spl_assoc_token_ix_interface,
+		error_assertions::assert_multisig_wallet_error,
+		multisig_fixture::{MultisigFixture, MultisigScenario},
 	},
     solana_program_test::tokio,
-    solana_sdk::{
-        account::Account, pubkey::Pubkey, rent::Rent, signature::Keypair, signer::Signer, system_program,
-    },
+    solana_sdk::pubkey::Pubkey,
 };
+use multisig_wallet::error::MultisigWalletError;
+
+/// A 2-of-3 multisig needing 2 cancel votes, with a transfer proposal from `signer_0`
+async fn proposed() -> (MultisigScenario, u64) {
+	let mut scenario = MultisigFixture::new()
+		.signers(3)
+		.threshold(2)
+		.cancel_threshold(2)
+		.start()
+		.await;
+	let proposal_id = scenario.propose_transfer("signer_0", Pubkey::new_unique(), Pubkey::new_unique(), 400).await.unwrap();
+
+	(scenario, proposal_id)
+}
 
 
 #[tokio::test]
 async fn cancel_proposal_ix_success() {
-	let mut program_test = get_program_test();
-
-	// PROGRAMS
-	program_test.prefer_bpf(true);
-
-	program_test.add_program(
-		"account_compression",
-		Pubkey::from_str("cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK").unwrap(),
-		None,
-	);
-
-	program_test.add_program(
-		"noop",
-		Pubkey::from_str("noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV").unwrap(),
-		None,
-	);
-
-	// DATA
-	let proposal_seed_proposal_id: u64 = Default::default();
-
-	// KEYPAIR
-	let fee_payer_keypair = Keypair::new();
-	let creator_keypair = Keypair::new();
-
-	// PUBKEY
-	let fee_payer_pubkey = fee_payer_keypair.pubkey();
-	let creator_pubkey = creator_keypair.pubkey();
-
-	// PDA
-	let (multisig_pda, _multisig_pda_bump) = Pubkey::find_program_address(
-		&[
-			b"multisig",
-		],
-		&multisig_wallet::ID,
-	);
-
-	let (proposal_pda, _proposal_pda_bump) = Pubkey::find_program_address(
-		&[
-			b"proposal",
-			multisig_pubkey.as_ref(),
-			proposal_seed_proposal_id.to_le_bytes().as_ref(),
-		],
-		&multisig_wallet::ID,
-	);
-
-	// ACCOUNT PROGRAM TEST SETUP
-	program_test.add_account(
-		fee_payer_pubkey,
-		Account {
-			lamports: 1_000_000_000_000,
-			data: vec![],
-			owner: system_program::ID,
-			executable: false,
-			rent_epoch: 0,
-		},
-	);
-
-	program_test.add_account(
-		creator_pubkey,
-		Account {
-			lamports: 0,
-			data: vec![],
-			owner: system_program::ID,
-			executable: false,
-			rent_epoch: 0,
-		},
-	);
+	let (mut scenario, proposal_id) = proposed().await;
 
 	// INSTRUCTIONS
-	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
-
-	let ix = multisig_wallet_ix_interface::cancel_proposal_ix_setup(
-		&fee_payer_keypair,
-		multisig_pda,
-		proposal_pda,
-		&creator_keypair,
-		proposal_seed_proposal_id,
-		recent_blockhash,
-	);
-
-	let result = banks_client.process_transaction(ix).await;
+	let result = scenario.cancel("signer_0", proposal_id).await;
 
 	// ASSERTIONS
 	assert!(result.is_ok());
+	assert!(scenario.proposal_state(proposal_id).await.cancelled());
+
+	let result = scenario.approve("signer_1", proposal_id).await;
+	assert_multisig_wallet_error(result, MultisigWalletError::ProposalCancelled);
+}
+
+#[tokio::test]
+async fn cancel_approved_proposal_by_cancel_votes() {
+	let (mut scenario, proposal_id) = proposed().await;
+	scenario.approve("signer_1", proposal_id).await.unwrap();
+
+	// The creator only votes once the proposal is approved
+	scenario.cancel("signer_0", proposal_id).await.unwrap();
+
+	let proposal = scenario.proposal_state(proposal_id).await;
+	assert!(!proposal.cancelled());
+	assert_eq!(proposal.cancel_vote_count(), 1);
+
+	scenario.cancel("signer_2", proposal_id).await.unwrap();
+
+	let proposal = scenario.proposal_state(proposal_id).await;
+	assert!(proposal.cancelled());
+	assert_eq!(proposal.cancel_vote_count(), 2);
+}
 
+#[tokio::test]
+async fn cancel_proposal_voting_twice() {
+	let (mut scenario, proposal_id) = proposed().await;
+	scenario.cancel("signer_1", proposal_id).await.unwrap();
+
+	let result = scenario.cancel("signer_1", proposal_id).await;
+
+	assert_multisig_wallet_error(result, MultisigWalletError::AlreadyVotedToCancel);
+	assert_eq!(scenario.proposal_state(proposal_id).await.cancel_vote_count(), 1);
+}
+
+#[tokio::test]
+async fn cancel_proposal_as_non_signer() {
+	let (mut scenario, proposal_id) = proposed().await;
+	scenario.add_multisig(&["outsider"], 1).await;
+
+	let result = scenario.cancel("outsider", proposal_id).await;
+
+	assert_multisig_wallet_error(result, MultisigWalletError::NotASigner);
+	assert!(!scenario.proposal_state(proposal_id).await.cancelled());
+}
+
+#[tokio::test]
+async fn cancel_proposal_defaulting_to_approval_threshold() {
+	let mut scenario = MultisigFixture::new()
+		.signers(3)
+		.threshold(3)
+		.start()
+		.await;
+	let proposal_id = scenario.propose_transfer("signer_0", Pubkey::new_unique(), Pubkey::new_unique(), 400).await.unwrap();
+	scenario.approve("signer_0", proposal_id).await.unwrap();
+
+	scenario.cancel("signer_1", proposal_id).await.unwrap();
+	scenario.cancel("signer_2", proposal_id).await.unwrap();
+	assert!(!scenario.proposal_state(proposal_id).await.cancelled());
+
+	scenario.cancel("signer_0", proposal_id).await.unwrap();
+	assert!(scenario.proposal_state(proposal_id).await.cancelled());
 }
@@ -0,0 +1,124 @@
+pub mod common;
+
+use {
+    common::{
+		error_assertions::assert_multisig_wallet_error,
+		multisig_fixture::{MultisigFixture, MultisigScenario},
+	},
+    solana_program_test::tokio,
+    solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer},
+};
+use multisig_wallet::{error::MultisigWalletError, VestingStream, VestingStreamTerms};
+
+const VAULT_BALANCE: u64 = 5_000;
+const TOTAL_AMOUNT: u64 = 1_000;
+const DURATION: i64 = 1_000;
+
+/// A 1-of-1 multisig with a stream of `TOTAL_AMOUNT` vesting linearly over `DURATION` seconds from
+/// now to `recipient`, returning the scenario, stream id and mint
+async fn streaming(recipient: Pubkey) -> (MultisigScenario, u64, Pubkey) {
+	let mint = Pubkey::new_unique();
+	let mut scenario = MultisigFixture::new()
+		.funded_vault(mint, VAULT_BALANCE)
+		.start()
+		.await;
+
+	let now = scenario.now().await;
+	let stream_id = scenario.open_vesting_stream(VestingStreamTerms {
+		recipient,
+		mint,
+		total_amount: TOTAL_AMOUNT,
+		start_time: now,
+		cliff_time: now,
+		end_time: now + DURATION,
+	}).await.unwrap();
+
+	(scenario, stream_id, mint)
+}
+
+/// Proposes and approves cancelling `stream_id`, returning the proposal id
+async fn approve_cancellation(scenario: &mut MultisigScenario, stream_id: u64) -> u64 {
+	let proposal_id = scenario.propose_cancel_stream("signer_0", stream_id).await.unwrap();
+	scenario.approve("signer_0", proposal_id).await.unwrap();
+
+	proposal_id
+}
+
+
+#[tokio::test]
+async fn cancel_stream_ix_success() {
+	let recipient = Keypair::new();
+	let (mut scenario, stream_id, mint) = streaming(recipient.pubkey()).await;
+	let proposal_id = approve_cancellation(&mut scenario, stream_id).await;
+	scenario.warp(DURATION / 4).await;
+
+	// INSTRUCTIONS
+	let result = scenario.cancel_stream(proposal_id, stream_id, mint).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+	assert!(scenario.proposal_state(proposal_id).await.executed());
+
+	// The unvested three quarters go back to the vault, the vested quarter stays in escrow
+	let vault_token_account = scenario.vault_token_account(&mint);
+	assert_eq!(scenario.token_balance(vault_token_account).await, VAULT_BALANCE - TOTAL_AMOUNT / 4);
+	let escrow = scenario.stream_escrow(stream_id, &mint);
+	assert_eq!(scenario.token_balance(escrow).await, TOTAL_AMOUNT / 4);
+
+	let stream: VestingStream = scenario.account_state(scenario.vesting_stream(stream_id)).await;
+	assert!(stream.cancelled);
+	assert_eq!(stream.total_amount, TOTAL_AMOUNT / 4);
+
+	// What had vested stays withdrawable, and nothing more vests
+	scenario.warp(DURATION).await;
+	let destination = scenario.create_token_account(recipient.pubkey(), mint);
+	scenario.withdraw_vested(&recipient, stream_id, mint, destination).await.unwrap();
+	assert_eq!(scenario.token_balance(destination).await, TOTAL_AMOUNT / 4);
+}
+
+#[tokio::test]
+async fn cancel_stream_of_other_stream() {
+	let (mut scenario, stream_id, mint) = streaming(Pubkey::new_unique()).await;
+	let now = scenario.now().await;
+	let other_stream_id = scenario.open_vesting_stream(VestingStreamTerms {
+		recipient: Pubkey::new_unique(),
+		mint,
+		total_amount: TOTAL_AMOUNT,
+		start_time: now,
+		cliff_time: now,
+		end_time: now + DURATION,
+	}).await.unwrap();
+	let proposal_id = approve_cancellation(&mut scenario, stream_id).await;
+
+	let result = scenario.cancel_stream(proposal_id, other_stream_id, mint).await;
+
+	assert_multisig_wallet_error(result, MultisigWalletError::AccountMismatch);
+	let other_stream: VestingStream = scenario.account_state(scenario.vesting_stream(other_stream_id)).await;
+	assert!(!other_stream.cancelled);
+}
+
+#[tokio::test]
+async fn cancel_stream_without_threshold() {
+	let (mut scenario, stream_id, mint) = streaming(Pubkey::new_unique()).await;
+	let proposal_id = scenario.propose_cancel_stream("signer_0", stream_id).await.unwrap();
+
+	let result = scenario.cancel_stream(proposal_id, stream_id, mint).await;
+
+	assert_multisig_wallet_error(result, MultisigWalletError::InsufficientApprovals);
+	let stream: VestingStream = scenario.account_state(scenario.vesting_stream(stream_id)).await;
+	assert!(!stream.cancelled);
+}
+
+#[tokio::test]
+async fn cancel_stream_after_full_vesting() {
+	let (mut scenario, stream_id, mint) = streaming(Pubkey::new_unique()).await;
+	let proposal_id = approve_cancellation(&mut scenario, stream_id).await;
+	scenario.warp(DURATION).await;
+
+	let result = scenario.cancel_stream(proposal_id, stream_id, mint).await;
+
+	// Everything has vested, so nothing goes back to the vault
+	assert!(result.is_ok());
+	let vault_token_account = scenario.vault_token_account(&mint);
+	assert_eq!(scenario.token_balance(vault_token_account).await, VAULT_BALANCE - TOTAL_AMOUNT);
+}
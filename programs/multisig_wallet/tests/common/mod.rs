@@ -50,6 +50,14 @@ pub mod multisig_wallet_ix_interface {
 			ID as PROGRAM_ID,
 			accounts as multisig_wallet_accounts,
 			instruction as multisig_wallet_instruction,
+			AddressBookChange,
+			PaymentScheduleTerms,
+			ProgramUpgradeChange,
+			ProposalMetadata,
+			StakeChange,
+			TransferPolicyChange,
+			TransferTerms,
+			VestingStreamTerms,
 		},
 		anchor_lang::{
 			prelude::*,
@@ -59,14 +67,19 @@ pub mod multisig_wallet_ix_interface {
 
 	pub fn create_multisig_ix_setup(
 		fee_payer: &Keypair,
+		create_key: &Keypair,
 		multisig: Pubkey,
 		system_program: Pubkey,
 		signers: Vec<Pubkey>,
 		threshold: u8,
+		max_proposal_lifetime: i64,
+		guardian: Option<Pubkey>,
+		cancel_threshold: u8,
 		recent_blockhash: Hash,
 	) -> Transaction {
 		let accounts = multisig_wallet_accounts::CreateMultisig {
 			fee_payer: fee_payer.pubkey(),
+			create_key: create_key.pubkey(),
 			multisig: multisig,
 			system_program: system_program,
 		};
@@ -74,6 +87,9 @@ pub mod multisig_wallet_ix_interface {
 		let data = 	multisig_wallet_instruction::CreateMultisig {
 				signers,
 				threshold,
+				max_proposal_lifetime,
+				guardian,
+				cancel_threshold,
 		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
 		let mut transaction = Transaction::new_with_payer(
 			&[instruction], 
@@ -82,6 +98,7 @@ pub mod multisig_wallet_ix_interface {
 
 		transaction.sign(&[
 			&fee_payer,
+			&create_key,
 		], recent_blockhash);
 
 		return transaction;
@@ -92,11 +109,12 @@ pub mod multisig_wallet_ix_interface {
 		multisig: Pubkey,
 		proposal: Pubkey,
 		creator: &Keypair,
+		address_book: Option<Pubkey>,
 		system_program: Pubkey,
-		destination: Pubkey,
-		amount: u64,
-		token_mint: Pubkey,
+		terms: TransferTerms,
+		vault_index: u8,
 		expiration_time: i64,
+		metadata: ProposalMetadata,
 		proposal_seed_proposal_id: u64,
 		recent_blockhash: Hash,
 	) -> Transaction {
@@ -105,15 +123,16 @@ pub mod multisig_wallet_ix_interface {
 			multisig: multisig,
 			proposal: proposal,
 			creator: creator.pubkey(),
+			address_book: address_book,
 			system_program: system_program,
 		};
 
 		let data = 	multisig_wallet_instruction::CreateProposal {
-				destination,
-				amount,
-				token_mint,
+				terms,
+				vault_index,
 				expiration_time,
-				_proposal_seed_proposal_id: proposal_seed_proposal_id,
+				metadata,
+				proposal_seed_proposal_id,
 		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
 		let mut transaction = Transaction::new_with_payer(
 			&[instruction], 
@@ -159,21 +178,52 @@ pub mod multisig_wallet_ix_interface {
 		return transaction;
 	}
 
+	pub fn unapprove_proposal_ix_setup(
+		fee_payer: &Keypair,
+		multisig: Pubkey,
+		proposal: Pubkey,
+		signer: &Keypair,
+		proposal_seed_proposal_id: u64,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = multisig_wallet_accounts::UnapproveProposal {
+			fee_payer: fee_payer.pubkey(),
+			multisig: multisig,
+			proposal: proposal,
+			signer: signer.pubkey(),
+		};
+
+		let data = 	multisig_wallet_instruction::UnapproveProposal {
+				_proposal_seed_proposal_id: proposal_seed_proposal_id,
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+			&signer,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
 	pub fn execute_proposal_ix_setup(
 		fee_payer: &Keypair,
 		multisig: Pubkey,
 		proposal: Pubkey,
+		vault: Pubkey,
 		source: Pubkey,
 		destination: Pubkey,
-		authority: &Keypair,
 		funding: &Keypair,
-		assoc_token_account: Pubkey,
 		wallet: Pubkey,
 		mint: Pubkey,
 		system_program: Pubkey,
 		token_program: Pubkey,
-		csl_spl_token_v0_0_0: Pubkey,
-		csl_spl_assoc_token_v0_0_0: Pubkey,
+		associated_token_program: Pubkey,
+		memo_program: Pubkey,
+		transfer_policy: Option<Pubkey>,
 		proposal_seed_proposal_id: u64,
 		recent_blockhash: Hash,
 	) -> Transaction {
@@ -181,17 +231,17 @@ pub mod multisig_wallet_ix_interface {
 			fee_payer: fee_payer.pubkey(),
 			multisig: multisig,
 			proposal: proposal,
+			vault: vault,
 			source: source,
 			destination: destination,
-			authority: authority.pubkey(),
 			funding: funding.pubkey(),
-			assoc_token_account: assoc_token_account,
 			wallet: wallet,
 			mint: mint,
 			system_program: system_program,
 			token_program: token_program,
-			csl_spl_token_v0_0_0: csl_spl_token_v0_0_0,
-			csl_spl_assoc_token_v0_0_0: csl_spl_assoc_token_v0_0_0,
+			associated_token_program: associated_token_program,
+			memo_program: memo_program,
+			transfer_policy: transfer_policy,
 		};
 
 		let data = 	multisig_wallet_instruction::ExecuteProposal {
@@ -204,7 +254,6 @@ pub mod multisig_wallet_ix_interface {
 
 		transaction.sign(&[
 			&fee_payer,
-			&authority,
 			&funding,
 		], recent_blockhash);
 
@@ -215,7 +264,7 @@ pub mod multisig_wallet_ix_interface {
 		fee_payer: &Keypair,
 		multisig: Pubkey,
 		proposal: Pubkey,
-		creator: &Keypair,
+		signer: &Keypair,
 		proposal_seed_proposal_id: u64,
 		recent_blockhash: Hash,
 	) -> Transaction {
@@ -223,7 +272,7 @@ pub mod multisig_wallet_ix_interface {
 			fee_payer: fee_payer.pubkey(),
 			multisig: multisig,
 			proposal: proposal,
-			creator: creator.pubkey(),
+			signer: signer.pubkey(),
 		};
 
 		let data = 	multisig_wallet_instruction::CancelProposal {
@@ -234,6 +283,67 @@ pub mod multisig_wallet_ix_interface {
 			Some(&fee_payer.pubkey()),
 		);
 
+		transaction.sign(&[
+			&fee_payer,
+			&signer,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn expire_proposal_ix_setup(
+		fee_payer: &Keypair,
+		multisig: Pubkey,
+		proposal: Pubkey,
+		rent_receiver: Pubkey,
+		proposal_seed_proposal_id: u64,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = multisig_wallet_accounts::ExpireProposal {
+			fee_payer: fee_payer.pubkey(),
+			multisig: multisig,
+			proposal: proposal,
+			rent_receiver: rent_receiver,
+		};
+
+		let data = 	multisig_wallet_instruction::ExpireProposal {
+				_proposal_seed_proposal_id: proposal_seed_proposal_id,
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn create_address_book_ix_setup(
+		fee_payer: &Keypair,
+		multisig: Pubkey,
+		address_book: Pubkey,
+		creator: &Keypair,
+		system_program: Pubkey,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = multisig_wallet_accounts::CreateAddressBook {
+			fee_payer: fee_payer.pubkey(),
+			multisig: multisig,
+			address_book: address_book,
+			creator: creator.pubkey(),
+			system_program: system_program,
+		};
+
+		let data = 	multisig_wallet_instruction::CreateAddressBook {
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
 		transaction.sign(&[
 			&fee_payer,
 			&creator,
@@ -242,23 +352,29 @@ pub mod multisig_wallet_ix_interface {
 		return transaction;
 	}
 
-	pub fn update_multisig_ix_setup(
+	pub fn create_address_book_proposal_ix_setup(
 		fee_payer: &Keypair,
 		multisig: Pubkey,
-		new_signers: Vec<Pubkey>,
-		new_threshold: u8,
-		all_current_signers_approved: bool,
+		proposal: Pubkey,
+		creator: &Keypair,
+		system_program: Pubkey,
+		change: AddressBookChange,
+		expiration_time: i64,
+		proposal_seed_proposal_id: u64,
 		recent_blockhash: Hash,
 	) -> Transaction {
-		let accounts = multisig_wallet_accounts::UpdateMultisig {
+		let accounts = multisig_wallet_accounts::CreateAddressBookProposal {
 			fee_payer: fee_payer.pubkey(),
 			multisig: multisig,
+			proposal: proposal,
+			creator: creator.pubkey(),
+			system_program: system_program,
 		};
 
-		let data = 	multisig_wallet_instruction::UpdateMultisig {
-				new_signers,
-				new_threshold,
-				all_current_signers_approved,
+		let data = 	multisig_wallet_instruction::CreateAddressBookProposal {
+				change,
+				expiration_time,
+				proposal_seed_proposal_id,
 		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
 		let mut transaction = Transaction::new_with_payer(
 			&[instruction], 
@@ -267,59 +383,2634 @@ pub mod multisig_wallet_ix_interface {
 
 		transaction.sign(&[
 			&fee_payer,
+			&creator,
 		], recent_blockhash);
 
 		return transaction;
 	}
 
-}
+	pub fn execute_address_book_proposal_ix_setup(
+		fee_payer: &Keypair,
+		multisig: Pubkey,
+		proposal: Pubkey,
+		address_book: Pubkey,
+		proposal_seed_proposal_id: u64,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = multisig_wallet_accounts::ExecuteAddressBookProposal {
+			fee_payer: fee_payer.pubkey(),
+			multisig: multisig,
+			proposal: proposal,
+			address_book: address_book,
+		};
 
-pub mod csl_spl_token_ix_interface {
+		let data = 	multisig_wallet_instruction::ExecuteAddressBookProposal {
+				_proposal_seed_proposal_id: proposal_seed_proposal_id,
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
 
-	use {
-		solana_sdk::{
-			hash::Hash,
-			signature::{Keypair, Signer},
-			instruction::Instruction,
-			pubkey::Pubkey,
-			transaction::Transaction,
-		},
-		csl_spl_token::{
-			ID as PROGRAM_ID,
-			accounts as csl_spl_token_accounts,
-			instruction as csl_spl_token_instruction,
-		},
-		anchor_lang::{
-			prelude::*,
-			InstructionData,
-		}
-	};
+		transaction.sign(&[
+			&fee_payer,
+		], recent_blockhash);
 
-	declare_id!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+		return transaction;
+	}
 
-}
+	pub fn create_transfer_policy_ix_setup(
+		fee_payer: &Keypair,
+		multisig: Pubkey,
+		transfer_policy: Pubkey,
+		creator: &Keypair,
+		system_program: Pubkey,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = multisig_wallet_accounts::CreateTransferPolicy {
+			fee_payer: fee_payer.pubkey(),
+			multisig: multisig,
+			transfer_policy: transfer_policy,
+			creator: creator.pubkey(),
+			system_program: system_program,
+		};
 
-pub mod csl_spl_assoc_token_ix_interface {
+		let data = 	multisig_wallet_instruction::CreateTransferPolicy {
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
 
-	use {
-		solana_sdk::{
-			hash::Hash,
-			signature::{Keypair, Signer},
-			instruction::Instruction,
-			pubkey::Pubkey,
-			transaction::Transaction,
-		},
-		csl_spl_assoc_token::{
-			ID as PROGRAM_ID,
-			accounts as csl_spl_assoc_token_accounts,
-			instruction as csl_spl_assoc_token_instruction,
-		},
-		anchor_lang::{
-			prelude::*,
-			InstructionData,
-		}
-	};
+		transaction.sign(&[
+			&fee_payer,
+			&creator,
+		], recent_blockhash);
 
-	declare_id!("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL");
+		return transaction;
+	}
+
+	pub fn create_transfer_policy_proposal_ix_setup(
+		fee_payer: &Keypair,
+		multisig: Pubkey,
+		proposal: Pubkey,
+		creator: &Keypair,
+		system_program: Pubkey,
+		change: TransferPolicyChange,
+		expiration_time: i64,
+		proposal_seed_proposal_id: u64,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = multisig_wallet_accounts::CreateTransferPolicyProposal {
+			fee_payer: fee_payer.pubkey(),
+			multisig: multisig,
+			proposal: proposal,
+			creator: creator.pubkey(),
+			system_program: system_program,
+		};
+
+		let data = 	multisig_wallet_instruction::CreateTransferPolicyProposal {
+				change,
+				expiration_time,
+				proposal_seed_proposal_id,
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+			&creator,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn execute_transfer_policy_proposal_ix_setup(
+		fee_payer: &Keypair,
+		multisig: Pubkey,
+		proposal: Pubkey,
+		transfer_policy: Pubkey,
+		proposal_seed_proposal_id: u64,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = multisig_wallet_accounts::ExecuteTransferPolicyProposal {
+			fee_payer: fee_payer.pubkey(),
+			multisig: multisig,
+			proposal: proposal,
+			transfer_policy: transfer_policy,
+		};
+
+		let data = 	multisig_wallet_instruction::ExecuteTransferPolicyProposal {
+				_proposal_seed_proposal_id: proposal_seed_proposal_id,
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn freeze_multisig_ix_setup(
+		fee_payer: &Keypair,
+		multisig: Pubkey,
+		guardian: &Keypair,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = multisig_wallet_accounts::FreezeMultisig {
+			fee_payer: fee_payer.pubkey(),
+			multisig: multisig,
+			guardian: guardian.pubkey(),
+		};
 
+		let data = 	multisig_wallet_instruction::FreezeMultisig {
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+			&guardian,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn unfreeze_multisig_ix_setup(
+		fee_payer: &Keypair,
+		multisig: Pubkey,
+		signers: &[&Keypair],
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = multisig_wallet_accounts::UnfreezeMultisig {
+			fee_payer: fee_payer.pubkey(),
+			multisig: multisig,
+		};
+
+		let mut account_metas = accounts.to_account_metas(None);
+		account_metas.extend(signers.iter().map(|signer| AccountMeta::new_readonly(signer.pubkey(), true)));
+
+		let data = 	multisig_wallet_instruction::UnfreezeMultisig {
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), account_metas);
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		let mut keypairs: Vec<&Keypair> = vec![fee_payer];
+		keypairs.extend(signers);
+		transaction.sign(&keypairs, recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn configure_recovery_ix_setup(
+		fee_payer: &Keypair,
+		multisig: Pubkey,
+		signers: &[&Keypair],
+		recovery_keys: Vec<Pubkey>,
+		recovery_threshold: u8,
+		recovery_delay: i64,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = multisig_wallet_accounts::ConfigureRecovery {
+			fee_payer: fee_payer.pubkey(),
+			multisig: multisig,
+		};
+
+		let mut account_metas = accounts.to_account_metas(None);
+		account_metas.extend(signers.iter().map(|signer| AccountMeta::new_readonly(signer.pubkey(), true)));
+
+		let data = 	multisig_wallet_instruction::ConfigureRecovery {
+				recovery_keys,
+				recovery_threshold,
+				recovery_delay,
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), account_metas);
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		let mut keypairs: Vec<&Keypair> = vec![fee_payer];
+		keypairs.extend(signers);
+		transaction.sign(&keypairs, recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn create_recovery_request_ix_setup(
+		fee_payer: &Keypair,
+		multisig: Pubkey,
+		recovery_request: Pubkey,
+		recovery_key: &Keypair,
+		system_program: Pubkey,
+		lost_signer: Pubkey,
+		new_signer: Pubkey,
+		recovery_request_seed_recovery_id: u64,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = multisig_wallet_accounts::CreateRecoveryRequest {
+			fee_payer: fee_payer.pubkey(),
+			multisig: multisig,
+			recovery_request: recovery_request,
+			recovery_key: recovery_key.pubkey(),
+			system_program: system_program,
+		};
+
+		let data = 	multisig_wallet_instruction::CreateRecoveryRequest {
+				lost_signer,
+				new_signer,
+				_recovery_request_seed_recovery_id: recovery_request_seed_recovery_id,
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+			&recovery_key,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn approve_recovery_request_ix_setup(
+		fee_payer: &Keypair,
+		multisig: Pubkey,
+		recovery_request: Pubkey,
+		recovery_key: &Keypair,
+		recovery_request_seed_recovery_id: u64,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = multisig_wallet_accounts::ApproveRecoveryRequest {
+			fee_payer: fee_payer.pubkey(),
+			multisig: multisig,
+			recovery_request: recovery_request,
+			recovery_key: recovery_key.pubkey(),
+		};
+
+		let data = 	multisig_wallet_instruction::ApproveRecoveryRequest {
+				_recovery_request_seed_recovery_id: recovery_request_seed_recovery_id,
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+			&recovery_key,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn veto_recovery_request_ix_setup(
+		fee_payer: &Keypair,
+		multisig: Pubkey,
+		recovery_request: Pubkey,
+		signer: &Keypair,
+		recovery_request_seed_recovery_id: u64,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = multisig_wallet_accounts::VetoRecoveryRequest {
+			fee_payer: fee_payer.pubkey(),
+			multisig: multisig,
+			recovery_request: recovery_request,
+			signer: signer.pubkey(),
+		};
+
+		let data = 	multisig_wallet_instruction::VetoRecoveryRequest {
+				_recovery_request_seed_recovery_id: recovery_request_seed_recovery_id,
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+			&signer,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn execute_recovery_request_ix_setup(
+		fee_payer: &Keypair,
+		multisig: Pubkey,
+		recovery_request: Pubkey,
+		recovery_request_seed_recovery_id: u64,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = multisig_wallet_accounts::ExecuteRecoveryRequest {
+			fee_payer: fee_payer.pubkey(),
+			multisig: multisig,
+			recovery_request: recovery_request,
+		};
+
+		let data = 	multisig_wallet_instruction::ExecuteRecoveryRequest {
+				_recovery_request_seed_recovery_id: recovery_request_seed_recovery_id,
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn create_program_upgrade_proposal_ix_setup(
+		fee_payer: &Keypair,
+		multisig: Pubkey,
+		proposal: Pubkey,
+		creator: &Keypair,
+		vault: Pubkey,
+		program_data: Pubkey,
+		buffer: Option<Pubkey>,
+		system_program: Pubkey,
+		change: ProgramUpgradeChange,
+		vault_index: u8,
+		expiration_time: i64,
+		proposal_seed_proposal_id: u64,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = multisig_wallet_accounts::CreateProgramUpgradeProposal {
+			fee_payer: fee_payer.pubkey(),
+			multisig: multisig,
+			proposal: proposal,
+			creator: creator.pubkey(),
+			vault: vault,
+			program_data: program_data,
+			buffer: buffer,
+			system_program: system_program,
+		};
+
+		let data = 	multisig_wallet_instruction::CreateProgramUpgradeProposal {
+				change,
+				vault_index,
+				expiration_time,
+				proposal_seed_proposal_id,
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+			&creator,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn execute_program_upgrade_proposal_ix_setup(
+		fee_payer: &Keypair,
+		multisig: Pubkey,
+		proposal: Pubkey,
+		vault: Pubkey,
+		program: Pubkey,
+		program_data: Pubkey,
+		buffer: Option<Pubkey>,
+		spill: Option<Pubkey>,
+		new_authority: Option<Pubkey>,
+		rent: Pubkey,
+		clock: Pubkey,
+		bpf_loader_upgradeable: Pubkey,
+		proposal_seed_proposal_id: u64,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = multisig_wallet_accounts::ExecuteProgramUpgradeProposal {
+			fee_payer: fee_payer.pubkey(),
+			multisig: multisig,
+			proposal: proposal,
+			vault: vault,
+			program: program,
+			program_data: program_data,
+			buffer: buffer,
+			spill: spill,
+			new_authority: new_authority,
+			rent: rent,
+			clock: clock,
+			bpf_loader_upgradeable: bpf_loader_upgradeable,
+		};
+
+		let data = 	multisig_wallet_instruction::ExecuteProgramUpgradeProposal {
+				_proposal_seed_proposal_id: proposal_seed_proposal_id,
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn create_stake_proposal_ix_setup(
+		fee_payer: &Keypair,
+		multisig: Pubkey,
+		proposal: Pubkey,
+		creator: &Keypair,
+		address_book: Option<Pubkey>,
+		system_program: Pubkey,
+		change: StakeChange,
+		vault_index: u8,
+		expiration_time: i64,
+		proposal_seed_proposal_id: u64,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = multisig_wallet_accounts::CreateStakeProposal {
+			fee_payer: fee_payer.pubkey(),
+			multisig: multisig,
+			proposal: proposal,
+			creator: creator.pubkey(),
+			address_book: address_book,
+			system_program: system_program,
+		};
+
+		let data = 	multisig_wallet_instruction::CreateStakeProposal {
+				change,
+				vault_index,
+				expiration_time,
+				proposal_seed_proposal_id,
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+			&creator,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn execute_stake_proposal_ix_setup(
+		fee_payer: &Keypair,
+		multisig: Pubkey,
+		proposal: Pubkey,
+		vault: Pubkey,
+		stake_account: Pubkey,
+		vote_account: Option<Pubkey>,
+		destination: Option<Pubkey>,
+		rent: Pubkey,
+		clock: Pubkey,
+		stake_history: Pubkey,
+		stake_config: Pubkey,
+		system_program: Pubkey,
+		stake_program: Pubkey,
+		proposal_seed_proposal_id: u64,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = multisig_wallet_accounts::ExecuteStakeProposal {
+			fee_payer: fee_payer.pubkey(),
+			multisig: multisig,
+			proposal: proposal,
+			vault: vault,
+			stake_account: stake_account,
+			vote_account: vote_account,
+			destination: destination,
+			rent: rent,
+			clock: clock,
+			stake_history: stake_history,
+			stake_config: stake_config,
+			system_program: system_program,
+			stake_program: stake_program,
+		};
+
+		let data = 	multisig_wallet_instruction::ExecuteStakeProposal {
+				proposal_seed_proposal_id,
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn create_payment_schedule_proposal_ix_setup(
+		fee_payer: &Keypair,
+		multisig: Pubkey,
+		proposal: Pubkey,
+		creator: &Keypair,
+		address_book: Option<Pubkey>,
+		system_program: Pubkey,
+		terms: PaymentScheduleTerms,
+		vault_index: u8,
+		expiration_time: i64,
+		proposal_seed_proposal_id: u64,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = multisig_wallet_accounts::CreatePaymentScheduleProposal {
+			fee_payer: fee_payer.pubkey(),
+			multisig: multisig,
+			proposal: proposal,
+			creator: creator.pubkey(),
+			address_book: address_book,
+			system_program: system_program,
+		};
+
+		let data = 	multisig_wallet_instruction::CreatePaymentScheduleProposal {
+				terms,
+				vault_index,
+				expiration_time,
+				proposal_seed_proposal_id,
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+			&creator,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn execute_payment_schedule_proposal_ix_setup(
+		fee_payer: &Keypair,
+		multisig: Pubkey,
+		proposal: Pubkey,
+		payment_schedule: Pubkey,
+		system_program: Pubkey,
+		proposal_seed_proposal_id: u64,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = multisig_wallet_accounts::ExecutePaymentScheduleProposal {
+			fee_payer: fee_payer.pubkey(),
+			multisig: multisig,
+			proposal: proposal,
+			payment_schedule: payment_schedule,
+			system_program: system_program,
+		};
+
+		let data = 	multisig_wallet_instruction::ExecutePaymentScheduleProposal {
+				proposal_seed_proposal_id,
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn crank_schedule_ix_setup(
+		fee_payer: &Keypair,
+		multisig: Pubkey,
+		payment_schedule: Pubkey,
+		vault: Pubkey,
+		source: Pubkey,
+		destination: Pubkey,
+		mint: Pubkey,
+		token_program: Pubkey,
+		transfer_policy: Option<Pubkey>,
+		payment_schedule_seed_schedule_id: u64,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = multisig_wallet_accounts::CrankSchedule {
+			fee_payer: fee_payer.pubkey(),
+			multisig: multisig,
+			payment_schedule: payment_schedule,
+			vault: vault,
+			source: source,
+			destination: destination,
+			mint: mint,
+			token_program: token_program,
+			transfer_policy: transfer_policy,
+		};
+
+		let data = 	multisig_wallet_instruction::CrankSchedule {
+				_payment_schedule_seed_schedule_id: payment_schedule_seed_schedule_id,
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn create_vesting_stream_proposal_ix_setup(
+		fee_payer: &Keypair,
+		multisig: Pubkey,
+		proposal: Pubkey,
+		creator: &Keypair,
+		address_book: Option<Pubkey>,
+		system_program: Pubkey,
+		terms: VestingStreamTerms,
+		vault_index: u8,
+		expiration_time: i64,
+		proposal_seed_proposal_id: u64,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = multisig_wallet_accounts::CreateVestingStreamProposal {
+			fee_payer: fee_payer.pubkey(),
+			multisig: multisig,
+			proposal: proposal,
+			creator: creator.pubkey(),
+			address_book: address_book,
+			system_program: system_program,
+		};
+
+		let data = 	multisig_wallet_instruction::CreateVestingStreamProposal {
+				terms,
+				vault_index,
+				expiration_time,
+				proposal_seed_proposal_id,
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+			&creator,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn execute_vesting_stream_proposal_ix_setup(
+		fee_payer: &Keypair,
+		multisig: Pubkey,
+		proposal: Pubkey,
+		vault: Pubkey,
+		vesting_stream: Pubkey,
+		source: Pubkey,
+		escrow: Pubkey,
+		mint: Pubkey,
+		system_program: Pubkey,
+		token_program: Pubkey,
+		associated_token_program: Pubkey,
+		transfer_policy: Option<Pubkey>,
+		proposal_seed_proposal_id: u64,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = multisig_wallet_accounts::ExecuteVestingStreamProposal {
+			fee_payer: fee_payer.pubkey(),
+			multisig: multisig,
+			proposal: proposal,
+			vault: vault,
+			vesting_stream: vesting_stream,
+			source: source,
+			escrow: escrow,
+			mint: mint,
+			system_program: system_program,
+			token_program: token_program,
+			associated_token_program: associated_token_program,
+			transfer_policy: transfer_policy,
+		};
+
+		let data = 	multisig_wallet_instruction::ExecuteVestingStreamProposal {
+				proposal_seed_proposal_id,
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn withdraw_vested_ix_setup(
+		fee_payer: &Keypair,
+		multisig: Pubkey,
+		vesting_stream: Pubkey,
+		recipient: &Keypair,
+		escrow: Pubkey,
+		destination: Pubkey,
+		mint: Pubkey,
+		token_program: Pubkey,
+		vesting_stream_seed_stream_id: u64,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = multisig_wallet_accounts::WithdrawVested {
+			fee_payer: fee_payer.pubkey(),
+			multisig: multisig,
+			vesting_stream: vesting_stream,
+			recipient: recipient.pubkey(),
+			escrow: escrow,
+			destination: destination,
+			mint: mint,
+			token_program: token_program,
+		};
+
+		let data = 	multisig_wallet_instruction::WithdrawVested {
+				vesting_stream_seed_stream_id,
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+			&recipient,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn create_cancel_stream_proposal_ix_setup(
+		fee_payer: &Keypair,
+		multisig: Pubkey,
+		proposal: Pubkey,
+		creator: &Keypair,
+		vesting_stream: Pubkey,
+		system_program: Pubkey,
+		expiration_time: i64,
+		proposal_seed_proposal_id: u64,
+		vesting_stream_seed_stream_id: u64,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = multisig_wallet_accounts::CreateCancelStreamProposal {
+			fee_payer: fee_payer.pubkey(),
+			multisig: multisig,
+			proposal: proposal,
+			creator: creator.pubkey(),
+			vesting_stream: vesting_stream,
+			system_program: system_program,
+		};
+
+		let data = 	multisig_wallet_instruction::CreateCancelStreamProposal {
+				expiration_time,
+				proposal_seed_proposal_id,
+				_vesting_stream_seed_stream_id: vesting_stream_seed_stream_id,
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+			&creator,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn cancel_stream_ix_setup(
+		fee_payer: &Keypair,
+		multisig: Pubkey,
+		proposal: Pubkey,
+		vesting_stream: Pubkey,
+		vault: Pubkey,
+		escrow: Pubkey,
+		vault_token_account: Pubkey,
+		mint: Pubkey,
+		token_program: Pubkey,
+		proposal_seed_proposal_id: u64,
+		vesting_stream_seed_stream_id: u64,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = multisig_wallet_accounts::CancelStream {
+			fee_payer: fee_payer.pubkey(),
+			multisig: multisig,
+			proposal: proposal,
+			vesting_stream: vesting_stream,
+			vault: vault,
+			escrow: escrow,
+			vault_token_account: vault_token_account,
+			mint: mint,
+			token_program: token_program,
+		};
+
+		let data = 	multisig_wallet_instruction::CancelStream {
+				_proposal_seed_proposal_id: proposal_seed_proposal_id,
+				vesting_stream_seed_stream_id,
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn set_vault_threshold_ix_setup(
+		fee_payer: &Keypair,
+		multisig: Pubkey,
+		signers: &[&Keypair],
+		vault_index: u8,
+		threshold: Option<u8>,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = multisig_wallet_accounts::SetVaultThreshold {
+			fee_payer: fee_payer.pubkey(),
+			multisig: multisig,
+		};
+
+		let mut account_metas = accounts.to_account_metas(None);
+		account_metas.extend(signers.iter().map(|signer| AccountMeta::new_readonly(signer.pubkey(), true)));
+
+		let data = 	multisig_wallet_instruction::SetVaultThreshold {
+				vault_index,
+				threshold,
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), account_metas);
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		let mut keypairs: Vec<&Keypair> = vec![fee_payer];
+		keypairs.extend(signers);
+		transaction.sign(&keypairs, recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn create_nested_approval_proposal_ix_setup(
+		fee_payer: &Keypair,
+		multisig: Pubkey,
+		proposal: Pubkey,
+		creator: &Keypair,
+		vault: Pubkey,
+		parent_multisig: Pubkey,
+		system_program: Pubkey,
+		parent_proposal_id: u64,
+		vault_index: u8,
+		expiration_time: i64,
+		proposal_seed_proposal_id: u64,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = multisig_wallet_accounts::CreateNestedApprovalProposal {
+			fee_payer: fee_payer.pubkey(),
+			multisig: multisig,
+			proposal: proposal,
+			creator: creator.pubkey(),
+			vault: vault,
+			parent_multisig: parent_multisig,
+			system_program: system_program,
+		};
+
+		let data = 	multisig_wallet_instruction::CreateNestedApprovalProposal {
+				parent_proposal_id,
+				vault_index,
+				expiration_time,
+				proposal_seed_proposal_id,
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+			&creator,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn execute_nested_approval_proposal_ix_setup(
+		fee_payer: &Keypair,
+		multisig: Pubkey,
+		proposal: Pubkey,
+		vault: Pubkey,
+		parent_multisig: Pubkey,
+		parent_proposal: Pubkey,
+		multisig_wallet_program: Pubkey,
+		proposal_seed_proposal_id: u64,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = multisig_wallet_accounts::ExecuteNestedApprovalProposal {
+			fee_payer: fee_payer.pubkey(),
+			multisig: multisig,
+			proposal: proposal,
+			vault: vault,
+			parent_multisig: parent_multisig,
+			parent_proposal: parent_proposal,
+			multisig_wallet_program: multisig_wallet_program,
+		};
+
+		let data = 	multisig_wallet_instruction::ExecuteNestedApprovalProposal {
+				_proposal_seed_proposal_id: proposal_seed_proposal_id,
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn configure_optimistic_ix_setup(
+		fee_payer: &Keypair,
+		multisig: Pubkey,
+		signers: &[&Keypair],
+		optimistic: bool,
+		veto_window: i64,
+		veto_threshold: u8,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = multisig_wallet_accounts::ConfigureOptimistic {
+			fee_payer: fee_payer.pubkey(),
+			multisig: multisig,
+		};
+
+		let mut account_metas = accounts.to_account_metas(None);
+		account_metas.extend(signers.iter().map(|signer| AccountMeta::new_readonly(signer.pubkey(), true)));
+
+		let data = 	multisig_wallet_instruction::ConfigureOptimistic {
+				optimistic,
+				veto_window,
+				veto_threshold,
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), account_metas);
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		let mut keypairs: Vec<&Keypair> = vec![fee_payer];
+		keypairs.extend(signers);
+		transaction.sign(&keypairs, recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn veto_proposal_ix_setup(
+		fee_payer: &Keypair,
+		multisig: Pubkey,
+		proposal: Pubkey,
+		signer: &Keypair,
+		proposal_seed_proposal_id: u64,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = multisig_wallet_accounts::VetoProposal {
+			fee_payer: fee_payer.pubkey(),
+			multisig: multisig,
+			proposal: proposal,
+			signer: signer.pubkey(),
+		};
+
+		let data = 	multisig_wallet_instruction::VetoProposal {
+				_proposal_seed_proposal_id: proposal_seed_proposal_id,
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+			&signer,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn migrate_multisig_ix_setup(
+		fee_payer: &Keypair,
+		multisig: Pubkey,
+		system_program: Pubkey,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = multisig_wallet_accounts::MigrateMultisig {
+			fee_payer: fee_payer.pubkey(),
+			multisig: multisig,
+			system_program: system_program,
+		};
+
+		let data = 	multisig_wallet_instruction::MigrateMultisig {
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn migrate_proposal_ix_setup(
+		fee_payer: &Keypair,
+		multisig: Pubkey,
+		proposal: Pubkey,
+		system_program: Pubkey,
+		proposal_seed_proposal_id: u64,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = multisig_wallet_accounts::MigrateProposal {
+			fee_payer: fee_payer.pubkey(),
+			multisig: multisig,
+			proposal: proposal,
+			system_program: system_program,
+		};
+
+		let data = 	multisig_wallet_instruction::MigrateProposal {
+				_proposal_seed_proposal_id: proposal_seed_proposal_id,
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+	pub fn update_multisig_ix_setup(
+		fee_payer: &Keypair,
+		multisig: Pubkey,
+		new_signers: Vec<Pubkey>,
+		new_threshold: u8,
+		all_current_signers_approved: bool,
+		recent_blockhash: Hash,
+	) -> Transaction {
+		let accounts = multisig_wallet_accounts::UpdateMultisig {
+			fee_payer: fee_payer.pubkey(),
+			multisig: multisig,
+		};
+
+		let data = 	multisig_wallet_instruction::UpdateMultisig {
+				new_signers,
+				new_threshold,
+				all_current_signers_approved,
+		};		let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data.data(), accounts.to_account_metas(None));
+		let mut transaction = Transaction::new_with_payer(
+			&[instruction], 
+			Some(&fee_payer.pubkey()),
+		);
+
+		transaction.sign(&[
+			&fee_payer,
+		], recent_blockhash);
+
+		return transaction;
+	}
+
+}
+
+pub mod csl_spl_token_ix_interface {
+
+	use {
+		solana_sdk::{
+			hash::Hash,
+			signature::{Keypair, Signer},
+			instruction::Instruction,
+			pubkey::Pubkey,
+			transaction::Transaction,
+		},
+		csl_spl_token::{
+			ID as PROGRAM_ID,
+			accounts as csl_spl_token_accounts,
+			instruction as csl_spl_token_instruction,
+		},
+		anchor_lang::{
+			prelude::*,
+			InstructionData,
+		}
+	};
+
+	declare_id!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+
+}
+
+pub mod csl_spl_assoc_token_ix_interface {
+
+	use {
+		solana_sdk::{
+			hash::Hash,
+			signature::{Keypair, Signer},
+			instruction::Instruction,
+			pubkey::Pubkey,
+			transaction::Transaction,
+		},
+		csl_spl_assoc_token::{
+			ID as PROGRAM_ID,
+			accounts as csl_spl_assoc_token_accounts,
+			instruction as csl_spl_assoc_token_instruction,
+		},
+		anchor_lang::{
+			prelude::*,
+			InstructionData,
+		}
+	};
+
+	declare_id!("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL");
+
+}
+
+pub mod legacy_fixtures {
+
+	use {
+		solana_sdk::pubkey::Pubkey,
+		multisig_wallet::{
+			MultisigConfig,
+			Proposal,
+		},
+		anchor_lang::Discriminator,
+	};
+
+	/// Address of the program's single legacy multisig, seeded by `b"multisig"` alone
+	pub fn legacy_multisig() -> Pubkey {
+		Pubkey::find_program_address(&[b"multisig"], &multisig_wallet::ID).0
+	}
+
+	/// Bytes of a multisig account written before accounts were versioned
+	pub fn legacy_multisig_config(signers: &[Pubkey], threshold: u8, proposal_count: u64) -> Vec<u8> {
+		let mut data = MultisigConfig::DISCRIMINATOR.to_vec();
+		data.extend_from_slice(&(signers.len() as u32).to_le_bytes());
+		signers.iter().for_each(|signer| data.extend_from_slice(signer.as_ref()));
+		data.push(threshold);
+		data.extend_from_slice(&proposal_count.to_le_bytes());
+		data.push(0); // nonce, never written by the legacy program
+		data.resize(342, 0);
+
+		data
+	}
+
+	/// Bytes of a pending transfer proposal written before accounts were versioned
+	pub fn legacy_proposal(
+		id: u64,
+		creator: Pubkey,
+		destination: Pubkey,
+		amount: u64,
+		token_mint: Pubkey,
+		approvals: &[Pubkey],
+		expiration_time: i64,
+		multisig: Pubkey,
+	) -> Vec<u8> {
+		let mut data = Proposal::DISCRIMINATOR.to_vec();
+		data.extend_from_slice(&id.to_le_bytes());
+		data.extend_from_slice(creator.as_ref());
+		data.extend_from_slice(destination.as_ref());
+		data.extend_from_slice(&amount.to_le_bytes());
+		data.extend_from_slice(token_mint.as_ref());
+		data.extend_from_slice(&(approvals.len() as u32).to_le_bytes());
+		approvals.iter().for_each(|approval| data.extend_from_slice(approval.as_ref()));
+		data.push(0); // executed
+		data.push(0); // cancelled
+		data.extend_from_slice(&expiration_time.to_le_bytes());
+		data.extend_from_slice(multisig.as_ref());
+		data.push(0); // nonce, never written by the legacy program
+		data.resize(487, 0);
+
+		data
+	}
+}
+
+pub mod state_fixtures {
+
+	use {
+		solana_sdk::{
+			account::Account,
+			bpf_loader_upgradeable::{self, UpgradeableLoaderState},
+			clock::Clock,
+			pubkey::Pubkey,
+			rent::Rent,
+			vote::{self, state::{VoteInit, VoteState, VoteStateVersions}},
+		},
+		multisig_wallet::MultisigConfig,
+		anchor_lang::AccountSerialize,
+		anchor_spl::token::spl_token::{
+			self,
+			solana_program::{program_option::COption, program_pack::Pack},
+			state::{Account as TokenAccount, AccountState, Mint},
+		},
+	};
+
+	fn rent_exempt_account(data: Vec<u8>, owner: Pubkey) -> Account {
+		Account {
+			lamports: Rent::default().minimum_balance(data.len()),
+			data,
+			owner,
+			executable: false,
+			rent_epoch: 0,
+		}
+	}
+
+	/// A current-layout multisig account with `signers` and `threshold`, seeded by `create_key`
+	pub fn multisig_config_account(signers: &[Pubkey], threshold: u8, create_key: Pubkey) -> Account {
+		let (_multisig, nonce) = Pubkey::find_program_address(
+			&[
+				b"multisig",
+				create_key.as_ref(),
+			],
+			&multisig_wallet::ID,
+		);
+
+		let multisig = MultisigConfig {
+			version: MultisigConfig::CURRENT_VERSION,
+			signers: signers.to_vec(),
+			threshold,
+			nonce,
+			create_key,
+			..Default::default()
+		};
+
+		let mut data = Vec::with_capacity(MultisigConfig::SPACE);
+		multisig.try_serialize(&mut data).unwrap();
+		data.resize(MultisigConfig::SPACE, 0);
+
+		rent_exempt_account(data, multisig_wallet::ID)
+	}
+
+	/// An initialized SPL Token mint
+	pub fn mint_account(mint_authority: Pubkey, decimals: u8) -> Account {
+		let mut data = vec![0; Mint::LEN];
+		Mint::pack(Mint {
+			mint_authority: COption::Some(mint_authority),
+			supply: 0,
+			decimals,
+			is_initialized: true,
+			freeze_authority: COption::None,
+		}, &mut data).unwrap();
+
+		rent_exempt_account(data, spl_token::ID)
+	}
+
+	/// An initialized SPL Token account of `mint` owned by `owner` holding `amount`
+	pub fn token_account(mint: Pubkey, owner: Pubkey, amount: u64) -> Account {
+		let mut data = vec![0; TokenAccount::LEN];
+		TokenAccount::pack(TokenAccount {
+			mint,
+			owner,
+			amount,
+			delegate: COption::None,
+			state: AccountState::Initialized,
+			is_native: COption::None,
+			delegated_amount: 0,
+			close_authority: COption::None,
+		}, &mut data).unwrap();
+
+		rent_exempt_account(data, spl_token::ID)
+	}
+
+	/// The amount held by a packed SPL Token account
+	pub fn token_amount(account: &Account) -> u64 {
+		TokenAccount::unpack(&account.data).unwrap().amount
+	}
+
+	fn upgradeable_loader_account(state: UpgradeableLoaderState, metadata_len: usize, elf: &[u8]) -> Account {
+		let mut account = Account::new_data_with_space(0, &state, metadata_len + elf.len(), &bpf_loader_upgradeable::ID).unwrap();
+		account.data[metadata_len..].copy_from_slice(elf);
+		account.lamports = Rent::default().minimum_balance(account.data.len());
+
+		account
+	}
+
+	/// An executable upgradeable program whose code lives in `programdata_address`
+	pub fn upgradeable_program_account(programdata_address: Pubkey) -> Account {
+		let mut account = upgradeable_loader_account(
+			UpgradeableLoaderState::Program { programdata_address },
+			UpgradeableLoaderState::size_of_program(),
+			&[],
+		);
+		account.executable = true;
+
+		account
+	}
+
+	/// The program data account of a program holding `elf`, upgradeable by `upgrade_authority`
+	pub fn program_data_account(upgrade_authority: Option<Pubkey>, elf: &[u8]) -> Account {
+		upgradeable_loader_account(
+			UpgradeableLoaderState::ProgramData { slot: 0, upgrade_authority_address: upgrade_authority },
+			UpgradeableLoaderState::size_of_programdata_metadata(),
+			elf,
+		)
+	}
+
+	/// A validator vote account whose node, voter and withdrawer are `node`
+	pub fn vote_account(node: Pubkey) -> Account {
+		let vote_state = VoteState::new(
+			&VoteInit {
+				node_pubkey: node,
+				authorized_voter: node,
+				authorized_withdrawer: node,
+				commission: 0,
+			},
+			&Clock::default(),
+		);
+
+		let mut account = Account::new_data_with_space(
+			0,
+			&VoteStateVersions::new_current(vote_state),
+			VoteState::size_of(),
+			&vote::program::ID,
+		).unwrap();
+		account.lamports = Rent::default().minimum_balance(account.data.len());
+
+		account
+	}
+
+	/// A buffer holding `elf`, written by `authority`
+	pub fn buffer_account(authority: Pubkey, elf: &[u8]) -> Account {
+		upgradeable_loader_account(
+			UpgradeableLoaderState::Buffer { authority_address: Some(authority) },
+			UpgradeableLoaderState::size_of_buffer_metadata(),
+			elf,
+		)
+	}
+}
+
+pub mod error_assertions {
+
+	use {
+		solana_program_test::BanksClientError,
+		solana_sdk::{instruction::InstructionError, transaction::TransactionError},
+		multisig_wallet::error::MultisigWalletError,
+		anchor_lang::error::{ErrorCode, ERROR_CODE_OFFSET},
+	};
+
+	/// Asserts that the transaction's first instruction failed with the program error `error`
+	pub fn assert_multisig_wallet_error(result: Result<(), BanksClientError>, error: MultisigWalletError) {
+		let code = ERROR_CODE_OFFSET + error as u32;
+
+		assert_eq!(
+			result.unwrap_err().unwrap(),
+			TransactionError::InstructionError(0, InstructionError::Custom(code)),
+			"expected {error:?}",
+		);
+	}
+
+	/// Asserts that the transaction's first instruction failed with the Anchor framework error `error`
+	/// (e.g. a violated account constraint)
+	pub fn assert_anchor_error(result: Result<(), BanksClientError>, error: ErrorCode) {
+		assert_eq!(
+			result.unwrap_err().unwrap(),
+			TransactionError::InstructionError(0, InstructionError::Custom(error as u32)),
+			"expected {error:?}",
+		);
+	}
+}
+
+pub mod multisig_fixture {
+
+	use {
+		super::{get_program_test, multisig_wallet_ix_interface, state_fixtures},
+		solana_program_test::{BanksClientError, ProgramTestContext},
+		solana_sdk::{
+			account::Account,
+			bpf_loader_upgradeable,
+			clock::Clock,
+			pubkey::Pubkey,
+			rent::Rent,
+			signature::{Keypair, Signer},
+			stake,
+			system_program,
+			sysvar,
+			transaction::Transaction,
+		},
+		multisig_wallet::{
+			AddressBookChange, MultisigConfig, PaymentScheduleTerms, ProgramUpgradeChange, Proposal, ProposalMetadata,
+			StakeChange, TransferPolicyChange, TransferTerms, VestingStreamTerms,
+		},
+		anchor_lang::{AccountDeserialize, AccountSerialize},
+		anchor_spl::{associated_token::get_associated_token_address, token::spl_token},
+	};
+
+	/// Fluent builder for a multisig scenario, e.g.
+	/// `MultisigFixture::new().signers(5).threshold(3).funded_vault(mint, amount).start().await`
+	pub struct MultisigFixture {
+		members: Vec<String>,
+		threshold: Option<u8>,
+		max_proposal_lifetime: i64,
+		cancel_threshold: u8,
+		guardian: bool,
+		vault_index: u8,
+		funded_mints: Vec<(Pubkey, u64)>,
+	}
+
+	impl Default for MultisigFixture {
+		fn default() -> Self {
+			MultisigFixture::new()
+		}
+	}
+
+	impl MultisigFixture {
+		/// A 1-of-1 multisig with an empty vault 0
+		pub fn new() -> Self {
+			MultisigFixture {
+				members: vec!["signer_0".to_string()],
+				threshold: None,
+				max_proposal_lifetime: 0,
+				cancel_threshold: 0,
+				guardian: false,
+				vault_index: 0,
+				funded_mints: Vec::new(),
+			}
+		}
+
+		/// `count` signers named `signer_0`, `signer_1`, ...
+		pub fn signers(mut self, count: usize) -> Self {
+			self.members = (0..count).map(|index| format!("signer_{index}")).collect();
+			self
+		}
+
+		/// Signers with the given names, in signer order
+		pub fn members(mut self, names: &[&str]) -> Self {
+			self.members = names.iter().map(|name| name.to_string()).collect();
+			self
+		}
+
+		/// The approval threshold (defaults to all signers)
+		pub fn threshold(mut self, threshold: u8) -> Self {
+			self.threshold = Some(threshold);
+			self
+		}
+
+		pub fn max_proposal_lifetime(mut self, max_proposal_lifetime: i64) -> Self {
+			self.max_proposal_lifetime = max_proposal_lifetime;
+			self
+		}
+
+		pub fn cancel_threshold(mut self, cancel_threshold: u8) -> Self {
+			self.cancel_threshold = cancel_threshold;
+			self
+		}
+
+		/// A guardian, as a member named `guardian` that is not a signer
+		pub fn guardian(mut self) -> Self {
+			self.guardian = true;
+			self
+		}
+
+		/// The vault whose token accounts get funded and that transfer proposals spend from
+		pub fn vault_index(mut self, vault_index: u8) -> Self {
+			self.vault_index = vault_index;
+			self
+		}
+
+		/// Creates `mint` and the vault's associated token account holding `amount` of it
+		pub fn funded_vault(mut self, mint: Pubkey, amount: u64) -> Self {
+			self.funded_mints.push((mint, amount));
+			self
+		}
+
+		/// Boots the program, creates the mints and vault token accounts, and creates the multisig
+		/// through `create_multisig`
+		pub async fn start(self) -> MultisigScenario {
+			let mut program_test = get_program_test();
+			program_test.prefer_bpf(true);
+
+			let members: Vec<(String, Keypair)> = self.members.into_iter()
+				.map(|name| (name, Keypair::new()))
+				.collect();
+			let create_key = Keypair::new();
+
+			let (multisig, _multisig_bump) = Pubkey::find_program_address(
+				&[
+					b"multisig",
+					create_key.pubkey().as_ref(),
+				],
+				&multisig_wallet::ID,
+			);
+
+			let (vault, _vault_bump) = Pubkey::find_program_address(
+				&[
+					b"vault",
+					multisig.as_ref(),
+					&[self.vault_index],
+				],
+				&multisig_wallet::ID,
+			);
+
+			for (_, member) in &members {
+				program_test.add_account(
+					member.pubkey(),
+					Account {
+						lamports: 1_000_000_000,
+						data: vec![],
+						owner: system_program::ID,
+						executable: false,
+						rent_epoch: 0,
+					},
+				);
+			}
+
+			for (mint, amount) in &self.funded_mints {
+				program_test.add_account(*mint, state_fixtures::mint_account(Pubkey::new_unique(), 6));
+				program_test.add_account(
+					get_associated_token_address(&vault, mint),
+					state_fixtures::token_account(*mint, vault, *amount),
+				);
+			}
+
+			let mut scenario = MultisigScenario {
+				context: program_test.start_with_context().await,
+				multisig,
+				vault,
+				vault_index: self.vault_index,
+				members,
+				last_compute_units: 0,
+			};
+
+			let guardian = self.guardian.then(Keypair::new);
+			let threshold = self.threshold.unwrap_or(scenario.members.len() as u8);
+			scenario.process(|scenario| multisig_wallet_ix_interface::create_multisig_ix_setup(
+				&scenario.context.payer,
+				&create_key,
+				multisig,
+				system_program::ID,
+				scenario.members.iter().map(|(_, member)| member.pubkey()).collect(),
+				threshold,
+				self.max_proposal_lifetime,
+				guardian.as_ref().map(|guardian| guardian.pubkey()),
+				self.cancel_threshold,
+				scenario.context.last_blockhash,
+			)).await.unwrap();
+
+			scenario.members.extend(guardian.map(|guardian| ("guardian".to_string(), guardian)));
+
+			scenario
+		}
+	}
+
+	/// A started multisig scenario with helpers acting as its named members
+	pub struct MultisigScenario {
+		pub context: ProgramTestContext,
+		pub multisig: Pubkey,
+		pub vault: Pubkey,
+		pub vault_index: u8,
+		pub members: Vec<(String, Keypair)>,
+		/// Compute units consumed by the last transaction sent through `process`, failed or not
+		pub last_compute_units: u64,
+	}
+
+	impl MultisigScenario {
+		/// The member named `name`
+		pub fn member(&self, name: &str) -> &Keypair {
+			self.members.iter()
+				.find(|(member_name, _)| member_name == name)
+				.map(|(_, member)| member)
+				.unwrap_or_else(|| panic!("no member named {name}"))
+		}
+
+		pub fn proposal(&self, proposal_id: u64) -> Pubkey {
+			self.proposal_of(self.multisig, proposal_id)
+		}
+
+		/// Proposal `proposal_id` of another multisig of the scenario
+		pub fn proposal_of(&self, multisig: Pubkey, proposal_id: u64) -> Pubkey {
+			Pubkey::find_program_address(
+				&[
+					b"proposal",
+					multisig.as_ref(),
+					proposal_id.to_le_bytes().as_ref(),
+				],
+				&multisig_wallet::ID,
+			).0
+		}
+
+		pub fn address_book(&self) -> Pubkey {
+			Pubkey::find_program_address(
+				&[
+					b"address_book",
+					self.multisig.as_ref(),
+				],
+				&multisig_wallet::ID,
+			).0
+		}
+
+		pub fn transfer_policy(&self) -> Pubkey {
+			Pubkey::find_program_address(
+				&[
+					b"transfer_policy",
+					self.multisig.as_ref(),
+				],
+				&multisig_wallet::ID,
+			).0
+		}
+
+		pub fn recovery_request(&self, recovery_id: u64) -> Pubkey {
+			Pubkey::find_program_address(
+				&[
+					b"recovery_request",
+					self.multisig.as_ref(),
+					recovery_id.to_le_bytes().as_ref(),
+				],
+				&multisig_wallet::ID,
+			).0
+		}
+
+		/// The stake account created by stake proposal `proposal_id`
+		pub fn stake_account(&self, proposal_id: u64) -> Pubkey {
+			Pubkey::find_program_address(
+				&[
+					b"stake_account",
+					self.multisig.as_ref(),
+					proposal_id.to_le_bytes().as_ref(),
+				],
+				&multisig_wallet::ID,
+			).0
+		}
+
+		/// The payment schedule created by payment schedule proposal `proposal_id`
+		pub fn payment_schedule(&self, proposal_id: u64) -> Pubkey {
+			Pubkey::find_program_address(
+				&[
+					b"payment_schedule",
+					self.multisig.as_ref(),
+					proposal_id.to_le_bytes().as_ref(),
+				],
+				&multisig_wallet::ID,
+			).0
+		}
+
+		/// The vesting stream created by vesting stream proposal `proposal_id`
+		pub fn vesting_stream(&self, proposal_id: u64) -> Pubkey {
+			Pubkey::find_program_address(
+				&[
+					b"vesting_stream",
+					self.multisig.as_ref(),
+					proposal_id.to_le_bytes().as_ref(),
+				],
+				&multisig_wallet::ID,
+			).0
+		}
+
+		/// The escrow holding the unwithdrawn `mint` of stream `stream_id`
+		pub fn stream_escrow(&self, stream_id: u64, mint: &Pubkey) -> Pubkey {
+			get_associated_token_address(&self.vesting_stream(stream_id), mint)
+		}
+
+		/// Creates another multisig in the same bank whose signers are new members named `names`, for
+		/// scenarios spanning several multisigs
+		pub async fn add_multisig(&mut self, names: &[&str], threshold: u8) -> Pubkey {
+			self.add_multisig_with_signers(names, vec![], threshold).await
+		}
+
+		/// Creates another multisig like `add_multisig`, with the vault as one more signer, for nested
+		/// approvals
+		pub async fn add_parent_multisig(&mut self, names: &[&str], threshold: u8) -> Pubkey {
+			let vault = self.vault;
+
+			self.add_multisig_with_signers(names, vec![vault], threshold).await
+		}
+
+		async fn add_multisig_with_signers(&mut self, names: &[&str], other_signers: Vec<Pubkey>, threshold: u8) -> Pubkey {
+			let create_key = Keypair::new();
+			let (multisig, _multisig_bump) = Pubkey::find_program_address(
+				&[
+					b"multisig",
+					create_key.pubkey().as_ref(),
+				],
+				&multisig_wallet::ID,
+			);
+
+			let signers: Vec<Keypair> = names.iter().map(|_| Keypair::new()).collect();
+			let signer_pubkeys: Vec<Pubkey> = signers.iter()
+				.map(|signer| signer.pubkey())
+				.chain(other_signers)
+				.collect();
+
+			self.process(|scenario| multisig_wallet_ix_interface::create_multisig_ix_setup(
+				&scenario.context.payer,
+				&create_key,
+				multisig,
+				system_program::ID,
+				signer_pubkeys,
+				threshold,
+				0,
+				None,
+				0,
+				scenario.context.last_blockhash,
+			)).await.unwrap();
+
+			self.members.extend(names.iter().map(|name| name.to_string()).zip(signers));
+
+			multisig
+		}
+
+		pub fn vault_token_account(&self, mint: &Pubkey) -> Pubkey {
+			get_associated_token_address(&self.vault, mint)
+		}
+
+		/// Sends `transaction`, built by `build` against a fresh blockhash so that repeating a call is
+		/// not rejected as a duplicate transaction
+		pub async fn process(&mut self, build: impl FnOnce(&Self) -> Transaction) -> Result<(), BanksClientError> {
+			self.context.last_blockhash = self.context.get_new_latest_blockhash().await.unwrap();
+			let transaction = build(self);
+
+			let outcome = self.context.banks_client.process_transaction_with_metadata(transaction).await?;
+			self.last_compute_units = outcome.metadata.map_or(0, |metadata| metadata.compute_units_consumed);
+
+			outcome.result.map_err(BanksClientError::TransactionError)
+		}
+
+		pub async fn multisig_config(&mut self) -> MultisigConfig {
+			let account = self.context.banks_client.get_account(self.multisig).await.unwrap().unwrap();
+
+			MultisigConfig::try_deserialize(&mut account.data.as_slice()).unwrap()
+		}
+
+		/// Writes `data` to a rent-exempt account owned by the program at `address`, e.g. a legacy
+		/// account to migrate
+		pub fn set_program_account(&mut self, address: Pubkey, data: Vec<u8>) {
+			self.context.set_account(&address, &Account {
+				lamports: Rent::default().minimum_balance(data.len()),
+				data,
+				owner: multisig_wallet::ID,
+				executable: false,
+				rent_epoch: 0,
+			}.into());
+		}
+
+		/// Migrates the legacy multisig at `multisig` to the current layout
+		pub async fn migrate_multisig(&mut self, multisig: Pubkey) -> Result<(), BanksClientError> {
+			self.process(|scenario| multisig_wallet_ix_interface::migrate_multisig_ix_setup(
+				&scenario.context.payer,
+				multisig,
+				system_program::ID,
+				scenario.context.last_blockhash,
+			)).await
+		}
+
+		/// Migrates legacy proposal `proposal_id` of the migrated multisig at `multisig`
+		pub async fn migrate_proposal(&mut self, multisig: Pubkey, proposal_id: u64) -> Result<(), BanksClientError> {
+			self.process(|scenario| multisig_wallet_ix_interface::migrate_proposal_ix_setup(
+				&scenario.context.payer,
+				multisig,
+				scenario.proposal_of(multisig, proposal_id),
+				system_program::ID,
+				proposal_id,
+				scenario.context.last_blockhash,
+			)).await
+		}
+
+		/// Overwrites the multisig account with `multisig`, e.g. to reach states no instruction leads to
+		pub async fn set_multisig_config(&mut self, multisig: &MultisigConfig) {
+			let mut account = self.context.banks_client.get_account(self.multisig).await.unwrap().unwrap();
+			let mut data = Vec::with_capacity(account.data.len());
+			multisig.try_serialize(&mut data).unwrap();
+			data.resize(account.data.len(), 0);
+			account.data = data;
+
+			self.context.set_account(&self.multisig, &account.into());
+		}
+
+		pub async fn proposal_state(&mut self, proposal_id: u64) -> Proposal {
+			let account = self.context.banks_client.get_account(self.proposal(proposal_id)).await.unwrap().unwrap();
+
+			Proposal::try_deserialize(&mut account.data.as_slice()).unwrap()
+		}
+
+		/// The program account at `address`, deserialized
+		pub async fn account_state<T: AccountDeserialize>(&mut self, address: Pubkey) -> T {
+			let account = self.context.banks_client.get_account(address).await.unwrap().unwrap();
+
+			T::try_deserialize(&mut account.data.as_slice()).unwrap()
+		}
+
+		pub async fn account_exists(&mut self, address: Pubkey) -> bool {
+			self.context.banks_client.get_account(address).await.unwrap().is_some()
+		}
+
+		/// Creates the associated token account of `wallet` for `mint`, empty
+		pub fn create_token_account(&mut self, wallet: Pubkey, mint: Pubkey) -> Pubkey {
+			let token_account = get_associated_token_address(&wallet, &mint);
+			self.context.set_account(&token_account, &state_fixtures::token_account(mint, wallet, 0).into());
+
+			token_account
+		}
+
+		pub async fn token_balance(&mut self, token_account: Pubkey) -> u64 {
+			let account = self.context.banks_client.get_account(token_account).await.unwrap().unwrap();
+
+			state_fixtures::token_amount(&account)
+		}
+
+		pub async fn now(&mut self) -> i64 {
+			self.context.banks_client.get_sysvar::<Clock>().await.unwrap().unix_timestamp
+		}
+
+		/// Moves the clock `seconds` forward
+		pub async fn warp(&mut self, seconds: i64) {
+			let mut clock = self.context.banks_client.get_sysvar::<Clock>().await.unwrap();
+			clock.unix_timestamp += seconds;
+			self.context.set_sysvar(&clock);
+		}
+
+		pub async fn lamports(&mut self, address: Pubkey) -> u64 {
+			self.context.banks_client.get_balance(address).await.unwrap()
+		}
+
+		/// The id the multisig's next proposal gets
+		pub async fn next_proposal_id(&mut self) -> u64 {
+			self.multisig_config().await.proposal_count
+		}
+
+		/// Creates transfer proposal `proposal_id` on `terms` from the vault as member `name`, passing
+		/// the address book once it exists
+		pub async fn create_proposal(
+			&mut self,
+			name: &str,
+			proposal_id: u64,
+			terms: TransferTerms,
+			metadata: ProposalMetadata,
+		) -> Result<(), BanksClientError> {
+			let address_book = self.address_book();
+			let address_book = self.account_exists(address_book).await.then_some(address_book);
+
+			self.process(|scenario| multisig_wallet_ix_interface::create_proposal_ix_setup(
+				&scenario.context.payer,
+				scenario.multisig,
+				scenario.proposal(proposal_id),
+				scenario.member(name),
+				address_book,
+				system_program::ID,
+				terms,
+				scenario.vault_index,
+				0,
+				metadata,
+				proposal_id,
+				scenario.context.last_blockhash,
+			)).await
+		}
+
+		/// Proposes a transfer of `amount` of `mint` from the vault to `destination` as member `name`,
+		/// returning the proposal id
+		pub async fn propose_transfer(
+			&mut self,
+			name: &str,
+			destination: Pubkey,
+			mint: Pubkey,
+			amount: u64,
+		) -> Result<u64, BanksClientError> {
+			let proposal_id = self.next_proposal_id().await;
+			let terms = TransferTerms { destination, amount, token_mint: mint };
+			self.create_proposal(name, proposal_id, terms, ProposalMetadata::default()).await?;
+
+			Ok(proposal_id)
+		}
+
+		/// Approves proposal `proposal_id` as member `name`
+		pub async fn approve(&mut self, name: &str, proposal_id: u64) -> Result<(), BanksClientError> {
+			self.process(|scenario| multisig_wallet_ix_interface::approve_proposal_ix_setup(
+				&scenario.context.payer,
+				scenario.multisig,
+				scenario.proposal(proposal_id),
+				scenario.member(name),
+				proposal_id,
+				scenario.context.last_blockhash,
+			)).await
+		}
+
+		/// Approves proposal `proposal_id` as each member in `names`
+		pub async fn approve_as(&mut self, names: &[&str], proposal_id: u64) -> Result<(), BanksClientError> {
+			for name in names {
+				self.approve(name, proposal_id).await?;
+			}
+
+			Ok(())
+		}
+
+		/// Cancels proposal `proposal_id` as member `name`, or votes to cancel it once it is approved
+		pub async fn cancel(&mut self, name: &str, proposal_id: u64) -> Result<(), BanksClientError> {
+			self.process(|scenario| multisig_wallet_ix_interface::cancel_proposal_ix_setup(
+				&scenario.context.payer,
+				scenario.multisig,
+				scenario.proposal(proposal_id),
+				scenario.member(name),
+				proposal_id,
+				scenario.context.last_blockhash,
+			)).await
+		}
+
+		/// Withdraws the approval of member `name` from proposal `proposal_id`
+		pub async fn unapprove(&mut self, name: &str, proposal_id: u64) -> Result<(), BanksClientError> {
+			self.process(|scenario| multisig_wallet_ix_interface::unapprove_proposal_ix_setup(
+				&scenario.context.payer,
+				scenario.multisig,
+				scenario.proposal(proposal_id),
+				scenario.member(name),
+				proposal_id,
+				scenario.context.last_blockhash,
+			)).await
+		}
+
+		/// Executes transfer proposal `proposal_id`, paying `destination`'s associated token account
+		/// for `mint` from the vault, created at the payer's expense when missing, under the transfer
+		/// policy once it exists
+		pub async fn execute_transfer(
+			&mut self,
+			proposal_id: u64,
+			destination: Pubkey,
+			mint: Pubkey,
+		) -> Result<(), BanksClientError> {
+			let transfer_policy = self.transfer_policy();
+			let transfer_policy = self.account_exists(transfer_policy).await.then_some(transfer_policy);
+
+			self.process(|scenario| {
+				let destination_token_account = get_associated_token_address(&destination, &mint);
+
+				multisig_wallet_ix_interface::execute_proposal_ix_setup(
+					&scenario.context.payer,
+					scenario.multisig,
+					scenario.proposal(proposal_id),
+					scenario.vault,
+					scenario.vault_token_account(&mint),
+					destination_token_account,
+					&scenario.context.payer,
+					destination,
+					mint,
+					system_program::ID,
+					spl_token::ID,
+					anchor_spl::associated_token::ID,
+					anchor_spl::memo::ID,
+					transfer_policy,
+					proposal_id,
+					scenario.context.last_blockhash,
+				)
+			}).await
+		}
+
+		/// Creates the multisig's address book as member `name`
+		pub async fn create_address_book(&mut self, name: &str) -> Result<(), BanksClientError> {
+			self.process(|scenario| multisig_wallet_ix_interface::create_address_book_ix_setup(
+				&scenario.context.payer,
+				scenario.multisig,
+				scenario.address_book(),
+				scenario.member(name),
+				system_program::ID,
+				scenario.context.last_blockhash,
+			)).await
+		}
+
+		/// Proposes `change` to the address book as member `name`, returning the proposal id
+		pub async fn propose_address_book_change(&mut self, name: &str, change: AddressBookChange) -> Result<u64, BanksClientError> {
+			let proposal_id = self.next_proposal_id().await;
+
+			self.process(|scenario| multisig_wallet_ix_interface::create_address_book_proposal_ix_setup(
+				&scenario.context.payer,
+				scenario.multisig,
+				scenario.proposal(proposal_id),
+				scenario.member(name),
+				system_program::ID,
+				change,
+				0,
+				proposal_id,
+				scenario.context.last_blockhash,
+			)).await?;
+
+			Ok(proposal_id)
+		}
+
+		pub async fn execute_address_book_proposal(&mut self, proposal_id: u64) -> Result<(), BanksClientError> {
+			self.process(|scenario| multisig_wallet_ix_interface::execute_address_book_proposal_ix_setup(
+				&scenario.context.payer,
+				scenario.multisig,
+				scenario.proposal(proposal_id),
+				scenario.address_book(),
+				proposal_id,
+				scenario.context.last_blockhash,
+			)).await
+		}
+
+		/// Creates the multisig's transfer policy as member `name`
+		pub async fn create_transfer_policy(&mut self, name: &str) -> Result<(), BanksClientError> {
+			self.process(|scenario| multisig_wallet_ix_interface::create_transfer_policy_ix_setup(
+				&scenario.context.payer,
+				scenario.multisig,
+				scenario.transfer_policy(),
+				scenario.member(name),
+				system_program::ID,
+				scenario.context.last_blockhash,
+			)).await
+		}
+
+		/// Proposes `change` to the transfer policy as member `name`, returning the proposal id
+		pub async fn propose_transfer_policy_change(&mut self, name: &str, change: TransferPolicyChange) -> Result<u64, BanksClientError> {
+			let proposal_id = self.next_proposal_id().await;
+
+			self.process(|scenario| multisig_wallet_ix_interface::create_transfer_policy_proposal_ix_setup(
+				&scenario.context.payer,
+				scenario.multisig,
+				scenario.proposal(proposal_id),
+				scenario.member(name),
+				system_program::ID,
+				change,
+				0,
+				proposal_id,
+				scenario.context.last_blockhash,
+			)).await?;
+
+			Ok(proposal_id)
+		}
+
+		pub async fn execute_transfer_policy_proposal(&mut self, proposal_id: u64) -> Result<(), BanksClientError> {
+			self.process(|scenario| multisig_wallet_ix_interface::execute_transfer_policy_proposal_ix_setup(
+				&scenario.context.payer,
+				scenario.multisig,
+				scenario.proposal(proposal_id),
+				scenario.transfer_policy(),
+				proposal_id,
+				scenario.context.last_blockhash,
+			)).await
+		}
+
+		/// Freezes the multisig as member `name`
+		pub async fn freeze(&mut self, name: &str) -> Result<(), BanksClientError> {
+			self.process(|scenario| multisig_wallet_ix_interface::freeze_multisig_ix_setup(
+				&scenario.context.payer,
+				scenario.multisig,
+				scenario.member(name),
+				scenario.context.last_blockhash,
+			)).await
+		}
+
+		/// Unfreezes the multisig, signed by members `names`
+		pub async fn unfreeze(&mut self, names: &[&str]) -> Result<(), BanksClientError> {
+			self.process(|scenario| multisig_wallet_ix_interface::unfreeze_multisig_ix_setup(
+				&scenario.context.payer,
+				scenario.multisig,
+				&names.iter().map(|name| scenario.member(name)).collect::<Vec<_>>(),
+				scenario.context.last_blockhash,
+			)).await
+		}
+
+		/// Adds members named `names` as the multisig's recovery keys, with `recovery_threshold` and
+		/// `recovery_delay`, signed by every signer in `signers`
+		pub async fn configure_recovery(
+			&mut self,
+			signers: &[&str],
+			names: &[&str],
+			recovery_threshold: u8,
+			recovery_delay: i64,
+		) -> Result<(), BanksClientError> {
+			for name in names {
+				if !self.members.iter().any(|(member_name, _)| member_name == name) {
+					self.members.push((name.to_string(), Keypair::new()));
+				}
+			}
+
+			self.process(|scenario| multisig_wallet_ix_interface::configure_recovery_ix_setup(
+				&scenario.context.payer,
+				scenario.multisig,
+				&signers.iter().map(|name| scenario.member(name)).collect::<Vec<_>>(),
+				names.iter().map(|name| scenario.member(name).pubkey()).collect(),
+				recovery_threshold,
+				recovery_delay,
+				scenario.context.last_blockhash,
+			)).await
+		}
+
+		/// Sets the threshold override of vault `vault_index`, signed by the members named `signers`
+		pub async fn set_vault_threshold(
+			&mut self,
+			signers: &[&str],
+			vault_index: u8,
+			threshold: Option<u8>,
+		) -> Result<(), BanksClientError> {
+			self.process(|scenario| multisig_wallet_ix_interface::set_vault_threshold_ix_setup(
+				&scenario.context.payer,
+				scenario.multisig,
+				&signers.iter().map(|name| scenario.member(name)).collect::<Vec<_>>(),
+				vault_index,
+				threshold,
+				scenario.context.last_blockhash,
+			)).await
+		}
+
+		/// Configures optimistic mode, signed by the members named `signers`
+		pub async fn configure_optimistic(
+			&mut self,
+			signers: &[&str],
+			optimistic: bool,
+			veto_window: i64,
+			veto_threshold: u8,
+		) -> Result<(), BanksClientError> {
+			self.process(|scenario| multisig_wallet_ix_interface::configure_optimistic_ix_setup(
+				&scenario.context.payer,
+				scenario.multisig,
+				&signers.iter().map(|name| scenario.member(name)).collect::<Vec<_>>(),
+				optimistic,
+				veto_window,
+				veto_threshold,
+				scenario.context.last_blockhash,
+			)).await
+		}
+
+		/// Vetoes optimistic proposal `proposal_id` as member `name`
+		pub async fn veto(&mut self, name: &str, proposal_id: u64) -> Result<(), BanksClientError> {
+			self.process(|scenario| multisig_wallet_ix_interface::veto_proposal_ix_setup(
+				&scenario.context.payer,
+				scenario.multisig,
+				scenario.proposal(proposal_id),
+				scenario.member(name),
+				proposal_id,
+				scenario.context.last_blockhash,
+			)).await
+		}
+
+		/// Requests replacing `lost_signer` by `new_signer` as recovery key `name`, returning the
+		/// request id
+		pub async fn request_recovery(&mut self, name: &str, lost_signer: Pubkey, new_signer: Pubkey) -> Result<u64, BanksClientError> {
+			let recovery_id = self.multisig_config().await.recovery_count;
+			self.create_recovery_request(name, recovery_id, lost_signer, new_signer).await?;
+
+			Ok(recovery_id)
+		}
+
+		/// Creates recovery request `recovery_id` replacing `lost_signer` by `new_signer` as recovery
+		/// key `name`
+		pub async fn create_recovery_request(
+			&mut self,
+			name: &str,
+			recovery_id: u64,
+			lost_signer: Pubkey,
+			new_signer: Pubkey,
+		) -> Result<(), BanksClientError> {
+			self.process(|scenario| multisig_wallet_ix_interface::create_recovery_request_ix_setup(
+				&scenario.context.payer,
+				scenario.multisig,
+				scenario.recovery_request(recovery_id),
+				scenario.member(name),
+				system_program::ID,
+				lost_signer,
+				new_signer,
+				recovery_id,
+				scenario.context.last_blockhash,
+			)).await
+		}
+
+		pub async fn approve_recovery(&mut self, name: &str, recovery_id: u64) -> Result<(), BanksClientError> {
+			self.process(|scenario| multisig_wallet_ix_interface::approve_recovery_request_ix_setup(
+				&scenario.context.payer,
+				scenario.multisig,
+				scenario.recovery_request(recovery_id),
+				scenario.member(name),
+				recovery_id,
+				scenario.context.last_blockhash,
+			)).await
+		}
+
+		pub async fn veto_recovery(&mut self, name: &str, recovery_id: u64) -> Result<(), BanksClientError> {
+			self.process(|scenario| multisig_wallet_ix_interface::veto_recovery_request_ix_setup(
+				&scenario.context.payer,
+				scenario.multisig,
+				scenario.recovery_request(recovery_id),
+				scenario.member(name),
+				recovery_id,
+				scenario.context.last_blockhash,
+			)).await
+		}
+
+		pub async fn execute_recovery(&mut self, recovery_id: u64) -> Result<(), BanksClientError> {
+			self.process(|scenario| multisig_wallet_ix_interface::execute_recovery_request_ix_setup(
+				&scenario.context.payer,
+				scenario.multisig,
+				scenario.recovery_request(recovery_id),
+				recovery_id,
+				scenario.context.last_blockhash,
+			)).await
+		}
+
+		/// Deploys an upgradeable program holding `elf` whose upgrade authority is the vault,
+		/// returning the program and its program data account
+		pub fn deploy_vault_program(&mut self, elf: &[u8]) -> (Pubkey, Pubkey) {
+			let program = Pubkey::new_unique();
+			let program_data = bpf_loader_upgradeable::get_program_data_address(&program);
+
+			self.context.set_account(&program, &state_fixtures::upgradeable_program_account(program_data).into());
+			self.context.set_account(&program_data, &state_fixtures::program_data_account(Some(self.vault), elf).into());
+
+			(program, program_data)
+		}
+
+		/// Proposes `change` from the vault as member `name`, returning the proposal id
+		pub async fn propose_program_upgrade(&mut self, name: &str, change: ProgramUpgradeChange) -> Result<u64, BanksClientError> {
+			let proposal_id = self.next_proposal_id().await;
+			let program_data = bpf_loader_upgradeable::get_program_data_address(&change.program());
+			let buffer = match &change {
+				ProgramUpgradeChange::Upgrade { buffer, .. } => Some(*buffer),
+				ProgramUpgradeChange::SetAuthority { .. } => None,
+			};
+
+			self.process(|scenario| multisig_wallet_ix_interface::create_program_upgrade_proposal_ix_setup(
+				&scenario.context.payer,
+				scenario.multisig,
+				scenario.proposal(proposal_id),
+				scenario.member(name),
+				scenario.vault,
+				program_data,
+				buffer,
+				system_program::ID,
+				change,
+				scenario.vault_index,
+				0,
+				proposal_id,
+				scenario.context.last_blockhash,
+			)).await?;
+
+			Ok(proposal_id)
+		}
+
+		/// Executes program upgrade proposal `proposal_id`, passing the accounts `change` names
+		pub async fn execute_program_upgrade_proposal(&mut self, proposal_id: u64, change: &ProgramUpgradeChange) -> Result<(), BanksClientError> {
+			let program = change.program();
+			let (buffer, spill, new_authority) = match *change {
+				ProgramUpgradeChange::Upgrade { buffer, spill, .. } => (Some(buffer), Some(spill), None),
+				ProgramUpgradeChange::SetAuthority { new_authority, .. } => (None, None, new_authority),
+			};
+
+			self.process(|scenario| multisig_wallet_ix_interface::execute_program_upgrade_proposal_ix_setup(
+				&scenario.context.payer,
+				scenario.multisig,
+				scenario.proposal(proposal_id),
+				scenario.vault,
+				program,
+				bpf_loader_upgradeable::get_program_data_address(&program),
+				buffer,
+				spill,
+				new_authority,
+				sysvar::rent::ID,
+				sysvar::clock::ID,
+				bpf_loader_upgradeable::ID,
+				proposal_id,
+				scenario.context.last_blockhash,
+			)).await
+		}
+
+		/// Gives the vault `lamports` of SOL
+		pub fn fund_vault_lamports(&mut self, lamports: u64) {
+			self.context.set_account(&self.vault, &Account {
+				lamports,
+				data: vec![],
+				owner: system_program::ID,
+				executable: false,
+				rent_epoch: 0,
+			}.into());
+		}
+
+		/// Proposes `change` from the vault as member `name`, returning the proposal id
+		pub async fn propose_stake_change(&mut self, name: &str, change: StakeChange) -> Result<u64, BanksClientError> {
+			let proposal_id = self.next_proposal_id().await;
+			let address_book = self.address_book();
+			let address_book = self.account_exists(address_book).await.then_some(address_book);
+
+			self.process(|scenario| multisig_wallet_ix_interface::create_stake_proposal_ix_setup(
+				&scenario.context.payer,
+				scenario.multisig,
+				scenario.proposal(proposal_id),
+				scenario.member(name),
+				address_book,
+				system_program::ID,
+				change,
+				scenario.vault_index,
+				0,
+				proposal_id,
+				scenario.context.last_blockhash,
+			)).await?;
+
+			Ok(proposal_id)
+		}
+
+		/// Executes stake proposal `proposal_id` on `stake_account`, with the vote account and
+		/// destination the change needs
+		#[allow(deprecated)]
+		pub async fn execute_stake_proposal(
+			&mut self,
+			proposal_id: u64,
+			stake_account: Pubkey,
+			vote_account: Option<Pubkey>,
+			destination: Option<Pubkey>,
+		) -> Result<(), BanksClientError> {
+			self.process(|scenario| multisig_wallet_ix_interface::execute_stake_proposal_ix_setup(
+				&scenario.context.payer,
+				scenario.multisig,
+				scenario.proposal(proposal_id),
+				scenario.vault,
+				stake_account,
+				vote_account,
+				destination,
+				sysvar::rent::ID,
+				sysvar::clock::ID,
+				sysvar::stake_history::ID,
+				stake::config::ID,
+				system_program::ID,
+				stake::program::ID,
+				proposal_id,
+				scenario.context.last_blockhash,
+			)).await
+		}
+
+		/// Proposes the payment schedule `terms` from the vault as member `name`, returning the
+		/// proposal id
+		pub async fn propose_payment_schedule(
+			&mut self,
+			name: &str,
+			terms: PaymentScheduleTerms,
+		) -> Result<u64, BanksClientError> {
+			let proposal_id = self.next_proposal_id().await;
+			let address_book = self.address_book();
+			let address_book = self.account_exists(address_book).await.then_some(address_book);
+
+			self.process(|scenario| multisig_wallet_ix_interface::create_payment_schedule_proposal_ix_setup(
+				&scenario.context.payer,
+				scenario.multisig,
+				scenario.proposal(proposal_id),
+				scenario.member(name),
+				address_book,
+				system_program::ID,
+				terms,
+				scenario.vault_index,
+				0,
+				proposal_id,
+				scenario.context.last_blockhash,
+			)).await?;
+
+			Ok(proposal_id)
+		}
+
+		pub async fn execute_payment_schedule_proposal(&mut self, proposal_id: u64) -> Result<(), BanksClientError> {
+			self.process(|scenario| multisig_wallet_ix_interface::execute_payment_schedule_proposal_ix_setup(
+				&scenario.context.payer,
+				scenario.multisig,
+				scenario.proposal(proposal_id),
+				scenario.payment_schedule(proposal_id),
+				system_program::ID,
+				proposal_id,
+				scenario.context.last_blockhash,
+			)).await
+		}
+
+		/// Pays the next payment of schedule `schedule_id` in `mint` into `destination`, under the
+		/// transfer policy once it exists
+		pub async fn crank_schedule(
+			&mut self,
+			schedule_id: u64,
+			mint: Pubkey,
+			destination: Pubkey,
+		) -> Result<(), BanksClientError> {
+			let transfer_policy = self.transfer_policy();
+			let transfer_policy = self.account_exists(transfer_policy).await.then_some(transfer_policy);
+
+			self.process(|scenario| multisig_wallet_ix_interface::crank_schedule_ix_setup(
+				&scenario.context.payer,
+				scenario.multisig,
+				scenario.payment_schedule(schedule_id),
+				scenario.vault,
+				scenario.vault_token_account(&mint),
+				destination,
+				mint,
+				spl_token::ID,
+				transfer_policy,
+				schedule_id,
+				scenario.context.last_blockhash,
+			)).await
+		}
+
+		/// Proposes the vesting stream `terms` from the vault as member `name`, returning the proposal
+		/// id
+		pub async fn propose_vesting_stream(
+			&mut self,
+			name: &str,
+			terms: VestingStreamTerms,
+		) -> Result<u64, BanksClientError> {
+			let proposal_id = self.next_proposal_id().await;
+			let address_book = self.address_book();
+			let address_book = self.account_exists(address_book).await.then_some(address_book);
+
+			self.process(|scenario| multisig_wallet_ix_interface::create_vesting_stream_proposal_ix_setup(
+				&scenario.context.payer,
+				scenario.multisig,
+				scenario.proposal(proposal_id),
+				scenario.member(name),
+				address_book,
+				system_program::ID,
+				terms,
+				scenario.vault_index,
+				0,
+				proposal_id,
+				scenario.context.last_blockhash,
+			)).await?;
+
+			Ok(proposal_id)
+		}
+
+		/// Executes vesting stream proposal `proposal_id`, funding the stream's escrow in `mint` from
+		/// the vault, under the transfer policy once it exists
+		pub async fn execute_vesting_stream_proposal(
+			&mut self,
+			proposal_id: u64,
+			mint: Pubkey,
+		) -> Result<(), BanksClientError> {
+			let transfer_policy = self.transfer_policy();
+			let transfer_policy = self.account_exists(transfer_policy).await.then_some(transfer_policy);
+
+			self.process(|scenario| multisig_wallet_ix_interface::execute_vesting_stream_proposal_ix_setup(
+				&scenario.context.payer,
+				scenario.multisig,
+				scenario.proposal(proposal_id),
+				scenario.vault,
+				scenario.vesting_stream(proposal_id),
+				scenario.vault_token_account(&mint),
+				scenario.stream_escrow(proposal_id, &mint),
+				mint,
+				system_program::ID,
+				spl_token::ID,
+				anchor_spl::associated_token::ID,
+				transfer_policy,
+				proposal_id,
+				scenario.context.last_blockhash,
+			)).await
+		}
+
+		/// Proposes, approves and executes the vesting stream `terms` as `signer_0` of a 1-of-1
+		/// multisig, returning the stream id
+		pub async fn open_vesting_stream(&mut self, terms: VestingStreamTerms) -> Result<u64, BanksClientError> {
+			let mint = terms.mint;
+			let stream_id = self.propose_vesting_stream("signer_0", terms).await?;
+			self.approve("signer_0", stream_id).await?;
+			self.execute_vesting_stream_proposal(stream_id, mint).await?;
+
+			Ok(stream_id)
+		}
+
+		/// Withdraws what has vested on stream `stream_id` into `destination`, signed by `recipient`
+		pub async fn withdraw_vested(
+			&mut self,
+			recipient: &Keypair,
+			stream_id: u64,
+			mint: Pubkey,
+			destination: Pubkey,
+		) -> Result<(), BanksClientError> {
+			self.process(|scenario| multisig_wallet_ix_interface::withdraw_vested_ix_setup(
+				&scenario.context.payer,
+				scenario.multisig,
+				scenario.vesting_stream(stream_id),
+				recipient,
+				scenario.stream_escrow(stream_id, &mint),
+				destination,
+				mint,
+				spl_token::ID,
+				stream_id,
+				scenario.context.last_blockhash,
+			)).await
+		}
+
+		/// Proposes cancelling stream `stream_id` as member `name`, returning the proposal id
+		pub async fn propose_cancel_stream(&mut self, name: &str, stream_id: u64) -> Result<u64, BanksClientError> {
+			let proposal_id = self.next_proposal_id().await;
+
+			self.process(|scenario| multisig_wallet_ix_interface::create_cancel_stream_proposal_ix_setup(
+				&scenario.context.payer,
+				scenario.multisig,
+				scenario.proposal(proposal_id),
+				scenario.member(name),
+				scenario.vesting_stream(stream_id),
+				system_program::ID,
+				0,
+				proposal_id,
+				stream_id,
+				scenario.context.last_blockhash,
+			)).await?;
+
+			Ok(proposal_id)
+		}
+
+		/// Executes cancel stream proposal `proposal_id` on stream `stream_id`, returning the unvested
+		/// `mint` to the vault
+		pub async fn cancel_stream(
+			&mut self,
+			proposal_id: u64,
+			stream_id: u64,
+			mint: Pubkey,
+		) -> Result<(), BanksClientError> {
+			self.process(|scenario| multisig_wallet_ix_interface::cancel_stream_ix_setup(
+				&scenario.context.payer,
+				scenario.multisig,
+				scenario.proposal(proposal_id),
+				scenario.vesting_stream(stream_id),
+				scenario.vault,
+				scenario.stream_escrow(stream_id, &mint),
+				scenario.vault_token_account(&mint),
+				mint,
+				spl_token::ID,
+				proposal_id,
+				stream_id,
+				scenario.context.last_blockhash,
+			)).await
+		}
+
+		/// Proposes a transfer from the first vault of multisig `parent` as its member `name`,
+		/// returning the proposal id
+		pub async fn propose_parent_transfer(&mut self, parent: Pubkey, name: &str) -> Result<u64, BanksClientError> {
+			let proposal_id = self.account_state::<MultisigConfig>(parent).await.proposal_count;
+
+			self.process(|scenario| multisig_wallet_ix_interface::create_proposal_ix_setup(
+				&scenario.context.payer,
+				parent,
+				scenario.proposal_of(parent, proposal_id),
+				scenario.member(name),
+				None,
+				system_program::ID,
+				TransferTerms { destination: Pubkey::new_unique(), amount: 1, token_mint: Pubkey::new_unique() },
+				0,
+				0,
+				ProposalMetadata::default(),
+				proposal_id,
+				scenario.context.last_blockhash,
+			)).await?;
+
+			Ok(proposal_id)
+		}
+
+		/// Proposes approving proposal `parent_proposal_id` of multisig `parent` with the vault as
+		/// member `name`, returning the proposal id
+		pub async fn propose_nested_approval(
+			&mut self,
+			name: &str,
+			parent: Pubkey,
+			parent_proposal_id: u64,
+		) -> Result<u64, BanksClientError> {
+			let proposal_id = self.next_proposal_id().await;
+
+			self.process(|scenario| multisig_wallet_ix_interface::create_nested_approval_proposal_ix_setup(
+				&scenario.context.payer,
+				scenario.multisig,
+				scenario.proposal(proposal_id),
+				scenario.member(name),
+				scenario.vault,
+				parent,
+				system_program::ID,
+				parent_proposal_id,
+				scenario.vault_index,
+				0,
+				proposal_id,
+				scenario.context.last_blockhash,
+			)).await?;
+
+			Ok(proposal_id)
+		}
+
+		/// Executes nested approval proposal `proposal_id` on proposal `parent_proposal_id` of
+		/// multisig `parent`
+		pub async fn execute_nested_approval_proposal(
+			&mut self,
+			proposal_id: u64,
+			parent: Pubkey,
+			parent_proposal_id: u64,
+		) -> Result<(), BanksClientError> {
+			self.process(|scenario| multisig_wallet_ix_interface::execute_nested_approval_proposal_ix_setup(
+				&scenario.context.payer,
+				scenario.multisig,
+				scenario.proposal(proposal_id),
+				scenario.vault,
+				parent,
+				scenario.proposal_of(parent, parent_proposal_id),
+				multisig_wallet::ID,
+				proposal_id,
+				scenario.context.last_blockhash,
+			)).await
+		}
+	}
 }
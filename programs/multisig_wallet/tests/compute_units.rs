@@ -0,0 +1,509 @@
+pub mod common;
+
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::path::PathBuf;
+use {
+    common::{
+		legacy_fixtures,
+		multisig_fixture::{MultisigFixture, MultisigScenario},
+		multisig_wallet_ix_interface,
+		state_fixtures,
+	},
+    solana_program_test::{find_file, read_file, tokio, BanksClientError},
+    solana_sdk::{
+        hash::hash, native_token::LAMPORTS_PER_SOL, pubkey::Pubkey, signature::Keypair, signer::Signer,
+    },
+};
+use multisig_wallet::{
+	AddressBookChange, PaymentScheduleTerms, ProgramUpgradeChange, StakeChange, TransferPolicyChange,
+	VestingStreamTerms, MAX_SIGNERS, MIN_RECOVERY_DELAY,
+};
+
+// Compute-unit benchmark: runs every instruction of the program, the signer-dependent ones against
+// multisigs of 1..=MAX_SIGNERS signers and the others once, writes the consumed units to a report
+// and fails on regressions against the checked-in baseline or on measurements missing from it. Run
+// with UPDATE_COMPUTE_UNITS_BASELINE=1 to rewrite the baseline after an intended change; a baseline
+// without measurements is written by the first run.
+
+const BASELINE_PATH: &str = "tests/compute_units_baseline.txt";
+const REPORT_FILE: &str = "compute_units_report.txt";
+const REGRESSION_TOLERANCE_PERCENT: u64 = 2;
+
+/// Every instruction of the program, each of which the benchmark must measure
+const INSTRUCTIONS: [&str; 40] = [
+	"create_multisig",
+	"create_proposal",
+	"approve_proposal",
+	"unapprove_proposal",
+	"execute_proposal",
+	"cancel_proposal",
+	"expire_proposal",
+	"create_address_book",
+	"create_address_book_proposal",
+	"execute_address_book_proposal",
+	"create_transfer_policy",
+	"create_transfer_policy_proposal",
+	"execute_transfer_policy_proposal",
+	"freeze_multisig",
+	"unfreeze_multisig",
+	"configure_recovery",
+	"create_recovery_request",
+	"approve_recovery_request",
+	"veto_recovery_request",
+	"execute_recovery_request",
+	"create_program_upgrade_proposal",
+	"execute_program_upgrade_proposal",
+	"create_stake_proposal",
+	"execute_stake_proposal",
+	"create_payment_schedule_proposal",
+	"execute_payment_schedule_proposal",
+	"crank_schedule",
+	"create_vesting_stream_proposal",
+	"execute_vesting_stream_proposal",
+	"withdraw_vested",
+	"create_cancel_stream_proposal",
+	"cancel_stream",
+	"set_vault_threshold",
+	"create_nested_approval_proposal",
+	"execute_nested_approval_proposal",
+	"configure_optimistic",
+	"veto_proposal",
+	"migrate_multisig",
+	"migrate_proposal",
+	"update_multisig",
+];
+
+const VAULT_BALANCE: u64 = 1_000_000;
+
+/// (instruction, signer count) -> consumed compute units
+type Measurements = BTreeMap<(String, usize), u64>;
+
+struct Benchmark {
+	measurements: Measurements,
+}
+
+impl Benchmark {
+	/// Records the units of the scenario's last transaction as the cost of `instruction` on a
+	/// multisig of `signer_count` signers, returning the outcome of that transaction
+	fn record<T>(
+		&mut self,
+		scenario: &MultisigScenario,
+		instruction: &str,
+		signer_count: usize,
+		result: Result<T, BanksClientError>,
+	) -> T {
+		let value = result.unwrap_or_else(|error| panic!("{instruction} failed: {error}"));
+		self.measurements.insert((instruction.to_string(), signer_count), scenario.last_compute_units);
+
+		value
+	}
+}
+
+/// Measures the instructions whose cost grows with the signer list on a fresh `signer_count`-of-
+/// `signer_count` multisig with a guardian, recording the last signer's call where each signer
+/// takes a turn
+async fn measure_signer_scaling(benchmark: &mut Benchmark, signer_count: usize) {
+	let mint = Pubkey::new_unique();
+	let mut scenario = MultisigFixture::new()
+		.signers(signer_count)
+		.guardian()
+		.funded_vault(mint, VAULT_BALANCE)
+		.start()
+		.await;
+	benchmark.record(&scenario, "create_multisig", signer_count, Ok(()));
+
+	let names: Vec<String> = (0..signer_count).map(|index| format!("signer_{index}")).collect();
+	let names: Vec<&str> = names.iter().map(String::as_str).collect();
+	let last = names[signer_count - 1];
+
+	// All-signer configuration
+	let result = scenario.set_vault_threshold(&names, 1, Some(1)).await;
+	benchmark.record(&scenario, "set_vault_threshold", signer_count, result);
+
+	let result = scenario.configure_recovery(&names, &["recovery_0", "recovery_1"], 2, MIN_RECOVERY_DELAY).await;
+	benchmark.record(&scenario, "configure_recovery", signer_count, result);
+
+	let result = scenario.freeze("guardian").await;
+	benchmark.record(&scenario, "freeze_multisig", signer_count, result);
+
+	let result = scenario.unfreeze(&names).await;
+	benchmark.record(&scenario, "unfreeze_multisig", signer_count, result);
+
+	let result = scenario.process(|scenario| multisig_wallet_ix_interface::update_multisig_ix_setup(
+		&scenario.context.payer,
+		scenario.multisig,
+		scenario.members.iter().take(signer_count).map(|(_, member)| member.pubkey()).collect(),
+		signer_count as u8,
+		true,
+		scenario.context.last_blockhash,
+	)).await;
+	benchmark.record(&scenario, "update_multisig", signer_count, result);
+
+	// Approval flow on a transfer proposal
+	let destination = Pubkey::new_unique();
+	scenario.create_token_account(destination, mint);
+
+	let result = scenario.propose_transfer("signer_0", destination, mint, 100).await;
+	let proposal_id = benchmark.record(&scenario, "create_proposal", signer_count, result);
+
+	for name in &names {
+		let result = scenario.approve(name, proposal_id).await;
+		benchmark.record(&scenario, "approve_proposal", signer_count, result);
+	}
+
+	let result = scenario.unapprove(last, proposal_id).await;
+	benchmark.record(&scenario, "unapprove_proposal", signer_count, result);
+	scenario.approve(last, proposal_id).await.unwrap();
+
+	let result = scenario.execute_transfer(proposal_id, destination, mint).await;
+	benchmark.record(&scenario, "execute_proposal", signer_count, result);
+
+	// Cancel vote flow, approved by its creator so that it cannot cancel it outright
+	let proposal_id = scenario.propose_transfer("signer_0", destination, mint, 100).await.unwrap();
+	scenario.approve("signer_0", proposal_id).await.unwrap();
+	for name in &names {
+		let result = scenario.cancel(name, proposal_id).await;
+		benchmark.record(&scenario, "cancel_proposal", signer_count, result);
+	}
+
+	// Veto flow on an optimistic proposal
+	let result = scenario.configure_optimistic(&names, true, 3_600, signer_count as u8).await;
+	benchmark.record(&scenario, "configure_optimistic", signer_count, result);
+
+	let proposal_id = scenario.propose_transfer("signer_0", destination, mint, 100).await.unwrap();
+	for name in &names {
+		let result = scenario.veto(name, proposal_id).await;
+		benchmark.record(&scenario, "veto_proposal", signer_count, result);
+	}
+}
+
+/// Measures proposal expiry on a 1-of-1 multisig
+async fn measure_expiry(benchmark: &mut Benchmark) {
+	let mut scenario = MultisigFixture::new()
+		.max_proposal_lifetime(60)
+		.start()
+		.await;
+	let proposal_id = scenario.propose_transfer("signer_0", Pubkey::new_unique(), Pubkey::new_unique(), 1).await.unwrap();
+	scenario.warp(61).await;
+
+	let result = scenario.process(|scenario| multisig_wallet_ix_interface::expire_proposal_ix_setup(
+		&scenario.context.payer,
+		scenario.multisig,
+		scenario.proposal(proposal_id),
+		scenario.member("signer_0").pubkey(),
+		proposal_id,
+		scenario.context.last_blockhash,
+	)).await;
+	benchmark.record(&scenario, "expire_proposal", 1, result);
+}
+
+/// Measures the address book and transfer policy instructions on a 1-of-1 multisig
+async fn measure_policies(benchmark: &mut Benchmark) {
+	let mut scenario = MultisigFixture::new().start().await;
+
+	let result = scenario.create_address_book("signer_0").await;
+	benchmark.record(&scenario, "create_address_book", 1, result);
+
+	let change = AddressBookChange::AddEntry { address: Pubkey::new_unique(), label: "treasury".to_string() };
+	let result = scenario.propose_address_book_change("signer_0", change).await;
+	let proposal_id = benchmark.record(&scenario, "create_address_book_proposal", 1, result);
+	scenario.approve("signer_0", proposal_id).await.unwrap();
+
+	let result = scenario.execute_address_book_proposal(proposal_id).await;
+	benchmark.record(&scenario, "execute_address_book_proposal", 1, result);
+
+	let result = scenario.create_transfer_policy("signer_0").await;
+	benchmark.record(&scenario, "create_transfer_policy", 1, result);
+
+	let change = TransferPolicyChange::SetLimit {
+		mint: Pubkey::new_unique(),
+		max_transfer_amount: 1_000,
+		max_window_volume: 10_000,
+	};
+	let result = scenario.propose_transfer_policy_change("signer_0", change).await;
+	let proposal_id = benchmark.record(&scenario, "create_transfer_policy_proposal", 1, result);
+	scenario.approve("signer_0", proposal_id).await.unwrap();
+
+	let result = scenario.execute_transfer_policy_proposal(proposal_id).await;
+	benchmark.record(&scenario, "execute_transfer_policy_proposal", 1, result);
+}
+
+/// Measures the recovery flow on a 2-of-2 multisig with two recovery keys
+async fn measure_recovery(benchmark: &mut Benchmark) {
+	let mut scenario = MultisigFixture::new()
+		.signers(2)
+		.start()
+		.await;
+	scenario.configure_recovery(&["signer_0", "signer_1"], &["recovery_0", "recovery_1"], 2, MIN_RECOVERY_DELAY)
+		.await
+		.unwrap();
+	let signer_0 = scenario.member("signer_0").pubkey();
+	let signer_1 = scenario.member("signer_1").pubkey();
+
+	let result = scenario.request_recovery("recovery_0", signer_1, Pubkey::new_unique()).await;
+	let recovery_id = benchmark.record(&scenario, "create_recovery_request", 2, result);
+
+	let result = scenario.approve_recovery("recovery_1", recovery_id).await;
+	benchmark.record(&scenario, "approve_recovery_request", 2, result);
+
+	let vetoed_id = scenario.request_recovery("recovery_0", signer_0, Pubkey::new_unique()).await.unwrap();
+	let result = scenario.veto_recovery("signer_0", vetoed_id).await;
+	benchmark.record(&scenario, "veto_recovery_request", 2, result);
+
+	scenario.warp(MIN_RECOVERY_DELAY).await;
+	let result = scenario.execute_recovery(recovery_id).await;
+	benchmark.record(&scenario, "execute_recovery_request", 2, result);
+}
+
+/// Measures a program upgrade signed by the vault of a 1-of-1 multisig
+async fn measure_program_upgrade(benchmark: &mut Benchmark) {
+	let elf = read_file(find_file("multisig_wallet.so").unwrap());
+	let mut scenario = MultisigFixture::new().start().await;
+	let (program, _program_data) = scenario.deploy_vault_program(&elf);
+
+	let buffer = Pubkey::new_unique();
+	scenario.context.set_account(&buffer, &state_fixtures::buffer_account(scenario.vault, &elf).into());
+	let change = ProgramUpgradeChange::Upgrade {
+		program,
+		buffer,
+		spill: Pubkey::new_unique(),
+		buffer_hash: hash(&elf).to_bytes(),
+	};
+
+	let result = scenario.propose_program_upgrade("signer_0", change.clone()).await;
+	let proposal_id = benchmark.record(&scenario, "create_program_upgrade_proposal", 1, result);
+	scenario.approve("signer_0", proposal_id).await.unwrap();
+
+	let result = scenario.execute_program_upgrade_proposal(proposal_id, &change).await;
+	benchmark.record(&scenario, "execute_program_upgrade_proposal", 1, result);
+}
+
+/// Measures creating a vault stake account on a 1-of-1 multisig
+async fn measure_stake(benchmark: &mut Benchmark) {
+	let mut scenario = MultisigFixture::new().start().await;
+	scenario.fund_vault_lamports(10 * LAMPORTS_PER_SOL);
+
+	let result = scenario.propose_stake_change("signer_0", StakeChange::Create { lamports: 2 * LAMPORTS_PER_SOL }).await;
+	let proposal_id = benchmark.record(&scenario, "create_stake_proposal", 1, result);
+	scenario.approve("signer_0", proposal_id).await.unwrap();
+
+	let stake_account = scenario.stake_account(proposal_id);
+	let result = scenario.execute_stake_proposal(proposal_id, stake_account, None, None).await;
+	benchmark.record(&scenario, "execute_stake_proposal", 1, result);
+}
+
+/// Measures a payment schedule and its first payment on a 1-of-1 multisig
+async fn measure_payment_schedule(benchmark: &mut Benchmark) {
+	let mint = Pubkey::new_unique();
+	let mut scenario = MultisigFixture::new()
+		.funded_vault(mint, VAULT_BALANCE)
+		.start()
+		.await;
+	let recipient = Pubkey::new_unique();
+	let destination = scenario.create_token_account(recipient, mint);
+
+	let terms = PaymentScheduleTerms {
+		destination: recipient,
+		mint,
+		amount: 100,
+		interval: 7 * 24 * 60 * 60,
+		payment_count: 4,
+		start_time: 0,
+	};
+	let result = scenario.propose_payment_schedule("signer_0", terms).await;
+	let schedule_id = benchmark.record(&scenario, "create_payment_schedule_proposal", 1, result);
+	scenario.approve("signer_0", schedule_id).await.unwrap();
+
+	let result = scenario.execute_payment_schedule_proposal(schedule_id).await;
+	benchmark.record(&scenario, "execute_payment_schedule_proposal", 1, result);
+
+	let result = scenario.crank_schedule(schedule_id, mint, destination).await;
+	benchmark.record(&scenario, "crank_schedule", 1, result);
+}
+
+/// Measures a vesting stream, a withdrawal and its cancellation on a 1-of-1 multisig
+async fn measure_vesting_stream(benchmark: &mut Benchmark) {
+	let mint = Pubkey::new_unique();
+	let mut scenario = MultisigFixture::new()
+		.funded_vault(mint, VAULT_BALANCE)
+		.start()
+		.await;
+	let recipient = Keypair::new();
+	let destination = scenario.create_token_account(recipient.pubkey(), mint);
+
+	let now = scenario.now().await;
+	let terms = VestingStreamTerms {
+		recipient: recipient.pubkey(),
+		mint,
+		total_amount: 1_000,
+		start_time: now,
+		cliff_time: now,
+		end_time: now + 1_000,
+	};
+	let result = scenario.propose_vesting_stream("signer_0", terms).await;
+	let stream_id = benchmark.record(&scenario, "create_vesting_stream_proposal", 1, result);
+	scenario.approve("signer_0", stream_id).await.unwrap();
+
+	let result = scenario.execute_vesting_stream_proposal(stream_id, mint).await;
+	benchmark.record(&scenario, "execute_vesting_stream_proposal", 1, result);
+
+	scenario.warp(500).await;
+	let result = scenario.withdraw_vested(&recipient, stream_id, mint, destination).await;
+	benchmark.record(&scenario, "withdraw_vested", 1, result);
+
+	let result = scenario.propose_cancel_stream("signer_0", stream_id).await;
+	let proposal_id = benchmark.record(&scenario, "create_cancel_stream_proposal", 1, result);
+	scenario.approve("signer_0", proposal_id).await.unwrap();
+
+	let result = scenario.cancel_stream(proposal_id, stream_id, mint).await;
+	benchmark.record(&scenario, "cancel_stream", 1, result);
+}
+
+/// Measures a nested approval of a parent multisig's proposal by the vault of a 1-of-1 multisig
+async fn measure_nested_approval(benchmark: &mut Benchmark) {
+	let mut scenario = MultisigFixture::new().start().await;
+	let parent = scenario.add_parent_multisig(&["board_0"], 2).await;
+	let parent_proposal_id = scenario.propose_parent_transfer(parent, "board_0").await.unwrap();
+
+	let result = scenario.propose_nested_approval("signer_0", parent, parent_proposal_id).await;
+	let proposal_id = benchmark.record(&scenario, "create_nested_approval_proposal", 1, result);
+	scenario.approve("signer_0", proposal_id).await.unwrap();
+
+	let result = scenario.execute_nested_approval_proposal(proposal_id, parent, parent_proposal_id).await;
+	benchmark.record(&scenario, "execute_nested_approval_proposal", 1, result);
+}
+
+/// Measures migrating a legacy 2-of-3 multisig and one of its pending proposals
+async fn measure_migrations(benchmark: &mut Benchmark) {
+	let mut scenario = MultisigFixture::new().start().await;
+	let signers = vec![Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique()];
+
+	let legacy_multisig = legacy_fixtures::legacy_multisig();
+	scenario.set_program_account(legacy_multisig, legacy_fixtures::legacy_multisig_config(&signers, 2, 1));
+	scenario.set_program_account(scenario.proposal_of(legacy_multisig, 0), legacy_fixtures::legacy_proposal(
+		0,
+		signers[0],
+		Pubkey::new_unique(),
+		1_000,
+		Pubkey::new_unique(),
+		&signers[..1],
+		1_800_000_000,
+		legacy_multisig,
+	));
+
+	let result = scenario.migrate_multisig(legacy_multisig).await;
+	benchmark.record(&scenario, "migrate_multisig", 3, result);
+
+	let result = scenario.migrate_proposal(legacy_multisig, 0).await;
+	benchmark.record(&scenario, "migrate_proposal", 3, result);
+}
+
+fn read_baseline(path: &PathBuf) -> Measurements {
+	let baseline = std::fs::read_to_string(path).unwrap_or_default();
+
+	baseline.lines()
+		.map(str::trim)
+		.filter(|line| !line.is_empty() && !line.starts_with('#'))
+		.map(|line| {
+			let fields: Vec<&str> = line.split_whitespace().collect();
+			assert_eq!(fields.len(), 3, "malformed baseline line: {line}");
+
+			((fields[0].to_string(), fields[1].parse().unwrap()), fields[2].parse().unwrap())
+		})
+		.collect()
+}
+
+fn write_baseline(path: &PathBuf, measurements: &Measurements) {
+	let mut baseline = String::from("# instruction signers compute_units\n");
+	baseline.push_str("# Regenerate with: UPDATE_COMPUTE_UNITS_BASELINE=1 cargo test --test compute_units\n");
+	for ((instruction, signer_count), units) in measurements {
+		writeln!(baseline, "{instruction} {signer_count} {units}").unwrap();
+	}
+
+	std::fs::write(path, baseline).unwrap();
+}
+
+/// Writes the report comparing `measurements` to `baseline`, returning the regressed entries and
+/// the entries the baseline lacks
+fn write_report(path: &PathBuf, measurements: &Measurements, baseline: &Measurements) -> (Vec<String>, Vec<String>) {
+	let mut report = format!(
+		"{:<34} {:>7} {:>9} {:>9} {:>8}  status\n",
+		"instruction", "signers", "units", "baseline", "delta",
+	);
+	let mut regressions = Vec::new();
+	let mut missing = Vec::new();
+
+	for (key, units) in measurements {
+		let (instruction, signer_count) = key;
+		let status = match baseline.get(key) {
+			None => {
+				missing.push(format!("{instruction} with {signer_count} signers"));
+				"MISSING"
+			}
+			Some(base) if units * 100 > base * (100 + REGRESSION_TOLERANCE_PERCENT) => {
+				regressions.push(format!("{instruction} with {signer_count} signers: {base} -> {units}"));
+				"REGRESSION"
+			}
+			Some(base) if units < base => "improved",
+			Some(_) => "ok",
+		};
+		let (base, delta) = match baseline.get(key) {
+			Some(base) => (base.to_string(), format!("{:+}", *units as i64 - *base as i64)),
+			None => ("-".to_string(), "-".to_string()),
+		};
+
+		writeln!(report, "{instruction:<34} {signer_count:>7} {units:>9} {base:>9} {delta:>8}  {status}").unwrap();
+	}
+
+	std::fs::write(path, report).unwrap();
+
+	(regressions, missing)
+}
+
+#[tokio::test]
+async fn compute_units_benchmark() {
+	let mut benchmark = Benchmark { measurements: Measurements::new() };
+	for signer_count in 1..=MAX_SIGNERS {
+		measure_signer_scaling(&mut benchmark, signer_count).await;
+	}
+	measure_expiry(&mut benchmark).await;
+	measure_policies(&mut benchmark).await;
+	measure_recovery(&mut benchmark).await;
+	measure_program_upgrade(&mut benchmark).await;
+	measure_stake(&mut benchmark).await;
+	measure_payment_schedule(&mut benchmark).await;
+	measure_vesting_stream(&mut benchmark).await;
+	measure_nested_approval(&mut benchmark).await;
+	measure_migrations(&mut benchmark).await;
+	let measurements = benchmark.measurements;
+
+	let unmeasured: Vec<&str> = INSTRUCTIONS.into_iter()
+		.filter(|instruction| !measurements.keys().any(|(measured, _)| measured == instruction))
+		.collect();
+	assert!(unmeasured.is_empty(), "instructions left out of the benchmark: {unmeasured:?}");
+
+	let baseline_path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(BASELINE_PATH);
+	let baseline = read_baseline(&baseline_path);
+	if std::env::var_os("UPDATE_COMPUTE_UNITS_BASELINE").is_some() || baseline.is_empty() {
+		write_baseline(&baseline_path, &measurements);
+		println!("compute unit baseline written to {}, commit it", baseline_path.display());
+
+		return;
+	}
+
+	let report_path = PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join(REPORT_FILE);
+	let (regressions, missing) = write_report(&report_path, &measurements, &baseline);
+	println!("compute unit report written to {}", report_path.display());
+
+	assert!(
+		missing.is_empty(),
+		"the baseline has no entry for:\n{}\nregenerate it with UPDATE_COMPUTE_UNITS_BASELINE=1",
+		missing.join("\n"),
+	);
+	assert!(
+		regressions.is_empty(),
+		"compute units regressed by more than {REGRESSION_TOLERANCE_PERCENT}%:\n{}",
+		regressions.join("\n"),
+	);
+}
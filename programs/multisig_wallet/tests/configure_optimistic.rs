@@ -0,0 +1,103 @@
+pub mod common;
+
+use {
+    common::{error_assertions::assert_multisig_wallet_error, multisig_fixture::MultisigFixture},
+    solana_program_test::tokio,
+    solana_sdk::pubkey::Pubkey,
+};
+use multisig_wallet::{error::MultisigWalletError, MAX_VETO_WINDOW};
+
+const ALL_SIGNERS: [&str; 3] = ["signer_0", "signer_1", "signer_2"];
+const VETO_WINDOW: i64 = 24 * 60 * 60;
+
+
+#[tokio::test]
+async fn configure_optimistic_ix_success() {
+	let mut scenario = MultisigFixture::new()
+		.signers(3)
+		.threshold(2)
+		.start()
+		.await;
+
+	// INSTRUCTIONS
+	let result = scenario.configure_optimistic(&ALL_SIGNERS, true, VETO_WINDOW, 1).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+	let multisig = scenario.multisig_config().await;
+	assert!(multisig.optimistic);
+	assert_eq!(multisig.veto_window, VETO_WINDOW);
+	assert_eq!(multisig.veto_threshold, 1);
+
+	// New transfer proposals get a veto deadline
+	let now = scenario.now().await;
+	let proposal_id = scenario.propose_transfer("signer_0", Pubkey::new_unique(), Pubkey::new_unique(), 1).await.unwrap();
+	let proposal = scenario.proposal_state(proposal_id).await;
+	assert!(proposal.is_optimistic());
+	assert_eq!(proposal.veto_deadline, now + VETO_WINDOW);
+}
+
+#[tokio::test]
+async fn configure_optimistic_turning_off() {
+	let mut scenario = MultisigFixture::new()
+		.signers(3)
+		.threshold(2)
+		.start()
+		.await;
+	scenario.configure_optimistic(&ALL_SIGNERS, true, VETO_WINDOW, 1).await.unwrap();
+
+	let result = scenario.configure_optimistic(&ALL_SIGNERS, false, 0, 0).await;
+
+	assert!(result.is_ok());
+	assert!(!scenario.multisig_config().await.optimistic);
+	let proposal_id = scenario.propose_transfer("signer_0", Pubkey::new_unique(), Pubkey::new_unique(), 1).await.unwrap();
+	assert!(!scenario.proposal_state(proposal_id).await.is_optimistic());
+}
+
+#[tokio::test]
+async fn configure_optimistic_with_invalid_veto_window() {
+	let mut scenario = MultisigFixture::new()
+		.signers(3)
+		.threshold(2)
+		.start()
+		.await;
+
+	for veto_window in [0, -1, MAX_VETO_WINDOW + 1, i64::MAX] {
+		let result = scenario.configure_optimistic(&ALL_SIGNERS, true, veto_window, 1).await;
+
+		assert_multisig_wallet_error(result, MultisigWalletError::InvalidOptimisticConfig);
+	}
+
+	let result = scenario.configure_optimistic(&ALL_SIGNERS, true, MAX_VETO_WINDOW, 1).await;
+	assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn configure_optimistic_with_invalid_veto_threshold() {
+	let mut scenario = MultisigFixture::new()
+		.signers(3)
+		.threshold(2)
+		.start()
+		.await;
+
+	for veto_threshold in [0, 4] {
+		let result = scenario.configure_optimistic(&ALL_SIGNERS, true, VETO_WINDOW, veto_threshold).await;
+
+		assert_multisig_wallet_error(result, MultisigWalletError::InvalidOptimisticConfig);
+	}
+	assert!(!scenario.multisig_config().await.optimistic);
+}
+
+#[tokio::test]
+async fn configure_optimistic_without_every_signer() {
+	let mut scenario = MultisigFixture::new()
+		.signers(3)
+		.threshold(2)
+		.start()
+		.await;
+
+	let result = scenario.configure_optimistic(&["signer_0", "signer_1"], true, VETO_WINDOW, 1).await;
+
+	assert_multisig_wallet_error(result, MultisigWalletError::NotAllSignersApproved);
+	assert!(!scenario.multisig_config().await.optimistic);
+}
@@ -0,0 +1,93 @@
+pub mod common;
+
+use {
+    common::{error_assertions::assert_multisig_wallet_error, multisig_fixture::MultisigFixture},
+    solana_program_test::tokio,
+    solana_sdk::signer::Signer,
+};
+use multisig_wallet::{error::MultisigWalletError, MIN_RECOVERY_DELAY};
+
+
+#[tokio::test]
+async fn configure_recovery_ix_success() {
+	let mut scenario = MultisigFixture::new()
+		.signers(2)
+		.threshold(1)
+		.start()
+		.await;
+
+	// INSTRUCTIONS
+	let result = scenario.configure_recovery(
+		&["signer_0", "signer_1"],
+		&["recovery_0", "recovery_1", "recovery_2"],
+		2,
+		MIN_RECOVERY_DELAY,
+	).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+
+	let multisig = scenario.multisig_config().await;
+	assert_eq!(multisig.recovery_keys, vec![
+		scenario.member("recovery_0").pubkey(),
+		scenario.member("recovery_1").pubkey(),
+		scenario.member("recovery_2").pubkey(),
+	]);
+	assert_eq!(multisig.recovery_threshold, 2);
+	assert_eq!(multisig.recovery_delay, MIN_RECOVERY_DELAY);
+}
+
+#[tokio::test]
+async fn configure_recovery_disabling_recovery() {
+	let mut scenario = MultisigFixture::new().start().await;
+	scenario.configure_recovery(&["signer_0"], &["recovery_0"], 1, MIN_RECOVERY_DELAY).await.unwrap();
+
+	let result = scenario.configure_recovery(&["signer_0"], &[], 0, 0).await;
+
+	assert!(result.is_ok());
+
+	let multisig = scenario.multisig_config().await;
+	assert!(multisig.recovery_keys.is_empty());
+	assert_eq!(multisig.recovery_threshold, 0);
+}
+
+#[tokio::test]
+async fn configure_recovery_without_every_signer() {
+	let mut scenario = MultisigFixture::new()
+		.signers(2)
+		.threshold(1)
+		.start()
+		.await;
+
+	let result = scenario.configure_recovery(&["signer_0"], &["recovery_0"], 1, MIN_RECOVERY_DELAY).await;
+
+	assert_multisig_wallet_error(result, MultisigWalletError::NotAllSignersApproved);
+	assert!(scenario.multisig_config().await.recovery_keys.is_empty());
+}
+
+#[tokio::test]
+async fn configure_recovery_with_short_delay() {
+	let mut scenario = MultisigFixture::new().start().await;
+
+	for recovery_delay in [0, MIN_RECOVERY_DELAY - 1] {
+		let result = scenario.configure_recovery(&["signer_0"], &["recovery_0"], 1, recovery_delay).await;
+
+		assert_multisig_wallet_error(result, MultisigWalletError::InvalidRecoveryConfig);
+	}
+}
+
+#[tokio::test]
+async fn configure_recovery_with_invalid_threshold() {
+	let mut scenario = MultisigFixture::new().start().await;
+
+	for recovery_threshold in [0, 3] {
+		let result = scenario.configure_recovery(
+			&["signer_0"],
+			&["recovery_0", "recovery_1"],
+			recovery_threshold,
+			MIN_RECOVERY_DELAY,
+		).await;
+
+		assert_multisig_wallet_error(result, MultisigWalletError::InvalidRecoveryConfig);
+	}
+}
@@ -0,0 +1,141 @@
+pub mod common;
+
+use {
+    common::{
+		error_assertions::{assert_anchor_error, assert_multisig_wallet_error},
+		multisig_fixture::{MultisigFixture, MultisigScenario},
+	},
+    anchor_lang::error::ErrorCode,
+    solana_program_test::tokio,
+    solana_sdk::pubkey::Pubkey,
+};
+use multisig_wallet::{error::MultisigWalletError, PaymentSchedule, PaymentScheduleTerms};
+
+const VAULT_BALANCE: u64 = 1_000;
+const AMOUNT: u64 = 100;
+const INTERVAL: i64 = 7 * 24 * 60 * 60;
+const PAYMENT_COUNT: u32 = 2;
+
+struct Schedule {
+	scenario: MultisigScenario,
+	schedule_id: u64,
+	mint: Pubkey,
+	/// The wallet named in the schedule
+	recipient: Pubkey,
+	/// The recipient's token account for the mint
+	destination: Pubkey,
+}
+
+impl Schedule {
+	/// A 1-of-1 multisig with a funded vault and an executed schedule paying `AMOUNT` weekly,
+	/// the first payment due now
+	async fn new() -> Self {
+		let mint = Pubkey::new_unique();
+		let mut scenario = MultisigFixture::new()
+			.funded_vault(mint, VAULT_BALANCE)
+			.start()
+			.await;
+
+		let recipient = Pubkey::new_unique();
+		let destination = scenario.create_token_account(recipient, mint);
+
+		let schedule_id = scenario.propose_payment_schedule("signer_0", PaymentScheduleTerms {
+			destination: recipient,
+			mint,
+			amount: AMOUNT,
+			interval: INTERVAL,
+			payment_count: PAYMENT_COUNT,
+			start_time: 0,
+		}).await.unwrap();
+		scenario.approve("signer_0", schedule_id).await.unwrap();
+		scenario.execute_payment_schedule_proposal(schedule_id).await.unwrap();
+
+		Schedule { scenario, schedule_id, mint, recipient, destination }
+	}
+
+	async fn crank(&mut self) -> Result<(), solana_program_test::BanksClientError> {
+		self.scenario.crank_schedule(self.schedule_id, self.mint, self.destination).await
+	}
+
+	async fn state(&mut self) -> PaymentSchedule {
+		let schedule_address = self.scenario.payment_schedule(self.schedule_id);
+
+		self.scenario.account_state(schedule_address).await
+	}
+}
+
+
+#[tokio::test]
+async fn crank_schedule_ix_success() {
+	let mut schedule = Schedule::new().await;
+	let first_payment_time = schedule.state().await.next_payment_time;
+
+	// INSTRUCTIONS
+	let result = schedule.crank().await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+	assert_eq!(schedule.scenario.token_balance(schedule.destination).await, AMOUNT);
+	let vault_token_account = schedule.scenario.vault_token_account(&schedule.mint);
+	assert_eq!(schedule.scenario.token_balance(vault_token_account).await, VAULT_BALANCE - AMOUNT);
+
+	let state = schedule.state().await;
+	assert_eq!(state.payments_made, 1);
+	assert_eq!(state.next_payment_time, first_payment_time + INTERVAL);
+}
+
+#[tokio::test]
+async fn crank_schedule_before_due() {
+	let mut schedule = Schedule::new().await;
+	schedule.crank().await.unwrap();
+
+	let result = schedule.crank().await;
+	assert_multisig_wallet_error(result, MultisigWalletError::PaymentNotDue);
+
+	schedule.scenario.warp(INTERVAL).await;
+	let result = schedule.crank().await;
+	assert!(result.is_ok());
+	assert_eq!(schedule.scenario.token_balance(schedule.destination).await, 2 * AMOUNT);
+}
+
+#[tokio::test]
+async fn crank_schedule_after_last_payment() {
+	let mut schedule = Schedule::new().await;
+	for _ in 0..PAYMENT_COUNT {
+		schedule.crank().await.unwrap();
+		schedule.scenario.warp(INTERVAL).await;
+	}
+
+	let result = schedule.crank().await;
+
+	assert_multisig_wallet_error(result, MultisigWalletError::PaymentScheduleCompleted);
+	assert_eq!(schedule.scenario.token_balance(schedule.destination).await, PAYMENT_COUNT as u64 * AMOUNT);
+}
+
+#[tokio::test]
+async fn crank_schedule_into_token_account_of_other_wallet() {
+	let mut schedule = Schedule::new().await;
+	let other_destination = schedule.scenario.create_token_account(Pubkey::new_unique(), schedule.mint);
+
+	let result = schedule.scenario.crank_schedule(schedule.schedule_id, schedule.mint, other_destination).await;
+
+	assert_anchor_error(result, ErrorCode::ConstraintTokenOwner);
+	assert_eq!(schedule.scenario.token_balance(other_destination).await, 0);
+}
+
+#[tokio::test]
+async fn crank_schedule_into_other_token_account_of_recipient() {
+	let mut schedule = Schedule::new().await;
+
+	// Any token account the recipient owns for the mint is accepted, not only its associated one
+	let other_token_account = Pubkey::new_unique();
+	schedule.scenario.context.set_account(
+		&other_token_account,
+		&common::state_fixtures::token_account(schedule.mint, schedule.recipient, 0).into(),
+	);
+
+	let result = schedule.scenario.crank_schedule(schedule.schedule_id, schedule.mint, other_token_account).await;
+
+	assert!(result.is_ok());
+	assert_eq!(schedule.scenario.token_balance(other_token_account).await, AMOUNT);
+}
@@ -0,0 +1,49 @@
+pub mod common;
+
+use {
+    common::multisig_fixture::MultisigFixture,
+    common::error_assertions::assert_multisig_wallet_error,
+    solana_program_test::tokio,
+};
+use multisig_wallet::{error::MultisigWalletError, AddressBook};
+
+
+#[tokio::test]
+async fn create_address_book_ix_success() {
+	let mut scenario = MultisigFixture::new()
+		.signers(2)
+		.start()
+		.await;
+
+	// INSTRUCTIONS
+	let result = scenario.create_address_book("signer_1").await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+
+	let address_book: AddressBook = scenario.account_state(scenario.address_book()).await;
+	assert_eq!(address_book.multisig, scenario.multisig);
+	assert!(address_book.entries.is_empty());
+	assert!(!scenario.multisig_config().await.address_book_enforced);
+}
+
+#[tokio::test]
+async fn create_address_book_as_non_signer() {
+	let mut scenario = MultisigFixture::new().start().await;
+	scenario.add_multisig(&["outsider"], 1).await;
+
+	let result = scenario.create_address_book("outsider").await;
+
+	assert_multisig_wallet_error(result, MultisigWalletError::NotASigner);
+	assert!(!scenario.account_exists(scenario.address_book()).await);
+}
+
+#[tokio::test]
+async fn create_address_book_twice() {
+	let mut scenario = MultisigFixture::new().start().await;
+	scenario.create_address_book("signer_0").await.unwrap();
+
+	let result = scenario.create_address_book("signer_0").await;
+
+	assert!(result.is_err());
+}
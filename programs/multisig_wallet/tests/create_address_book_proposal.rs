@@ -0,0 +1,54 @@
+pub mod common;
+
+use {
+    common::{error_assertions::assert_multisig_wallet_error, multisig_fixture::MultisigFixture},
+    solana_program_test::tokio,
+    solana_sdk::{pubkey::Pubkey, signer::Signer},
+};
+use multisig_wallet::{error::MultisigWalletError, AddressBookChange, ProposalKind, MAX_LABEL_LEN};
+
+
+#[tokio::test]
+async fn create_address_book_proposal_ix_success() {
+	let mut scenario = MultisigFixture::new()
+		.signers(2)
+		.start()
+		.await;
+
+	// DATA
+	let change = AddressBookChange::AddEntry { address: Pubkey::new_unique(), label: "payroll".to_string() };
+
+	// INSTRUCTIONS
+	let result = scenario.propose_address_book_change("signer_1", change.clone()).await;
+
+	// ASSERTIONS
+	let proposal_id = result.unwrap();
+	let proposal = scenario.proposal_state(proposal_id).await;
+	assert_eq!(proposal.id, proposal_id);
+	assert_eq!(proposal.creator, scenario.member("signer_1").pubkey());
+	assert_eq!(proposal.kind().unwrap(), ProposalKind::AddressBook(change));
+	assert!(!proposal.executed());
+
+	assert_eq!(scenario.next_proposal_id().await, proposal_id + 1);
+}
+
+#[tokio::test]
+async fn create_address_book_proposal_with_too_long_label() {
+	let mut scenario = MultisigFixture::new().start().await;
+	let change = AddressBookChange::AddEntry { address: Pubkey::new_unique(), label: "l".repeat(MAX_LABEL_LEN + 1) };
+
+	let result = scenario.propose_address_book_change("signer_0", change).await;
+
+	assert_multisig_wallet_error(result.map(|_| ()), MultisigWalletError::LabelTooLong);
+	assert_eq!(scenario.next_proposal_id().await, 0);
+}
+
+#[tokio::test]
+async fn create_address_book_proposal_as_non_signer() {
+	let mut scenario = MultisigFixture::new().start().await;
+	scenario.add_multisig(&["outsider"], 1).await;
+
+	let result = scenario.propose_address_book_change("outsider", AddressBookChange::SetEnforced { enforced: true }).await;
+
+	assert_multisig_wallet_error(result.map(|_| ()), MultisigWalletError::NotASigner);
+}
@@ -0,0 +1,78 @@
+pub mod common;
+
+use {
+    common::{error_assertions::assert_multisig_wallet_error, multisig_fixture::MultisigFixture},
+    solana_program_test::tokio,
+    solana_sdk::pubkey::Pubkey,
+};
+use multisig_wallet::{error::MultisigWalletError, ProposalKind, VestingStreamTerms};
+
+const TOTAL_AMOUNT: u64 = 1_000;
+
+fn grant(mint: Pubkey, start_time: i64) -> VestingStreamTerms {
+	VestingStreamTerms {
+		recipient: Pubkey::new_unique(),
+		mint,
+		total_amount: TOTAL_AMOUNT,
+		start_time,
+		cliff_time: start_time,
+		end_time: start_time + 1_000,
+	}
+}
+
+
+#[tokio::test]
+async fn create_cancel_stream_proposal_ix_success() {
+	let mint = Pubkey::new_unique();
+	let mut scenario = MultisigFixture::new()
+		.vault_index(2)
+		.funded_vault(mint, TOTAL_AMOUNT)
+		.start()
+		.await;
+	let now = scenario.now().await;
+	let stream_id = scenario.open_vesting_stream(grant(mint, now)).await.unwrap();
+
+	// INSTRUCTIONS
+	let result = scenario.propose_cancel_stream("signer_0", stream_id).await;
+
+	// ASSERTIONS
+	let proposal_id = result.unwrap();
+	let proposal = scenario.proposal_state(proposal_id).await;
+	assert_eq!(proposal.kind().unwrap(), ProposalKind::CancelStream { stream_id });
+	assert_eq!(proposal.vault_index, 2);
+	assert_eq!(scenario.next_proposal_id().await, proposal_id + 1);
+}
+
+#[tokio::test]
+async fn create_cancel_stream_proposal_of_cancelled_stream() {
+	let mint = Pubkey::new_unique();
+	let mut scenario = MultisigFixture::new()
+		.funded_vault(mint, TOTAL_AMOUNT)
+		.start()
+		.await;
+	let now = scenario.now().await;
+	let stream_id = scenario.open_vesting_stream(grant(mint, now)).await.unwrap();
+	let proposal_id = scenario.propose_cancel_stream("signer_0", stream_id).await.unwrap();
+	scenario.approve("signer_0", proposal_id).await.unwrap();
+	scenario.cancel_stream(proposal_id, stream_id, mint).await.unwrap();
+
+	let result = scenario.propose_cancel_stream("signer_0", stream_id).await;
+
+	assert_multisig_wallet_error(result.map(|_| ()), MultisigWalletError::StreamCancelled);
+}
+
+#[tokio::test]
+async fn create_cancel_stream_proposal_as_non_signer() {
+	let mint = Pubkey::new_unique();
+	let mut scenario = MultisigFixture::new()
+		.funded_vault(mint, TOTAL_AMOUNT)
+		.start()
+		.await;
+	let now = scenario.now().await;
+	let stream_id = scenario.open_vesting_stream(grant(mint, now)).await.unwrap();
+	scenario.add_multisig(&["outsider"], 1).await;
+
+	let result = scenario.propose_cancel_stream("outsider", stream_id).await;
+
+	assert_multisig_wallet_error(result.map(|_| ()), MultisigWalletError::NotASigner);
+}
@@ -1,87 +1,61 @@
 pub mod common;
 
-use std::str::FromStr;
 use {
     common::{
-		get_program_test,
+		multisig_fixture::MultisigFixture,
 		multisig_wallet_ix_interface,
-		csl_spl_token_ix_interface,
-		csl_spl_assoc_token_ix_interface,
 	},
     solana_program_test::tokio,
-    solana_sdk::{
-        account::Account, pubkey::Pubkey, rent::Rent, signature::Keypair, signer::Signer, system_program,
-    },
+    solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer, system_program},
 };
+use multisig_wallet::MultisigConfig;
 
 
 #[tokio::test]
 async fn create_multisig_ix_success() {
-	let mut program_test = get_program_test();
-
-	// PROGRAMS
-	program_test.prefer_bpf(true);
-
-	program_test.add_program(
-		"account_compression",
-		Pubkey::from_str("cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK").unwrap(),
-		None,
-	);
-
-	program_test.add_program(
-		"noop",
-		Pubkey::from_str("noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV").unwrap(),
-		None,
-	);
+	let mut scenario = MultisigFixture::new().start().await;
 
 	// DATA
-	let signers: Vec<Pubkey> = vec![Pubkey::default()];
-	let threshold: u8 = Default::default();
-
-	// KEYPAIR
-	let fee_payer_keypair = Keypair::new();
-
-	// PUBKEY
-	let fee_payer_pubkey = fee_payer_keypair.pubkey();
-
-	// EXECUTABLE PUBKEY
-	let system_program_pubkey = Pubkey::from_str("11111111111111111111111111111111").unwrap();
-
-	// PDA
-	let (multisig_pda, _multisig_pda_bump) = Pubkey::find_program_address(
+	let signers: Vec<Pubkey> = vec![Pubkey::new_unique(), Pubkey::new_unique()];
+	let threshold: u8 = 2;
+	let max_proposal_lifetime: i64 = 3_600;
+	let guardian = Pubkey::new_unique();
+	let cancel_threshold: u8 = 1;
+	let create_key = Keypair::new();
+
+	let (multisig, multisig_bump) = Pubkey::find_program_address(
 		&[
 			b"multisig",
+			create_key.pubkey().as_ref(),
 		],
 		&multisig_wallet::ID,
 	);
 
-	// ACCOUNT PROGRAM TEST SETUP
-	program_test.add_account(
-		fee_payer_pubkey,
-		Account {
-			lamports: 1_000_000_000_000,
-			data: vec![],
-			owner: system_program::ID,
-			executable: false,
-			rent_epoch: 0,
-		},
-	);
-
 	// INSTRUCTIONS
-	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
-
-	let ix = multisig_wallet_ix_interface::create_multisig_ix_setup(
-		&fee_payer_keypair,
-		multisig_pda,
-		system_program_pubkey,
-		signers,
+	let result = scenario.process(|scenario| multisig_wallet_ix_interface::create_multisig_ix_setup(
+		&scenario.context.payer,
+		&create_key,
+		multisig,
+		system_program::ID,
+		signers.clone(),
 		threshold,
-		recent_blockhash,
-	);
-
-	let result = banks_client.process_transaction(ix).await;
+		max_proposal_lifetime,
+		Some(guardian),
+		cancel_threshold,
+		scenario.context.last_blockhash,
+	)).await;
 
 	// ASSERTIONS
 	assert!(result.is_ok());
 
+	let multisig: MultisigConfig = scenario.account_state(multisig).await;
+	assert_eq!(multisig.version, MultisigConfig::CURRENT_VERSION);
+	assert_eq!(multisig.signers, signers);
+	assert_eq!(multisig.threshold, threshold);
+	assert_eq!(multisig.max_proposal_lifetime, max_proposal_lifetime);
+	assert_eq!(multisig.guardian, Some(guardian));
+	assert_eq!(multisig.cancel_threshold, cancel_threshold);
+	assert_eq!(multisig.create_key, create_key.pubkey());
+	assert_eq!(multisig.nonce, multisig_bump);
+	assert_eq!(multisig.proposal_count, 0);
 }
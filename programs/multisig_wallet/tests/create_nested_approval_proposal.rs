@@ -0,0 +1,58 @@
+pub mod common;
+
+use {
+    common::{error_assertions::assert_multisig_wallet_error, multisig_fixture::MultisigFixture},
+    solana_program_test::tokio,
+};
+use multisig_wallet::{error::MultisigWalletError, MultisigConfig, ProposalKind};
+
+
+#[tokio::test]
+async fn create_nested_approval_proposal_ix_success() {
+	let mut scenario = MultisigFixture::new()
+		.signers(2)
+		.vault_index(4)
+		.start()
+		.await;
+	let parent = scenario.add_parent_multisig(&["board_0"], 2).await;
+	let parent_proposal_id = scenario.propose_parent_transfer(parent, "board_0").await.unwrap();
+
+	// INSTRUCTIONS
+	let result = scenario.propose_nested_approval("signer_1", parent, parent_proposal_id).await;
+
+	// ASSERTIONS
+	let proposal_id = result.unwrap();
+	let proposal = scenario.proposal_state(proposal_id).await;
+	assert_eq!(
+		proposal.kind().unwrap(),
+		ProposalKind::NestedApproval { parent_multisig: parent, parent_proposal_id },
+	);
+	assert_eq!(proposal.vault_index, 4);
+	assert_eq!(proposal.vault_nonce, MultisigConfig::vault_nonce(&scenario.multisig, 4));
+	assert_eq!(scenario.next_proposal_id().await, proposal_id + 1);
+}
+
+#[tokio::test]
+async fn create_nested_approval_proposal_for_multisig_without_vault() {
+	let mut scenario = MultisigFixture::new().start().await;
+
+	// The vault is not a signer of this multisig
+	let other = scenario.add_multisig(&["board_0"], 1).await;
+	let other_proposal_id = scenario.propose_parent_transfer(other, "board_0").await.unwrap();
+
+	let result = scenario.propose_nested_approval("signer_0", other, other_proposal_id).await;
+
+	assert_multisig_wallet_error(result.map(|_| ()), MultisigWalletError::NotASigner);
+}
+
+#[tokio::test]
+async fn create_nested_approval_proposal_as_non_signer() {
+	let mut scenario = MultisigFixture::new().start().await;
+	let parent = scenario.add_parent_multisig(&["board_0"], 2).await;
+	let parent_proposal_id = scenario.propose_parent_transfer(parent, "board_0").await.unwrap();
+
+	// Signers of the parent are not signers of the child
+	let result = scenario.propose_nested_approval("board_0", parent, parent_proposal_id).await;
+
+	assert_multisig_wallet_error(result.map(|_| ()), MultisigWalletError::NotASigner);
+}
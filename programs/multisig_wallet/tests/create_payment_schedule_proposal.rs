@@ -0,0 +1,89 @@
+pub mod common;
+
+use {
+    common::{error_assertions::assert_multisig_wallet_error, multisig_fixture::MultisigFixture},
+    solana_program_test::tokio,
+    solana_sdk::{pubkey::Pubkey, signer::Signer},
+};
+use multisig_wallet::{error::MultisigWalletError, AddressBookChange, PaymentScheduleTerms, ProposalKind};
+
+fn monthly_payroll(destination: Pubkey) -> PaymentScheduleTerms {
+	PaymentScheduleTerms {
+		destination,
+		mint: Pubkey::new_unique(),
+		amount: 500,
+		interval: 30 * 24 * 60 * 60,
+		payment_count: 12,
+		start_time: 0,
+	}
+}
+
+
+#[tokio::test]
+async fn create_payment_schedule_proposal_ix_success() {
+	let mut scenario = MultisigFixture::new()
+		.signers(2)
+		.start()
+		.await;
+
+	// DATA
+	let terms = monthly_payroll(Pubkey::new_unique());
+
+	// INSTRUCTIONS
+	let result = scenario.propose_payment_schedule("signer_1", terms.clone()).await;
+
+	// ASSERTIONS
+	let proposal_id = result.unwrap();
+	let proposal = scenario.proposal_state(proposal_id).await;
+	assert_eq!(proposal.kind().unwrap(), ProposalKind::PaymentSchedule(terms.clone()));
+	assert_eq!(proposal.creator, scenario.member("signer_1").pubkey());
+	assert_eq!(proposal.destination, terms.destination);
+	assert_eq!(scenario.next_proposal_id().await, proposal_id + 1);
+}
+
+#[tokio::test]
+async fn create_payment_schedule_proposal_with_invalid_terms() {
+	let mut scenario = MultisigFixture::new().start().await;
+
+	for terms in [
+		PaymentScheduleTerms { amount: 0, ..monthly_payroll(Pubkey::new_unique()) },
+		PaymentScheduleTerms { interval: 0, ..monthly_payroll(Pubkey::new_unique()) },
+		PaymentScheduleTerms { payment_count: 0, ..monthly_payroll(Pubkey::new_unique()) },
+		PaymentScheduleTerms { start_time: -1, ..monthly_payroll(Pubkey::new_unique()) },
+	] {
+		let result = scenario.propose_payment_schedule("signer_0", terms).await;
+
+		assert_multisig_wallet_error(result.map(|_| ()), MultisigWalletError::InvalidPaymentSchedule);
+	}
+}
+
+#[tokio::test]
+async fn create_payment_schedule_proposal_outside_address_book() {
+	let mut scenario = MultisigFixture::new().start().await;
+	let listed = Pubkey::new_unique();
+	scenario.create_address_book("signer_0").await.unwrap();
+	for change in [
+		AddressBookChange::AddEntry { address: listed, label: "payroll".to_string() },
+		AddressBookChange::SetEnforced { enforced: true },
+	] {
+		let proposal_id = scenario.propose_address_book_change("signer_0", change).await.unwrap();
+		scenario.approve("signer_0", proposal_id).await.unwrap();
+		scenario.execute_address_book_proposal(proposal_id).await.unwrap();
+	}
+
+	let result = scenario.propose_payment_schedule("signer_0", monthly_payroll(Pubkey::new_unique())).await;
+	assert_multisig_wallet_error(result.map(|_| ()), MultisigWalletError::DestinationNotAllowed);
+
+	let result = scenario.propose_payment_schedule("signer_0", monthly_payroll(listed)).await;
+	assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn create_payment_schedule_proposal_as_non_signer() {
+	let mut scenario = MultisigFixture::new().start().await;
+	scenario.add_multisig(&["outsider"], 1).await;
+
+	let result = scenario.propose_payment_schedule("outsider", monthly_payroll(Pubkey::new_unique())).await;
+
+	assert_multisig_wallet_error(result.map(|_| ()), MultisigWalletError::NotASigner);
+}
@@ -0,0 +1,109 @@
+pub mod common;
+
+use {
+    common::{
+		error_assertions::assert_multisig_wallet_error,
+		multisig_fixture::{MultisigFixture, MultisigScenario},
+		state_fixtures,
+	},
+    solana_program_test::tokio,
+    solana_sdk::{hash::hash, pubkey::Pubkey},
+};
+use multisig_wallet::{error::MultisigWalletError, ProgramUpgradeChange, ProposalKind};
+
+const ELF: &[u8] = b"new program bytes";
+
+/// A 2-of-2 multisig whose vault is the upgrade authority of a program, returning the program
+async fn upgradeable() -> (MultisigScenario, Pubkey) {
+	let mut scenario = MultisigFixture::new()
+		.signers(2)
+		.start()
+		.await;
+	let (program, _program_data) = scenario.deploy_vault_program(b"current program bytes");
+
+	(scenario, program)
+}
+
+/// Writes `ELF` to a new buffer written by `authority`
+fn write_buffer(scenario: &mut MultisigScenario, authority: Pubkey) -> Pubkey {
+	let buffer = Pubkey::new_unique();
+	scenario.context.set_account(&buffer, &state_fixtures::buffer_account(authority, ELF).into());
+
+	buffer
+}
+
+
+#[tokio::test]
+async fn create_program_upgrade_proposal_ix_success() {
+	let (mut scenario, program) = upgradeable().await;
+
+	// DATA
+	let buffer = write_buffer(&mut scenario, scenario.vault);
+	let change = ProgramUpgradeChange::Upgrade {
+		program,
+		buffer,
+		spill: Pubkey::new_unique(),
+		buffer_hash: hash(ELF).to_bytes(),
+	};
+
+	// INSTRUCTIONS
+	let result = scenario.propose_program_upgrade("signer_0", change.clone()).await;
+
+	// ASSERTIONS
+	let proposal_id = result.unwrap();
+	let proposal = scenario.proposal_state(proposal_id).await;
+	assert_eq!(proposal.kind().unwrap(), ProposalKind::ProgramUpgrade(change));
+	assert_eq!(proposal.vault_index, scenario.vault_index);
+	assert_eq!(scenario.next_proposal_id().await, proposal_id + 1);
+}
+
+#[tokio::test]
+async fn create_program_upgrade_proposal_with_buffer_hash_mismatch() {
+	let (mut scenario, program) = upgradeable().await;
+	let buffer = write_buffer(&mut scenario, scenario.vault);
+	let change = ProgramUpgradeChange::Upgrade {
+		program,
+		buffer,
+		spill: Pubkey::new_unique(),
+		buffer_hash: hash(b"reviewed program bytes").to_bytes(),
+	};
+
+	let result = scenario.propose_program_upgrade("signer_0", change).await;
+
+	assert_multisig_wallet_error(result.map(|_| ()), MultisigWalletError::BufferHashMismatch);
+}
+
+#[tokio::test]
+async fn create_program_upgrade_proposal_with_buffer_of_other_authority() {
+	let (mut scenario, program) = upgradeable().await;
+
+	// A buffer its writer could still change after the proposal is approved
+	let buffer = write_buffer(&mut scenario, Pubkey::new_unique());
+	let change = ProgramUpgradeChange::Upgrade {
+		program,
+		buffer,
+		spill: Pubkey::new_unique(),
+		buffer_hash: hash(ELF).to_bytes(),
+	};
+
+	let result = scenario.propose_program_upgrade("signer_0", change).await;
+
+	assert_multisig_wallet_error(result.map(|_| ()), MultisigWalletError::InvalidBuffer);
+}
+
+#[tokio::test]
+async fn create_program_upgrade_proposal_for_program_of_other_authority() {
+	let (mut scenario, _program) = upgradeable().await;
+	let program = Pubkey::new_unique();
+	let program_data = solana_sdk::bpf_loader_upgradeable::get_program_data_address(&program);
+	scenario.context.set_account(&program, &state_fixtures::upgradeable_program_account(program_data).into());
+	scenario.context.set_account(
+		&program_data,
+		&state_fixtures::program_data_account(Some(Pubkey::new_unique()), b"current program bytes").into(),
+	);
+
+	let change = ProgramUpgradeChange::SetAuthority { program, new_authority: None };
+	let result = scenario.propose_program_upgrade("signer_0", change).await;
+
+	assert_multisig_wallet_error(result.map(|_| ()), MultisigWalletError::InvalidProgramData);
+}
@@ -1,117 +1,88 @@
 pub mod common;
 
-use std::str::FromStr;
 use {
-    common::{
-		get_program_test,
-		multisig_wallet_ix_interface,
-		csl_spl_token_ix_interface,
-		csl_spl_assoc_token_ix_interface,
-	},
+    common::{error_assertions::assert_multisig_wallet_error, multisig_fixture::MultisigFixture},
     solana_program_test::tokio,
-    solana_sdk::{
-        account::Account, pubkey::Pubkey, rent::Rent, signature::Keypair, signer::Signer, system_program,
-    },
+    solana_sdk::{pubkey::Pubkey, signer::Signer},
 };
+use multisig_wallet::{error::MultisigWalletError, ProposalKind, ProposalMetadata, TransferTerms, MAX_TITLE_LEN};
 
 
 #[tokio::test]
 async fn create_proposal_ix_success() {
-	let mut program_test = get_program_test();
-
-	// PROGRAMS
-	program_test.prefer_bpf(true);
-
-	program_test.add_program(
-		"account_compression",
-		Pubkey::from_str("cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK").unwrap(),
-		None,
-	);
-
-	program_test.add_program(
-		"noop",
-		Pubkey::from_str("noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV").unwrap(),
-		None,
-	);
+	let mut scenario = MultisigFixture::new()
+		.signers(2)
+		.start()
+		.await;
 
 	// DATA
-	let destination: Pubkey = Pubkey::default();
-	let amount: u64 = Default::default();
-	let token_mint: Pubkey = Pubkey::default();
-	let expiration_time: i64 = Default::default();
-	let proposal_seed_proposal_id: u64 = Default::default();
-
-	// KEYPAIR
-	let fee_payer_keypair = Keypair::new();
-	let creator_keypair = Keypair::new();
-
-	// PUBKEY
-	let fee_payer_pubkey = fee_payer_keypair.pubkey();
-	let creator_pubkey = creator_keypair.pubkey();
-
-	// EXECUTABLE PUBKEY
-	let system_program_pubkey = Pubkey::from_str("11111111111111111111111111111111").unwrap();
-
-	// PDA
-	let (multisig_pda, _multisig_pda_bump) = Pubkey::find_program_address(
-		&[
-			b"multisig",
-		],
-		&multisig_wallet::ID,
-	);
-
-	let (proposal_pda, _proposal_pda_bump) = Pubkey::find_program_address(
-		&[
-			b"proposal",
-			multisig_pubkey.as_ref(),
-			proposal_seed_proposal_id.to_le_bytes().as_ref(),
-		],
-		&multisig_wallet::ID,
-	);
-
-	// ACCOUNT PROGRAM TEST SETUP
-	program_test.add_account(
-		fee_payer_pubkey,
-		Account {
-			lamports: 1_000_000_000_000,
-			data: vec![],
-			owner: system_program::ID,
-			executable: false,
-			rent_epoch: 0,
-		},
-	);
-
-	program_test.add_account(
-		creator_pubkey,
-		Account {
-			lamports: 0,
-			data: vec![],
-			owner: system_program::ID,
-			executable: false,
-			rent_epoch: 0,
-		},
-	);
+	let terms = TransferTerms {
+		destination: Pubkey::new_unique(),
+		amount: 400,
+		token_mint: Pubkey::new_unique(),
+	};
+	let metadata = ProposalMetadata {
+		title: Some("Pay the auditors".to_string()),
+		description_uri: Some("https://example.com/proposals/0".to_string()),
+		memo: Some("invoice 42".to_string()),
+	};
 
 	// INSTRUCTIONS
-	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
-
-	let ix = multisig_wallet_ix_interface::create_proposal_ix_setup(
-		&fee_payer_keypair,
-		multisig_pda,
-		proposal_pda,
-		&creator_keypair,
-		system_program_pubkey,
-		destination,
-		amount,
-		token_mint,
-		expiration_time,
-		proposal_seed_proposal_id,
-		recent_blockhash,
-	);
-
-	let result = banks_client.process_transaction(ix).await;
+	let result = scenario.create_proposal("signer_1", 0, terms.clone(), metadata).await;
 
 	// ASSERTIONS
 	assert!(result.is_ok());
 
+	let proposal = scenario.proposal_state(0).await;
+	assert_eq!(proposal.id, 0);
+	assert_eq!(proposal.creator, scenario.member("signer_1").pubkey());
+	assert_eq!(proposal.multisig, scenario.multisig);
+	assert_eq!(proposal.destination, terms.destination);
+	assert_eq!(proposal.amount, terms.amount);
+	assert_eq!(proposal.token_mint, terms.token_mint);
+	assert_eq!(proposal.kind().unwrap(), ProposalKind::Transfer);
+	assert_eq!(proposal.title(), Some("Pay the auditors"));
+	assert_eq!(proposal.description_uri(), Some("https://example.com/proposals/0"));
+	assert_eq!(proposal.memo(), Some("invoice 42"));
+	assert_eq!(proposal.approval_count(), 0);
+
+	assert_eq!(scenario.next_proposal_id().await, 1);
+}
+
+#[tokio::test]
+async fn create_proposal_with_other_proposal_id() {
+	let mut scenario = MultisigFixture::new().start().await;
+	let terms = TransferTerms { destination: Pubkey::new_unique(), amount: 400, token_mint: Pubkey::new_unique() };
+
+	// The PDA of a proposal id other than the multisig's proposal counter
+	let result = scenario.create_proposal("signer_0", 1, terms, ProposalMetadata::default()).await;
+
+	assert_multisig_wallet_error(result, MultisigWalletError::InvalidProposalId);
+	assert_eq!(scenario.next_proposal_id().await, 0);
+}
+
+#[tokio::test]
+async fn create_proposal_as_non_signer() {
+	let mut scenario = MultisigFixture::new().start().await;
+	let other_multisig_signer = "outsider";
+	scenario.add_multisig(&[other_multisig_signer], 1).await;
+	let terms = TransferTerms { destination: Pubkey::new_unique(), amount: 400, token_mint: Pubkey::new_unique() };
+
+	let result = scenario.create_proposal(other_multisig_signer, 0, terms, ProposalMetadata::default()).await;
+
+	assert_multisig_wallet_error(result, MultisigWalletError::NotASigner);
+}
+
+#[tokio::test]
+async fn create_proposal_with_too_long_title() {
+	let mut scenario = MultisigFixture::new().start().await;
+	let terms = TransferTerms { destination: Pubkey::new_unique(), amount: 400, token_mint: Pubkey::new_unique() };
+	let metadata = ProposalMetadata {
+		title: Some("t".repeat(MAX_TITLE_LEN + 1)),
+		..Default::default()
+	};
+
+	let result = scenario.create_proposal("signer_0", 0, terms, metadata).await;
+
+	assert_multisig_wallet_error(result, MultisigWalletError::TitleTooLong);
 }
@@ -0,0 +1,96 @@
+pub mod common;
+
+use {
+    common::{
+		error_assertions::assert_multisig_wallet_error,
+		multisig_fixture::{MultisigFixture, MultisigScenario},
+	},
+    solana_program_test::tokio,
+    solana_sdk::{pubkey::Pubkey, signer::Signer},
+};
+use multisig_wallet::{error::MultisigWalletError, RecoveryRequest, MIN_RECOVERY_DELAY};
+
+/// A 2-of-2 multisig recoverable by 2 of its 3 recovery keys
+async fn recoverable() -> MultisigScenario {
+	let mut scenario = MultisigFixture::new()
+		.signers(2)
+		.start()
+		.await;
+	scenario.configure_recovery(
+		&["signer_0", "signer_1"],
+		&["recovery_0", "recovery_1", "recovery_2"],
+		2,
+		MIN_RECOVERY_DELAY,
+	).await.unwrap();
+
+	scenario
+}
+
+
+#[tokio::test]
+async fn create_recovery_request_ix_success() {
+	let mut scenario = recoverable().await;
+
+	// DATA
+	let lost_signer = scenario.member("signer_1").pubkey();
+	let new_signer = Pubkey::new_unique();
+
+	// INSTRUCTIONS
+	let result = scenario.request_recovery("recovery_0", lost_signer, new_signer).await;
+
+	// ASSERTIONS
+	let recovery_id = result.unwrap();
+	let recovery_request: RecoveryRequest = scenario.account_state(scenario.recovery_request(recovery_id)).await;
+	assert_eq!(recovery_request.id, recovery_id);
+	assert_eq!(recovery_request.multisig, scenario.multisig);
+	assert_eq!(recovery_request.lost_signer, lost_signer);
+	assert_eq!(recovery_request.new_signer, new_signer);
+	assert_eq!(recovery_request.approvals, vec![scenario.member("recovery_0").pubkey()]);
+	assert_eq!(recovery_request.approved_at, 0);
+	assert!(!recovery_request.vetoed);
+	assert!(!recovery_request.executed);
+
+	assert_eq!(scenario.multisig_config().await.recovery_count, recovery_id + 1);
+}
+
+#[tokio::test]
+async fn create_recovery_request_as_signer() {
+	let mut scenario = recoverable().await;
+	let lost_signer = scenario.member("signer_1").pubkey();
+
+	let result = scenario.request_recovery("signer_0", lost_signer, Pubkey::new_unique()).await;
+
+	assert_multisig_wallet_error(result.map(|_| ()), MultisigWalletError::NotARecoveryKey);
+}
+
+#[tokio::test]
+async fn create_recovery_request_for_non_signer() {
+	let mut scenario = recoverable().await;
+
+	let result = scenario.request_recovery("recovery_0", Pubkey::new_unique(), Pubkey::new_unique()).await;
+
+	assert_multisig_wallet_error(result.map(|_| ()), MultisigWalletError::InvalidRecoveryTarget);
+}
+
+#[tokio::test]
+async fn create_recovery_request_to_current_signer() {
+	let mut scenario = recoverable().await;
+	let lost_signer = scenario.member("signer_1").pubkey();
+	let new_signer = scenario.member("signer_0").pubkey();
+
+	let result = scenario.request_recovery("recovery_0", lost_signer, new_signer).await;
+
+	assert_multisig_wallet_error(result.map(|_| ()), MultisigWalletError::InvalidRecoveryTarget);
+}
+
+#[tokio::test]
+async fn create_recovery_request_with_other_recovery_id() {
+	let mut scenario = recoverable().await;
+	let lost_signer = scenario.member("signer_1").pubkey();
+
+	// The PDA of a recovery id other than the multisig's recovery counter
+	let result = scenario.create_recovery_request("recovery_0", 1, lost_signer, Pubkey::new_unique()).await;
+
+	assert_multisig_wallet_error(result, MultisigWalletError::InvalidRecoveryId);
+	assert_eq!(scenario.multisig_config().await.recovery_count, 0);
+}
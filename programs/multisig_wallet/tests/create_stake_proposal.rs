@@ -0,0 +1,70 @@
+pub mod common;
+
+use {
+    common::{error_assertions::assert_multisig_wallet_error, multisig_fixture::MultisigFixture},
+    solana_program_test::tokio,
+    solana_sdk::pubkey::Pubkey,
+};
+use multisig_wallet::{error::MultisigWalletError, AddressBookChange, MultisigConfig, ProposalKind, StakeChange};
+
+
+#[tokio::test]
+async fn create_stake_proposal_ix_success() {
+	let mut scenario = MultisigFixture::new()
+		.signers(2)
+		.vault_index(3)
+		.start()
+		.await;
+
+	// DATA
+	let change = StakeChange::Create { lamports: 2_000_000_000 };
+
+	// INSTRUCTIONS
+	let result = scenario.propose_stake_change("signer_1", change.clone()).await;
+
+	// ASSERTIONS
+	let proposal_id = result.unwrap();
+	let proposal = scenario.proposal_state(proposal_id).await;
+	assert_eq!(proposal.kind().unwrap(), ProposalKind::Stake(change));
+	assert_eq!(proposal.vault_index, 3);
+	assert_eq!(proposal.vault_nonce, MultisigConfig::vault_nonce(&scenario.multisig, 3));
+	assert_eq!(scenario.next_proposal_id().await, proposal_id + 1);
+}
+
+#[tokio::test]
+async fn create_stake_proposal_withdrawing_outside_address_book() {
+	let mut scenario = MultisigFixture::new().start().await;
+	let listed = Pubkey::new_unique();
+	scenario.create_address_book("signer_0").await.unwrap();
+	for change in [
+		AddressBookChange::AddEntry { address: listed, label: "cold wallet".to_string() },
+		AddressBookChange::SetEnforced { enforced: true },
+	] {
+		let proposal_id = scenario.propose_address_book_change("signer_0", change).await.unwrap();
+		scenario.approve("signer_0", proposal_id).await.unwrap();
+		scenario.execute_address_book_proposal(proposal_id).await.unwrap();
+	}
+	let stake_account = Pubkey::new_unique();
+
+	let result = scenario.propose_stake_change(
+		"signer_0",
+		StakeChange::Withdraw { stake_account, destination: Pubkey::new_unique(), lamports: 1 },
+	).await;
+	assert_multisig_wallet_error(result.map(|_| ()), MultisigWalletError::DestinationNotAllowed);
+
+	let result = scenario.propose_stake_change(
+		"signer_0",
+		StakeChange::Withdraw { stake_account, destination: listed, lamports: 1 },
+	).await;
+	assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn create_stake_proposal_as_non_signer() {
+	let mut scenario = MultisigFixture::new().start().await;
+	scenario.add_multisig(&["outsider"], 1).await;
+
+	let result = scenario.propose_stake_change("outsider", StakeChange::Create { lamports: 2_000_000_000 }).await;
+
+	assert_multisig_wallet_error(result.map(|_| ()), MultisigWalletError::NotASigner);
+}
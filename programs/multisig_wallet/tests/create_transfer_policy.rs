@@ -0,0 +1,38 @@
+pub mod common;
+
+use {
+    common::{error_assertions::assert_multisig_wallet_error, multisig_fixture::MultisigFixture},
+    solana_program_test::tokio,
+};
+use multisig_wallet::{error::MultisigWalletError, TransferPolicy};
+
+
+#[tokio::test]
+async fn create_transfer_policy_ix_success() {
+	let mut scenario = MultisigFixture::new()
+		.signers(2)
+		.start()
+		.await;
+
+	// INSTRUCTIONS
+	let result = scenario.create_transfer_policy("signer_1").await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+
+	let transfer_policy: TransferPolicy = scenario.account_state(scenario.transfer_policy()).await;
+	assert_eq!(transfer_policy.multisig, scenario.multisig);
+	assert!(transfer_policy.limits.is_empty());
+	assert!(scenario.multisig_config().await.transfer_policy_enabled);
+}
+
+#[tokio::test]
+async fn create_transfer_policy_as_non_signer() {
+	let mut scenario = MultisigFixture::new().start().await;
+	scenario.add_multisig(&["outsider"], 1).await;
+
+	let result = scenario.create_transfer_policy("outsider").await;
+
+	assert_multisig_wallet_error(result, MultisigWalletError::NotASigner);
+	assert!(!scenario.multisig_config().await.transfer_policy_enabled);
+}
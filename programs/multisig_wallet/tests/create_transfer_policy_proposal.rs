@@ -0,0 +1,51 @@
+pub mod common;
+
+use {
+    common::{error_assertions::assert_multisig_wallet_error, multisig_fixture::MultisigFixture},
+    solana_program_test::tokio,
+    solana_sdk::{pubkey::Pubkey, signer::Signer},
+};
+use multisig_wallet::{error::MultisigWalletError, ProposalKind, TransferPolicyChange};
+
+
+#[tokio::test]
+async fn create_transfer_policy_proposal_ix_success() {
+	let mut scenario = MultisigFixture::new()
+		.signers(2)
+		.start()
+		.await;
+
+	// DATA
+	let change = TransferPolicyChange::SetLimit {
+		mint: Pubkey::new_unique(),
+		max_transfer_amount: 1_000_000,
+		max_window_volume: 5_000_000,
+	};
+
+	// INSTRUCTIONS
+	let result = scenario.propose_transfer_policy_change("signer_1", change.clone()).await;
+
+	// ASSERTIONS
+	let proposal_id = result.unwrap();
+	let proposal = scenario.proposal_state(proposal_id).await;
+	assert_eq!(proposal.id, proposal_id);
+	assert_eq!(proposal.creator, scenario.member("signer_1").pubkey());
+	assert_eq!(proposal.kind().unwrap(), ProposalKind::TransferPolicy(change));
+	assert!(!proposal.executed());
+
+	assert_eq!(scenario.next_proposal_id().await, proposal_id + 1);
+}
+
+#[tokio::test]
+async fn create_transfer_policy_proposal_as_non_signer() {
+	let mut scenario = MultisigFixture::new().start().await;
+	scenario.add_multisig(&["outsider"], 1).await;
+
+	let result = scenario.propose_transfer_policy_change(
+		"outsider",
+		TransferPolicyChange::RemoveLimit { mint: Pubkey::new_unique() },
+	).await;
+
+	assert_multisig_wallet_error(result.map(|_| ()), MultisigWalletError::NotASigner);
+	assert_eq!(scenario.next_proposal_id().await, 0);
+}
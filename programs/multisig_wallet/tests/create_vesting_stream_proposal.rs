@@ -0,0 +1,97 @@
+pub mod common;
+
+use {
+    common::{
+		error_assertions::assert_multisig_wallet_error,
+		multisig_fixture::MultisigFixture,
+		multisig_wallet_ix_interface,
+	},
+    solana_program_test::tokio,
+    solana_sdk::{pubkey::Pubkey, system_program},
+};
+use multisig_wallet::{error::MultisigWalletError, ProposalKind, VestingStreamTerms};
+
+fn four_year_grant(recipient: Pubkey) -> VestingStreamTerms {
+	let year = 365 * 24 * 60 * 60;
+
+	VestingStreamTerms {
+		recipient,
+		mint: Pubkey::new_unique(),
+		total_amount: 48_000,
+		start_time: 1_700_000_000,
+		cliff_time: 1_700_000_000 + year,
+		end_time: 1_700_000_000 + 4 * year,
+	}
+}
+
+
+#[tokio::test]
+async fn create_vesting_stream_proposal_ix_success() {
+	let mut scenario = MultisigFixture::new()
+		.signers(2)
+		.start()
+		.await;
+
+	// DATA
+	let terms = four_year_grant(Pubkey::new_unique());
+
+	// INSTRUCTIONS
+	let result = scenario.propose_vesting_stream("signer_1", terms.clone()).await;
+
+	// ASSERTIONS
+	let proposal_id = result.unwrap();
+	let proposal = scenario.proposal_state(proposal_id).await;
+	assert_eq!(proposal.kind().unwrap(), ProposalKind::VestingStream(terms));
+	assert_eq!(scenario.next_proposal_id().await, proposal_id + 1);
+}
+
+#[tokio::test]
+async fn create_vesting_stream_proposal_with_other_proposal_id() {
+	let mut scenario = MultisigFixture::new().start().await;
+
+	// The stream would be keyed by the seed id, so it must be the id the proposal is given
+	let proposal_id = scenario.next_proposal_id().await + 1;
+	let result = scenario.process(|scenario| multisig_wallet_ix_interface::create_vesting_stream_proposal_ix_setup(
+		&scenario.context.payer,
+		scenario.multisig,
+		scenario.proposal(proposal_id),
+		scenario.member("signer_0"),
+		None,
+		system_program::ID,
+		four_year_grant(Pubkey::new_unique()),
+		scenario.vault_index,
+		0,
+		proposal_id,
+		scenario.context.last_blockhash,
+	)).await;
+
+	assert_multisig_wallet_error(result, MultisigWalletError::InvalidProposalId);
+	assert!(!scenario.account_exists(scenario.proposal(proposal_id)).await);
+}
+
+#[tokio::test]
+async fn create_vesting_stream_proposal_with_invalid_schedule() {
+	let mut scenario = MultisigFixture::new().start().await;
+	let grant = four_year_grant(Pubkey::new_unique());
+
+	for terms in [
+		VestingStreamTerms { total_amount: 0, ..grant.clone() },
+		VestingStreamTerms { end_time: grant.start_time, cliff_time: grant.start_time, ..grant.clone() },
+		VestingStreamTerms { cliff_time: grant.start_time - 1, ..grant.clone() },
+		VestingStreamTerms { cliff_time: grant.end_time + 1, ..grant.clone() },
+	] {
+		let result = scenario.propose_vesting_stream("signer_0", terms).await;
+
+		assert_multisig_wallet_error(result.map(|_| ()), MultisigWalletError::InvalidVestingSchedule);
+	}
+}
+
+#[tokio::test]
+async fn create_vesting_stream_proposal_as_non_signer() {
+	let mut scenario = MultisigFixture::new().start().await;
+	scenario.add_multisig(&["outsider"], 1).await;
+
+	let result = scenario.propose_vesting_stream("outsider", four_year_grant(Pubkey::new_unique())).await;
+
+	assert_multisig_wallet_error(result.map(|_| ()), MultisigWalletError::NotASigner);
+}
@@ -0,0 +1,212 @@
+pub mod common;
+
+use {
+    common::{
+		error_assertions::{assert_anchor_error, assert_multisig_wallet_error},
+		multisig_fixture::{MultisigFixture, MultisigScenario},
+		multisig_wallet_ix_interface,
+	},
+    solana_program_test::tokio,
+    solana_sdk::{account::Account, pubkey::Pubkey, rent::Rent, signer::Signer, system_program},
+};
+use anchor_lang::{error::ErrorCode, AccountDeserialize};
+use anchor_spl::token::spl_token;
+use multisig_wallet::{error::MultisigWalletError, Proposal, ProposalKind};
+
+// Cross-multisig confusion attacks: a member of multisig B ("mallory") tries to act on proposals of
+// multisig A ("alice" and "bob", 2 of 2) by passing B, where she is a signer, as the multisig account.
+// A proposal's PDA is seeded by its multisig, so these fail the seeds constraint; the `has_one =
+// multisig` constraint backs it up for a proposal account whose stored multisig differs from its seed.
+
+const VAULT_BALANCE: u64 = 1_000;
+
+struct Attack {
+	scenario: MultisigScenario,
+	mint: Pubkey,
+	/// Multisig B, whose only signer is mallory (threshold 1)
+	other_multisig: Pubkey,
+	/// Proposal 0 of multisig A, a transfer of 400 approved by alice only
+	proposal_id: u64,
+}
+
+impl Attack {
+	async fn new() -> Self {
+		let mint = Pubkey::new_unique();
+		let mut scenario = MultisigFixture::new()
+			.members(&["alice", "bob"])
+			.threshold(2)
+			.funded_vault(mint, VAULT_BALANCE)
+			.start()
+			.await;
+
+		let other_multisig = scenario.add_multisig(&["mallory"], 1).await;
+
+		let destination = scenario.member("alice").pubkey();
+		scenario.create_token_account(destination, mint);
+		let proposal_id = scenario.propose_transfer("alice", destination, mint, 400).await.unwrap();
+		scenario.approve("alice", proposal_id).await.unwrap();
+
+		Attack { scenario, mint, other_multisig, proposal_id }
+	}
+
+	async fn proposal_state(&mut self) -> Proposal {
+		self.scenario.proposal_state(self.proposal_id).await
+	}
+}
+
+#[tokio::test]
+async fn approve_with_membership_in_other_multisig() {
+	let mut attack = Attack::new().await;
+	let proposal_id = attack.proposal_id;
+	let other_multisig = attack.other_multisig;
+
+	let result = attack.scenario.process(|scenario| multisig_wallet_ix_interface::approve_proposal_ix_setup(
+		&scenario.context.payer,
+		other_multisig,
+		scenario.proposal(proposal_id),
+		scenario.member("mallory"),
+		proposal_id,
+		scenario.context.last_blockhash,
+	)).await;
+
+	assert_anchor_error(result, ErrorCode::ConstraintSeeds);
+	assert_eq!(attack.proposal_state().await.approval_count(), 1);
+}
+
+#[tokio::test]
+async fn approve_as_member_of_other_multisig() {
+	let mut attack = Attack::new().await;
+
+	let result = attack.scenario.approve("mallory", attack.proposal_id).await;
+
+	assert_multisig_wallet_error(result, MultisigWalletError::NotASigner);
+	assert_eq!(attack.proposal_state().await.approval_count(), 1);
+}
+
+#[tokio::test]
+async fn unapprove_with_other_multisig() {
+	let mut attack = Attack::new().await;
+	let proposal_id = attack.proposal_id;
+	let other_multisig = attack.other_multisig;
+
+	let result = attack.scenario.process(|scenario| multisig_wallet_ix_interface::unapprove_proposal_ix_setup(
+		&scenario.context.payer,
+		other_multisig,
+		scenario.proposal(proposal_id),
+		scenario.member("mallory"),
+		proposal_id,
+		scenario.context.last_blockhash,
+	)).await;
+
+	assert_anchor_error(result, ErrorCode::ConstraintSeeds);
+	assert!(attack.proposal_state().await.has_approved(0));
+}
+
+#[tokio::test]
+async fn cancel_with_other_multisig() {
+	let mut attack = Attack::new().await;
+	let proposal_id = attack.proposal_id;
+	let other_multisig = attack.other_multisig;
+
+	let result = attack.scenario.process(|scenario| multisig_wallet_ix_interface::cancel_proposal_ix_setup(
+		&scenario.context.payer,
+		other_multisig,
+		scenario.proposal(proposal_id),
+		scenario.member("mallory"),
+		proposal_id,
+		scenario.context.last_blockhash,
+	)).await;
+
+	assert_anchor_error(result, ErrorCode::ConstraintSeeds);
+
+	let proposal = attack.proposal_state().await;
+	assert!(!proposal.cancelled());
+	assert_eq!(proposal.cancel_vote_count(), 0);
+}
+
+#[tokio::test]
+async fn execute_under_threshold_of_other_multisig() {
+	let mut attack = Attack::new().await;
+	let proposal_id = attack.proposal_id;
+	let other_multisig = attack.other_multisig;
+	let mint = attack.mint;
+
+	// One approval meets B's threshold of 1 but not A's threshold of 2
+	let result = attack.scenario.process(|scenario| {
+		let destination = scenario.member("alice").pubkey();
+
+		multisig_wallet_ix_interface::execute_proposal_ix_setup(
+			&scenario.context.payer,
+			other_multisig,
+			scenario.proposal(proposal_id),
+			scenario.vault,
+			scenario.vault_token_account(&mint),
+			anchor_spl::associated_token::get_associated_token_address(&destination, &mint),
+			&scenario.context.payer,
+			destination,
+			mint,
+			system_program::ID,
+			spl_token::ID,
+			anchor_spl::associated_token::ID,
+			anchor_spl::memo::ID,
+			None,
+			proposal_id,
+			scenario.context.last_blockhash,
+		)
+	}).await;
+
+	assert_anchor_error(result, ErrorCode::ConstraintSeeds);
+	assert!(!attack.proposal_state().await.executed());
+
+	let vault_token_account = attack.scenario.vault_token_account(&mint);
+	assert_eq!(attack.scenario.token_balance(vault_token_account).await, VAULT_BALANCE);
+}
+
+#[tokio::test]
+async fn approve_proposal_linked_to_other_multisig() {
+	let mut attack = Attack::new().await;
+	let other_multisig = attack.other_multisig;
+	let multisig = attack.scenario.multisig;
+
+	// A proposal account at B's proposal 0 address that claims to belong to A
+	let (forged_proposal, forged_proposal_bump) = Pubkey::find_program_address(
+		&[
+			b"proposal",
+			other_multisig.as_ref(),
+			0u64.to_le_bytes().as_ref(),
+		],
+		&multisig_wallet::ID,
+	);
+
+	let mut proposal = Proposal {
+		version: Proposal::CURRENT_VERSION,
+		nonce: forged_proposal_bump,
+		multisig,
+		..Default::default()
+	};
+	proposal.set_kind(&ProposalKind::Transfer).unwrap();
+
+	let data = proposal.to_account_data();
+	attack.scenario.context.set_account(&forged_proposal, &Account {
+		lamports: Rent::default().minimum_balance(data.len()),
+		data,
+		owner: multisig_wallet::ID,
+		executable: false,
+		rent_epoch: 0,
+	}.into());
+
+	let result = attack.scenario.process(|scenario| multisig_wallet_ix_interface::approve_proposal_ix_setup(
+		&scenario.context.payer,
+		other_multisig,
+		forged_proposal,
+		scenario.member("mallory"),
+		0,
+		scenario.context.last_blockhash,
+	)).await;
+
+	assert_multisig_wallet_error(result, MultisigWalletError::AccountMismatch);
+
+	let account = attack.scenario.context.banks_client.get_account(forged_proposal).await.unwrap().unwrap();
+	let forged = Proposal::try_deserialize(&mut account.data.as_slice()).unwrap();
+	assert_eq!(forged.approval_count(), 0);
+}
@@ -0,0 +1,120 @@
+pub mod common;
+
+use {
+    common::{
+		error_assertions::assert_multisig_wallet_error,
+		multisig_fixture::{MultisigFixture, MultisigScenario},
+	},
+    solana_program_test::tokio,
+    solana_sdk::pubkey::Pubkey,
+};
+use multisig_wallet::{error::MultisigWalletError, AddressBook, AddressBookChange};
+
+/// A 2-of-2 multisig with its address book created
+async fn start() -> MultisigScenario {
+	let mut scenario = MultisigFixture::new()
+		.signers(2)
+		.threshold(2)
+		.start()
+		.await;
+	scenario.create_address_book("signer_0").await.unwrap();
+
+	scenario
+}
+
+/// Proposes `change`, approves it by both signers and executes it
+async fn apply(scenario: &mut MultisigScenario, change: AddressBookChange) {
+	let proposal_id = scenario.propose_address_book_change("signer_0", change).await.unwrap();
+	scenario.approve_as(&["signer_0", "signer_1"], proposal_id).await.unwrap();
+	scenario.execute_address_book_proposal(proposal_id).await.unwrap();
+}
+
+async fn address_book(scenario: &mut MultisigScenario) -> AddressBook {
+	scenario.account_state(scenario.address_book()).await
+}
+
+
+#[tokio::test]
+async fn execute_address_book_proposal_ix_success() {
+	let mut scenario = start().await;
+
+	// DATA
+	let address = Pubkey::new_unique();
+	let proposal_id = scenario.propose_address_book_change(
+		"signer_0",
+		AddressBookChange::AddEntry { address, label: "payroll".to_string() },
+	).await.unwrap();
+	scenario.approve_as(&["signer_0", "signer_1"], proposal_id).await.unwrap();
+
+	// INSTRUCTIONS
+	let result = scenario.execute_address_book_proposal(proposal_id).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+
+	let address_book = address_book(&mut scenario).await;
+	assert_eq!(address_book.entries.len(), 1);
+	assert_eq!(address_book.entries[0].address, address);
+	assert_eq!(address_book.entries[0].label, "payroll");
+	assert!(scenario.proposal_state(proposal_id).await.executed());
+}
+
+#[tokio::test]
+async fn execute_address_book_proposal_removing_entry() {
+	let mut scenario = start().await;
+	let address = Pubkey::new_unique();
+	apply(&mut scenario, AddressBookChange::AddEntry { address, label: "payroll".to_string() }).await;
+
+	apply(&mut scenario, AddressBookChange::RemoveEntry { address }).await;
+
+	assert!(!address_book(&mut scenario).await.contains(&address));
+}
+
+#[tokio::test]
+async fn execute_address_book_proposal_enforcing_destinations() {
+	let mut scenario = start().await;
+	let listed = Pubkey::new_unique();
+	let mint = Pubkey::new_unique();
+	apply(&mut scenario, AddressBookChange::AddEntry { address: listed, label: "payroll".to_string() }).await;
+
+	apply(&mut scenario, AddressBookChange::SetEnforced { enforced: true }).await;
+
+	assert!(scenario.multisig_config().await.address_book_enforced);
+	assert!(scenario.propose_transfer("signer_0", listed, mint, 400).await.is_ok());
+
+	let result = scenario.propose_transfer("signer_0", Pubkey::new_unique(), mint, 400).await;
+	assert_multisig_wallet_error(result.map(|_| ()), MultisigWalletError::DestinationNotAllowed);
+}
+
+#[tokio::test]
+async fn execute_address_book_proposal_without_threshold() {
+	let mut scenario = start().await;
+	let proposal_id = scenario.propose_address_book_change(
+		"signer_0",
+		AddressBookChange::SetEnforced { enforced: true },
+	).await.unwrap();
+	scenario.approve("signer_0", proposal_id).await.unwrap();
+
+	let result = scenario.execute_address_book_proposal(proposal_id).await;
+
+	assert_multisig_wallet_error(result, MultisigWalletError::InsufficientApprovals);
+	assert!(!scenario.multisig_config().await.address_book_enforced);
+}
+
+#[tokio::test]
+async fn execute_address_book_proposal_adding_existing_entry() {
+	let mut scenario = start().await;
+	let address = Pubkey::new_unique();
+	apply(&mut scenario, AddressBookChange::AddEntry { address, label: "payroll".to_string() }).await;
+
+	let proposal_id = scenario.propose_address_book_change(
+		"signer_0",
+		AddressBookChange::AddEntry { address, label: "again".to_string() },
+	).await.unwrap();
+	scenario.approve_as(&["signer_0", "signer_1"], proposal_id).await.unwrap();
+
+	let result = scenario.execute_address_book_proposal(proposal_id).await;
+
+	assert_multisig_wallet_error(result, MultisigWalletError::AddressBookEntryExists);
+	assert_eq!(address_book(&mut scenario).await.entries.len(), 1);
+}
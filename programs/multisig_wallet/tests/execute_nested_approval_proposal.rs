@@ -0,0 +1,90 @@
+pub mod common;
+
+use {
+    common::{
+		error_assertions::assert_multisig_wallet_error,
+		multisig_fixture::{MultisigFixture, MultisigScenario},
+	},
+    solana_program_test::tokio,
+    solana_sdk::pubkey::Pubkey,
+};
+use multisig_wallet::{error::MultisigWalletError, MultisigConfig, Proposal};
+
+/// A 2-of-2 multisig whose vault is the last signer of a 2-of-2 parent multisig with one
+/// proposal, returning the scenario, the parent and its proposal id
+async fn nested() -> (MultisigScenario, Pubkey, u64) {
+	let mut scenario = MultisigFixture::new()
+		.signers(2)
+		.start()
+		.await;
+	let parent = scenario.add_parent_multisig(&["board_0"], 2).await;
+	let parent_proposal_id = scenario.propose_parent_transfer(parent, "board_0").await.unwrap();
+
+	(scenario, parent, parent_proposal_id)
+}
+
+async fn parent_proposal(scenario: &mut MultisigScenario, parent: Pubkey, parent_proposal_id: u64) -> Proposal {
+	let parent_proposal = scenario.proposal_of(parent, parent_proposal_id);
+
+	scenario.account_state(parent_proposal).await
+}
+
+
+#[tokio::test]
+async fn execute_nested_approval_proposal_ix_success() {
+	let (mut scenario, parent, parent_proposal_id) = nested().await;
+	let proposal_id = scenario.propose_nested_approval("signer_0", parent, parent_proposal_id).await.unwrap();
+	scenario.approve_as(&["signer_0", "signer_1"], proposal_id).await.unwrap();
+
+	// INSTRUCTIONS
+	let result = scenario.execute_nested_approval_proposal(proposal_id, parent, parent_proposal_id).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+	assert!(scenario.proposal_state(proposal_id).await.executed());
+
+	// The vault approved the parent proposal in its own signer slot
+	let parent_config: MultisigConfig = scenario.account_state(parent).await;
+	let vault_signer_index = parent_config.signer_index(&scenario.vault).unwrap();
+	let approved_proposal = parent_proposal(&mut scenario, parent, parent_proposal_id).await;
+	assert!(approved_proposal.has_approved(vault_signer_index));
+	assert_eq!(approved_proposal.approval_count(), 1);
+}
+
+#[tokio::test]
+async fn execute_nested_approval_proposal_without_threshold() {
+	let (mut scenario, parent, parent_proposal_id) = nested().await;
+	let proposal_id = scenario.propose_nested_approval("signer_0", parent, parent_proposal_id).await.unwrap();
+	scenario.approve("signer_0", proposal_id).await.unwrap();
+
+	let result = scenario.execute_nested_approval_proposal(proposal_id, parent, parent_proposal_id).await;
+
+	assert_multisig_wallet_error(result, MultisigWalletError::InsufficientApprovals);
+	assert_eq!(parent_proposal(&mut scenario, parent, parent_proposal_id).await.approval_count(), 0);
+}
+
+#[tokio::test]
+async fn execute_nested_approval_proposal_on_other_parent() {
+	let (mut scenario, parent, parent_proposal_id) = nested().await;
+	let other_parent = scenario.add_parent_multisig(&["board_1"], 2).await;
+	let other_proposal_id = scenario.propose_parent_transfer(other_parent, "board_1").await.unwrap();
+	let proposal_id = scenario.propose_nested_approval("signer_0", parent, parent_proposal_id).await.unwrap();
+	scenario.approve_as(&["signer_0", "signer_1"], proposal_id).await.unwrap();
+
+	let result = scenario.execute_nested_approval_proposal(proposal_id, other_parent, other_proposal_id).await;
+
+	assert_multisig_wallet_error(result, MultisigWalletError::AccountMismatch);
+	assert_eq!(parent_proposal(&mut scenario, other_parent, other_proposal_id).await.approval_count(), 0);
+}
+
+#[tokio::test]
+async fn execute_nested_approval_proposal_twice() {
+	let (mut scenario, parent, parent_proposal_id) = nested().await;
+	let proposal_id = scenario.propose_nested_approval("signer_0", parent, parent_proposal_id).await.unwrap();
+	scenario.approve_as(&["signer_0", "signer_1"], proposal_id).await.unwrap();
+	scenario.execute_nested_approval_proposal(proposal_id, parent, parent_proposal_id).await.unwrap();
+
+	let result = scenario.execute_nested_approval_proposal(proposal_id, parent, parent_proposal_id).await;
+
+	assert_multisig_wallet_error(result, MultisigWalletError::ProposalAlreadyExecuted);
+}
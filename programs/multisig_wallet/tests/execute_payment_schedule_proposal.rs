@@ -0,0 +1,100 @@
+pub mod common;
+
+use {
+    common::{error_assertions::assert_multisig_wallet_error, multisig_fixture::MultisigFixture},
+    solana_program_test::tokio,
+    solana_sdk::pubkey::Pubkey,
+};
+use multisig_wallet::{error::MultisigWalletError, PaymentSchedule, PaymentScheduleTerms};
+
+const INTERVAL: i64 = 7 * 24 * 60 * 60;
+
+fn weekly_payment() -> PaymentScheduleTerms {
+	PaymentScheduleTerms {
+		destination: Pubkey::new_unique(),
+		mint: Pubkey::new_unique(),
+		amount: 100,
+		interval: INTERVAL,
+		payment_count: 4,
+		start_time: 0,
+	}
+}
+
+
+#[tokio::test]
+async fn execute_payment_schedule_proposal_ix_success() {
+	let mut scenario = MultisigFixture::new()
+		.signers(2)
+		.vault_index(1)
+		.start()
+		.await;
+
+	// DATA
+	let terms = weekly_payment();
+	let proposal_id = scenario.propose_payment_schedule("signer_0", terms.clone()).await.unwrap();
+	scenario.approve_as(&["signer_0", "signer_1"], proposal_id).await.unwrap();
+	let now = scenario.now().await;
+
+	// INSTRUCTIONS
+	let result = scenario.execute_payment_schedule_proposal(proposal_id).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+	assert!(scenario.proposal_state(proposal_id).await.executed());
+
+	let schedule_address = scenario.payment_schedule(proposal_id);
+	let schedule: PaymentSchedule = scenario.account_state(schedule_address).await;
+	assert_eq!(schedule.id, proposal_id);
+	assert_eq!(schedule.multisig, scenario.multisig);
+	assert_eq!(schedule.destination, terms.destination);
+	assert_eq!(schedule.mint, terms.mint);
+	assert_eq!(schedule.amount, terms.amount);
+	assert_eq!(schedule.interval, INTERVAL);
+	assert_eq!(schedule.payment_count, 4);
+	assert_eq!(schedule.payments_made, 0);
+	assert_eq!(schedule.next_payment_time, now);
+	assert_eq!(schedule.vault_index, 1);
+}
+
+#[tokio::test]
+async fn execute_payment_schedule_proposal_starting_later() {
+	let mut scenario = MultisigFixture::new().start().await;
+	let start_time = scenario.now().await + INTERVAL;
+	let proposal_id = scenario.propose_payment_schedule(
+		"signer_0",
+		PaymentScheduleTerms { start_time, ..weekly_payment() },
+	).await.unwrap();
+	scenario.approve("signer_0", proposal_id).await.unwrap();
+
+	scenario.execute_payment_schedule_proposal(proposal_id).await.unwrap();
+
+	let schedule_address = scenario.payment_schedule(proposal_id);
+	let schedule: PaymentSchedule = scenario.account_state(schedule_address).await;
+	assert_eq!(schedule.next_payment_time, start_time);
+}
+
+#[tokio::test]
+async fn execute_payment_schedule_proposal_without_threshold() {
+	let mut scenario = MultisigFixture::new()
+		.signers(2)
+		.start()
+		.await;
+	let proposal_id = scenario.propose_payment_schedule("signer_0", weekly_payment()).await.unwrap();
+	scenario.approve("signer_0", proposal_id).await.unwrap();
+
+	let result = scenario.execute_payment_schedule_proposal(proposal_id).await;
+
+	assert_multisig_wallet_error(result, MultisigWalletError::InsufficientApprovals);
+	assert!(!scenario.account_exists(scenario.payment_schedule(proposal_id)).await);
+}
+
+#[tokio::test]
+async fn execute_payment_schedule_proposal_of_transfer() {
+	let mut scenario = MultisigFixture::new().start().await;
+	let proposal_id = scenario.propose_transfer("signer_0", Pubkey::new_unique(), Pubkey::new_unique(), 100).await.unwrap();
+	scenario.approve("signer_0", proposal_id).await.unwrap();
+
+	let result = scenario.execute_payment_schedule_proposal(proposal_id).await;
+
+	assert_multisig_wallet_error(result, MultisigWalletError::InvalidProposalKind);
+}
@@ -0,0 +1,124 @@
+pub mod common;
+
+use {
+    common::{
+		error_assertions::assert_multisig_wallet_error,
+		multisig_fixture::{MultisigFixture, MultisigScenario},
+		state_fixtures,
+	},
+    solana_program_test::{find_file, read_file, tokio},
+    solana_sdk::{
+        bpf_loader_upgradeable::{self, UpgradeableLoaderState},
+        hash::hash,
+        pubkey::Pubkey,
+    },
+};
+use multisig_wallet::{error::MultisigWalletError, ProgramUpgradeChange};
+
+/// A 2-of-2 multisig whose vault is the upgrade authority of a program running `elf`
+async fn upgradeable(elf: &[u8]) -> (MultisigScenario, Pubkey) {
+	let mut scenario = MultisigFixture::new()
+		.signers(2)
+		.start()
+		.await;
+	let (program, _program_data) = scenario.deploy_vault_program(elf);
+
+	(scenario, program)
+}
+
+/// Proposes `change` and approves it by both signers
+async fn approve_change(scenario: &mut MultisigScenario, change: &ProgramUpgradeChange) -> u64 {
+	let proposal_id = scenario.propose_program_upgrade("signer_0", change.clone()).await.unwrap();
+	scenario.approve_as(&["signer_0", "signer_1"], proposal_id).await.unwrap();
+
+	proposal_id
+}
+
+async fn upgrade_authority(scenario: &mut MultisigScenario, program: Pubkey) -> Option<Pubkey> {
+	let program_data = bpf_loader_upgradeable::get_program_data_address(&program);
+	let account = scenario.context.banks_client.get_account(program_data).await.unwrap().unwrap();
+
+	match account.deserialize_data().unwrap() {
+		UpgradeableLoaderState::ProgramData { upgrade_authority_address, .. } => upgrade_authority_address,
+		state => panic!("not a program data account: {state:?}"),
+	}
+}
+
+
+#[tokio::test]
+async fn execute_program_upgrade_proposal_ix_success() {
+	// The loader verifies the deployed code, so the upgrade deploys this program's own build
+	let elf = read_file(find_file("multisig_wallet.so").unwrap());
+	let (mut scenario, program) = upgradeable(&elf).await;
+
+	// DATA
+	let buffer = Pubkey::new_unique();
+	let spill = Pubkey::new_unique();
+	scenario.context.set_account(&buffer, &state_fixtures::buffer_account(scenario.vault, &elf).into());
+	let buffer_lamports = scenario.lamports(buffer).await;
+
+	let change = ProgramUpgradeChange::Upgrade { program, buffer, spill, buffer_hash: hash(&elf).to_bytes() };
+	let proposal_id = approve_change(&mut scenario, &change).await;
+
+	// INSTRUCTIONS
+	let result = scenario.execute_program_upgrade_proposal(proposal_id, &change).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+	assert!(scenario.proposal_state(proposal_id).await.executed());
+	assert!(!scenario.account_exists(buffer).await);
+	assert_eq!(scenario.lamports(spill).await, buffer_lamports);
+	assert_eq!(upgrade_authority(&mut scenario, program).await, Some(scenario.vault));
+}
+
+#[tokio::test]
+async fn execute_program_upgrade_proposal_setting_authority() {
+	let (mut scenario, program) = upgradeable(b"program bytes").await;
+	let new_authority = Pubkey::new_unique();
+	let change = ProgramUpgradeChange::SetAuthority { program, new_authority: Some(new_authority) };
+	let proposal_id = approve_change(&mut scenario, &change).await;
+
+	let result = scenario.execute_program_upgrade_proposal(proposal_id, &change).await;
+
+	assert!(result.is_ok());
+	assert_eq!(upgrade_authority(&mut scenario, program).await, Some(new_authority));
+}
+
+#[tokio::test]
+async fn execute_program_upgrade_proposal_making_program_immutable() {
+	let (mut scenario, program) = upgradeable(b"program bytes").await;
+	let change = ProgramUpgradeChange::SetAuthority { program, new_authority: None };
+	let proposal_id = approve_change(&mut scenario, &change).await;
+
+	let result = scenario.execute_program_upgrade_proposal(proposal_id, &change).await;
+
+	assert!(result.is_ok());
+	assert_eq!(upgrade_authority(&mut scenario, program).await, None);
+}
+
+#[tokio::test]
+async fn execute_program_upgrade_proposal_without_threshold() {
+	let (mut scenario, program) = upgradeable(b"program bytes").await;
+	let change = ProgramUpgradeChange::SetAuthority { program, new_authority: None };
+	let proposal_id = scenario.propose_program_upgrade("signer_0", change.clone()).await.unwrap();
+	scenario.approve("signer_0", proposal_id).await.unwrap();
+
+	let result = scenario.execute_program_upgrade_proposal(proposal_id, &change).await;
+
+	assert_multisig_wallet_error(result, MultisigWalletError::InsufficientApprovals);
+	assert_eq!(upgrade_authority(&mut scenario, program).await, Some(scenario.vault));
+}
+
+#[tokio::test]
+async fn execute_program_upgrade_proposal_to_other_authority() {
+	let (mut scenario, program) = upgradeable(b"program bytes").await;
+	let change = ProgramUpgradeChange::SetAuthority { program, new_authority: Some(Pubkey::new_unique()) };
+	let proposal_id = approve_change(&mut scenario, &change).await;
+
+	// Passing another new authority account than the approved one
+	let substituted = ProgramUpgradeChange::SetAuthority { program, new_authority: Some(Pubkey::new_unique()) };
+	let result = scenario.execute_program_upgrade_proposal(proposal_id, &substituted).await;
+
+	assert_multisig_wallet_error(result, MultisigWalletError::AccountMismatch);
+	assert_eq!(upgrade_authority(&mut scenario, program).await, Some(scenario.vault));
+}
@@ -1,160 +1,101 @@
 pub mod common;
 
-use std::str::FromStr;
 use {
     common::{
-		get_program_test,
+		error_assertions::assert_multisig_wallet_error,
+		multisig_fixture::MultisigFixture,
 		multisig_wallet_ix_interface,
-		csl_spl_token_ix_interface,
-		csl_spl_assoc_token_ix_interface,
 	},
     solana_program_test::tokio,
-    solana_sdk::{
-        account::Account, pubkey::Pubkey, rent::Rent, signature::Keypair, signer::Signer, system_program,
-    },
+    solana_sdk::{pubkey::Pubkey, system_program},
 };
+use anchor_spl::{associated_token::get_associated_token_address, token::spl_token};
+use multisig_wallet::error::MultisigWalletError;
 
 
 #[tokio::test]
 async fn execute_proposal_ix_success() {
-	let mut program_test = get_program_test();
+	let mint = Pubkey::new_unique();
+	let mut scenario = MultisigFixture::new()
+		.signers(3)
+		.threshold(2)
+		.funded_vault(mint, 1_000)
+		.start()
+		.await;
 
-	// PROGRAMS
-	program_test.prefer_bpf(true);
+	// DATA
+	let destination = Pubkey::new_unique();
+	let destination_token_account = scenario.create_token_account(destination, mint);
 
-	program_test.add_program(
-		"account_compression",
-		Pubkey::from_str("cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK").unwrap(),
-		None,
-	);
+	// INSTRUCTIONS
+	let proposal_id = scenario.propose_transfer("signer_0", destination, mint, 400).await.unwrap();
+	scenario.approve_as(&["signer_0", "signer_1"], proposal_id).await.unwrap();
 
-	program_test.add_program(
-		"noop",
-		Pubkey::from_str("noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV").unwrap(),
-		None,
-	);
+	let result = scenario.execute_transfer(proposal_id, destination, mint).await;
 
-	program_test.add_program(
-		"csl_spl_token",
-		Pubkey::from_str("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA").unwrap(),
-		None,
-	);
+	// ASSERTIONS
+	assert!(result.is_ok());
 
-	program_test.add_program(
-		"csl_spl_assoc_token",
-		Pubkey::from_str("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL").unwrap(),
-		None,
-	);
+	let vault_token_account = scenario.vault_token_account(&mint);
+	assert_eq!(scenario.token_balance(vault_token_account).await, 600);
+	assert_eq!(scenario.token_balance(destination_token_account).await, 400);
+	assert!(scenario.proposal_state(proposal_id).await.executed());
+}
 
-	// DATA
-	let proposal_seed_proposal_id: u64 = Default::default();
-
-	// KEYPAIR
-	let fee_payer_keypair = Keypair::new();
-	let authority_keypair = Keypair::new();
-	let funding_keypair = Keypair::new();
-	let mint_keypair = Keypair::new();
-
-	// PUBKEY
-	let fee_payer_pubkey = fee_payer_keypair.pubkey();
-	let authority_pubkey = authority_keypair.pubkey();
-	let funding_pubkey = funding_keypair.pubkey();
-	let source_pubkey = Pubkey::new_unique();
-	let destination_pubkey = Pubkey::new_unique();
-	let wallet_pubkey = Pubkey::new_unique();
-	let mint_pubkey = mint_keypair.pubkey();
-	let token_program_pubkey = csl_spl_token_ix_interface::ID;
-	let system_program_pubkey = Pubkey::new_unique();
-
-	// EXECUTABLE PUBKEY
-	let csl_spl_token_v0_0_0_pubkey = Pubkey::from_str("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA").unwrap();
-	let csl_spl_assoc_token_v0_0_0_pubkey = Pubkey::from_str("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL").unwrap();
-
-	// PDA
-	let (multisig_pda, _multisig_pda_bump) = Pubkey::find_program_address(
-		&[
-			b"multisig",
-		],
-		&multisig_wallet::ID,
-	);
-
-	let (proposal_pda, _proposal_pda_bump) = Pubkey::find_program_address(
-		&[
-			b"proposal",
-			multisig_pubkey.as_ref(),
-			proposal_seed_proposal_id.to_le_bytes().as_ref(),
-		],
-		&multisig_wallet::ID,
-	);
-
-	let (assoc_token_account_pda, _assoc_token_account_pda_bump) = Pubkey::find_program_address(
-		&[
-			wallet_pubkey.as_ref(),
-			token_program_pubkey.as_ref(),
-			mint_pubkey.as_ref(),
-		],
-		&csl_spl_token_ix_interface::ID,
-	);
-
-	// ACCOUNT PROGRAM TEST SETUP
-	program_test.add_account(
-		fee_payer_pubkey,
-		Account {
-			lamports: 1_000_000_000_000,
-			data: vec![],
-			owner: system_program::ID,
-			executable: false,
-			rent_epoch: 0,
-		},
-	);
-
-	program_test.add_account(
-		authority_pubkey,
-		Account {
-			lamports: 0,
-			data: vec![],
-			owner: system_program::ID,
-			executable: false,
-			rent_epoch: 0,
-		},
-	);
-
-	program_test.add_account(
-		funding_pubkey,
-		Account {
-			lamports: 1_000_000_000_000,
-			data: vec![],
-			owner: system_program::ID,
-			executable: false,
-			rent_epoch: 0,
-		},
-	);
+#[tokio::test]
+async fn execute_proposal_creates_destination_token_account() {
+	let mint = Pubkey::new_unique();
+	let mut scenario = MultisigFixture::new()
+		.funded_vault(mint, 1_000)
+		.start()
+		.await;
+	let destination = Pubkey::new_unique();
+	let destination_token_account = get_associated_token_address(&destination, &mint);
+	assert!(!scenario.account_exists(destination_token_account).await);
 
-	// INSTRUCTIONS
-	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
-
-	let ix = multisig_wallet_ix_interface::execute_proposal_ix_setup(
-		&fee_payer_keypair,
-		multisig_pda,
-		proposal_pda,
-		source_pubkey,
-		destination_pubkey,
-		&authority_keypair,
-		&funding_keypair,
-		assoc_token_account_pda,
-		wallet_pubkey,
-		mint_pubkey,
-		system_program_pubkey,
-		token_program_pubkey,
-		csl_spl_token_v0_0_0_pubkey,
-		csl_spl_assoc_token_v0_0_0_pubkey,
-		proposal_seed_proposal_id,
-		recent_blockhash,
-	);
-
-	let result = banks_client.process_transaction(ix).await;
+	let proposal_id = scenario.propose_transfer("signer_0", destination, mint, 400).await.unwrap();
+	scenario.approve("signer_0", proposal_id).await.unwrap();
+
+	let result = scenario.execute_transfer(proposal_id, destination, mint).await;
 
-	// ASSERTIONS
 	assert!(result.is_ok());
+	assert_eq!(scenario.token_balance(destination_token_account).await, 400);
+}
+
+#[tokio::test]
+async fn execute_proposal_to_other_wallet() {
+	let mint = Pubkey::new_unique();
+	let mut scenario = MultisigFixture::new()
+		.funded_vault(mint, 1_000)
+		.start()
+		.await;
+	let destination = Pubkey::new_unique();
+	let other_wallet = Pubkey::new_unique();
+	let other_token_account = scenario.create_token_account(other_wallet, mint);
+
+	let proposal_id = scenario.propose_transfer("signer_0", destination, mint, 400).await.unwrap();
+	scenario.approve("signer_0", proposal_id).await.unwrap();
+
+	// Paying into another wallet's associated token account than the proposal's destination
+	let result = scenario.process(|scenario| multisig_wallet_ix_interface::execute_proposal_ix_setup(
+		&scenario.context.payer,
+		scenario.multisig,
+		scenario.proposal(proposal_id),
+		scenario.vault,
+		scenario.vault_token_account(&mint),
+		other_token_account,
+		&scenario.context.payer,
+		other_wallet,
+		mint,
+		system_program::ID,
+		spl_token::ID,
+		anchor_spl::associated_token::ID,
+		anchor_spl::memo::ID,
+		None,
+		proposal_id,
+		scenario.context.last_blockhash,
+	)).await;
 
+	assert_multisig_wallet_error(result, MultisigWalletError::AccountMismatch);
+	assert_eq!(scenario.token_balance(other_token_account).await, 0);
 }
@@ -0,0 +1,103 @@
+pub mod common;
+
+use {
+    common::{
+		error_assertions::assert_multisig_wallet_error,
+		multisig_fixture::{MultisigFixture, MultisigScenario},
+	},
+    solana_program_test::tokio,
+    solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer},
+};
+use multisig_wallet::{error::MultisigWalletError, RecoveryRequest, MIN_RECOVERY_DELAY};
+
+/// A 2-of-2 multisig recoverable by 2 of its 2 recovery keys
+async fn recoverable() -> MultisigScenario {
+	let mut scenario = MultisigFixture::new()
+		.signers(2)
+		.start()
+		.await;
+	scenario.configure_recovery(
+		&["signer_0", "signer_1"],
+		&["recovery_0", "recovery_1"],
+		2,
+		MIN_RECOVERY_DELAY,
+	).await.unwrap();
+
+	scenario
+}
+
+/// Requests replacing `signer_1` by `new_signer`, approved by both recovery keys
+async fn approve_replacement(scenario: &mut MultisigScenario, new_signer: Pubkey) -> u64 {
+	let lost_signer = scenario.member("signer_1").pubkey();
+	let recovery_id = scenario.request_recovery("recovery_0", lost_signer, new_signer).await.unwrap();
+	scenario.approve_recovery("recovery_1", recovery_id).await.unwrap();
+
+	recovery_id
+}
+
+
+#[tokio::test]
+async fn execute_recovery_request_ix_success() {
+	let mut scenario = recoverable().await;
+
+	// DATA
+	let new_signer = Keypair::new();
+	let recovery_id = approve_replacement(&mut scenario, new_signer.pubkey()).await;
+	scenario.members.push(("new_signer".to_string(), new_signer));
+
+	// INSTRUCTIONS
+	scenario.warp(MIN_RECOVERY_DELAY).await;
+	let result = scenario.execute_recovery(recovery_id).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+
+	let multisig = scenario.multisig_config().await;
+	assert_eq!(multisig.signers, vec![
+		scenario.member("signer_0").pubkey(),
+		scenario.member("new_signer").pubkey(),
+	]);
+
+	let recovery_request: RecoveryRequest = scenario.account_state(scenario.recovery_request(recovery_id)).await;
+	assert!(recovery_request.executed);
+
+	let result = scenario.propose_transfer("signer_1", Pubkey::new_unique(), Pubkey::new_unique(), 400).await;
+	assert_multisig_wallet_error(result.map(|_| ()), MultisigWalletError::NotASigner);
+	assert!(scenario.propose_transfer("new_signer", Pubkey::new_unique(), Pubkey::new_unique(), 400).await.is_ok());
+}
+
+#[tokio::test]
+async fn execute_recovery_request_during_time_lock() {
+	let mut scenario = recoverable().await;
+	let recovery_id = approve_replacement(&mut scenario, Pubkey::new_unique()).await;
+
+	scenario.warp(MIN_RECOVERY_DELAY - 1).await;
+	let result = scenario.execute_recovery(recovery_id).await;
+
+	assert_multisig_wallet_error(result, MultisigWalletError::RecoveryTimelockActive);
+	assert!(scenario.multisig_config().await.signers.contains(&scenario.member("signer_1").pubkey()));
+}
+
+#[tokio::test]
+async fn execute_recovery_request_under_recovery_threshold() {
+	let mut scenario = recoverable().await;
+	let lost_signer = scenario.member("signer_1").pubkey();
+	let recovery_id = scenario.request_recovery("recovery_0", lost_signer, Pubkey::new_unique()).await.unwrap();
+
+	scenario.warp(MIN_RECOVERY_DELAY).await;
+	let result = scenario.execute_recovery(recovery_id).await;
+
+	assert_multisig_wallet_error(result, MultisigWalletError::RecoveryThresholdNotMet);
+}
+
+#[tokio::test]
+async fn execute_recovery_request_twice() {
+	let mut scenario = recoverable().await;
+	let recovery_id = approve_replacement(&mut scenario, Pubkey::new_unique()).await;
+	scenario.warp(MIN_RECOVERY_DELAY).await;
+	scenario.execute_recovery(recovery_id).await.unwrap();
+
+	let result = scenario.execute_recovery(recovery_id).await;
+
+	assert_multisig_wallet_error(result, MultisigWalletError::RecoveryAlreadyExecuted);
+}
@@ -0,0 +1,171 @@
+pub mod common;
+
+use {
+    common::{
+		error_assertions::assert_multisig_wallet_error,
+		multisig_fixture::{MultisigFixture, MultisigScenario},
+		state_fixtures,
+	},
+    solana_program_test::tokio,
+    solana_sdk::{native_token::LAMPORTS_PER_SOL, pubkey::Pubkey, stake::state::StakeStateV2},
+};
+use multisig_wallet::{error::MultisigWalletError, StakeChange};
+
+const VAULT_LAMPORTS: u64 = 10 * LAMPORTS_PER_SOL;
+const STAKE_LAMPORTS: u64 = 2 * LAMPORTS_PER_SOL;
+
+/// A 2-of-2 multisig whose vault holds `VAULT_LAMPORTS`
+async fn staking() -> MultisigScenario {
+	let mut scenario = MultisigFixture::new()
+		.signers(2)
+		.start()
+		.await;
+	scenario.fund_vault_lamports(VAULT_LAMPORTS);
+
+	scenario
+}
+
+/// Proposes `change` and approves it by both signers
+async fn approve_change(scenario: &mut MultisigScenario, change: StakeChange) -> u64 {
+	let proposal_id = scenario.propose_stake_change("signer_0", change).await.unwrap();
+	scenario.approve_as(&["signer_0", "signer_1"], proposal_id).await.unwrap();
+
+	proposal_id
+}
+
+/// Creates a stake account of `STAKE_LAMPORTS` through a stake proposal, returning its address
+async fn create_stake_account(scenario: &mut MultisigScenario) -> Pubkey {
+	let proposal_id = approve_change(scenario, StakeChange::Create { lamports: STAKE_LAMPORTS }).await;
+	let stake_account = scenario.stake_account(proposal_id);
+	scenario.execute_stake_proposal(proposal_id, stake_account, None, None).await.unwrap();
+
+	stake_account
+}
+
+async fn stake_state(scenario: &mut MultisigScenario, stake_account: Pubkey) -> StakeStateV2 {
+	let account = scenario.context.banks_client.get_account(stake_account).await.unwrap().unwrap();
+
+	account.deserialize_data().unwrap()
+}
+
+/// Delegates `stake_account` to a new vote account, returning the vote account
+async fn delegate(scenario: &mut MultisigScenario, stake_account: Pubkey) -> Pubkey {
+	let vote_account = Pubkey::new_unique();
+	scenario.context.set_account(&vote_account, &state_fixtures::vote_account(Pubkey::new_unique()).into());
+
+	let proposal_id = approve_change(scenario, StakeChange::Delegate { stake_account, vote_account }).await;
+	scenario.execute_stake_proposal(proposal_id, stake_account, Some(vote_account), None).await.unwrap();
+
+	vote_account
+}
+
+
+#[tokio::test]
+async fn execute_stake_proposal_ix_success() {
+	let mut scenario = staking().await;
+
+	// DATA
+	let proposal_id = approve_change(&mut scenario, StakeChange::Create { lamports: STAKE_LAMPORTS }).await;
+	let stake_account = scenario.stake_account(proposal_id);
+
+	// INSTRUCTIONS
+	let result = scenario.execute_stake_proposal(proposal_id, stake_account, None, None).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+	assert!(scenario.proposal_state(proposal_id).await.executed());
+	assert_eq!(scenario.lamports(scenario.vault).await, VAULT_LAMPORTS - STAKE_LAMPORTS);
+	assert_eq!(scenario.lamports(stake_account).await, STAKE_LAMPORTS);
+
+	let StakeStateV2::Initialized(meta) = stake_state(&mut scenario, stake_account).await else {
+		panic!("stake account not initialized");
+	};
+	assert_eq!(meta.authorized.staker, scenario.vault);
+	assert_eq!(meta.authorized.withdrawer, scenario.vault);
+}
+
+#[tokio::test]
+async fn execute_stake_proposal_creating_other_stake_account() {
+	let mut scenario = staking().await;
+	let proposal_id = approve_change(&mut scenario, StakeChange::Create { lamports: STAKE_LAMPORTS }).await;
+
+	// The stake account address of another proposal
+	let other_stake_account = scenario.stake_account(proposal_id + 1);
+	let result = scenario.execute_stake_proposal(proposal_id, other_stake_account, None, None).await;
+
+	assert_multisig_wallet_error(result, MultisigWalletError::AccountMismatch);
+	assert_eq!(scenario.lamports(scenario.vault).await, VAULT_LAMPORTS);
+}
+
+#[tokio::test]
+async fn execute_stake_proposal_delegating() {
+	let mut scenario = staking().await;
+	let stake_account = create_stake_account(&mut scenario).await;
+
+	let vote_account = delegate(&mut scenario, stake_account).await;
+
+	let StakeStateV2::Stake(_meta, stake, _flags) = stake_state(&mut scenario, stake_account).await else {
+		panic!("stake account not delegated");
+	};
+	assert_eq!(stake.delegation.voter_pubkey, vote_account);
+	assert_eq!(stake.delegation.deactivation_epoch, u64::MAX);
+}
+
+#[tokio::test]
+async fn execute_stake_proposal_deactivating() {
+	let mut scenario = staking().await;
+	let stake_account = create_stake_account(&mut scenario).await;
+	delegate(&mut scenario, stake_account).await;
+
+	let proposal_id = approve_change(&mut scenario, StakeChange::Deactivate { stake_account }).await;
+	let result = scenario.execute_stake_proposal(proposal_id, stake_account, None, None).await;
+
+	assert!(result.is_ok());
+
+	let StakeStateV2::Stake(_meta, stake, _flags) = stake_state(&mut scenario, stake_account).await else {
+		panic!("stake account not delegated");
+	};
+	assert_ne!(stake.delegation.deactivation_epoch, u64::MAX);
+}
+
+#[tokio::test]
+async fn execute_stake_proposal_withdrawing() {
+	let mut scenario = staking().await;
+	let stake_account = create_stake_account(&mut scenario).await;
+	let destination = Pubkey::new_unique();
+
+	let change = StakeChange::Withdraw { stake_account, destination, lamports: LAMPORTS_PER_SOL };
+	let proposal_id = approve_change(&mut scenario, change).await;
+	let result = scenario.execute_stake_proposal(proposal_id, stake_account, None, Some(destination)).await;
+
+	assert!(result.is_ok());
+	assert_eq!(scenario.lamports(destination).await, LAMPORTS_PER_SOL);
+	assert_eq!(scenario.lamports(stake_account).await, STAKE_LAMPORTS - LAMPORTS_PER_SOL);
+}
+
+#[tokio::test]
+async fn execute_stake_proposal_withdrawing_to_other_destination() {
+	let mut scenario = staking().await;
+	let stake_account = create_stake_account(&mut scenario).await;
+
+	let change = StakeChange::Withdraw { stake_account, destination: Pubkey::new_unique(), lamports: LAMPORTS_PER_SOL };
+	let proposal_id = approve_change(&mut scenario, change).await;
+	let other_destination = Pubkey::new_unique();
+	let result = scenario.execute_stake_proposal(proposal_id, stake_account, None, Some(other_destination)).await;
+
+	assert_multisig_wallet_error(result, MultisigWalletError::AccountMismatch);
+	assert_eq!(scenario.lamports(stake_account).await, STAKE_LAMPORTS);
+}
+
+#[tokio::test]
+async fn execute_stake_proposal_without_threshold() {
+	let mut scenario = staking().await;
+	let proposal_id = scenario.propose_stake_change("signer_0", StakeChange::Create { lamports: STAKE_LAMPORTS }).await.unwrap();
+	scenario.approve("signer_0", proposal_id).await.unwrap();
+
+	let stake_account = scenario.stake_account(proposal_id);
+	let result = scenario.execute_stake_proposal(proposal_id, stake_account, None, None).await;
+
+	assert_multisig_wallet_error(result, MultisigWalletError::InsufficientApprovals);
+	assert!(!scenario.account_exists(stake_account).await);
+}
@@ -0,0 +1,166 @@
+pub mod common;
+
+use {
+    common::{
+		error_assertions::assert_multisig_wallet_error,
+		multisig_fixture::{MultisigFixture, MultisigScenario},
+	},
+    solana_program_test::{tokio, BanksClientError},
+    solana_sdk::pubkey::Pubkey,
+};
+use multisig_wallet::{
+	error::MultisigWalletError, TransferPolicy, TransferPolicyChange, TRANSFER_VOLUME_BUCKET, TRANSFER_VOLUME_WINDOW,
+};
+
+const VAULT_BALANCE: u64 = 10_000;
+const MAX_WINDOW_VOLUME: u64 = 1_000;
+
+struct Policy {
+	scenario: MultisigScenario,
+	mint: Pubkey,
+	destination: Pubkey,
+}
+
+impl Policy {
+	/// A 1-of-1 multisig with a funded vault and its transfer policy created, without limits
+	async fn new() -> Self {
+		let mint = Pubkey::new_unique();
+		let mut scenario = MultisigFixture::new()
+			.funded_vault(mint, VAULT_BALANCE)
+			.start()
+			.await;
+		scenario.create_transfer_policy("signer_0").await.unwrap();
+
+		let destination = Pubkey::new_unique();
+		scenario.create_token_account(destination, mint);
+
+		Policy { scenario, mint, destination }
+	}
+
+	/// Proposes, approves and executes `change`
+	async fn apply(&mut self, change: TransferPolicyChange) -> Result<(), BanksClientError> {
+		let proposal_id = self.scenario.propose_transfer_policy_change("signer_0", change).await?;
+		self.scenario.approve("signer_0", proposal_id).await?;
+
+		self.scenario.execute_transfer_policy_proposal(proposal_id).await
+	}
+
+	/// Limits the mint to `MAX_WINDOW_VOLUME` per transfer and per window
+	async fn limit(mut self) -> Self {
+		let mint = self.mint;
+		self.apply(TransferPolicyChange::SetLimit {
+			mint,
+			max_transfer_amount: MAX_WINDOW_VOLUME,
+			max_window_volume: MAX_WINDOW_VOLUME,
+		}).await.unwrap();
+
+		self
+	}
+
+	/// Proposes, approves and executes a transfer of `amount` to the destination
+	async fn transfer(&mut self, amount: u64) -> Result<(), BanksClientError> {
+		let (destination, mint) = (self.destination, self.mint);
+		let proposal_id = self.scenario.propose_transfer("signer_0", destination, mint, amount).await?;
+		self.scenario.approve("signer_0", proposal_id).await?;
+
+		self.scenario.execute_transfer(proposal_id, destination, mint).await
+	}
+
+	async fn transfer_policy(&mut self) -> TransferPolicy {
+		let transfer_policy = self.scenario.transfer_policy();
+
+		self.scenario.account_state(transfer_policy).await
+	}
+}
+
+
+#[tokio::test]
+async fn execute_transfer_policy_proposal_ix_success() {
+	let mut policy = Policy::new().await;
+	let mint = policy.mint;
+
+	// INSTRUCTIONS
+	let result = policy.apply(TransferPolicyChange::SetLimit {
+		mint,
+		max_transfer_amount: 400,
+		max_window_volume: MAX_WINDOW_VOLUME,
+	}).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+
+	let transfer_policy = policy.transfer_policy().await;
+	assert_eq!(transfer_policy.limits.len(), 1);
+	assert_eq!(transfer_policy.limits[0].mint, mint);
+	assert_eq!(transfer_policy.limits[0].max_transfer_amount, 400);
+	assert_eq!(transfer_policy.limits[0].max_window_volume, MAX_WINDOW_VOLUME);
+}
+
+#[tokio::test]
+async fn execute_transfer_policy_proposal_removing_limit() {
+	let mut policy = Policy::new().await.limit().await;
+	let mint = policy.mint;
+
+	policy.apply(TransferPolicyChange::RemoveLimit { mint }).await.unwrap();
+
+	assert!(policy.transfer_policy().await.limits.is_empty());
+	assert!(policy.transfer(MAX_WINDOW_VOLUME + 1).await.is_ok());
+}
+
+#[tokio::test]
+async fn execute_transfer_policy_proposal_removing_missing_limit() {
+	let mut policy = Policy::new().await;
+
+	let result = policy.apply(TransferPolicyChange::RemoveLimit { mint: Pubkey::new_unique() }).await;
+
+	assert_multisig_wallet_error(result, MultisigWalletError::TransferLimitNotFound);
+}
+
+#[tokio::test]
+async fn transfer_over_transfer_limit() {
+	let mut policy = Policy::new().await.limit().await;
+
+	let result = policy.transfer(MAX_WINDOW_VOLUME + 1).await;
+
+	assert_multisig_wallet_error(result, MultisigWalletError::TransferLimitExceeded);
+}
+
+#[tokio::test]
+async fn transfer_over_window_volume() {
+	let mut policy = Policy::new().await.limit().await;
+	policy.transfer(600).await.unwrap();
+
+	let result = policy.transfer(401).await;
+
+	assert_multisig_wallet_error(result, MultisigWalletError::TransferVolumeExceeded);
+	assert!(policy.transfer(400).await.is_ok());
+}
+
+#[tokio::test]
+async fn transfer_volume_across_window_boundary() {
+	let mut policy = Policy::new().await.limit().await;
+
+	// A window that restarted with the first transfer would allow a second full volume right after it
+	policy.transfer(1).await.unwrap();
+	policy.scenario.warp(TRANSFER_VOLUME_WINDOW - 2).await;
+	policy.transfer(MAX_WINDOW_VOLUME - 1).await.unwrap();
+	policy.scenario.warp(2).await;
+
+	let result = policy.transfer(MAX_WINDOW_VOLUME - 1).await;
+
+	assert_multisig_wallet_error(result, MultisigWalletError::TransferVolumeExceeded);
+}
+
+#[tokio::test]
+async fn transfer_volume_rolls_out_of_window() {
+	let mut policy = Policy::new().await.limit().await;
+	policy.transfer(MAX_WINDOW_VOLUME).await.unwrap();
+
+	policy.scenario.warp(TRANSFER_VOLUME_WINDOW - 1).await;
+	let result = policy.transfer(1).await;
+	assert_multisig_wallet_error(result, MultisigWalletError::TransferVolumeExceeded);
+
+	// The whole volume is released once its bucket left the window
+	policy.scenario.warp(TRANSFER_VOLUME_BUCKET + 1).await;
+	assert!(policy.transfer(MAX_WINDOW_VOLUME).await.is_ok());
+}
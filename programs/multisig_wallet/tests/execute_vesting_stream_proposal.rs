@@ -0,0 +1,113 @@
+pub mod common;
+
+use {
+    common::{error_assertions::assert_multisig_wallet_error, multisig_fixture::MultisigFixture},
+    solana_program_test::tokio,
+    solana_sdk::pubkey::Pubkey,
+};
+use multisig_wallet::{error::MultisigWalletError, VestingStream, VestingStreamTerms};
+
+const VAULT_BALANCE: u64 = 10_000;
+const TOTAL_AMOUNT: u64 = 1_000;
+
+fn grant(mint: Pubkey, start_time: i64) -> VestingStreamTerms {
+	VestingStreamTerms {
+		recipient: Pubkey::new_unique(),
+		mint,
+		total_amount: TOTAL_AMOUNT,
+		start_time,
+		cliff_time: start_time + 100,
+		end_time: start_time + 1_000,
+	}
+}
+
+
+#[tokio::test]
+async fn execute_vesting_stream_proposal_ix_success() {
+	let mint = Pubkey::new_unique();
+	let mut scenario = MultisigFixture::new()
+		.signers(2)
+		.funded_vault(mint, VAULT_BALANCE)
+		.start()
+		.await;
+
+	// DATA
+	let now = scenario.now().await;
+	let terms = grant(mint, now);
+	let proposal_id = scenario.propose_vesting_stream("signer_0", terms.clone()).await.unwrap();
+	scenario.approve_as(&["signer_0", "signer_1"], proposal_id).await.unwrap();
+
+	// INSTRUCTIONS
+	let result = scenario.execute_vesting_stream_proposal(proposal_id, mint).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+	assert!(scenario.proposal_state(proposal_id).await.executed());
+
+	let vault_token_account = scenario.vault_token_account(&mint);
+	assert_eq!(scenario.token_balance(vault_token_account).await, VAULT_BALANCE - TOTAL_AMOUNT);
+	let escrow = scenario.stream_escrow(proposal_id, &mint);
+	assert_eq!(scenario.token_balance(escrow).await, TOTAL_AMOUNT);
+
+	let stream: VestingStream = scenario.account_state(scenario.vesting_stream(proposal_id)).await;
+	assert_eq!(stream.id, proposal_id);
+	assert_eq!(stream.multisig, scenario.multisig);
+	assert_eq!(stream.recipient, terms.recipient);
+	assert_eq!(stream.total_amount, TOTAL_AMOUNT);
+	assert_eq!(stream.withdrawn_amount, 0);
+	assert_eq!(stream.cliff_time, terms.cliff_time);
+	assert!(!stream.cancelled);
+}
+
+#[tokio::test]
+async fn execute_vesting_stream_proposal_after_other_proposals() {
+	let mint = Pubkey::new_unique();
+	let mut scenario = MultisigFixture::new()
+		.funded_vault(mint, VAULT_BALANCE)
+		.start()
+		.await;
+	scenario.propose_transfer("signer_0", Pubkey::new_unique(), mint, 1).await.unwrap();
+	scenario.propose_transfer("signer_0", Pubkey::new_unique(), mint, 1).await.unwrap();
+
+	let now = scenario.now().await;
+	let stream_id = scenario.open_vesting_stream(grant(mint, now)).await.unwrap();
+
+	// The stream is keyed by the seed id and records that same id
+	assert_eq!(stream_id, 2);
+	let stream: VestingStream = scenario.account_state(scenario.vesting_stream(stream_id)).await;
+	assert_eq!(stream.id, stream_id);
+}
+
+#[tokio::test]
+async fn execute_vesting_stream_proposal_without_threshold() {
+	let mint = Pubkey::new_unique();
+	let mut scenario = MultisigFixture::new()
+		.signers(2)
+		.funded_vault(mint, VAULT_BALANCE)
+		.start()
+		.await;
+	let now = scenario.now().await;
+	let proposal_id = scenario.propose_vesting_stream("signer_0", grant(mint, now)).await.unwrap();
+	scenario.approve("signer_0", proposal_id).await.unwrap();
+
+	let result = scenario.execute_vesting_stream_proposal(proposal_id, mint).await;
+
+	assert_multisig_wallet_error(result, MultisigWalletError::InsufficientApprovals);
+	assert!(!scenario.account_exists(scenario.vesting_stream(proposal_id)).await);
+}
+
+#[tokio::test]
+async fn execute_vesting_stream_proposal_over_vault_balance() {
+	let mint = Pubkey::new_unique();
+	let mut scenario = MultisigFixture::new()
+		.funded_vault(mint, TOTAL_AMOUNT - 1)
+		.start()
+		.await;
+
+	let now = scenario.now().await;
+	let result = scenario.open_vesting_stream(grant(mint, now)).await;
+
+	assert!(result.is_err());
+	let vault_token_account = scenario.vault_token_account(&mint);
+	assert_eq!(scenario.token_balance(vault_token_account).await, TOTAL_AMOUNT - 1);
+}
@@ -0,0 +1,104 @@
+pub mod common;
+
+use {
+    common::{
+		error_assertions::assert_multisig_wallet_error,
+		multisig_fixture::{MultisigFixture, MultisigScenario},
+		multisig_wallet_ix_interface,
+	},
+    solana_program_test::{tokio, BanksClientError},
+    solana_sdk::{pubkey::Pubkey, signer::Signer},
+};
+use multisig_wallet::error::MultisigWalletError;
+
+const MAX_PROPOSAL_LIFETIME: i64 = 60;
+
+async fn expire(scenario: &mut MultisigScenario, proposal_id: u64, rent_receiver: Pubkey) -> Result<(), BanksClientError> {
+	scenario.process(|scenario| multisig_wallet_ix_interface::expire_proposal_ix_setup(
+		&scenario.context.payer,
+		scenario.multisig,
+		scenario.proposal(proposal_id),
+		rent_receiver,
+		proposal_id,
+		scenario.context.last_blockhash,
+	)).await
+}
+
+#[tokio::test]
+async fn expire_proposal_ix_success() {
+	let mut scenario = MultisigFixture::new()
+		.signers(2)
+		.max_proposal_lifetime(MAX_PROPOSAL_LIFETIME)
+		.start()
+		.await;
+
+	// DATA
+	let creator = scenario.member("signer_1").pubkey();
+	let proposal_id = scenario.propose_transfer("signer_1", Pubkey::new_unique(), Pubkey::new_unique(), 400).await.unwrap();
+	let proposal = scenario.proposal(proposal_id);
+	let proposal_rent = scenario.lamports(proposal).await;
+	let creator_lamports = scenario.lamports(creator).await;
+
+	// INSTRUCTIONS
+	scenario.warp(MAX_PROPOSAL_LIFETIME + 1).await;
+	let result = expire(&mut scenario, proposal_id, creator).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+	assert!(scenario.context.banks_client.get_account(proposal).await.unwrap().is_none());
+	assert_eq!(scenario.lamports(creator).await, creator_lamports + proposal_rent);
+}
+
+#[tokio::test]
+async fn expire_proposal_before_expiration_time() {
+	let mut scenario = MultisigFixture::new()
+		.max_proposal_lifetime(MAX_PROPOSAL_LIFETIME)
+		.start()
+		.await;
+	let creator = scenario.member("signer_0").pubkey();
+	let proposal_id = scenario.propose_transfer("signer_0", Pubkey::new_unique(), Pubkey::new_unique(), 400).await.unwrap();
+
+	scenario.warp(MAX_PROPOSAL_LIFETIME - 1).await;
+	let result = expire(&mut scenario, proposal_id, creator).await;
+
+	assert_multisig_wallet_error(result, MultisigWalletError::ProposalNotExpired);
+	assert!(!scenario.proposal_state(proposal_id).await.expired());
+}
+
+#[tokio::test]
+async fn expire_proposal_to_other_rent_receiver() {
+	let mut scenario = MultisigFixture::new()
+		.max_proposal_lifetime(MAX_PROPOSAL_LIFETIME)
+		.start()
+		.await;
+	let proposal_id = scenario.propose_transfer("signer_0", Pubkey::new_unique(), Pubkey::new_unique(), 400).await.unwrap();
+
+	scenario.warp(MAX_PROPOSAL_LIFETIME + 1).await;
+	let result = expire(&mut scenario, proposal_id, Pubkey::new_unique()).await;
+
+	assert_multisig_wallet_error(result, MultisigWalletError::AccountMismatch);
+	assert!(!scenario.proposal_state(proposal_id).await.expired());
+}
+
+#[tokio::test]
+async fn expire_executed_proposal() {
+	let mint = Pubkey::new_unique();
+	let mut scenario = MultisigFixture::new()
+		.max_proposal_lifetime(MAX_PROPOSAL_LIFETIME)
+		.funded_vault(mint, 1_000)
+		.start()
+		.await;
+	let creator = scenario.member("signer_0").pubkey();
+	let destination = Pubkey::new_unique();
+	scenario.create_token_account(destination, mint);
+
+	let proposal_id = scenario.propose_transfer("signer_0", destination, mint, 400).await.unwrap();
+	scenario.approve("signer_0", proposal_id).await.unwrap();
+	scenario.execute_transfer(proposal_id, destination, mint).await.unwrap();
+
+	scenario.warp(MAX_PROPOSAL_LIFETIME + 1).await;
+	let result = expire(&mut scenario, proposal_id, creator).await;
+
+	assert_multisig_wallet_error(result, MultisigWalletError::ProposalAlreadyExecuted);
+	assert!(scenario.proposal_state(proposal_id).await.executed());
+}
@@ -0,0 +1,67 @@
+pub mod common;
+
+use {
+    common::{error_assertions::assert_multisig_wallet_error, multisig_fixture::MultisigFixture},
+    solana_program_test::tokio,
+    solana_sdk::pubkey::Pubkey,
+};
+use multisig_wallet::error::MultisigWalletError;
+
+
+#[tokio::test]
+async fn freeze_multisig_ix_success() {
+	let mut scenario = MultisigFixture::new()
+		.signers(2)
+		.guardian()
+		.start()
+		.await;
+	let proposal_id = scenario.propose_transfer("signer_0", Pubkey::new_unique(), Pubkey::new_unique(), 400).await.unwrap();
+
+	// INSTRUCTIONS
+	let result = scenario.freeze("guardian").await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+	assert!(scenario.multisig_config().await.frozen);
+
+	let result = scenario.approve("signer_0", proposal_id).await;
+	assert_multisig_wallet_error(result, MultisigWalletError::MultisigFrozen);
+
+	let result = scenario.propose_transfer("signer_0", Pubkey::new_unique(), Pubkey::new_unique(), 400).await;
+	assert_multisig_wallet_error(result.map(|_| ()), MultisigWalletError::MultisigFrozen);
+}
+
+#[tokio::test]
+async fn freeze_multisig_as_signer() {
+	let mut scenario = MultisigFixture::new()
+		.guardian()
+		.start()
+		.await;
+
+	let result = scenario.freeze("signer_0").await;
+
+	assert_multisig_wallet_error(result, MultisigWalletError::NotGuardian);
+	assert!(!scenario.multisig_config().await.frozen);
+}
+
+#[tokio::test]
+async fn freeze_multisig_without_guardian() {
+	let mut scenario = MultisigFixture::new().start().await;
+
+	let result = scenario.freeze("signer_0").await;
+
+	assert_multisig_wallet_error(result, MultisigWalletError::NotGuardian);
+}
+
+#[tokio::test]
+async fn freeze_frozen_multisig() {
+	let mut scenario = MultisigFixture::new()
+		.guardian()
+		.start()
+		.await;
+	scenario.freeze("guardian").await.unwrap();
+
+	let result = scenario.freeze("guardian").await;
+
+	assert_multisig_wallet_error(result, MultisigWalletError::MultisigFrozen);
+}
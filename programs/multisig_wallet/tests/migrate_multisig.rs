@@ -0,0 +1,91 @@
+pub mod common;
+
+use {
+    common::{
+		error_assertions::assert_multisig_wallet_error,
+		legacy_fixtures,
+		multisig_fixture::MultisigFixture,
+	},
+    solana_program_test::tokio,
+    solana_sdk::pubkey::Pubkey,
+};
+use multisig_wallet::{error::MultisigWalletError, MultisigConfig};
+
+
+#[tokio::test]
+async fn migrate_multisig_ix_success() {
+	let mut scenario = MultisigFixture::new().start().await;
+
+	// DATA
+	let signers: Vec<Pubkey> = vec![Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique()];
+	let legacy_multisig = legacy_fixtures::legacy_multisig();
+	let (_, legacy_multisig_bump) = Pubkey::find_program_address(&[b"multisig"], &multisig_wallet::ID);
+	scenario.set_program_account(legacy_multisig, legacy_fixtures::legacy_multisig_config(&signers, 2, 7));
+
+	// INSTRUCTIONS
+	let result = scenario.migrate_multisig(legacy_multisig).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+
+	let account = scenario.context.banks_client.get_account(legacy_multisig).await.unwrap().unwrap();
+	assert_eq!(account.data.len(), MultisigConfig::SPACE);
+
+	let multisig: MultisigConfig = scenario.account_state(legacy_multisig).await;
+	assert_eq!(multisig.version, MultisigConfig::CURRENT_VERSION);
+	assert_eq!(multisig.signers, signers);
+	assert_eq!(multisig.threshold, 2);
+	assert_eq!(multisig.proposal_count, 7);
+	assert_eq!(multisig.nonce, legacy_multisig_bump);
+	assert_eq!(multisig.create_key, Pubkey::default());
+}
+
+#[tokio::test]
+async fn migrate_multisig_twice() {
+	let mut scenario = MultisigFixture::new().start().await;
+	let legacy_multisig = legacy_fixtures::legacy_multisig();
+	scenario.set_program_account(legacy_multisig, legacy_fixtures::legacy_multisig_config(&[Pubkey::new_unique()], 1, 0));
+	scenario.migrate_multisig(legacy_multisig).await.unwrap();
+
+	let result = scenario.migrate_multisig(legacy_multisig).await;
+
+	assert_multisig_wallet_error(result, MultisigWalletError::AccountAlreadyMigrated);
+}
+
+#[tokio::test]
+async fn migrate_multisig_with_foreign_discriminator() {
+	let mut scenario = MultisigFixture::new().start().await;
+	let legacy_multisig = legacy_fixtures::legacy_multisig();
+
+	// A legacy proposal's bytes where the legacy multisig should be
+	let signer = Pubkey::new_unique();
+	scenario.set_program_account(legacy_multisig, legacy_fixtures::legacy_proposal(
+		0,
+		signer,
+		Pubkey::new_unique(),
+		1_000,
+		Pubkey::new_unique(),
+		&[signer],
+		0,
+		legacy_multisig,
+	));
+
+	let result = scenario.migrate_multisig(legacy_multisig).await;
+
+	assert_multisig_wallet_error(result, MultisigWalletError::InvalidLegacyAccount);
+}
+
+#[tokio::test]
+async fn migrate_multisig_with_truncated_data() {
+	let mut scenario = MultisigFixture::new().start().await;
+	let legacy_multisig = legacy_fixtures::legacy_multisig();
+
+	// The multisig discriminator followed by a signer count with no signers behind it
+	let mut data = legacy_fixtures::legacy_multisig_config(&[Pubkey::new_unique()], 1, 0);
+	data.truncate(12);
+	scenario.set_program_account(legacy_multisig, data);
+
+	let result = scenario.migrate_multisig(legacy_multisig).await;
+
+	assert_multisig_wallet_error(result, MultisigWalletError::InvalidLegacyAccount);
+}
@@ -0,0 +1,125 @@
+pub mod common;
+
+use {
+    common::{
+		error_assertions::assert_multisig_wallet_error,
+		legacy_fixtures,
+		multisig_fixture::{MultisigFixture, MultisigScenario},
+	},
+    solana_program_test::tokio,
+    solana_sdk::pubkey::Pubkey,
+};
+use multisig_wallet::{error::MultisigWalletError, MultisigConfig, Proposal, ProposalKind};
+
+const EXPIRATION_TIME: i64 = 1_800_000_000;
+
+/// A scenario holding the migrated legacy 2-of-3 multisig and its signers
+async fn migrated() -> (MultisigScenario, Pubkey, Vec<Pubkey>) {
+	let mut scenario = MultisigFixture::new().start().await;
+	let signers: Vec<Pubkey> = vec![Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique()];
+	let legacy_multisig = legacy_fixtures::legacy_multisig();
+	scenario.set_program_account(legacy_multisig, legacy_fixtures::legacy_multisig_config(&signers, 2, 1));
+	scenario.migrate_multisig(legacy_multisig).await.unwrap();
+
+	(scenario, legacy_multisig, signers)
+}
+
+/// Bytes of legacy transfer proposal 0 of `multisig`, created and approved by `signers[0]`
+fn legacy_proposal(signers: &[Pubkey], destination: Pubkey, token_mint: Pubkey, multisig: Pubkey) -> Vec<u8> {
+	legacy_fixtures::legacy_proposal(
+		0,
+		signers[0],
+		destination,
+		1_000_000,
+		token_mint,
+		&signers[..1],
+		EXPIRATION_TIME,
+		multisig,
+	)
+}
+
+
+#[tokio::test]
+async fn migrate_proposal_ix_success() {
+	let (mut scenario, multisig, signers) = migrated().await;
+
+	// DATA
+	let destination = Pubkey::new_unique();
+	let token_mint = Pubkey::new_unique();
+	let proposal = scenario.proposal_of(multisig, 0);
+	let (_, proposal_bump) = Pubkey::find_program_address(
+		&[
+			b"proposal",
+			multisig.as_ref(),
+			0u64.to_le_bytes().as_ref(),
+		],
+		&multisig_wallet::ID,
+	);
+	scenario.set_program_account(proposal, legacy_proposal(&signers, destination, token_mint, multisig));
+
+	// INSTRUCTIONS
+	let result = scenario.migrate_proposal(multisig, 0).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+
+	let account = scenario.context.banks_client.get_account(proposal).await.unwrap().unwrap();
+	assert_eq!(account.data.len(), Proposal::SPACE);
+
+	let proposal: Proposal = scenario.account_state(proposal).await;
+	assert_eq!(proposal.version, Proposal::CURRENT_VERSION);
+	assert_eq!(proposal.id, 0);
+	assert_eq!(proposal.creator, signers[0]);
+	assert_eq!(proposal.destination, destination);
+	assert_eq!(proposal.amount, 1_000_000);
+	assert_eq!(proposal.token_mint, token_mint);
+	assert!(proposal.has_approved(0));
+	assert_eq!(proposal.approval_count(), 1);
+	assert_eq!(proposal.expiration_time, EXPIRATION_TIME);
+	assert_eq!(proposal.multisig, multisig);
+	assert_eq!(proposal.nonce, proposal_bump);
+	assert_eq!(proposal.vault_nonce, MultisigConfig::vault_nonce(&multisig, 0));
+	assert_eq!(proposal.kind().unwrap(), ProposalKind::Transfer);
+	assert_eq!(proposal.cancel_vote_count() + proposal.veto_count(), 0);
+}
+
+#[tokio::test]
+async fn migrate_proposal_twice() {
+	let (mut scenario, multisig, signers) = migrated().await;
+	let proposal = scenario.proposal_of(multisig, 0);
+	scenario.set_program_account(proposal, legacy_proposal(&signers, Pubkey::new_unique(), Pubkey::new_unique(), multisig));
+	scenario.migrate_proposal(multisig, 0).await.unwrap();
+
+	let result = scenario.migrate_proposal(multisig, 0).await;
+
+	assert_multisig_wallet_error(result, MultisigWalletError::AccountAlreadyMigrated);
+}
+
+#[tokio::test]
+async fn migrate_proposal_with_foreign_discriminator() {
+	let (mut scenario, multisig, signers) = migrated().await;
+
+	// A legacy multisig's bytes where the legacy proposal should be
+	let proposal = scenario.proposal_of(multisig, 0);
+	scenario.set_program_account(proposal, legacy_fixtures::legacy_multisig_config(&signers, 2, 1));
+
+	let result = scenario.migrate_proposal(multisig, 0).await;
+
+	assert_multisig_wallet_error(result, MultisigWalletError::InvalidLegacyAccount);
+}
+
+#[tokio::test]
+async fn migrate_proposal_of_other_multisig() {
+	let (mut scenario, multisig, signers) = migrated().await;
+
+	// A legacy proposal at this multisig's PDA that records another multisig
+	let proposal = scenario.proposal_of(multisig, 0);
+	scenario.set_program_account(
+		proposal,
+		legacy_proposal(&signers, Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique()),
+	);
+
+	let result = scenario.migrate_proposal(multisig, 0).await;
+
+	assert_multisig_wallet_error(result, MultisigWalletError::InvalidLegacyAccount);
+}
@@ -0,0 +1,80 @@
+pub mod common;
+
+use {
+    common::multisig_fixture::MultisigFixture,
+    solana_program_test::tokio,
+    solana_sdk::{pubkey::Pubkey, signer::Signer},
+};
+
+
+#[tokio::test]
+async fn multisig_fixture_start() {
+	// DATA
+	let mint = Pubkey::new_unique();
+	let other_mint = Pubkey::new_unique();
+
+	// INSTRUCTIONS
+	let mut scenario = MultisigFixture::new()
+		.signers(5)
+		.threshold(3)
+		.max_proposal_lifetime(3_600)
+		.guardian()
+		.vault_index(2)
+		.funded_vault(mint, 1_000)
+		.funded_vault(other_mint, 250)
+		.start()
+		.await;
+
+	// ASSERTIONS
+	let config = scenario.multisig_config().await;
+	let signers: Vec<Pubkey> = (0..5).map(|index| scenario.member(&format!("signer_{index}")).pubkey()).collect();
+	assert_eq!(config.signers, signers);
+	assert_eq!(config.threshold, 3);
+	assert_eq!(config.max_proposal_lifetime, 3_600);
+	assert_eq!(config.guardian, Some(scenario.member("guardian").pubkey()));
+	assert_eq!(config.proposal_count, 0);
+
+	assert_eq!(scenario.vault_index, 2);
+	let vault_token_account = scenario.vault_token_account(&mint);
+	assert_eq!(scenario.token_balance(vault_token_account).await, 1_000);
+	let other_vault_token_account = scenario.vault_token_account(&other_mint);
+	assert_eq!(scenario.token_balance(other_vault_token_account).await, 250);
+	assert!(scenario.last_compute_units > 0);
+}
+
+#[tokio::test]
+async fn multisig_fixture_named_members() {
+	let mut scenario = MultisigFixture::new()
+		.members(&["alice", "bob"])
+		.threshold(1)
+		.start()
+		.await;
+
+	let config = scenario.multisig_config().await;
+	assert_eq!(config.signers, vec![scenario.member("alice").pubkey(), scenario.member("bob").pubkey()]);
+	assert_eq!(config.threshold, 1);
+	assert_eq!(config.guardian, None);
+}
+
+#[tokio::test]
+async fn multisig_fixture_transfer_flow() {
+	let mint = Pubkey::new_unique();
+	let mut scenario = MultisigFixture::new()
+		.signers(3)
+		.threshold(2)
+		.funded_vault(mint, 1_000)
+		.start()
+		.await;
+	let destination = Pubkey::new_unique();
+	let destination_token_account = scenario.create_token_account(destination, mint);
+
+	let proposal_id = scenario.propose_transfer("signer_0", destination, mint, 400).await.unwrap();
+	scenario.approve_as(&["signer_0", "signer_2"], proposal_id).await.unwrap();
+	scenario.execute_transfer(proposal_id, destination, mint).await.unwrap();
+
+	assert!(scenario.proposal_state(proposal_id).await.executed());
+	assert_eq!(scenario.next_proposal_id().await, proposal_id + 1);
+	assert_eq!(scenario.token_balance(destination_token_account).await, 400);
+	let vault_token_account = scenario.vault_token_account(&mint);
+	assert_eq!(scenario.token_balance(vault_token_account).await, 600);
+}
@@ -0,0 +1,263 @@
+pub mod common;
+
+use {
+    common::{
+		error_assertions::assert_multisig_wallet_error,
+		multisig_fixture::{MultisigFixture, MultisigScenario},
+		multisig_wallet_ix_interface,
+	},
+    solana_program_test::{tokio, BanksClientError},
+    solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer, system_program},
+};
+use multisig_wallet::{error::MultisigWalletError, MAX_SIGNERS};
+
+// Negative paths: each test drives one MultisigWalletError through the program and asserts its exact
+// code. Execution below the multisig threshold fails with InsufficientApprovals; ThresholdNotMet is
+// raised by an optimistic proposal short of its vault's threshold override.
+
+const EXPIRATION_DELAY: i64 = 60;
+
+/// A multisig of `signer_count` signers with `threshold` whose proposals expire after
+/// `EXPIRATION_DELAY`, with transfer proposal 0 open on a funded vault
+async fn with_proposal(signer_count: usize, threshold: u8) -> (MultisigScenario, Pubkey, Pubkey) {
+	let mint = Pubkey::new_unique();
+	let mut scenario = MultisigFixture::new()
+		.signers(signer_count)
+		.threshold(threshold)
+		.max_proposal_lifetime(EXPIRATION_DELAY)
+		.funded_vault(mint, 1_000)
+		.start()
+		.await;
+	let destination = Pubkey::new_unique();
+	scenario.create_token_account(destination, mint);
+	scenario.propose_transfer("signer_0", destination, mint, 400).await.unwrap();
+
+	(scenario, destination, mint)
+}
+
+#[tokio::test]
+async fn not_a_signer_error() {
+	let (mut scenario, _, _) = with_proposal(2, 1).await;
+	scenario.add_multisig(&["outsider"], 1).await;
+
+	let result = scenario.approve("outsider", 0).await;
+
+	assert_multisig_wallet_error(result, MultisigWalletError::NotASigner);
+}
+
+#[tokio::test]
+async fn proposal_expired_error() {
+	let (mut scenario, _, _) = with_proposal(2, 1).await;
+	scenario.warp(EXPIRATION_DELAY + 1).await;
+
+	let result = scenario.approve("signer_0", 0).await;
+
+	assert_multisig_wallet_error(result, MultisigWalletError::ProposalExpired);
+}
+
+#[tokio::test]
+async fn proposal_not_expired_until_expiration_time() {
+	let (mut scenario, _, _) = with_proposal(2, 1).await;
+	scenario.warp(EXPIRATION_DELAY - 1).await;
+
+	let result = scenario.approve("signer_0", 0).await;
+
+	assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn proposal_already_executed_error() {
+	let (mut scenario, destination, mint) = with_proposal(2, 1).await;
+	scenario.approve("signer_0", 0).await.unwrap();
+	scenario.execute_transfer(0, destination, mint).await.unwrap();
+
+	let result = scenario.execute_transfer(0, destination, mint).await;
+
+	assert_multisig_wallet_error(result, MultisigWalletError::ProposalAlreadyExecuted);
+}
+
+#[tokio::test]
+async fn proposal_cancelled_error() {
+	let (mut scenario, _, _) = with_proposal(2, 1).await;
+	scenario.cancel("signer_0", 0).await.unwrap();
+
+	let result = scenario.approve("signer_1", 0).await;
+
+	assert_multisig_wallet_error(result, MultisigWalletError::ProposalCancelled);
+}
+
+#[tokio::test]
+async fn insufficient_approvals_error() {
+	let (mut scenario, destination, mint) = with_proposal(3, 2).await;
+	scenario.approve("signer_0", 0).await.unwrap();
+
+	let result = scenario.execute_transfer(0, destination, mint).await;
+
+	assert_multisig_wallet_error(result, MultisigWalletError::InsufficientApprovals);
+}
+
+#[tokio::test]
+async fn not_all_signers_approved_error() {
+	let mut scenario = MultisigFixture::new()
+		.signers(3)
+		.threshold(2)
+		.start()
+		.await;
+	let vault_index = scenario.vault_index;
+
+	let result = scenario.set_vault_threshold(&["signer_0", "signer_1"], vault_index, Some(1)).await;
+
+	assert_multisig_wallet_error(result, MultisigWalletError::NotAllSignersApproved);
+}
+
+#[tokio::test]
+async fn invalid_threshold_error() {
+	let mut scenario = MultisigFixture::new()
+		.signers(2)
+		.threshold(1)
+		.start()
+		.await;
+	let vault_index = scenario.vault_index;
+
+	for threshold in [0, 3] {
+		let result = scenario.set_vault_threshold(&["signer_0", "signer_1"], vault_index, Some(threshold)).await;
+
+		assert_multisig_wallet_error(result, MultisigWalletError::InvalidThreshold);
+	}
+}
+
+/// Creates another multisig of `signers` with `threshold` through `create_multisig`
+async fn create_multisig(signers: Vec<Pubkey>, threshold: u8) -> Result<(), BanksClientError> {
+	let mut scenario = MultisigFixture::new().start().await;
+	let create_key = Keypair::new();
+
+	let (multisig, _multisig_bump) = Pubkey::find_program_address(
+		&[
+			b"multisig",
+			create_key.pubkey().as_ref(),
+		],
+		&multisig_wallet::ID,
+	);
+
+	scenario.process(|scenario| multisig_wallet_ix_interface::create_multisig_ix_setup(
+		&scenario.context.payer,
+		&create_key,
+		multisig,
+		system_program::ID,
+		signers,
+		threshold,
+		0,
+		None,
+		0,
+		scenario.context.last_blockhash,
+	)).await
+}
+
+#[tokio::test]
+async fn no_signers_error() {
+	let result = create_multisig(vec![], 1).await;
+
+	assert_multisig_wallet_error(result, MultisigWalletError::NoSigners);
+}
+
+#[tokio::test]
+async fn max_signers_exceeded_error() {
+	let signers = (0..=MAX_SIGNERS).map(|_| Pubkey::new_unique()).collect();
+	let result = create_multisig(signers, 1).await;
+
+	assert_multisig_wallet_error(result, MultisigWalletError::MaxSignersExceeded);
+}
+
+#[tokio::test]
+async fn duplicate_signer_error() {
+	let signer = Pubkey::new_unique();
+	let result = create_multisig(vec![signer, Pubkey::new_unique(), signer], 1).await;
+
+	assert_multisig_wallet_error(result, MultisigWalletError::DuplicateSigner);
+}
+
+#[tokio::test]
+async fn create_multisig_invalid_threshold_error() {
+	for threshold in [0, 3] {
+		let result = create_multisig(vec![Pubkey::new_unique(), Pubkey::new_unique()], threshold).await;
+
+		assert_multisig_wallet_error(result, MultisigWalletError::InvalidThreshold);
+	}
+}
+
+#[tokio::test]
+async fn account_mismatch_error() {
+	let mint = Pubkey::new_unique();
+	let other_mint = Pubkey::new_unique();
+	let mut scenario = MultisigFixture::new()
+		.funded_vault(mint, 1_000)
+		.funded_vault(other_mint, 1_000)
+		.start()
+		.await;
+
+	let destination = Pubkey::new_unique();
+	let other_destination_token_account = scenario.create_token_account(destination, other_mint);
+
+	let proposal_id = scenario.propose_transfer("signer_0", destination, mint, 400).await.unwrap();
+	scenario.approve("signer_0", proposal_id).await.unwrap();
+
+	// Paying out of the vault's account for another mint than the proposal's
+	let result = scenario.process(|scenario| multisig_wallet_ix_interface::execute_proposal_ix_setup(
+		&scenario.context.payer,
+		scenario.multisig,
+		scenario.proposal(proposal_id),
+		scenario.vault,
+		scenario.vault_token_account(&other_mint),
+		other_destination_token_account,
+		&scenario.context.payer,
+		destination,
+		other_mint,
+		system_program::ID,
+		anchor_spl::token::ID,
+		anchor_spl::associated_token::ID,
+		anchor_spl::memo::ID,
+		None,
+		proposal_id,
+		scenario.context.last_blockhash,
+	)).await;
+
+	assert_multisig_wallet_error(result, MultisigWalletError::AccountMismatch);
+}
+
+#[tokio::test]
+async fn threshold_not_met_error() {
+	let mint = Pubkey::new_unique();
+	let mut scenario = MultisigFixture::new()
+		.signers(3)
+		.threshold(2)
+		.funded_vault(mint, 1_000)
+		.start()
+		.await;
+	let all_signers = ["signer_0", "signer_1", "signer_2"];
+	let vault_index = scenario.vault_index;
+	scenario.set_vault_threshold(&all_signers, vault_index, Some(3)).await.unwrap();
+	scenario.configure_optimistic(&all_signers, true, EXPIRATION_DELAY, 2).await.unwrap();
+
+	let destination = Pubkey::new_unique();
+	scenario.create_token_account(destination, mint);
+	let proposal_id = scenario.propose_transfer("signer_0", destination, mint, 400).await.unwrap();
+	scenario.approve_as(&["signer_0", "signer_1"], proposal_id).await.unwrap();
+	scenario.warp(EXPIRATION_DELAY + 1).await;
+
+	// Unvetoed past its window, but with fewer approvals than the vault's override
+	let result = scenario.execute_transfer(proposal_id, destination, mint).await;
+
+	assert_multisig_wallet_error(result, MultisigWalletError::ThresholdNotMet);
+}
+
+#[tokio::test]
+async fn id_overflow_error() {
+	let mut scenario = MultisigFixture::new().start().await;
+	let mut multisig = scenario.multisig_config().await;
+	multisig.proposal_count = u64::MAX;
+	scenario.set_multisig_config(&multisig).await;
+
+	let result = scenario.propose_transfer("signer_0", Pubkey::new_unique(), Pubkey::new_unique(), 1).await;
+
+	assert_multisig_wallet_error(result.map(|_| ()), MultisigWalletError::IdOverflow);
+}
@@ -0,0 +1,374 @@
+pub mod common;
+
+use {
+    common::{
+		get_program_test,
+		multisig_wallet_ix_interface,
+		state_fixtures,
+	},
+    rand::{rngs::StdRng, Rng, SeedableRng},
+    solana_program_test::{tokio, BanksClient},
+    solana_sdk::{
+        account::Account, hash::Hash, pubkey::Pubkey, signature::Keypair, signer::Signer, system_program,
+        transaction::Transaction,
+    },
+};
+use anchor_lang::AccountDeserialize;
+use anchor_spl::{associated_token::get_associated_token_address, token::spl_token};
+use multisig_wallet::{MultisigConfig, Proposal, ProposalMetadata, TransferTerms, MAX_SIGNERS};
+
+// Stateful fuzzing of the proposal lifecycle: every case boots a multisig with a random signer set and
+// threshold and a funded vault, then runs random create/approve/unapprove/cancel/execute calls from
+// random members and outsiders, checking after every call that
+// - no proposal executes below its threshold or more than once,
+// - no approval bit belongs to a non-member,
+// - tokens only leave the vault through executed proposals and are never created or lost.
+// Signer set and threshold changes are not covered: update_multisig has no behavior yet.
+// FUZZ_SEED and FUZZ_CASES override the default seed and number of cases.
+
+const DEFAULT_SEED: u64 = 0x6d75_6c74_6973_6967;
+const DEFAULT_CASES: u64 = 8;
+const STEPS_PER_CASE: usize = 48;
+const OUTSIDERS: usize = 2;
+const RECIPIENTS: usize = 3;
+const VAULT_BALANCE: u64 = 1_000_000;
+
+#[derive(Debug, Clone, Copy)]
+enum Op {
+	Create { actor: usize, recipient: usize, amount: u64 },
+	Approve { proposal: usize, actor: usize },
+	Unapprove { proposal: usize, actor: usize },
+	Cancel { proposal: usize, actor: usize },
+	Execute { proposal: usize },
+}
+
+struct TrackedProposal {
+	id: u64,
+	pda: Pubkey,
+	recipient: usize,
+	amount: u64,
+}
+
+struct Recipient {
+	wallet: Pubkey,
+	token_account: Pubkey,
+}
+
+struct Lifecycle {
+	banks_client: BanksClient,
+	recent_blockhash: Hash,
+	// A fresh fee payer per step keeps repeated calls from being deduplicated as the same transaction
+	fee_payers: Vec<Keypair>,
+	step: usize,
+	multisig: Pubkey,
+	vault: Pubkey,
+	vault_token_account: Pubkey,
+	mint: Pubkey,
+	members: Vec<Keypair>,
+	outsiders: Vec<Keypair>,
+	recipients: Vec<Recipient>,
+	proposals: Vec<TrackedProposal>,
+}
+
+impl Lifecycle {
+	async fn new(rng: &mut StdRng) -> Self {
+		let mut program_test = get_program_test();
+		program_test.prefer_bpf(true);
+
+		let signer_count = rng.gen_range(1..=MAX_SIGNERS);
+		let threshold = rng.gen_range(1..=signer_count) as u8;
+		let members: Vec<Keypair> = (0..signer_count).map(|_| Keypair::new()).collect();
+		let outsiders: Vec<Keypair> = (0..OUTSIDERS).map(|_| Keypair::new()).collect();
+		let fee_payers: Vec<Keypair> = (0..=STEPS_PER_CASE).map(|_| Keypair::new()).collect();
+		let member_pubkeys: Vec<Pubkey> = members.iter().map(|member| member.pubkey()).collect();
+		let create_key = Pubkey::new_unique();
+		let mint = Pubkey::new_unique();
+
+		let (multisig, _multisig_bump) = Pubkey::find_program_address(
+			&[
+				b"multisig",
+				create_key.as_ref(),
+			],
+			&multisig_wallet::ID,
+		);
+
+		let (vault, _vault_bump) = Pubkey::find_program_address(
+			&[
+				b"vault",
+				multisig.as_ref(),
+				&[0],
+			],
+			&multisig_wallet::ID,
+		);
+		let vault_token_account = get_associated_token_address(&vault, &mint);
+
+		for fee_payer in &fee_payers {
+			program_test.add_account(
+				fee_payer.pubkey(),
+				Account {
+					lamports: 1_000_000_000_000,
+					data: vec![],
+					owner: system_program::ID,
+					executable: false,
+					rent_epoch: 0,
+				},
+			);
+		}
+
+		program_test.add_account(multisig, state_fixtures::multisig_config_account(&member_pubkeys, threshold, create_key));
+		program_test.add_account(mint, state_fixtures::mint_account(Pubkey::new_unique(), 6));
+		program_test.add_account(vault_token_account, state_fixtures::token_account(mint, vault, VAULT_BALANCE));
+
+		let recipients: Vec<Recipient> = (0..RECIPIENTS).map(|_| {
+			let wallet = Pubkey::new_unique();
+
+			Recipient { wallet, token_account: get_associated_token_address(&wallet, &mint) }
+		}).collect();
+
+		for recipient in &recipients {
+			program_test.add_account(recipient.token_account, state_fixtures::token_account(mint, recipient.wallet, 0));
+		}
+
+		let (banks_client, _, recent_blockhash) = program_test.start().await;
+
+		Lifecycle {
+			banks_client,
+			recent_blockhash,
+			fee_payers,
+			step: 0,
+			multisig,
+			vault,
+			vault_token_account,
+			mint,
+			members,
+			outsiders,
+			recipients,
+			proposals: Vec::new(),
+		}
+	}
+
+	fn actor(&self, index: usize) -> &Keypair {
+		match index < self.members.len() {
+			true => &self.members[index],
+			false => &self.outsiders[index - self.members.len()],
+		}
+	}
+
+	/// A random call, made one time in five by an outsider
+	fn random_op(&self, rng: &mut StdRng) -> Op {
+		let actor = match rng.gen_ratio(1, 5) {
+			true => self.members.len() + rng.gen_range(0..OUTSIDERS),
+			false => rng.gen_range(0..self.members.len()),
+		};
+
+		if self.proposals.is_empty() {
+			return Op::Create { actor, recipient: rng.gen_range(0..RECIPIENTS), amount: rng.gen_range(1..=VAULT_BALANCE / 4) };
+		}
+
+		let proposal = rng.gen_range(0..self.proposals.len());
+		match rng.gen_range(0..19) {
+			0..=2 => Op::Create { actor, recipient: rng.gen_range(0..RECIPIENTS), amount: rng.gen_range(1..=VAULT_BALANCE / 4) },
+			3..=10 => Op::Approve { proposal, actor },
+			11..=12 => Op::Unapprove { proposal, actor },
+			13 => Op::Cancel { proposal, actor },
+			_ => Op::Execute { proposal },
+		}
+	}
+
+	async fn multisig_config(&mut self) -> MultisigConfig {
+		let account = self.banks_client.get_account(self.multisig).await.unwrap().unwrap();
+
+		MultisigConfig::try_deserialize(&mut account.data.as_slice()).unwrap()
+	}
+
+	async fn proposal(&mut self, index: usize) -> Proposal {
+		let account = self.banks_client.get_account(self.proposals[index].pda).await.unwrap().unwrap();
+
+		Proposal::try_deserialize(&mut account.data.as_slice()).unwrap()
+	}
+
+	async fn token_amount(&mut self, token_account: Pubkey) -> u64 {
+		let account = self.banks_client.get_account(token_account).await.unwrap().unwrap();
+
+		state_fixtures::token_amount(&account)
+	}
+
+	fn execute_transaction(&self, proposal: &TrackedProposal) -> Transaction {
+		let fee_payer = &self.fee_payers[self.step];
+		let recipient = &self.recipients[proposal.recipient];
+
+		multisig_wallet_ix_interface::execute_proposal_ix_setup(
+			fee_payer,
+			self.multisig,
+			proposal.pda,
+			self.vault,
+			self.vault_token_account,
+			recipient.token_account,
+			fee_payer,
+			recipient.wallet,
+			self.mint,
+			system_program::ID,
+			spl_token::ID,
+			anchor_spl::associated_token::ID,
+			anchor_spl::memo::ID,
+			None,
+			proposal.id,
+			self.recent_blockhash,
+		)
+	}
+
+	fn transaction(&self, op: Op, proposal_count: u64) -> Transaction {
+		let fee_payer = &self.fee_payers[self.step];
+
+		match op {
+			Op::Create { actor, recipient, amount } => {
+				let (proposal, _proposal_bump) = proposal_pda(self.multisig, proposal_count);
+
+				multisig_wallet_ix_interface::create_proposal_ix_setup(
+					fee_payer,
+					self.multisig,
+					proposal,
+					self.actor(actor),
+					None,
+					system_program::ID,
+					TransferTerms { destination: self.recipients[recipient].wallet, amount, token_mint: self.mint },
+					0,
+					0,
+					ProposalMetadata::default(),
+					proposal_count,
+					self.recent_blockhash,
+				)
+			}
+			Op::Approve { proposal, actor } => multisig_wallet_ix_interface::approve_proposal_ix_setup(
+				fee_payer,
+				self.multisig,
+				self.proposals[proposal].pda,
+				self.actor(actor),
+				self.proposals[proposal].id,
+				self.recent_blockhash,
+			),
+			Op::Unapprove { proposal, actor } => multisig_wallet_ix_interface::unapprove_proposal_ix_setup(
+				fee_payer,
+				self.multisig,
+				self.proposals[proposal].pda,
+				self.actor(actor),
+				self.proposals[proposal].id,
+				self.recent_blockhash,
+			),
+			Op::Cancel { proposal, actor } => multisig_wallet_ix_interface::cancel_proposal_ix_setup(
+				fee_payer,
+				self.multisig,
+				self.proposals[proposal].pda,
+				self.actor(actor),
+				self.proposals[proposal].id,
+				self.recent_blockhash,
+			),
+			Op::Execute { proposal } => self.execute_transaction(&self.proposals[proposal]),
+		}
+	}
+
+	/// Runs `op` and checks the lifecycle invariants against the state before and after it
+	async fn step(&mut self, op: Op) {
+		let multisig = self.multisig_config().await;
+		let before = match op {
+			Op::Execute { proposal } => Some(self.proposal(proposal).await),
+			_ => None,
+		};
+		let vault_before = self.token_amount(self.vault_token_account).await;
+
+		let transaction = self.transaction(op, multisig.proposal_count);
+		let succeeded = self.banks_client.process_transaction(transaction).await.is_ok();
+		self.step += 1;
+
+		match op {
+			Op::Create { actor, recipient, amount } => {
+				let is_member = multisig.signers.contains(&self.actor(actor).pubkey());
+				assert!(succeeded == is_member, "{op:?}: proposal creation by a member must succeed and by a non-member fail");
+
+				if succeeded {
+					let (pda, _) = proposal_pda(self.multisig, multisig.proposal_count);
+					self.proposals.push(TrackedProposal { id: multisig.proposal_count, pda, recipient, amount });
+				}
+			}
+			Op::Approve { actor, .. } => {
+				let is_member = multisig.signers.contains(&self.actor(actor).pubkey());
+				assert!(!succeeded || is_member, "{op:?}: a non-member approved a proposal");
+			}
+			Op::Execute { proposal } => {
+				let before = before.unwrap();
+				let vault_after = self.token_amount(self.vault_token_account).await;
+
+				if succeeded {
+					let threshold = multisig.proposal_threshold(&before.kind().unwrap(), before.vault_index);
+					assert!(!before.executed(), "{op:?}: proposal executed twice");
+					assert!(before.approval_count() >= threshold, "{op:?}: proposal executed below its threshold");
+					assert_eq!(vault_before - vault_after, self.proposals[proposal].amount, "{op:?}: vault debited by the wrong amount");
+				} else {
+					assert_eq!(vault_before, vault_after, "{op:?}: failed execution moved funds");
+				}
+			}
+			_ => {}
+		}
+
+		self.check_invariants().await;
+	}
+
+	async fn check_invariants(&mut self) {
+		let multisig = self.multisig_config().await;
+		let member_bits = (1u32 << multisig.signers.len()) - 1;
+		let mut executed_amount = 0;
+
+		for index in 0..self.proposals.len() {
+			let proposal = self.proposal(index).await;
+
+			assert_eq!(proposal.approvals as u32 & !member_bits, 0, "approval bit set for a non-member");
+			if proposal.executed() {
+				executed_amount += self.proposals[index].amount;
+			}
+		}
+
+		let vault = self.token_amount(self.vault_token_account).await;
+		let recipient_token_accounts: Vec<Pubkey> = self.recipients.iter()
+			.map(|recipient| recipient.token_account)
+			.collect();
+		let mut received = 0;
+		for token_account in recipient_token_accounts {
+			received += self.token_amount(token_account).await;
+		}
+
+		assert_eq!(vault + received, VAULT_BALANCE, "tokens were created or lost");
+		assert_eq!(vault, VAULT_BALANCE - executed_amount, "vault balance does not match the executed proposals");
+	}
+}
+
+fn proposal_pda(multisig: Pubkey, proposal_id: u64) -> (Pubkey, u8) {
+	Pubkey::find_program_address(
+		&[
+			b"proposal",
+			multisig.as_ref(),
+			proposal_id.to_le_bytes().as_ref(),
+		],
+		&multisig_wallet::ID,
+	)
+}
+
+fn env_u64(name: &str, default: u64) -> u64 {
+	std::env::var(name).map_or(default, |value| value.parse().unwrap())
+}
+
+#[tokio::test]
+async fn proposal_lifecycle_fuzz() {
+	let seed = env_u64("FUZZ_SEED", DEFAULT_SEED);
+	let cases = env_u64("FUZZ_CASES", DEFAULT_CASES);
+
+	for case in 0..cases {
+		println!("proposal lifecycle case {case} (FUZZ_SEED={seed})");
+		let mut rng = StdRng::seed_from_u64(seed.wrapping_add(case));
+		let mut lifecycle = Lifecycle::new(&mut rng).await;
+
+		for _ in 0..STEPS_PER_CASE {
+			let op = lifecycle.random_op(&mut rng);
+			lifecycle.step(op).await;
+		}
+	}
+}
@@ -0,0 +1,121 @@
+pub mod common;
+
+use {
+    common::{error_assertions::assert_multisig_wallet_error, multisig_fixture::MultisigFixture},
+    solana_program_test::tokio,
+    solana_sdk::pubkey::Pubkey,
+};
+use multisig_wallet::{error::MultisigWalletError, MAX_VAULT_THRESHOLDS};
+
+const ALL_SIGNERS: [&str; 3] = ["signer_0", "signer_1", "signer_2"];
+
+
+#[tokio::test]
+async fn set_vault_threshold_ix_success() {
+	let mut scenario = MultisigFixture::new()
+		.signers(3)
+		.threshold(2)
+		.start()
+		.await;
+
+	// INSTRUCTIONS
+	let result = scenario.set_vault_threshold(&ALL_SIGNERS, 1, Some(3)).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+	let multisig = scenario.multisig_config().await;
+	assert_eq!(multisig.vault_threshold(1), 3);
+	assert_eq!(multisig.vault_threshold(0), 2);
+	assert_eq!(multisig.threshold, 2);
+}
+
+#[tokio::test]
+async fn set_vault_threshold_gating_transfers() {
+	let mint = Pubkey::new_unique();
+	let mut scenario = MultisigFixture::new()
+		.signers(3)
+		.threshold(2)
+		.funded_vault(mint, 1_000)
+		.start()
+		.await;
+	let vault_index = scenario.vault_index;
+	scenario.set_vault_threshold(&ALL_SIGNERS, vault_index, Some(3)).await.unwrap();
+	let destination = Pubkey::new_unique();
+	scenario.create_token_account(destination, mint);
+
+	let proposal_id = scenario.propose_transfer("signer_0", destination, mint, 100).await.unwrap();
+	scenario.approve_as(&["signer_0", "signer_1"], proposal_id).await.unwrap();
+
+	// The multisig threshold is met but not the vault's
+	let result = scenario.execute_transfer(proposal_id, destination, mint).await;
+	assert_multisig_wallet_error(result, MultisigWalletError::InsufficientApprovals);
+
+	scenario.approve("signer_2", proposal_id).await.unwrap();
+	let result = scenario.execute_transfer(proposal_id, destination, mint).await;
+	assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn set_vault_threshold_removing_override() {
+	let mut scenario = MultisigFixture::new()
+		.signers(3)
+		.threshold(2)
+		.start()
+		.await;
+	scenario.set_vault_threshold(&ALL_SIGNERS, 1, Some(1)).await.unwrap();
+
+	let result = scenario.set_vault_threshold(&ALL_SIGNERS, 1, None).await;
+
+	assert!(result.is_ok());
+	let multisig = scenario.multisig_config().await;
+	assert!(multisig.vault_thresholds.is_empty());
+	assert_eq!(multisig.vault_threshold(1), 2);
+}
+
+#[tokio::test]
+async fn set_vault_threshold_without_every_signer() {
+	let mut scenario = MultisigFixture::new()
+		.signers(3)
+		.threshold(2)
+		.start()
+		.await;
+
+	let result = scenario.set_vault_threshold(&["signer_0", "signer_1"], 1, Some(1)).await;
+
+	assert_multisig_wallet_error(result, MultisigWalletError::NotAllSignersApproved);
+	assert!(scenario.multisig_config().await.vault_thresholds.is_empty());
+}
+
+#[tokio::test]
+async fn set_vault_threshold_out_of_range() {
+	let mut scenario = MultisigFixture::new()
+		.signers(3)
+		.threshold(2)
+		.start()
+		.await;
+
+	for threshold in [0, 4] {
+		let result = scenario.set_vault_threshold(&ALL_SIGNERS, 1, Some(threshold)).await;
+
+		assert_multisig_wallet_error(result, MultisigWalletError::InvalidThreshold);
+	}
+}
+
+#[tokio::test]
+async fn set_vault_threshold_beyond_capacity() {
+	let mut scenario = MultisigFixture::new()
+		.signers(3)
+		.threshold(2)
+		.start()
+		.await;
+	for vault_index in 0..MAX_VAULT_THRESHOLDS as u8 {
+		scenario.set_vault_threshold(&ALL_SIGNERS, vault_index, Some(3)).await.unwrap();
+	}
+
+	let result = scenario.set_vault_threshold(&ALL_SIGNERS, MAX_VAULT_THRESHOLDS as u8, Some(3)).await;
+	assert_multisig_wallet_error(result, MultisigWalletError::VaultThresholdsFull);
+
+	// Existing overrides can still change
+	let result = scenario.set_vault_threshold(&ALL_SIGNERS, 0, Some(1)).await;
+	assert!(result.is_ok());
+}
@@ -0,0 +1,91 @@
+pub mod common;
+
+use {
+    common::{
+		error_assertions::assert_multisig_wallet_error,
+		multisig_fixture::{MultisigFixture, MultisigScenario},
+	},
+    solana_program_test::tokio,
+    solana_sdk::pubkey::Pubkey,
+};
+use multisig_wallet::error::MultisigWalletError;
+
+const VAULT_BALANCE: u64 = 1_000;
+
+/// A 2-of-3 multisig with a transfer proposal approved by `signer_0` and `signer_1`
+async fn approved() -> (MultisigScenario, Pubkey, Pubkey, u64) {
+	let mint = Pubkey::new_unique();
+	let mut scenario = MultisigFixture::new()
+		.signers(3)
+		.threshold(2)
+		.funded_vault(mint, VAULT_BALANCE)
+		.start()
+		.await;
+
+	let destination = Pubkey::new_unique();
+	scenario.create_token_account(destination, mint);
+	let proposal_id = scenario.propose_transfer("signer_0", destination, mint, 400).await.unwrap();
+	scenario.approve_as(&["signer_0", "signer_1"], proposal_id).await.unwrap();
+
+	(scenario, mint, destination, proposal_id)
+}
+
+
+#[tokio::test]
+async fn unapprove_proposal_ix_success() {
+	let (mut scenario, mint, destination, proposal_id) = approved().await;
+
+	// INSTRUCTIONS
+	let result = scenario.unapprove("signer_1", proposal_id).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+
+	let proposal = scenario.proposal_state(proposal_id).await;
+	assert_eq!(proposal.approval_count(), 1);
+	assert!(proposal.has_approved(0));
+	assert!(!proposal.has_approved(1));
+
+	let result = scenario.execute_transfer(proposal_id, destination, mint).await;
+	assert_multisig_wallet_error(result, MultisigWalletError::InsufficientApprovals);
+}
+
+#[tokio::test]
+async fn unapprove_proposal_and_approve_again() {
+	let (mut scenario, mint, destination, proposal_id) = approved().await;
+	scenario.unapprove("signer_1", proposal_id).await.unwrap();
+
+	scenario.approve("signer_1", proposal_id).await.unwrap();
+
+	assert!(scenario.execute_transfer(proposal_id, destination, mint).await.is_ok());
+}
+
+#[tokio::test]
+async fn unapprove_proposal_not_approved() {
+	let (mut scenario, _, _, proposal_id) = approved().await;
+
+	let result = scenario.unapprove("signer_2", proposal_id).await;
+
+	assert_multisig_wallet_error(result, MultisigWalletError::NotApproved);
+}
+
+#[tokio::test]
+async fn unapprove_proposal_as_non_signer() {
+	let (mut scenario, _, _, proposal_id) = approved().await;
+	scenario.add_multisig(&["outsider"], 1).await;
+
+	let result = scenario.unapprove("outsider", proposal_id).await;
+
+	assert_multisig_wallet_error(result, MultisigWalletError::NotApproved);
+	assert_eq!(scenario.proposal_state(proposal_id).await.approval_count(), 2);
+}
+
+#[tokio::test]
+async fn unapprove_executed_proposal() {
+	let (mut scenario, mint, destination, proposal_id) = approved().await;
+	scenario.execute_transfer(proposal_id, destination, mint).await.unwrap();
+
+	let result = scenario.unapprove("signer_1", proposal_id).await;
+
+	assert_multisig_wallet_error(result, MultisigWalletError::ProposalAlreadyExecuted);
+}
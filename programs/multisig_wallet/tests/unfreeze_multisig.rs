@@ -0,0 +1,67 @@
+pub mod common;
+
+use {
+    common::{
+		error_assertions::assert_multisig_wallet_error,
+		multisig_fixture::{MultisigFixture, MultisigScenario},
+	},
+    solana_program_test::tokio,
+    solana_sdk::pubkey::Pubkey,
+};
+use multisig_wallet::error::MultisigWalletError;
+
+/// A 2-of-3 multisig frozen by its guardian
+async fn frozen() -> MultisigScenario {
+	let mut scenario = MultisigFixture::new()
+		.signers(3)
+		.threshold(2)
+		.guardian()
+		.start()
+		.await;
+	scenario.freeze("guardian").await.unwrap();
+
+	scenario
+}
+
+
+#[tokio::test]
+async fn unfreeze_multisig_ix_success() {
+	let mut scenario = frozen().await;
+
+	// INSTRUCTIONS
+	let result = scenario.unfreeze(&["signer_0", "signer_1", "signer_2"]).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+	assert!(!scenario.multisig_config().await.frozen);
+	assert!(scenario.propose_transfer("signer_0", Pubkey::new_unique(), Pubkey::new_unique(), 400).await.is_ok());
+}
+
+#[tokio::test]
+async fn unfreeze_multisig_with_threshold_of_signers() {
+	let mut scenario = frozen().await;
+
+	let result = scenario.unfreeze(&["signer_0", "signer_1"]).await;
+
+	assert_multisig_wallet_error(result, MultisigWalletError::NotAllSignersApproved);
+	assert!(scenario.multisig_config().await.frozen);
+}
+
+#[tokio::test]
+async fn unfreeze_multisig_as_guardian() {
+	let mut scenario = frozen().await;
+
+	let result = scenario.unfreeze(&["guardian"]).await;
+
+	assert_multisig_wallet_error(result, MultisigWalletError::NotAllSignersApproved);
+	assert!(scenario.multisig_config().await.frozen);
+}
+
+#[tokio::test]
+async fn unfreeze_multisig_not_frozen() {
+	let mut scenario = MultisigFixture::new().signers(2).start().await;
+
+	let result = scenario.unfreeze(&["signer_0", "signer_1"]).await;
+
+	assert_multisig_wallet_error(result, MultisigWalletError::MultisigNotFrozen);
+}
@@ -1,85 +0,0 @@
-pub mod common;
-
-use std::str::FromStr;
-use {
-    common::{
-		get_program_test,
-		multisig_wallet_ix_interface,
-		csl_spl_token_ix_interface,
-		csl_spl_assoc_token_ix_interface,
-	},
-    solana_program_test::tokio,
-    solana_sdk::{
-        account::Account, pubkey::Pubkey, rent::Rent, signature::Keypair, signer::Signer, system_program,
-    },
-};
-
-
-#[tokio::test]
-async fn update_multisig_ix_success() {
-	let mut program_test = get_program_test();
-
-	// PROGRAMS
-	program_test.prefer_bpf(true);
-
-	program_test.add_program(
-		"account_compression",
-		Pubkey::from_str("cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK").unwrap(),
-		None,
-	);
-
-	program_test.add_program(
-		"noop",
-		Pubkey::from_str("noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV").unwrap(),
-		None,
-	);
-
-	// DATA
-	let new_signers: Vec<Pubkey> = vec![Pubkey::default()];
-	let new_threshold: u8 = Default::default();
-	let all_current_signers_approved: bool = Default::default();
-
-	// KEYPAIR
-	let fee_payer_keypair = Keypair::new();
-
-	// PUBKEY
-	let fee_payer_pubkey = fee_payer_keypair.pubkey();
-
-	// PDA
-	let (multisig_pda, _multisig_pda_bump) = Pubkey::find_program_address(
-		&[
-			b"multisig",
-		],
-		&multisig_wallet::ID,
-	);
-
-	// ACCOUNT PROGRAM TEST SETUP
-	program_test.add_account(
-		fee_payer_pubkey,
-		Account {
-			lamports: 1_000_000_000_000,
-			data: vec![],
-			owner: system_program::ID,
-			executable: false,
-			rent_epoch: 0,
-		},
-	);
-
-	// INSTRUCTIONS
-	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
-
-	let ix = multisig_wallet_ix_interface::update_multisig_ix_setup(
-		&fee_payer_keypair,
-		multisig_pda,
-		new_signers,
-		new_threshold,
-		all_current_signers_approved,
-		recent_blockhash,
-	);
-
-	let result = banks_client.process_transaction(ix).await;
-
-	// ASSERTIONS
-	assert!(result.is_ok());
-
-}
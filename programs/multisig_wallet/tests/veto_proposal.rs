@@ -0,0 +1,149 @@
+pub mod common;
+
+use {
+    common::{
+		error_assertions::assert_multisig_wallet_error,
+		multisig_fixture::{MultisigFixture, MultisigScenario},
+	},
+    solana_program_test::tokio,
+    solana_sdk::pubkey::Pubkey,
+};
+use multisig_wallet::error::MultisigWalletError;
+
+const ALL_SIGNERS: [&str; 3] = ["signer_0", "signer_1", "signer_2"];
+const VETO_WINDOW: i64 = 24 * 60 * 60;
+const VAULT_BALANCE: u64 = 1_000;
+const AMOUNT: u64 = 100;
+
+struct Optimistic {
+	scenario: MultisigScenario,
+	mint: Pubkey,
+	destination: Pubkey,
+}
+
+impl Optimistic {
+	/// A 2-of-3 multisig in optimistic mode where 2 vetoes block a proposal, with a funded vault
+	async fn new() -> Self {
+		let mint = Pubkey::new_unique();
+		let mut scenario = MultisigFixture::new()
+			.signers(3)
+			.threshold(2)
+			.funded_vault(mint, VAULT_BALANCE)
+			.start()
+			.await;
+		scenario.configure_optimistic(&ALL_SIGNERS, true, VETO_WINDOW, 2).await.unwrap();
+
+		let destination = Pubkey::new_unique();
+		scenario.create_token_account(destination, mint);
+
+		Optimistic { scenario, mint, destination }
+	}
+
+	/// Proposes an optimistic transfer of `AMOUNT` to the destination
+	async fn propose(&mut self) -> u64 {
+		let (destination, mint) = (self.destination, self.mint);
+
+		self.scenario.propose_transfer("signer_0", destination, mint, AMOUNT).await.unwrap()
+	}
+
+	async fn execute(&mut self, proposal_id: u64) -> Result<(), solana_program_test::BanksClientError> {
+		let (destination, mint) = (self.destination, self.mint);
+
+		self.scenario.execute_transfer(proposal_id, destination, mint).await
+	}
+}
+
+
+#[tokio::test]
+async fn veto_proposal_ix_success() {
+	let mut optimistic = Optimistic::new().await;
+	let proposal_id = optimistic.propose().await;
+
+	// INSTRUCTIONS
+	let result = optimistic.scenario.veto("signer_1", proposal_id).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+	assert_eq!(optimistic.scenario.proposal_state(proposal_id).await.veto_count(), 1);
+
+	// One veto is below the veto threshold, so the proposal still passes after the window
+	optimistic.scenario.warp(VETO_WINDOW + 1).await;
+	let result = optimistic.execute(proposal_id).await;
+	assert!(result.is_ok());
+	assert_eq!(optimistic.scenario.token_balance(optimistic.destination).await, AMOUNT);
+}
+
+#[tokio::test]
+async fn veto_proposal_blocking_execution() {
+	let mut optimistic = Optimistic::new().await;
+	let proposal_id = optimistic.propose().await;
+	optimistic.scenario.veto("signer_1", proposal_id).await.unwrap();
+	optimistic.scenario.veto("signer_2", proposal_id).await.unwrap();
+	optimistic.scenario.warp(VETO_WINDOW + 1).await;
+
+	let result = optimistic.execute(proposal_id).await;
+
+	assert_multisig_wallet_error(result, MultisigWalletError::ProposalVetoed);
+	assert_eq!(optimistic.scenario.token_balance(optimistic.destination).await, 0);
+}
+
+#[tokio::test]
+async fn veto_proposal_before_window_elapsed() {
+	let mut optimistic = Optimistic::new().await;
+	let proposal_id = optimistic.propose().await;
+
+	let result = optimistic.execute(proposal_id).await;
+
+	assert_multisig_wallet_error(result, MultisigWalletError::VetoWindowOpen);
+}
+
+#[tokio::test]
+async fn veto_proposal_after_window_closed() {
+	let mut optimistic = Optimistic::new().await;
+	let proposal_id = optimistic.propose().await;
+	optimistic.scenario.warp(VETO_WINDOW + 1).await;
+
+	let result = optimistic.scenario.veto("signer_1", proposal_id).await;
+
+	assert_multisig_wallet_error(result, MultisigWalletError::VetoWindowClosed);
+}
+
+#[tokio::test]
+async fn veto_proposal_twice() {
+	let mut optimistic = Optimistic::new().await;
+	let proposal_id = optimistic.propose().await;
+	optimistic.scenario.veto("signer_1", proposal_id).await.unwrap();
+
+	let result = optimistic.scenario.veto("signer_1", proposal_id).await;
+
+	assert_multisig_wallet_error(result, MultisigWalletError::AlreadyVetoed);
+}
+
+#[tokio::test]
+async fn veto_proposal_as_non_signer() {
+	let mut optimistic = Optimistic::new().await;
+	let proposal_id = optimistic.propose().await;
+	optimistic.scenario.add_multisig(&["outsider"], 1).await;
+
+	let result = optimistic.scenario.veto("outsider", proposal_id).await;
+
+	assert_multisig_wallet_error(result, MultisigWalletError::NotASigner);
+}
+
+#[tokio::test]
+async fn veto_proposal_window_on_vault_with_threshold_override() {
+	let mut optimistic = Optimistic::new().await;
+	let vault_index = optimistic.scenario.vault_index;
+	optimistic.scenario.set_vault_threshold(&ALL_SIGNERS, vault_index, Some(3)).await.unwrap();
+	let proposal_id = optimistic.propose().await;
+	optimistic.scenario.approve_as(&["signer_0", "signer_1"], proposal_id).await.unwrap();
+	optimistic.scenario.warp(VETO_WINDOW + 1).await;
+
+	// An unvetoed optimistic proposal still needs the approvals the vault's override asks for
+	let result = optimistic.execute(proposal_id).await;
+	assert_multisig_wallet_error(result, MultisigWalletError::ThresholdNotMet);
+
+	optimistic.scenario.approve("signer_2", proposal_id).await.unwrap();
+	let result = optimistic.execute(proposal_id).await;
+	assert!(result.is_ok());
+}
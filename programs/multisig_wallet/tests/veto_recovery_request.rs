@@ -0,0 +1,71 @@
+pub mod common;
+
+use {
+    common::{
+		error_assertions::assert_multisig_wallet_error,
+		multisig_fixture::{MultisigFixture, MultisigScenario},
+	},
+    solana_program_test::tokio,
+    solana_sdk::{pubkey::Pubkey, signer::Signer},
+};
+use multisig_wallet::{error::MultisigWalletError, RecoveryRequest, MIN_RECOVERY_DELAY};
+
+/// A 2-of-2 multisig with an approved request from its recovery keys to replace `signer_1`
+async fn approved() -> (MultisigScenario, u64) {
+	let mut scenario = MultisigFixture::new()
+		.signers(2)
+		.start()
+		.await;
+	scenario.configure_recovery(
+		&["signer_0", "signer_1"],
+		&["recovery_0", "recovery_1"],
+		2,
+		MIN_RECOVERY_DELAY,
+	).await.unwrap();
+
+	let lost_signer = scenario.member("signer_1").pubkey();
+	let recovery_id = scenario.request_recovery("recovery_0", lost_signer, Pubkey::new_unique()).await.unwrap();
+	scenario.approve_recovery("recovery_1", recovery_id).await.unwrap();
+
+	(scenario, recovery_id)
+}
+
+
+#[tokio::test]
+async fn veto_recovery_request_ix_success() {
+	let (mut scenario, recovery_id) = approved().await;
+
+	// INSTRUCTIONS
+	let result = scenario.veto_recovery("signer_1", recovery_id).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+
+	let recovery_request: RecoveryRequest = scenario.account_state(scenario.recovery_request(recovery_id)).await;
+	assert!(recovery_request.vetoed);
+
+	scenario.warp(MIN_RECOVERY_DELAY).await;
+	let result = scenario.execute_recovery(recovery_id).await;
+	assert_multisig_wallet_error(result, MultisigWalletError::RecoveryVetoed);
+	assert!(scenario.multisig_config().await.signers.contains(&scenario.member("signer_1").pubkey()));
+}
+
+#[tokio::test]
+async fn veto_recovery_request_as_recovery_key() {
+	let (mut scenario, recovery_id) = approved().await;
+
+	let result = scenario.veto_recovery("recovery_0", recovery_id).await;
+
+	assert_multisig_wallet_error(result, MultisigWalletError::NotASigner);
+}
+
+#[tokio::test]
+async fn veto_executed_recovery_request() {
+	let (mut scenario, recovery_id) = approved().await;
+	scenario.warp(MIN_RECOVERY_DELAY).await;
+	scenario.execute_recovery(recovery_id).await.unwrap();
+
+	let result = scenario.veto_recovery("signer_0", recovery_id).await;
+
+	assert_multisig_wallet_error(result, MultisigWalletError::RecoveryAlreadyExecuted);
+}
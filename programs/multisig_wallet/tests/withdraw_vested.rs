@@ -0,0 +1,117 @@
+pub mod common;
+
+use {
+    common::{
+		error_assertions::assert_multisig_wallet_error,
+		multisig_fixture::{MultisigFixture, MultisigScenario},
+	},
+    solana_program_test::{tokio, BanksClientError},
+    solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer},
+};
+use multisig_wallet::{error::MultisigWalletError, VestingStream, VestingStreamTerms};
+
+const TOTAL_AMOUNT: u64 = 1_000;
+const CLIFF: i64 = 100;
+const DURATION: i64 = 1_000;
+
+struct Stream {
+	scenario: MultisigScenario,
+	stream_id: u64,
+	mint: Pubkey,
+	recipient: Keypair,
+	/// The recipient's token account for the mint
+	destination: Pubkey,
+}
+
+impl Stream {
+	/// A 1-of-1 multisig with a stream of `TOTAL_AMOUNT` vesting linearly over `DURATION` seconds
+	/// from now, after a cliff of `CLIFF` seconds
+	async fn new() -> Self {
+		let mint = Pubkey::new_unique();
+		let mut scenario = MultisigFixture::new()
+			.funded_vault(mint, TOTAL_AMOUNT)
+			.start()
+			.await;
+
+		let recipient = Keypair::new();
+		let destination = scenario.create_token_account(recipient.pubkey(), mint);
+
+		let now = scenario.now().await;
+		let stream_id = scenario.open_vesting_stream(VestingStreamTerms {
+			recipient: recipient.pubkey(),
+			mint,
+			total_amount: TOTAL_AMOUNT,
+			start_time: now,
+			cliff_time: now + CLIFF,
+			end_time: now + DURATION,
+		}).await.unwrap();
+
+		Stream { scenario, stream_id, mint, recipient, destination }
+	}
+
+	async fn withdraw(&mut self) -> Result<(), BanksClientError> {
+		self.scenario.withdraw_vested(&self.recipient, self.stream_id, self.mint, self.destination).await
+	}
+
+	async fn withdrawn(&mut self) -> u64 {
+		self.scenario.token_balance(self.destination).await
+	}
+}
+
+
+#[tokio::test]
+async fn withdraw_vested_ix_success() {
+	let mut stream = Stream::new().await;
+	stream.scenario.warp(DURATION / 2).await;
+
+	// INSTRUCTIONS
+	let result = stream.withdraw().await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+	assert_eq!(stream.withdrawn().await, TOTAL_AMOUNT / 2);
+
+	let escrow = stream.scenario.stream_escrow(stream.stream_id, &stream.mint);
+	assert_eq!(stream.scenario.token_balance(escrow).await, TOTAL_AMOUNT / 2);
+
+	let state: VestingStream = stream.scenario.account_state(stream.scenario.vesting_stream(stream.stream_id)).await;
+	assert_eq!(state.withdrawn_amount, TOTAL_AMOUNT / 2);
+}
+
+#[tokio::test]
+async fn withdraw_vested_before_cliff() {
+	let mut stream = Stream::new().await;
+	stream.scenario.warp(CLIFF - 1).await;
+
+	let result = stream.withdraw().await;
+
+	assert_multisig_wallet_error(result, MultisigWalletError::NothingToWithdraw);
+}
+
+#[tokio::test]
+async fn withdraw_vested_twice() {
+	let mut stream = Stream::new().await;
+	stream.scenario.warp(DURATION / 4).await;
+	stream.withdraw().await.unwrap();
+
+	// Nothing more has vested since the first withdrawal
+	let result = stream.withdraw().await;
+	assert_multisig_wallet_error(result, MultisigWalletError::NothingToWithdraw);
+
+	stream.scenario.warp(DURATION).await;
+	stream.withdraw().await.unwrap();
+	assert_eq!(stream.withdrawn().await, TOTAL_AMOUNT);
+}
+
+#[tokio::test]
+async fn withdraw_vested_as_other_wallet() {
+	let mut stream = Stream::new().await;
+	stream.scenario.warp(DURATION).await;
+	let other = Keypair::new();
+	let other_destination = stream.scenario.create_token_account(other.pubkey(), stream.mint);
+
+	let result = stream.scenario.withdraw_vested(&other, stream.stream_id, stream.mint, other_destination).await;
+
+	assert_multisig_wallet_error(result, MultisigWalletError::NotStreamRecipient);
+	assert_eq!(stream.scenario.token_balance(other_destination).await, 0);
+}